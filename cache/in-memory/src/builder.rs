@@ -2,20 +2,66 @@ use super::{
     config::{Config, ResourceType},
     InMemoryCache,
 };
+use std::{
+    collections::hash_map::RandomState,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    hash::BuildHasher,
+};
+
+/// Shard amount configuration is invalid.
+///
+/// Returned by [`InMemoryCacheBuilder::shard_amount`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ShardAmountError {
+    /// Provided shard amount is not a power of two greater than one.
+    NotPowerOfTwo {
+        /// Provided value.
+        value: usize,
+    },
+}
+
+impl Display for ShardAmountError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::NotPowerOfTwo { value } => f.write_fmt(format_args!(
+                "provided shard amount {} is not a power of two greater than one",
+                value,
+            )),
+        }
+    }
+}
+
+impl Error for ShardAmountError {}
 
 /// Builder to configure and construct an [`InMemoryCache`].
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct InMemoryCacheBuilder(Config);
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryCacheBuilder<S = RandomState>(Config, S);
 
 impl InMemoryCacheBuilder {
     /// Creates a builder to configure and construct an [`InMemoryCache`].
     pub fn new() -> Self {
         Self::default()
     }
+}
 
+impl<S: BuildHasher + Clone> InMemoryCacheBuilder<S> {
     /// Consume the builder, returning a configured cache.
-    pub fn build(self) -> InMemoryCache {
-        InMemoryCache::new_with_config(self.0)
+    pub fn build(self) -> InMemoryCache<S> {
+        InMemoryCache::new_with_config(self.0, self.1)
+    }
+
+    /// Sets the hasher used by the internal maps of the cache.
+    ///
+    /// Faster, non-cryptographic hashers such as those provided by the `ahash`
+    /// or `fxhash` crates can reduce the cost of hashing for caches holding a
+    /// large number of resources. The built cache is generic over the hasher,
+    /// so using one has no additional cost.
+    ///
+    /// Defaults to the standard library's [`RandomState`].
+    pub fn hasher<T: BuildHasher + Clone>(self, hasher: T) -> InMemoryCacheBuilder<T> {
+        InMemoryCacheBuilder(self.0, hasher)
     }
 
     /// Sets the list of resource types for the cache to handle.
@@ -35,13 +81,107 @@ impl InMemoryCacheBuilder {
 
         self
     }
+
+    /// Sets the number of shards used by each internal map.
+    ///
+    /// Each map is split into a number of independently locked shards. A
+    /// higher amount reduces lock contention when many threads update the
+    /// cache at the same time, at the cost of some memory.
+    ///
+    /// Defaults to an amount based on the number of CPUs.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ShardAmountError::NotPowerOfTwo`] if the provided amount is
+    /// not a power of two greater than one.
+    pub fn shard_amount(mut self, shard_amount: usize) -> Result<Self, ShardAmountError> {
+        if shard_amount < 2 || !shard_amount.is_power_of_two() {
+            return Err(ShardAmountError::NotPowerOfTwo {
+                value: shard_amount,
+            });
+        }
+
+        self.0.shard_amount.replace(shard_amount);
+
+        Ok(self)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::InMemoryCacheBuilder;
+    use super::{InMemoryCacheBuilder, ShardAmountError};
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{
+        error::Error,
+        fmt::Debug,
+        hash::{BuildHasherDefault, Hasher},
+    };
+    use twilight_model::{
+        gateway::payload::RoleCreate,
+        guild::{Permissions, Role},
+        id::{GuildId, RoleId},
+    };
 
     assert_impl_all!(InMemoryCacheBuilder: Clone, Debug, Default, Send, Sync);
+    assert_impl_all!(ShardAmountError: Debug, Error, Send, Sync);
+
+    #[test]
+    fn test_shard_amount() {
+        assert!(matches!(
+            InMemoryCacheBuilder::new().shard_amount(0),
+            Err(ShardAmountError::NotPowerOfTwo { value: 0 })
+        ));
+        assert!(InMemoryCacheBuilder::new().shard_amount(1).is_err());
+        assert!(InMemoryCacheBuilder::new().shard_amount(12).is_err());
+
+        let builder = InMemoryCacheBuilder::new().shard_amount(64).unwrap();
+        assert_eq!(Some(64), builder.build().config().shard_amount());
+    }
+
+    #[test]
+    fn test_hasher() {
+        #[derive(Default)]
+        struct IdentityHasher(u64);
+
+        impl Hasher for IdentityHasher {
+            fn finish(&self) -> u64 {
+                self.0
+            }
+
+            fn write(&mut self, bytes: &[u8]) {
+                for byte in bytes {
+                    self.0 = (self.0 << 8) | u64::from(*byte);
+                }
+            }
+        }
+
+        let cache = InMemoryCacheBuilder::new()
+            .message_cache_size(5)
+            .hasher(BuildHasherDefault::<IdentityHasher>::default())
+            .build();
+        assert_eq!(5, cache.config().message_cache_size());
+
+        let role = Role {
+            color: 0,
+            hoist: false,
+            id: RoleId::new(2),
+            managed: false,
+            mentionable: false,
+            name: "role".to_owned(),
+            permissions: Permissions::empty(),
+            position: 0,
+            tags: None,
+        };
+        cache.update(&RoleCreate {
+            guild_id: GuildId::new(1),
+            role: role.clone(),
+        });
+
+        assert_eq!(role, *cache.role(RoleId::new(2)).unwrap());
+        assert!(cache
+            .guild_roles(GuildId::new(1))
+            .unwrap()
+            .contains(&RoleId::new(2)));
+        assert!(cache.role(RoleId::new(3)).is_none());
+    }
 }
@@ -0,0 +1,250 @@
+//! Notifications of changes applied to the cache.
+//!
+//! Subscribe to changes with [`InMemoryCache::subscribe`].
+//!
+//! [`InMemoryCache::subscribe`]: crate::InMemoryCache::subscribe
+
+use crate::model::{CachedGuild, CachedMember};
+use futures_channel::mpsc::{self, Receiver, Sender};
+use futures_util::stream::{Stream, StreamExt};
+use std::{
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+use twilight_model::{
+    channel::GuildChannel,
+    guild::Role,
+    id::{ChannelId, GuildId, RoleId, UserId},
+};
+
+/// Change applied to the cache while processing an event.
+///
+/// Resources removed along with their guild, such as when the current user
+/// leaves a guild, aren't notified individually; only a
+/// [`GuildRemoved`] change is emitted.
+///
+/// [`GuildRemoved`]: Self::GuildRemoved
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum CacheChange {
+    /// Guild channel was cached for the first time.
+    ChannelCreated {
+        /// Newly cached channel.
+        channel: Arc<GuildChannel>,
+    },
+    /// Guild channel was removed from the cache.
+    ChannelRemoved {
+        /// ID of the channel's guild.
+        guild: GuildId,
+        /// ID of the removed channel.
+        channel: ChannelId,
+    },
+    /// Cached guild channel was replaced with a newer version.
+    ChannelUpdated {
+        /// Previously cached version of the channel.
+        old: Arc<GuildChannel>,
+        /// Currently cached version of the channel.
+        new: Arc<GuildChannel>,
+    },
+    /// Guild was cached for the first time.
+    GuildCreated {
+        /// Newly cached guild.
+        guild: Arc<CachedGuild>,
+    },
+    /// Guild was removed from the cache, either because the current user
+    /// left it or because it became unavailable.
+    GuildRemoved {
+        /// ID of the removed guild.
+        guild: GuildId,
+    },
+    /// Cached guild was replaced with a newer version.
+    GuildUpdated {
+        /// Previously cached version of the guild.
+        old: Arc<CachedGuild>,
+        /// Currently cached version of the guild.
+        new: Arc<CachedGuild>,
+    },
+    /// Member was cached for the first time.
+    MemberAdded {
+        /// Newly cached member.
+        member: Arc<CachedMember>,
+    },
+    /// Member was removed from the cache.
+    MemberRemoved {
+        /// ID of the member's guild.
+        guild: GuildId,
+        /// ID of the member's user.
+        user: UserId,
+    },
+    /// Cached member was replaced with a newer version.
+    MemberUpdated {
+        /// Previously cached version of the member.
+        old: Arc<CachedMember>,
+        /// Currently cached version of the member.
+        new: Arc<CachedMember>,
+    },
+    /// Role was cached for the first time.
+    RoleCreated {
+        /// ID of the role's guild.
+        guild: GuildId,
+        /// Newly cached role.
+        role: Arc<Role>,
+    },
+    /// Role was removed from the cache.
+    RoleRemoved {
+        /// ID of the role's guild.
+        guild: GuildId,
+        /// ID of the removed role.
+        role: RoleId,
+    },
+    /// Cached role was replaced with a newer version.
+    RoleUpdated {
+        /// ID of the role's guild.
+        guild: GuildId,
+        /// Previously cached version of the role.
+        old: Arc<Role>,
+        /// Currently cached version of the role.
+        new: Arc<Role>,
+    },
+}
+
+/// Number of changes buffered for each subscriber before further changes are
+/// dropped.
+pub(crate) const BUFFER: usize = 1024;
+
+/// Create a bounded channel of changes for a subscriber.
+pub(crate) fn channel(capacity: usize) -> (ChangeSender, CacheChanges) {
+    // The sender is guaranteed a slot in addition to the buffer.
+    let (tx, rx) = mpsc::channel(capacity.saturating_sub(1));
+    let dropped = Arc::new(AtomicU64::new(0));
+
+    let sender = ChangeSender {
+        dropped: Arc::clone(&dropped),
+        tx,
+    };
+
+    (sender, CacheChanges { dropped, rx })
+}
+
+/// Sending half of a [`CacheChanges`] stream.
+#[derive(Debug)]
+pub(crate) struct ChangeSender {
+    dropped: Arc<AtomicU64>,
+    tx: Sender<CacheChange>,
+}
+
+impl ChangeSender {
+    /// Send a change, dropping it if the subscriber's buffer is full.
+    ///
+    /// Returns whether the subscriber is still subscribed.
+    pub(crate) fn send(&mut self, change: CacheChange) -> bool {
+        match self.tx.try_send(change) {
+            Ok(()) => true,
+            Err(source) if source.is_full() => {
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+
+                true
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+/// Stream of changes applied to the cache, returned by
+/// [`InMemoryCache::subscribe`].
+///
+/// The stream ends once the cache has been dropped. Dropping the stream
+/// unsubscribes from the cache.
+///
+/// # Lagging
+///
+/// Up to 1024 changes are buffered for the stream. If the stream isn't polled
+/// quickly enough to keep up with the cache then changes are dropped rather
+/// than buffered without bound, and counted in [`dropped`]. A subscriber that
+/// lags behind should re-read the state it needs from the cache.
+///
+/// [`InMemoryCache::subscribe`]: crate::InMemoryCache::subscribe
+/// [`dropped`]: Self::dropped
+#[derive(Debug)]
+#[must_use = "streams do nothing unless you poll them"]
+pub struct CacheChanges {
+    dropped: Arc<AtomicU64>,
+    rx: Receiver<CacheChange>,
+}
+
+impl CacheChanges {
+    /// Number of changes that were dropped because the stream's buffer was
+    /// full.
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+impl Stream for CacheChanges {
+    type Item = CacheChange;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_next_unpin(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CacheChange, CacheChanges};
+    use futures_util::{
+        future::FutureExt,
+        stream::{Stream, StreamExt},
+    };
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::fmt::Debug;
+    use twilight_model::id::{GuildId, UserId};
+
+    assert_fields!(CacheChange::ChannelCreated: channel);
+    assert_fields!(CacheChange::ChannelRemoved: guild, channel);
+    assert_fields!(CacheChange::ChannelUpdated: old, new);
+    assert_fields!(CacheChange::GuildCreated: guild);
+    assert_fields!(CacheChange::GuildRemoved: guild);
+    assert_fields!(CacheChange::GuildUpdated: old, new);
+    assert_fields!(CacheChange::MemberAdded: member);
+    assert_fields!(CacheChange::MemberRemoved: guild, user);
+    assert_fields!(CacheChange::MemberUpdated: old, new);
+    assert_fields!(CacheChange::RoleCreated: guild, role);
+    assert_fields!(CacheChange::RoleRemoved: guild, role);
+    assert_fields!(CacheChange::RoleUpdated: guild, old, new);
+    assert_impl_all!(CacheChange: Clone, Debug, PartialEq, Send, Sync);
+    assert_impl_all!(CacheChanges: Debug, Send, Stream, Unpin);
+
+    #[test]
+    fn test_channel_drops_when_full() {
+        let (mut tx, mut rx) = super::channel(2);
+
+        for user in 1..=3 {
+            assert!(tx.send(CacheChange::MemberRemoved {
+                guild: GuildId::new(1),
+                user: UserId::new(user),
+            }));
+        }
+
+        assert_eq!(1, rx.dropped());
+
+        for user in 1..=2 {
+            assert_eq!(
+                Some(Some(CacheChange::MemberRemoved {
+                    guild: GuildId::new(1),
+                    user: UserId::new(user),
+                })),
+                rx.next().now_or_never(),
+            );
+        }
+        assert!(rx.next().now_or_never().is_none());
+
+        drop(rx);
+        assert!(!tx.send(CacheChange::GuildRemoved {
+            guild: GuildId::new(1),
+        }));
+    }
+}
@@ -19,6 +19,7 @@ bitflags! {
         const USER_CURRENT = 1 << 8;
         const USER = 1 << 9;
         const VOICE_STATE = 1 << 10;
+        const STICKER = 1 << 11;
     }
 }
 
@@ -29,6 +30,7 @@ bitflags! {
 pub struct Config {
     pub(super) resource_types: ResourceType,
     pub(super) message_cache_size: usize,
+    pub(super) shard_amount: Option<usize>,
 }
 
 impl Config {
@@ -50,6 +52,14 @@ impl Config {
     pub fn resource_types_mut(&mut self) -> &mut ResourceType {
         &mut self.resource_types
     }
+
+    /// Returns the number of shards used by each internal map.
+    ///
+    /// `None` means the default amount chosen by the map implementation, which
+    /// is based on the number of CPUs.
+    pub fn shard_amount(&self) -> Option<usize> {
+        self.shard_amount
+    }
 }
 
 impl Default for Config {
@@ -57,6 +67,7 @@ impl Default for Config {
         Self {
             resource_types: ResourceType::all(),
             message_cache_size: 100,
+            shard_amount: None,
         }
     }
 }
@@ -79,6 +90,7 @@ mod tests {
         assert_eq!(1 << 8, ResourceType::USER_CURRENT.bits());
         assert_eq!(1 << 9, ResourceType::USER.bits());
         assert_eq!(1 << 10, ResourceType::VOICE_STATE.bits());
+        assert_eq!(1 << 11, ResourceType::STICKER.bits());
     }
 
     #[test]
@@ -86,14 +98,20 @@ mod tests {
         let conf = Config {
             resource_types: ResourceType::all(),
             message_cache_size: 100,
+            shard_amount: None,
         };
         let default = Config::default();
         assert_eq!(conf.resource_types, default.resource_types);
         assert_eq!(conf.message_cache_size, default.message_cache_size);
+        assert_eq!(conf.shard_amount, default.shard_amount);
     }
 
     #[test]
     fn test_config_fields() {
-        static_assertions::assert_fields!(Config: resource_types, message_cache_size);
+        static_assertions::assert_fields!(
+            Config: resource_types,
+            message_cache_size,
+            shard_amount
+        );
     }
 }
@@ -0,0 +1,465 @@
+//! Populate a cache from the REST API.
+//!
+//! Large bots may take a long time to receive all of their `GUILD_CREATE`
+//! events after connecting to the gateway. [`Hydrate`] fetches the current
+//! user, its guilds, and their channels, roles, emojis, and optionally members
+//! over HTTP so that the cache can be used before the gateway has caught up.
+//!
+//! Events received afterwards will update the hydrated resources as usual.
+
+use crate::{config::ResourceType, InMemoryCache};
+use std::{
+    collections::hash_map::RandomState,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    hash::BuildHasher,
+};
+use twilight_http::{Client, Error as HttpError};
+use twilight_model::{
+    channel::GuildChannel,
+    guild::{Guild, Member},
+    id::{GuildId, UserId},
+};
+
+/// Maximum number of guilds that can be retrieved in a single request.
+const GUILDS_PER_REQUEST: u64 = 100;
+
+/// Maximum number of members that can be retrieved in a single request.
+const MEMBERS_PER_REQUEST: u64 = 1000;
+
+/// Hydrating the cache failed.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum HydrateError {
+    /// Sending an HTTP request failed.
+    Request {
+        /// Reason for the error.
+        source: HttpError,
+    },
+}
+
+impl Display for HydrateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Request { .. } => f.write_str("sending an http request failed"),
+        }
+    }
+}
+
+impl Error for HydrateError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Request { source } => Some(source),
+        }
+    }
+}
+
+impl From<HttpError> for HydrateError {
+    fn from(source: HttpError) -> Self {
+        Self::Request { source }
+    }
+}
+
+/// Populate an [`InMemoryCache`] from the REST API.
+///
+/// Only the resource types enabled on the cache will be hydrated.
+///
+/// # Examples
+///
+/// Hydrate a cache, including guild members, before starting a shard:
+///
+/// ```rust,no_run
+/// use std::env;
+/// use twilight_cache_inmemory::{hydrate::Hydrate, InMemoryCache};
+/// use twilight_http::Client;
+///
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let http = Client::new(env::var("DISCORD_TOKEN")?);
+/// let cache = InMemoryCache::new();
+///
+/// Hydrate::new(&cache, &http).members(true).await_all().await?;
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub struct Hydrate<'a, S = RandomState> {
+    cache: &'a InMemoryCache<S>,
+    http: &'a Client,
+    members: bool,
+}
+
+impl<'a, S: BuildHasher + Clone> Hydrate<'a, S> {
+    /// Create a new hydrator for a cache using an HTTP client.
+    ///
+    /// Members are not hydrated by default.
+    pub fn new(cache: &'a InMemoryCache<S>, http: &'a Client) -> Self {
+        Self {
+            cache,
+            http,
+            members: false,
+        }
+    }
+
+    /// Set whether to hydrate the members of every guild.
+    ///
+    /// This requires the [`GUILD_MEMBERS`] privileged intent to be enabled for
+    /// the application, and performs one request per 1000 members in a guild.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [`GUILD_MEMBERS`]: ::twilight_model::gateway::Intents::GUILD_MEMBERS
+    pub fn members(mut self, members: bool) -> Self {
+        self.members = members;
+
+        self
+    }
+
+    /// Fetch and cache all of the configured resources.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HydrateError::Request`] if one of the HTTP requests failed.
+    /// Resources cached before the failure remain in the cache.
+    pub async fn await_all(self) -> Result<(), HydrateError> {
+        if self.cache.wants(ResourceType::USER_CURRENT) {
+            let current_user = self.http.current_user().await?;
+            self.cache.cache_current_user(current_user);
+        }
+
+        if !self.cache.wants(ResourceType::GUILD) {
+            return Ok(());
+        }
+
+        for guild_id in self.guild_ids().await? {
+            self.guild(guild_id).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn guild_ids(&self) -> Result<Vec<GuildId>, HydrateError> {
+        let mut guild_ids = Vec::new();
+        let mut after = None;
+
+        loop {
+            let mut request = self
+                .http
+                .current_user_guilds()
+                .limit(GUILDS_PER_REQUEST)
+                .expect("guild limit is valid");
+
+            if let Some(after) = after {
+                request = request.after(after);
+            }
+
+            let guilds = request.await?;
+            let len = guilds.len();
+            guild_ids.extend(guilds.into_iter().map(|guild| guild.id));
+            after = guild_ids.last().copied();
+
+            if (len as u64) < GUILDS_PER_REQUEST {
+                return Ok(guild_ids);
+            }
+        }
+    }
+
+    async fn guild(&self, guild_id: GuildId) -> Result<(), HydrateError> {
+        let guild = match self.http.guild(guild_id).await? {
+            Some(guild) => guild,
+            None => return Ok(()),
+        };
+
+        let channels = if self.cache.wants(ResourceType::CHANNEL) {
+            self.http.guild_channels(guild_id).await?
+        } else {
+            Vec::new()
+        };
+
+        self.cache_guild(guild, channels);
+
+        if self.members && self.cache.wants(ResourceType::MEMBER) {
+            self.guild_members(guild_id).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn guild_members(&self, guild_id: GuildId) -> Result<(), HydrateError> {
+        let mut after = None;
+
+        loop {
+            let mut request = self
+                .http
+                .guild_members(guild_id)
+                .limit(MEMBERS_PER_REQUEST)
+                .expect("member limit is valid");
+
+            if let Some(after) = after {
+                request = request.after(after);
+            }
+
+            let members = request.await?;
+
+            after = match self.cache_member_page(guild_id, members) {
+                Some(after) => Some(after),
+                None => return Ok(()),
+            };
+        }
+    }
+
+    /// Cache a guild and its channels as returned by the REST API.
+    fn cache_guild(&self, guild: Guild, channels: Vec<GuildChannel>) {
+        let guild_id = guild.id;
+
+        self.cache.cache_guild(guild);
+
+        if self.cache.wants(ResourceType::CHANNEL) {
+            self.cache.cache_guild_channels(guild_id, channels);
+        }
+    }
+
+    /// Cache a page of a guild's members, returning the ID of the user to
+    /// request the next page after if this page was full.
+    fn cache_member_page(&self, guild_id: GuildId, members: Vec<Member>) -> Option<UserId> {
+        let after = if members.len() as u64 == MEMBERS_PER_REQUEST {
+            members.last().map(|member| member.user.id)
+        } else {
+            None
+        };
+
+        self.cache.cache_members(guild_id, members);
+
+        after
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Hydrate, HydrateError, MEMBERS_PER_REQUEST};
+    use crate::{config::ResourceType, InMemoryCache};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+    use twilight_http::Client;
+    use twilight_model::{
+        channel::{ChannelType, GuildChannel, TextChannel},
+        guild::{
+            DefaultMessageNotificationLevel, Emoji, ExplicitContentFilter, Guild, Member, MfaLevel,
+            Permissions, PremiumTier, Role, SystemChannelFlags, VerificationLevel,
+        },
+        id::{ChannelId, EmojiId, GuildId, RoleId, UserId},
+        user::User,
+        util::Locale,
+    };
+
+    assert_impl_all!(Hydrate<'static>: Debug);
+    assert_impl_all!(HydrateError: Debug, Error);
+
+    const GUILD_ID: GuildId = GuildId::new(1);
+
+    fn channel(id: ChannelId) -> GuildChannel {
+        GuildChannel::Text(TextChannel {
+            available_tags: Vec::new(),
+            default_forum_layout: None,
+            default_reaction_emoji: None,
+            default_sort_order: None,
+            id,
+            guild_id: None,
+            kind: ChannelType::GuildText,
+            last_message_id: None,
+            last_pin_timestamp: None,
+            name: "general".to_owned(),
+            nsfw: false,
+            permission_overwrites: Vec::new(),
+            parent_id: None,
+            position: 0,
+            rate_limit_per_user: None,
+            topic: None,
+        })
+    }
+
+    /// Guild as returned by the REST API, which doesn't include its channels,
+    /// members, presences, or voice states.
+    fn guild() -> Guild {
+        Guild {
+            id: GUILD_ID,
+            afk_channel_id: None,
+            afk_timeout: 300,
+            application_id: None,
+            banner: None,
+            channels: Vec::new(),
+            default_message_notifications: DefaultMessageNotificationLevel::Mentions,
+            description: None,
+            discovery_splash: None,
+            emojis: vec![Emoji {
+                animated: false,
+                available: true,
+                id: EmojiId::new(3),
+                managed: false,
+                name: "emoji".to_owned(),
+                require_colons: true,
+                roles: Vec::new(),
+                user: None,
+            }],
+            explicit_content_filter: ExplicitContentFilter::AllMembers,
+            features: Vec::new(),
+            icon: None,
+            joined_at: None,
+            large: false,
+            lazy: None,
+            max_members: Some(50),
+            max_presences: None,
+            member_count: None,
+            members: Vec::new(),
+            mfa_level: MfaLevel::Elevated,
+            name: "guild".to_owned(),
+            owner: None,
+            owner_id: UserId::new(2),
+            permissions: None,
+            preferred_locale: Locale::EnglishUs,
+            premium_subscription_count: Some(0),
+            premium_tier: PremiumTier::None,
+            presences: Vec::new(),
+            region: "us-east".to_owned(),
+            roles: vec![Role {
+                color: 0,
+                hoist: false,
+                id: RoleId::new(4),
+                managed: false,
+                mentionable: false,
+                name: "role".to_owned(),
+                permissions: Permissions::empty(),
+                position: 0,
+                tags: None,
+            }],
+            splash: None,
+            stickers: Vec::new(),
+            system_channel_id: None,
+            system_channel_flags: SystemChannelFlags::empty(),
+            rules_channel_id: None,
+            unavailable: false,
+            verification_level: VerificationLevel::VeryHigh,
+            voice_states: Vec::new(),
+            vanity_url_code: None,
+            widget_channel_id: None,
+            widget_enabled: None,
+            max_video_channel_users: None,
+            approximate_member_count: None,
+            approximate_presence_count: None,
+        }
+    }
+
+    fn member(id: UserId) -> Member {
+        Member {
+            deaf: false,
+            guild_id: GUILD_ID,
+            hoisted_role: None,
+            joined_at: None,
+            mute: false,
+            nick: Some("nick".to_owned()),
+            premium_since: None,
+            roles: Vec::new(),
+            user: User {
+                avatar: None,
+                bot: false,
+                discriminator: "0001".to_owned(),
+                email: None,
+                flags: None,
+                id,
+                locale: None,
+                mfa_enabled: None,
+                name: "user".to_owned(),
+                premium_type: None,
+                public_flags: None,
+                system: None,
+                verified: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_cache_guild() {
+        let cache = InMemoryCache::new();
+        let http = Client::new("token");
+        let hydrate = Hydrate::new(&cache, &http);
+
+        hydrate.cache_guild(guild(), vec![channel(ChannelId::new(5))]);
+
+        assert_eq!("guild", cache.guild(GUILD_ID).unwrap().name);
+        assert_eq!("role", cache.role(RoleId::new(4)).unwrap().name);
+        assert_eq!("emoji", cache.emoji(EmojiId::new(3)).unwrap().name);
+        assert!(cache
+            .guild_emojis(GUILD_ID)
+            .unwrap()
+            .contains(&EmojiId::new(3)));
+
+        // The channels are fetched separately from the guild, but are still
+        // attached to it.
+        assert!(cache
+            .guild_channels(GUILD_ID)
+            .unwrap()
+            .contains(&ChannelId::new(5)));
+
+        match *cache.guild_channel(ChannelId::new(5)).unwrap() {
+            GuildChannel::Text(ref channel) => assert_eq!(Some(GUILD_ID), channel.guild_id),
+            ref other => panic!("unexpected channel: {:?}", other),
+        }
+
+        // Members aren't part of the guild response.
+        assert!(cache.guild_members(GUILD_ID).unwrap().is_empty());
+        assert!(cache.member(GUILD_ID, UserId::new(2)).is_none());
+    }
+
+    #[test]
+    fn test_cache_guild_resource_types() {
+        let cache = InMemoryCache::builder()
+            .resource_types(ResourceType::GUILD)
+            .build();
+        let http = Client::new("token");
+        let hydrate = Hydrate::new(&cache, &http);
+
+        hydrate.cache_guild(guild(), vec![channel(ChannelId::new(5))]);
+
+        assert!(cache.guild(GUILD_ID).is_some());
+        assert!(cache.guild_channels(GUILD_ID).is_none());
+        assert!(cache.guild_channel(ChannelId::new(5)).is_none());
+        assert!(cache.guild_emojis(GUILD_ID).is_none());
+        assert!(cache.emoji(EmojiId::new(3)).is_none());
+        assert!(cache.guild_roles(GUILD_ID).is_none());
+        assert!(cache.role(RoleId::new(4)).is_none());
+    }
+
+    #[test]
+    fn test_cache_member_page() {
+        let cache = InMemoryCache::new();
+        let http = Client::new("token");
+        let hydrate = Hydrate::new(&cache, &http);
+        hydrate.cache_guild(guild(), Vec::new());
+
+        let members = vec![member(UserId::new(6)), member(UserId::new(7))];
+        assert!(hydrate.cache_member_page(GUILD_ID, members).is_none());
+
+        let cached = cache.member(GUILD_ID, UserId::new(6)).unwrap();
+        assert_eq!(Some("nick"), cached.nick.as_deref());
+        assert_eq!("user", cache.user(UserId::new(7)).unwrap().name);
+        assert_eq!(2, cache.guild_members(GUILD_ID).unwrap().len());
+        assert!(cache.member(GUILD_ID, UserId::new(8)).is_none());
+        assert!(cache.user(UserId::new(8)).is_none());
+    }
+
+    #[test]
+    fn test_cache_member_page_full() {
+        let cache = InMemoryCache::new();
+        let http = Client::new("token");
+        let hydrate = Hydrate::new(&cache, &http);
+        hydrate.cache_guild(guild(), Vec::new());
+
+        let members = (1..=MEMBERS_PER_REQUEST)
+            .map(|id| member(UserId::new(id)))
+            .collect();
+
+        assert_eq!(
+            Some(UserId::new(MEMBERS_PER_REQUEST)),
+            hydrate.cache_member_page(GUILD_ID, members),
+        );
+        assert_eq!(1000, cache.guild_members(GUILD_ID).unwrap().len(),);
+    }
+}
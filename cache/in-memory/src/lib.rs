@@ -33,6 +33,23 @@
 //! # Ok(()) }
 //! ```
 //!
+//! ## Features
+//!
+//! ### `metrics`
+//!
+//! The `metrics` feature provides metrics information via the `metrics` crate.
+//! Counters are recorded for every insertion, update, and eviction of cached
+//! resources, labelled by the type of resource. Gauges of the number of cached
+//! items of each type can be recorded periodically via
+//! `InMemoryCacheStats::record`.
+//!
+//! This is disabled by default.
+//!
+//! ### `twilight-http`
+//!
+//! The `twilight-http` feature enables the `hydrate` module, which can
+//! populate the cache from the REST API at startup.
+//!
 //! ## License
 //!
 //! All first-party crates are licensed under [ISC][LICENSE.md]
@@ -51,27 +68,43 @@
 
 pub mod model;
 
+#[cfg(feature = "twilight-http")]
+pub mod hydrate;
+
 mod builder;
+mod change;
 mod config;
+mod stats;
 mod updates;
 
 pub use self::{
-    builder::InMemoryCacheBuilder,
+    builder::{InMemoryCacheBuilder, ShardAmountError},
+    change::{CacheChange, CacheChanges},
     config::{Config, ResourceType},
+    stats::InMemoryCacheStats,
     updates::UpdateCache,
 };
 
-use self::model::*;
+use self::{change::ChangeSender, model::*};
 use dashmap::{mapref::entry::Entry, DashMap, DashSet};
 use std::{
     borrow::Cow,
-    collections::{BTreeMap, BTreeSet, HashSet},
-    hash::Hash,
-    sync::{Arc, Mutex},
+    collections::{hash_map::RandomState, BTreeMap, HashSet},
+    hash::{BuildHasher, Hash},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
 };
 use twilight_model::{
-    channel::{Group, GuildChannel, PrivateChannel},
-    gateway::presence::{Presence, UserOrId},
+    channel::{
+        message::sticker::{Sticker, StickerId},
+        Group, GuildChannel, PrivateChannel,
+    },
+    gateway::{
+        payload::VoiceServerUpdate,
+        presence::{Presence, UserOrId},
+    },
     guild::{Emoji, Guild, Member, PartialMember, Role},
     id::{ChannelId, EmojiId, GuildId, MessageId, RoleId, UserId},
     user::{CurrentUser, User},
@@ -84,8 +117,9 @@ struct GuildItem<T> {
     guild_id: GuildId,
 }
 
-fn upsert_guild_item<K: Eq + Hash, V: PartialEq>(
-    map: &DashMap<K, GuildItem<V>>,
+fn upsert_guild_item<K: Eq + Hash, V: PartialEq, S: BuildHasher + Clone>(
+    map: &DashMap<K, GuildItem<V>, S>,
+    resource: &'static str,
     guild_id: GuildId,
     k: K,
     v: V,
@@ -98,66 +132,109 @@ fn upsert_guild_item<K: Eq + Hash, V: PartialEq>(
                 data: Arc::clone(&v),
                 guild_id,
             });
+            stats::update(resource);
 
             v
         }
-        Entry::Vacant(e) => Arc::clone(
-            &e.insert(GuildItem {
-                data: Arc::new(v),
-                guild_id,
-            })
-            .data,
-        ),
+        Entry::Vacant(e) => {
+            stats::insert(resource);
+
+            Arc::clone(
+                &e.insert(GuildItem {
+                    data: Arc::new(v),
+                    guild_id,
+                })
+                .data,
+            )
+        }
     }
 }
 
-fn upsert_item<K: Eq + Hash, V: PartialEq>(map: &DashMap<K, Arc<V>>, k: K, v: V) -> Arc<V> {
+fn upsert_item<K: Eq + Hash, V: PartialEq, S: BuildHasher + Clone>(
+    map: &DashMap<K, Arc<V>, S>,
+    resource: &'static str,
+    k: K,
+    v: V,
+) -> Arc<V> {
     match map.entry(k) {
         Entry::Occupied(e) if **e.get() == v => Arc::clone(e.get()),
         Entry::Occupied(mut e) => {
             let v = Arc::new(v);
             e.insert(Arc::clone(&v));
+            stats::update(resource);
 
             v
         }
         Entry::Vacant(e) => {
             let v = Arc::new(v);
             e.insert(Arc::clone(&v));
+            stats::insert(resource);
 
             v
         }
     }
 }
 
-// When adding a field here, be sure to add it to `InMemoryCache::clear` if
-// necessary.
+/// Create a map using the hasher and configured shard amount.
+fn new_map<K: Eq + Hash, V, S: BuildHasher + Clone>(
+    config: &Config,
+    hasher: &S,
+) -> DashMap<K, V, S> {
+    let hasher = hasher.clone();
+
+    match config.shard_amount() {
+        Some(shard_amount) => {
+            DashMap::with_capacity_and_hasher_and_shard_amount(0, hasher, shard_amount)
+        }
+        None => DashMap::with_hasher(hasher),
+    }
+}
+
+// When adding a field here, be sure to add it to
+// `InMemoryCache::new_with_config` and, if necessary, `InMemoryCache::clear`.
 #[derive(Debug, Default)]
-struct InMemoryCacheRef {
+struct InMemoryCacheRef<S> {
     config: Arc<Config>,
-    channels_guild: DashMap<ChannelId, GuildItem<GuildChannel>>,
-    channels_private: DashMap<ChannelId, Arc<PrivateChannel>>,
+    channels_guild: DashMap<ChannelId, GuildItem<GuildChannel>, S>,
+    channels_private: DashMap<ChannelId, Arc<PrivateChannel>, S>,
     // So long as the lock isn't held across await or panic points this is fine.
     current_user: Mutex<Option<Arc<CurrentUser>>>,
-    emojis: DashMap<EmojiId, GuildItem<CachedEmoji>>,
-    groups: DashMap<ChannelId, Arc<Group>>,
-    guilds: DashMap<GuildId, Arc<CachedGuild>>,
-    guild_channels: DashMap<GuildId, HashSet<ChannelId>>,
-    guild_emojis: DashMap<GuildId, HashSet<EmojiId>>,
-    guild_members: DashMap<GuildId, HashSet<UserId>>,
-    guild_presences: DashMap<GuildId, HashSet<UserId>>,
-    guild_roles: DashMap<GuildId, HashSet<RoleId>>,
-    members: DashMap<(GuildId, UserId), Arc<CachedMember>>,
-    messages: DashMap<ChannelId, BTreeMap<MessageId, Arc<CachedMessage>>>,
-    presences: DashMap<(GuildId, UserId), Arc<CachedPresence>>,
-    roles: DashMap<RoleId, GuildItem<Role>>,
-    unavailable_guilds: DashSet<GuildId>,
-    users: DashMap<UserId, (Arc<User>, BTreeSet<GuildId>)>,
+    emojis: DashMap<EmojiId, GuildItem<CachedEmoji>, S>,
+    groups: DashMap<ChannelId, Arc<Group>, S>,
+    guilds: DashMap<GuildId, Arc<CachedGuild>, S>,
+    guild_channels: DashMap<GuildId, Arc<HashSet<ChannelId>>, S>,
+    guild_emojis: DashMap<GuildId, Arc<HashSet<EmojiId>>, S>,
+    guild_members: DashMap<GuildId, Arc<HashSet<UserId>>, S>,
+    guild_presences: DashMap<GuildId, Arc<HashSet<UserId>>, S>,
+    guild_roles: DashMap<GuildId, Arc<HashSet<RoleId>>, S>,
+    guild_stickers: DashMap<GuildId, Arc<HashSet<StickerId>>, S>,
+    members: DashMap<(GuildId, UserId), Arc<CachedMember>, S>,
+    messages: DashMap<ChannelId, BTreeMap<MessageId, Arc<CachedMessage>>, S>,
+    presences: DashMap<(GuildId, UserId), Arc<CachedPresence>, S>,
+    roles: DashMap<RoleId, GuildItem<Role>, S>,
+    stickers: DashMap<StickerId, GuildItem<CachedSticker>, S>,
+    /// Whether there may be subscribers to changes, checked before locking
+    /// [`subscribers`].
+    ///
+    /// [`subscribers`]: Self::subscribers
+    subscribed: AtomicBool,
+    /// Senders of the streams returned by [`InMemoryCache::subscribe`].
+    subscribers: Mutex<Vec<ChangeSender>>,
+    unavailable_guilds: DashSet<GuildId, S>,
+    /// Mapping of users and the number of cached resources referencing them.
+    ///
+    /// Cached members, messages, emojis, and stickers refer to users by ID,
+    /// so each user is stored only once. A user is removed once the last
+    /// resource referencing it is removed.
+    users: DashMap<UserId, (Arc<User>, usize), S>,
+    /// Mapping of guilds and the last voice server update received for them.
+    voice_servers: DashMap<GuildId, Arc<VoiceServerUpdate>, S>,
     /// Mapping of channels and the users currently connected.
-    voice_state_channels: DashMap<ChannelId, HashSet<(GuildId, UserId)>>,
+    voice_state_channels: DashMap<ChannelId, HashSet<(GuildId, UserId)>, S>,
     /// Mapping of guilds and users currently connected to its voice channels.
-    voice_state_guilds: DashMap<GuildId, HashSet<UserId>>,
+    voice_state_guilds: DashMap<GuildId, HashSet<UserId>, S>,
     /// Mapping of guild ID and user ID pairs to their voice states.
-    voice_states: DashMap<(GuildId, UserId), Arc<VoiceState>>,
+    voice_states: DashMap<(GuildId, UserId), Arc<VoiceState>, S>,
 }
 
 /// A thread-safe, in-memory-process cache of Discord data. It can be cloned and
@@ -183,6 +260,11 @@ struct InMemoryCacheRef {
 /// reference to that user is held but the cache updates the user, the reference
 /// held by you will be outdated, but still exist.
 ///
+/// The same applies to the sets of IDs of a guild's resources, such as those
+/// returned by [`guild_channels`] and [`guild_roles`]: they are shared with the
+/// cache and only copied when the cache updates them while a reference is
+/// still held.
+///
 /// The intended use is that data is held outside the cache for only as long
 /// as necessary, where the state of the value at that point time doesn't need
 /// to be up-to-date. If you need to ensure you always have the most up-to-date
@@ -197,9 +279,18 @@ struct InMemoryCacheRef {
 /// operation. If you need the guild to always be up-to-date between operations,
 /// then the intent is that you keep getting it from the cache.
 ///
+/// # Hashing
+///
+/// The internal maps use the standard library's [`RandomState`] by default.
+/// A different hasher, such as a faster non-cryptographic one, can be set via
+/// [`InMemoryCacheBuilder::hasher`], in which case the cache is generic over
+/// it.
+///
 /// [`Intents`]: ::twilight_model::gateway::Intents
+/// [`guild_channels`]: Self::guild_channels
+/// [`guild_roles`]: Self::guild_roles
 #[derive(Clone, Debug, Default)]
-pub struct InMemoryCache(Arc<InMemoryCacheRef>);
+pub struct InMemoryCache<S = RandomState>(Arc<InMemoryCacheRef<S>>);
 
 /// Implemented methods and types for the cache.
 impl InMemoryCache {
@@ -216,20 +307,46 @@ impl InMemoryCache {
     /// let cache = InMemoryCache::builder().message_cache_size(50).build();
     /// ```
     pub fn new() -> Self {
-        Self::default()
-    }
-
-    fn new_with_config(config: Config) -> Self {
-        Self(Arc::new(InMemoryCacheRef {
-            config: Arc::new(config),
-            ..Default::default()
-        }))
+        Self::new_with_config(Config::default(), RandomState::new())
     }
 
     /// Create a new builder to configure and construct an in-memory cache.
     pub fn builder() -> InMemoryCacheBuilder {
         InMemoryCacheBuilder::new()
     }
+}
+
+impl<S: BuildHasher + Clone> InMemoryCache<S> {
+    fn new_with_config(config: Config, hasher: S) -> Self {
+        Self(Arc::new(InMemoryCacheRef {
+            channels_guild: new_map(&config, &hasher),
+            channels_private: new_map(&config, &hasher),
+            current_user: Mutex::new(None),
+            emojis: new_map(&config, &hasher),
+            groups: new_map(&config, &hasher),
+            guilds: new_map(&config, &hasher),
+            guild_channels: new_map(&config, &hasher),
+            guild_emojis: new_map(&config, &hasher),
+            guild_members: new_map(&config, &hasher),
+            guild_presences: new_map(&config, &hasher),
+            guild_roles: new_map(&config, &hasher),
+            guild_stickers: new_map(&config, &hasher),
+            members: new_map(&config, &hasher),
+            messages: new_map(&config, &hasher),
+            presences: new_map(&config, &hasher),
+            roles: new_map(&config, &hasher),
+            stickers: new_map(&config, &hasher),
+            subscribed: AtomicBool::new(false),
+            subscribers: Mutex::new(Vec::new()),
+            unavailable_guilds: DashSet::with_hasher(hasher.clone()),
+            users: new_map(&config, &hasher),
+            voice_servers: new_map(&config, &hasher),
+            voice_state_channels: new_map(&config, &hasher),
+            voice_state_guilds: new_map(&config, &hasher),
+            voice_states: new_map(&config, &hasher),
+            config: Arc::new(config),
+        }))
+    }
 
     /// Returns a copy of the config cache.
     pub fn config(&self) -> Config {
@@ -237,10 +354,61 @@ impl InMemoryCache {
     }
 
     /// Update the cache with an event from the gateway.
-    pub fn update(&self, value: &impl UpdateCache) {
+    pub fn update(&self, value: &impl UpdateCache<S>) {
         value.update(self);
     }
 
+    /// Count the number of items of each resource type currently cached.
+    ///
+    /// Counts are calculated when this is called rather than tracked as events
+    /// are processed, so it should be called periodically rather than after
+    /// every event. When the `metrics` feature is enabled the counts can be
+    /// recorded as gauges via `InMemoryCacheStats::record`.
+    pub fn stats(&self) -> InMemoryCacheStats {
+        InMemoryCacheStats::new(self)
+    }
+
+    /// Subscribe to changes applied to the cache as events are processed.
+    ///
+    /// Changes are only tracked while there are subscribers, so caches that
+    /// are never subscribed to don't pay for them. Changes made before
+    /// subscribing aren't replayed; the current state should be read from
+    /// the cache first.
+    ///
+    /// Refer to [`CacheChange`] for the tracked changes.
+    ///
+    /// Changes are buffered for each subscriber, and are dropped if the
+    /// subscriber doesn't keep up; refer to [`CacheChanges`] for details.
+    ///
+    /// # Examples
+    ///
+    /// Remove members from a search index as they're removed from the cache:
+    ///
+    /// ```rust,no_run
+    /// use futures::stream::StreamExt;
+    /// use twilight_cache_inmemory::{CacheChange, InMemoryCache};
+    ///
+    /// # #[tokio::main] async fn main() {
+    /// let cache = InMemoryCache::new();
+    /// let mut changes = cache.subscribe();
+    ///
+    /// while let Some(change) = changes.next().await {
+    ///     if let CacheChange::MemberRemoved { guild, user } = change {
+    ///         println!("removing member {} of guild {} from the index", user, guild);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn subscribe(&self) -> CacheChanges {
+        let (tx, rx) = change::channel(change::BUFFER);
+
+        let mut subscribers = self.0.subscribers.lock().expect("subscribers poisoned");
+        subscribers.push(tx);
+        self.0.subscribed.store(true, Ordering::Release);
+
+        rx
+    }
+
     /// Gets a channel by ID.
     ///
     /// This is an O(1) operation. This requires the [`GUILDS`] intent.
@@ -292,71 +460,117 @@ impl InMemoryCache {
         self.0.guilds.get(&guild_id).map(|r| Arc::clone(r.value()))
     }
 
+    /// Gets the current user's voice connection information for a guild.
+    ///
+    /// This contains the current user's voice state and the last
+    /// [`VoiceServerUpdate`] received for the guild, allowing voice clients to
+    /// recover connection information without waiting for new events.
+    ///
+    /// Returns `None` if neither a voice state nor a voice server update is
+    /// cached for the guild.
+    ///
+    /// This is an O(1) operation. This requires the [`GUILD_VOICE_STATES`]
+    /// intent and the current user to be cached.
+    ///
+    /// [`GUILD_VOICE_STATES`]: ::twilight_model::gateway::Intents::GUILD_VOICE_STATES
+    pub fn own_voice_connection(&self, guild_id: GuildId) -> Option<OwnVoiceConnection> {
+        let voice_server = self
+            .0
+            .voice_servers
+            .get(&guild_id)
+            .map(|r| Arc::clone(r.value()));
+        let voice_state = self
+            .current_user()
+            .and_then(|user| self.voice_state(user.id, guild_id));
+
+        if voice_server.is_none() && voice_state.is_none() {
+            return None;
+        }
+
+        Some(OwnVoiceConnection {
+            voice_server,
+            voice_state,
+        })
+    }
+
     /// Gets the set of channels in a guild.
     ///
-    /// This is a O(m) operation, where m is the amount of channels in the
-    /// guild. This requires the [`GUILDS`] intent.
+    /// This is an O(1) operation. This requires the [`GUILDS`] intent.
     ///
     /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
-    pub fn guild_channels(&self, guild_id: GuildId) -> Option<HashSet<ChannelId>> {
+    pub fn guild_channels(&self, guild_id: GuildId) -> Option<Arc<HashSet<ChannelId>>> {
         self.0
             .guild_channels
             .get(&guild_id)
-            .map(|r| r.value().clone())
+            .map(|r| Arc::clone(r.value()))
     }
 
     /// Gets the set of emojis in a guild.
     ///
-    /// This is a O(m) operation, where m is the amount of emojis in the guild.
-    /// This requires both the [`GUILDS`] and [`GUILD_EMOJIS`] intents.
+    /// This is an O(1) operation. This requires both the [`GUILDS`] and
+    /// [`GUILD_EMOJIS`] intents.
     ///
     /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
     /// [`GUILD_EMOJIS`]: ::twilight_model::gateway::Intents::GUILD_EMOJIS
-    pub fn guild_emojis(&self, guild_id: GuildId) -> Option<HashSet<EmojiId>> {
+    pub fn guild_emojis(&self, guild_id: GuildId) -> Option<Arc<HashSet<EmojiId>>> {
         self.0
             .guild_emojis
             .get(&guild_id)
-            .map(|r| r.value().clone())
+            .map(|r| Arc::clone(r.value()))
     }
 
     /// Gets the set of members in a guild.
     ///
     /// This list may be incomplete if not all members have been cached.
     ///
-    /// This is a O(m) operation, where m is the amount of members in the guild.
-    /// This requires the [`GUILD_MEMBERS`] intent.
+    /// This is an O(1) operation. This requires the [`GUILD_MEMBERS`] intent.
     ///
     /// [`GUILD_MEMBERS`]: ::twilight_model::gateway::Intents::GUILD_MEMBERS
-    pub fn guild_members(&self, guild_id: GuildId) -> Option<HashSet<UserId>> {
+    pub fn guild_members(&self, guild_id: GuildId) -> Option<Arc<HashSet<UserId>>> {
         self.0
             .guild_members
             .get(&guild_id)
-            .map(|r| r.value().clone())
+            .map(|r| Arc::clone(r.value()))
     }
 
     /// Gets the set of presences in a guild.
     ///
     /// This list may be incomplete if not all members have been cached.
     ///
-    /// This is a O(m) operation, where m is the amount of members in the guild.
-    /// This requires the [`GUILD_PRESENCES`] intent.
+    /// This is an O(1) operation. This requires the [`GUILD_PRESENCES`] intent.
     ///
     /// [`GUILD_PRESENCES`]: ::twilight_model::gateway::Intents::GUILD_PRESENCES
-    pub fn guild_presences(&self, guild_id: GuildId) -> Option<HashSet<UserId>> {
+    pub fn guild_presences(&self, guild_id: GuildId) -> Option<Arc<HashSet<UserId>>> {
         self.0
             .guild_presences
             .get(&guild_id)
-            .map(|r| r.value().clone())
+            .map(|r| Arc::clone(r.value()))
     }
 
     /// Gets the set of roles in a guild.
     ///
-    /// This is a O(m) operation, where m is the amount of roles in the guild.
-    /// This requires the [`GUILDS`] intent.
+    /// This is an O(1) operation. This requires the [`GUILDS`] intent.
     ///
     /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
-    pub fn guild_roles(&self, guild_id: GuildId) -> Option<HashSet<RoleId>> {
-        self.0.guild_roles.get(&guild_id).map(|r| r.value().clone())
+    pub fn guild_roles(&self, guild_id: GuildId) -> Option<Arc<HashSet<RoleId>>> {
+        self.0
+            .guild_roles
+            .get(&guild_id)
+            .map(|r| Arc::clone(r.value()))
+    }
+
+    /// Gets the set of stickers in a guild.
+    ///
+    /// This is an O(1) operation. This requires both the [`GUILDS`] and
+    /// [`GUILD_EMOJIS`] intents.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    /// [`GUILD_EMOJIS`]: ::twilight_model::gateway::Intents::GUILD_EMOJIS
+    pub fn guild_stickers(&self, guild_id: GuildId) -> Option<Arc<HashSet<StickerId>>> {
+        self.0
+            .guild_stickers
+            .get(&guild_id)
+            .map(|r| Arc::clone(r.value()))
     }
 
     /// Gets a member by guild ID and user ID.
@@ -424,6 +638,18 @@ impl InMemoryCache {
             .map(|role| Arc::clone(&role.data))
     }
 
+    /// Gets a sticker by ID.
+    ///
+    /// This is an O(1) operation. This requires the [`GUILD_EMOJIS`] intent.
+    ///
+    /// [`GUILD_EMOJIS`]: ::twilight_model::gateway::Intents::GUILD_EMOJIS
+    pub fn sticker(&self, sticker_id: StickerId) -> Option<Arc<CachedSticker>> {
+        self.0
+            .stickers
+            .get(&sticker_id)
+            .map(|x| Arc::clone(&x.data))
+    }
+
     /// Gets a user by ID.
     ///
     /// This is an O(1) operation. This requires the [`GUILD_MEMBERS`] intent.
@@ -483,12 +709,15 @@ impl InMemoryCache {
         self.0.guild_members.clear();
         self.0.guild_presences.clear();
         self.0.guild_roles.clear();
+        self.0.guild_stickers.clear();
         self.0.members.clear();
         self.0.messages.clear();
         self.0.presences.clear();
         self.0.roles.clear();
+        self.0.stickers.clear();
         self.0.unavailable_guilds.clear();
         self.0.users.clear();
+        self.0.voice_servers.clear();
         self.0.voice_state_channels.clear();
         self.0.voice_state_guilds.clear();
         self.0.voice_states.clear();
@@ -536,13 +765,23 @@ impl InMemoryCache {
         }
 
         let id = channel.id();
-        self.0
-            .guild_channels
-            .entry(guild_id)
-            .or_default()
-            .insert(id);
+        Arc::make_mut(&mut self.0.guild_channels.entry(guild_id).or_default()).insert(id);
+
+        let old = self.previous_guild_item(&self.0.channels_guild, &id);
+        let new = upsert_guild_item(&self.0.channels_guild, "Channel", guild_id, id, channel);
+
+        match old {
+            Some(old) if Arc::ptr_eq(&old, &new) => {}
+            Some(old) => self.emit(|| CacheChange::ChannelUpdated {
+                old,
+                new: Arc::clone(&new),
+            }),
+            None => self.emit(|| CacheChange::ChannelCreated {
+                channel: Arc::clone(&new),
+            }),
+        }
 
-        upsert_guild_item(&self.0.channels_guild, guild_id, id, channel)
+        new
     }
 
     fn cache_emoji(&self, guild_id: GuildId, emoji: Emoji) -> Arc<CachedEmoji> {
@@ -551,10 +790,12 @@ impl InMemoryCache {
             Some(_) | None => {}
         }
 
-        let user = match emoji.user {
-            Some(u) => Some(self.cache_user(Cow::Owned(u), Some(guild_id))),
-            None => None,
-        };
+        let user_id = emoji.user.map(|user| {
+            let user_id = user.id;
+            self.cache_user(Cow::Owned(user), true);
+
+            user_id
+        });
 
         let cached = Arc::new(CachedEmoji {
             id: emoji.id,
@@ -563,11 +804,11 @@ impl InMemoryCache {
             managed: emoji.managed,
             require_colons: emoji.require_colons,
             roles: emoji.roles,
-            user,
+            user_id,
             available: emoji.available,
         });
 
-        self.0.emojis.insert(
+        let previous = self.0.emojis.insert(
             cached.id,
             GuildItem {
                 data: Arc::clone(&cached),
@@ -575,11 +816,15 @@ impl InMemoryCache {
             },
         );
 
-        self.0
-            .guild_emojis
-            .entry(guild_id)
-            .or_default()
-            .insert(emoji.id);
+        // The new emoji's creator was referenced above, so the previous
+        // emoji's reference is dropped.
+        if let Some(user_id) = previous.as_ref().and_then(|item| item.data.user_id) {
+            self.dereference_user(user_id);
+        }
+
+        stats::upsert("Emoji", previous);
+
+        Arc::make_mut(&mut self.0.guild_emojis.entry(guild_id).or_default()).insert(emoji.id);
 
         cached
     }
@@ -591,37 +836,42 @@ impl InMemoryCache {
     }
 
     fn cache_group(&self, group: Group) -> Arc<Group> {
-        upsert_item(&self.0.groups, group.id, group)
+        upsert_item(&self.0.groups, "Group", group.id, group)
     }
 
     fn cache_guild(&self, guild: Guild) {
         // The map and set creation needs to occur first, so caching states and
         // objects always has a place to put them.
         if self.wants(ResourceType::CHANNEL) {
-            self.0.guild_channels.insert(guild.id, HashSet::new());
+            self.0.guild_channels.insert(guild.id, Arc::default());
             self.cache_guild_channels(guild.id, guild.channels);
         }
 
         if self.wants(ResourceType::EMOJI) {
-            self.0.guild_emojis.insert(guild.id, HashSet::new());
+            self.0.guild_emojis.insert(guild.id, Arc::default());
             self.cache_emojis(guild.id, guild.emojis);
         }
 
         if self.wants(ResourceType::MEMBER) {
-            self.0.guild_members.insert(guild.id, HashSet::new());
+            self.0.guild_members.insert(guild.id, Arc::default());
             self.cache_members(guild.id, guild.members);
         }
 
         if self.wants(ResourceType::PRESENCE) {
-            self.0.guild_presences.insert(guild.id, HashSet::new());
+            self.0.guild_presences.insert(guild.id, Arc::default());
             self.cache_presences(guild.id, guild.presences);
         }
 
         if self.wants(ResourceType::ROLE) {
-            self.0.guild_roles.insert(guild.id, HashSet::new());
+            self.0.guild_roles.insert(guild.id, Arc::default());
             self.cache_roles(guild.id, guild.roles);
         }
 
+        if self.wants(ResourceType::STICKER) {
+            self.0.guild_stickers.insert(guild.id, Arc::default());
+            self.cache_stickers(guild.id, guild.stickers);
+        }
+
         if self.wants(ResourceType::VOICE_STATE) {
             self.0.voice_state_guilds.insert(guild.id, HashSet::new());
             self.cache_voice_states(guild.voice_states);
@@ -666,18 +916,36 @@ impl InMemoryCache {
         };
 
         self.0.unavailable_guilds.remove(&guild.id);
-        self.0.guilds.insert(guild.id, Arc::new(guild));
+        let guild = Arc::new(guild);
+        let previous = self.0.guilds.insert(guild.id, Arc::clone(&guild));
+
+        match &previous {
+            Some(old) => self.emit(|| CacheChange::GuildUpdated {
+                old: Arc::clone(old),
+                new: guild,
+            }),
+            None => self.emit(|| CacheChange::GuildCreated { guild }),
+        }
+
+        stats::upsert("Guild", previous);
     }
 
     fn cache_member(&self, guild_id: GuildId, member: Member) -> Arc<CachedMember> {
         let member_id = member.user.id;
         let id = (guild_id, member_id);
+
+        // The user may have been updated even if the member hasn't, and a new
+        // member is another reference to it.
+        self.cache_user(
+            Cow::Borrowed(&member.user),
+            !self.0.members.contains_key(&id),
+        );
+
         match self.0.members.get(&id) {
             Some(m) if **m == member => return Arc::clone(&m),
             Some(_) | None => {}
         }
 
-        let user = self.cache_user(Cow::Owned(member.user), Some(guild_id));
         let cached = Arc::new(CachedMember {
             deaf: member.deaf,
             guild_id,
@@ -686,14 +954,12 @@ impl InMemoryCache {
             nick: member.nick,
             premium_since: member.premium_since,
             roles: member.roles,
-            user,
+            user_id: member_id,
         });
-        self.0.members.insert(id, Arc::clone(&cached));
-        self.0
-            .guild_members
-            .entry(guild_id)
-            .or_default()
-            .insert(member_id);
+        let previous = self.0.members.insert(id, Arc::clone(&cached));
+        self.emit_member(previous.as_ref(), &cached);
+        stats::upsert("Member", previous);
+        Arc::make_mut(&mut self.0.guild_members.entry(guild_id).or_default()).insert(member_id);
         cached
     }
 
@@ -701,19 +967,18 @@ impl InMemoryCache {
         &self,
         guild_id: GuildId,
         member: &PartialMember,
-        user: Arc<User>,
+        user: &User,
     ) -> Arc<CachedMember> {
-        let id = (guild_id, user.id);
+        let user_id = user.id;
+        let id = (guild_id, user_id);
+        self.cache_user(Cow::Borrowed(user), !self.0.members.contains_key(&id));
+
         match self.0.members.get(&id) {
             Some(m) if **m == member => return Arc::clone(&m),
             Some(_) | None => {}
         }
 
-        self.0
-            .guild_members
-            .entry(guild_id)
-            .or_default()
-            .insert(user.id);
+        Arc::make_mut(&mut self.0.guild_members.entry(guild_id).or_default()).insert(user_id);
 
         let cached = Arc::new(CachedMember {
             deaf: member.deaf,
@@ -723,13 +988,29 @@ impl InMemoryCache {
             nick: member.nick.to_owned(),
             premium_since: None,
             roles: member.roles.to_owned(),
-            user,
+            user_id,
         });
-        self.0.members.insert(id, Arc::clone(&cached));
+        let previous = self.0.members.insert(id, Arc::clone(&cached));
+        self.emit_member(previous.as_ref(), &cached);
+        stats::upsert("Member", previous);
 
         cached
     }
 
+    /// Send a change for an upserted member, unless it's unchanged.
+    fn emit_member(&self, old: Option<&Arc<CachedMember>>, new: &Arc<CachedMember>) {
+        match old {
+            Some(old) if old == new => {}
+            Some(old) => self.emit(|| CacheChange::MemberUpdated {
+                old: Arc::clone(old),
+                new: Arc::clone(new),
+            }),
+            None => self.emit(|| CacheChange::MemberAdded {
+                member: Arc::clone(new),
+            }),
+        }
+    }
+
     fn cache_members(&self, guild_id: GuildId, members: impl IntoIterator<Item = Member>) {
         for member in members {
             self.cache_member(guild_id, member);
@@ -745,13 +1026,20 @@ impl InMemoryCache {
     fn cache_presence(&self, guild_id: GuildId, presence: Presence) -> Arc<CachedPresence> {
         let k = (guild_id, presence_user_id(&presence));
 
+        // Presences don't reference users, since they may only have the
+        // user's ID, but they can still carry a newer version of one.
+        if let UserOrId::User(user) = &presence.user {
+            self.cache_user(Cow::Borrowed(user), false);
+        }
+
         match self.0.presences.get(&k) {
             Some(p) if **p == presence => return Arc::clone(&p),
             Some(_) | None => {}
         }
         let cached = Arc::new(CachedPresence::from(&presence));
 
-        self.0.presences.insert(k, Arc::clone(&cached));
+        let previous = self.0.presences.insert(k, Arc::clone(&cached));
+        stats::upsert("Presence", previous);
 
         cached
     }
@@ -763,7 +1051,8 @@ impl InMemoryCache {
             Some(c) if **c == private_channel => Arc::clone(&c),
             Some(_) | None => {
                 let v = Arc::new(private_channel);
-                self.0.channels_private.insert(id, Arc::clone(&v));
+                let previous = self.0.channels_private.insert(id, Arc::clone(&v));
+                stats::upsert("PrivateChannel", previous);
 
                 v
             }
@@ -778,37 +1067,126 @@ impl InMemoryCache {
 
     fn cache_role(&self, guild_id: GuildId, role: Role) -> Arc<Role> {
         // Insert the role into the guild_roles map
-        self.0
-            .guild_roles
-            .entry(guild_id)
-            .or_default()
-            .insert(role.id);
+        Arc::make_mut(&mut self.0.guild_roles.entry(guild_id).or_default()).insert(role.id);
 
         // Insert the role into the all roles map
-        upsert_guild_item(&self.0.roles, guild_id, role.id, role)
+        let role_id = role.id;
+        let old = self.previous_guild_item(&self.0.roles, &role_id);
+        let new = upsert_guild_item(&self.0.roles, "Role", guild_id, role_id, role);
+
+        match old {
+            Some(old) if Arc::ptr_eq(&old, &new) => {}
+            Some(old) => self.emit(|| CacheChange::RoleUpdated {
+                guild: guild_id,
+                old,
+                new: Arc::clone(&new),
+            }),
+            None => self.emit(|| CacheChange::RoleCreated {
+                guild: guild_id,
+                role: Arc::clone(&new),
+            }),
+        }
+
+        new
     }
 
-    fn cache_user(&self, user: Cow<'_, User>, guild_id: Option<GuildId>) -> Arc<User> {
-        match self.0.users.get_mut(&user.id) {
-            Some(mut u) if *u.0 == *user => {
-                if let Some(guild_id) = guild_id {
-                    u.1.insert(guild_id);
+    fn cache_sticker(&self, guild_id: GuildId, sticker: Sticker) -> Arc<CachedSticker> {
+        match self.0.stickers.get(&sticker.id) {
+            Some(s) if *s.data == sticker => return Arc::clone(&s.data),
+            Some(_) | None => {}
+        }
+
+        let user_id = sticker.user.map(|user| {
+            let user_id = user.id;
+            self.cache_user(Cow::Owned(user), true);
+
+            user_id
+        });
+
+        let cached = Arc::new(CachedSticker {
+            available: sticker.available,
+            description: sticker.description,
+            format_type: sticker.format_type,
+            id: sticker.id,
+            name: sticker.name,
+            tags: sticker.tags,
+            user_id,
+        });
+
+        let previous = self.0.stickers.insert(
+            cached.id,
+            GuildItem {
+                data: Arc::clone(&cached),
+                guild_id,
+            },
+        );
+
+        if let Some(user_id) = previous.as_ref().and_then(|item| item.data.user_id) {
+            self.dereference_user(user_id);
+        }
+
+        stats::upsert("Sticker", previous);
+
+        Arc::make_mut(&mut self.0.guild_stickers.entry(guild_id).or_default()).insert(cached.id);
+
+        cached
+    }
+
+    fn cache_stickers(&self, guild_id: GuildId, stickers: impl IntoIterator<Item = Sticker>) {
+        for sticker in stickers {
+            self.cache_sticker(guild_id, sticker);
+        }
+    }
+
+    /// Cache a user, adding a reference to it if `reference` is set.
+    ///
+    /// Users that aren't referenced are only updated if they're already
+    /// cached.
+    fn cache_user(&self, user: Cow<'_, User>, reference: bool) {
+        match self.0.users.entry(user.id) {
+            Entry::Occupied(mut entry) => {
+                let (cached, references) = entry.get_mut();
+
+                if reference {
+                    *references += 1;
                 }
 
-                return Arc::clone(&u.value().0);
+                if **cached != *user {
+                    *cached = Arc::new(user.into_owned());
+                    stats::update("User");
+                }
+            }
+            Entry::Vacant(entry) => {
+                if reference {
+                    entry.insert((Arc::new(user.into_owned()), 1));
+                    stats::insert("User");
+                }
             }
-            Some(_) | None => {}
-        }
-        let user = Arc::new(user.into_owned());
-        if let Some(guild_id) = guild_id {
-            let mut guild_id_set = BTreeSet::new();
-            guild_id_set.insert(guild_id);
-            self.0
-                .users
-                .insert(user.id, (Arc::clone(&user), guild_id_set));
         }
+    }
+
+    /// Remove a reference to a user, removing the user if it was the last
+    /// one.
+    fn dereference_user(&self, user_id: UserId) {
+        // Avoid a deadlock by mutating the user, dropping the lock to the map,
+        // and then maybe conditionally removing the user later.
+        let maybe_remove_user = match self.0.users.get_mut(&user_id) {
+            Some(mut user_tuple) => {
+                user_tuple.1 = user_tuple.1.saturating_sub(1);
 
-        user
+                true
+            }
+            None => false,
+        };
+
+        if maybe_remove_user {
+            stats::evict(
+                "User",
+                self.0
+                    .users
+                    .remove_if(&user_id, |_, user_tuple| user_tuple.1 == 0),
+            );
+        }
     }
 
     fn cache_voice_states(&self, voice_states: impl IntoIterator<Item = VoiceState>) {
@@ -865,16 +1243,31 @@ impl InMemoryCache {
                 }
             }
 
-            let (_, state) = self.0.voice_states.remove(&(guild_id, user_id))?;
+            // The current user's voice server is no longer relevant once they
+            // have disconnected.
+            if self
+                .current_user()
+                .map(|user| user.id == user_id)
+                .unwrap_or_default()
+            {
+                stats::evict("VoiceServer", self.0.voice_servers.remove(&guild_id));
+            }
+
+            let (_, state) = stats::evict(
+                "VoiceState",
+                self.0.voice_states.remove(&(guild_id, user_id)),
+            )?;
 
             return Some(state);
         }
 
         let state = Arc::new(vs);
 
-        self.0
+        let previous = self
+            .0
             .voice_states
             .insert((guild_id, user_id), Arc::clone(&state));
+        stats::upsert("VoiceState", previous);
 
         self.0
             .voice_state_guilds
@@ -893,13 +1286,29 @@ impl InMemoryCache {
         Some(state)
     }
 
+    fn cache_voice_server(&self, voice_server: VoiceServerUpdate) {
+        let guild_id = match voice_server.guild_id {
+            Some(id) => id,
+            None => return,
+        };
+
+        let previous = self
+            .0
+            .voice_servers
+            .insert(guild_id, Arc::new(voice_server));
+        stats::upsert("VoiceServer", previous);
+    }
+
     fn delete_group(&self, channel_id: ChannelId) -> Option<Arc<Group>> {
-        self.0.groups.remove(&channel_id).map(|(_, v)| v)
+        stats::evict("Group", self.0.groups.remove(&channel_id)).map(|(_, v)| v)
     }
 
     fn unavailable_guild(&self, guild_id: GuildId) {
         self.0.unavailable_guilds.insert(guild_id);
-        self.0.guilds.remove(&guild_id);
+
+        if stats::evict("Guild", self.0.guilds.remove(&guild_id)).is_some() {
+            self.emit(|| CacheChange::GuildRemoved { guild: guild_id });
+        }
     }
 
     /// Delete a guild channel from the cache.
@@ -907,25 +1316,83 @@ impl InMemoryCache {
     /// The guild channel data itself and the channel entry in its guild's list
     /// of channels will be deleted.
     fn delete_guild_channel(&self, channel_id: ChannelId) -> Option<Arc<GuildChannel>> {
-        let GuildItem { data, guild_id } = self.0.channels_guild.remove(&channel_id)?.1;
+        let GuildItem { data, guild_id } =
+            stats::evict("Channel", self.0.channels_guild.remove(&channel_id))?.1;
 
         if let Some(mut guild_channels) = self.0.guild_channels.get_mut(&guild_id) {
-            guild_channels.remove(&channel_id);
+            Arc::make_mut(&mut guild_channels).remove(&channel_id);
         }
 
+        self.emit(|| CacheChange::ChannelRemoved {
+            guild: guild_id,
+            channel: channel_id,
+        });
+
         Some(data)
     }
 
     fn delete_role(&self, role_id: RoleId) -> Option<Arc<Role>> {
-        let role = self.0.roles.remove(&role_id).map(|(_, v)| v)?;
+        let role = stats::evict("Role", self.0.roles.remove(&role_id)).map(|(_, v)| v)?;
 
         if let Some(mut roles) = self.0.guild_roles.get_mut(&role.guild_id) {
-            roles.remove(&role_id);
+            Arc::make_mut(&mut roles).remove(&role_id);
         }
 
+        self.emit(|| CacheChange::RoleRemoved {
+            guild: role.guild_id,
+            role: role_id,
+        });
+
         Some(role.data)
     }
 
+    /// Retrieve the currently cached version of a guild item, if there are
+    /// subscribers to changes.
+    fn previous_guild_item<K: Eq + Hash, V>(
+        &self,
+        map: &DashMap<K, GuildItem<V>, S>,
+        k: &K,
+    ) -> Option<Arc<V>> {
+        if !self.is_subscribed() {
+            return None;
+        }
+
+        map.get(k).map(|item| Arc::clone(&item.data))
+    }
+
+    /// Send a change to all subscribers, if there are any.
+    ///
+    /// The change is only created when there are subscribers to send it to.
+    fn emit(&self, change: impl FnOnce() -> CacheChange) {
+        if !self.is_subscribed() {
+            return;
+        }
+
+        let mut subscribers = self.0.subscribers.lock().expect("subscribers poisoned");
+        let change = change();
+        let mut idx = 0;
+
+        while idx < subscribers.len() {
+            if subscribers[idx].send(change.clone()) {
+                idx += 1;
+            } else {
+                subscribers.swap_remove(idx);
+            }
+        }
+
+        if subscribers.is_empty() {
+            self.0.subscribed.store(false, Ordering::Release);
+        }
+    }
+
+    /// Whether there may be subscribers to changes.
+    ///
+    /// Used to avoid retrieving previous versions of resources for change
+    /// notifications when nobody would receive them.
+    fn is_subscribed(&self) -> bool {
+        self.0.subscribed.load(Ordering::Acquire)
+    }
+
     /// Determine whether the configured cache wants a specific resource to be
     /// processed.
     fn wants(&self, resource_type: ResourceType) -> bool {
@@ -942,17 +1409,21 @@ fn presence_user_id(presence: &Presence) -> UserId {
 
 #[cfg(test)]
 mod tests {
-    use crate::InMemoryCache;
-    use std::borrow::Cow;
+    use crate::{CacheChange, InMemoryCache};
+    use futures::{future::FutureExt, stream::StreamExt};
+    use std::{borrow::Cow, sync::Arc};
     use twilight_model::{
         channel::{ChannelType, GuildChannel, TextChannel},
-        gateway::payload::{MemberRemove, RoleDelete},
+        gateway::payload::{
+            GuildEmojisUpdate, MemberRemove, MemberUpdate, RoleDelete, VoiceServerUpdate,
+        },
         guild::{
             DefaultMessageNotificationLevel, Emoji, ExplicitContentFilter, Guild, Member, MfaLevel,
             Permissions, PremiumTier, Role, SystemChannelFlags, VerificationLevel,
         },
         id::{ChannelId, EmojiId, GuildId, RoleId, UserId},
         user::{CurrentUser, User},
+        util::Locale,
         voice::VoiceState,
     };
 
@@ -962,7 +1433,7 @@ mod tests {
             bot: true,
             discriminator: "9876".to_owned(),
             email: None,
-            id: UserId(id),
+            id: UserId::new(id),
             mfa_enabled: true,
             name: "test".to_owned(),
             verified: Some(true),
@@ -1068,7 +1539,11 @@ mod tests {
     fn test_guild_create_channels_have_guild_ids() {
         let mut channels = Vec::new();
         channels.push(GuildChannel::Text(TextChannel {
-            id: ChannelId(111),
+            available_tags: Vec::new(),
+            default_forum_layout: None,
+            default_reaction_emoji: None,
+            default_sort_order: None,
+            id: ChannelId::new(111),
             guild_id: None,
             kind: ChannelType::GuildText,
             last_message_id: None,
@@ -1083,7 +1558,7 @@ mod tests {
         }));
 
         let guild = Guild {
-            id: GuildId(123),
+            id: GuildId::new(123),
             afk_channel_id: None,
             afk_timeout: 300,
             application_id: None,
@@ -1106,15 +1581,16 @@ mod tests {
             mfa_level: MfaLevel::Elevated,
             name: "this is a guild".to_owned(),
             owner: Some(false),
-            owner_id: UserId(456),
+            owner_id: UserId::new(456),
             permissions: Some(Permissions::SEND_MESSAGES),
-            preferred_locale: "en-GB".to_owned(),
+            preferred_locale: Locale::EnglishUk,
             premium_subscription_count: Some(0),
             premium_tier: PremiumTier::None,
             presences: Vec::new(),
             region: "us-east".to_owned(),
             roles: Vec::new(),
             splash: None,
+            stickers: Vec::new(),
             system_channel_id: None,
             system_channel_flags: SystemChannelFlags::SUPPRESS_JOIN_NOTIFICATIONS,
             rules_channel_id: None,
@@ -1132,7 +1608,7 @@ mod tests {
         let cache = InMemoryCache::new();
         cache.cache_guild(guild);
 
-        let channel = cache.guild_channel(ChannelId(111)).unwrap();
+        let channel = cache.guild_channel(ChannelId::new(111)).unwrap();
 
         // The channel was given to the cache without a guild ID, but because
         // it's part of a guild create, the cache can automatically attach the
@@ -1140,7 +1616,7 @@ mod tests {
         // correct value.
         match *channel {
             GuildChannel::Text(ref c) => {
-                assert_eq!(Some(GuildId(123)), c.guild_id);
+                assert_eq!(Some(GuildId::new(123)), c.guild_id);
             }
             _ => assert!(false, "{:?}", channel),
         }
@@ -1150,55 +1626,119 @@ mod tests {
     fn test_syntax_update() {
         let cache = InMemoryCache::new();
         cache.update(&RoleDelete {
-            guild_id: GuildId(0),
-            role_id: RoleId(1),
+            guild_id: GuildId::new(0),
+            role_id: RoleId::new(1),
         });
     }
 
     #[test]
-    fn test_cache_user_guild_state() {
-        let user_id = UserId(2);
+    fn test_cache_user_references() {
+        let user_id = UserId::new(2);
         let cache = InMemoryCache::new();
-        cache.cache_user(Cow::Owned(user(user_id)), Some(GuildId(1)));
+        cache.cache_member(GuildId::new(1), member(user_id, GuildId::new(1)));
+        assert_eq!(1, cache.0.users.get(&user_id).unwrap().1);
 
-        // Test the guild's ID is the only one in the user's set of guilds.
-        {
-            let user = cache.0.users.get(&user_id).unwrap();
-            assert!(user.1.contains(&GuildId(1)));
-            assert_eq!(1, user.1.len());
-        }
-
-        // Test that a second guild will cause 2 in the set.
-        cache.cache_user(Cow::Owned(user(user_id)), Some(GuildId(3)));
+        // A member of a second guild is a second reference.
+        cache.cache_member(GuildId::new(3), member(user_id, GuildId::new(3)));
+        assert_eq!(2, cache.0.users.get(&user_id).unwrap().1);
 
-        {
-            let user = cache.0.users.get(&user_id).unwrap();
-            assert!(user.1.contains(&GuildId(3)));
-            assert_eq!(2, user.1.len());
-        }
+        // Caching an existing member again doesn't add a reference.
+        cache.cache_member(GuildId::new(3), member(user_id, GuildId::new(3)));
+        assert_eq!(2, cache.0.users.get(&user_id).unwrap().1);
 
-        // Test that removing a user from a guild will cause the ID to be
-        // removed from the set, leaving the other ID.
+        // Removing one of the members leaves the other's reference.
         cache.update(&MemberRemove {
-            guild_id: GuildId(3),
+            guild_id: GuildId::new(3),
             user: user(user_id),
         });
+        assert_eq!(1, cache.0.users.get(&user_id).unwrap().1);
 
-        {
-            let user = cache.0.users.get(&user_id).unwrap();
-            assert!(!user.1.contains(&GuildId(3)));
-            assert_eq!(1, user.1.len());
-        }
+        // Removing a member that isn't cached doesn't remove a reference.
+        cache.update(&MemberRemove {
+            guild_id: GuildId::new(3),
+            user: user(user_id),
+        });
+        assert_eq!(1, cache.0.users.get(&user_id).unwrap().1);
 
-        // Test that removing the user from its last guild removes the user's
-        // entry.
+        // Removing the last reference removes the user.
         cache.update(&MemberRemove {
-            guild_id: GuildId(1),
+            guild_id: GuildId::new(1),
             user: user(user_id),
         });
         assert!(!cache.0.users.contains_key(&user_id));
     }
 
+    #[test]
+    fn test_cache_user_unreferenced() {
+        let user_id = UserId::new(2);
+        let cache = InMemoryCache::new();
+
+        // Users that aren't referenced aren't cached.
+        cache.cache_user(Cow::Owned(user(user_id)), false);
+        assert!(cache.user(user_id).is_none());
+
+        // But they update already cached users.
+        cache.cache_user(Cow::Owned(user(user_id)), true);
+        let mut updated = user(user_id);
+        updated.name = "updated".to_owned();
+        cache.cache_user(Cow::Owned(updated), false);
+        assert_eq!("updated", cache.user(user_id).unwrap().name);
+        assert_eq!(1, cache.0.users.get(&user_id).unwrap().1);
+    }
+
+    #[test]
+    fn test_emoji_user_references() {
+        let user_id = UserId::new(1);
+        let cache = InMemoryCache::new();
+        cache.update(&GuildEmojisUpdate {
+            emojis: vec![
+                emoji(EmojiId::new(2), Some(user(user_id))),
+                emoji(EmojiId::new(3), Some(user(user_id))),
+            ],
+            guild_id: GuildId::new(1),
+        });
+        assert_eq!(2, cache.0.users.get(&user_id).unwrap().1);
+
+        // Updating an emoji replaces its reference.
+        let mut updated = emoji(EmojiId::new(2), Some(user(user_id)));
+        updated.name = "updated".to_owned();
+        cache.cache_emoji(GuildId::new(1), updated);
+        assert_eq!(2, cache.0.users.get(&user_id).unwrap().1);
+
+        // Deleted emojis no longer reference their creator.
+        cache.update(&GuildEmojisUpdate {
+            emojis: vec![emoji(EmojiId::new(3), Some(user(user_id)))],
+            guild_id: GuildId::new(1),
+        });
+        assert_eq!(1, cache.0.users.get(&user_id).unwrap().1);
+
+        cache.update(&GuildEmojisUpdate {
+            emojis: Vec::new(),
+            guild_id: GuildId::new(1),
+        });
+        assert!(cache.user(user_id).is_none());
+    }
+
+    #[test]
+    fn test_user_shared_between_guilds() {
+        let user_id = UserId::new(2);
+        let cache = InMemoryCache::new();
+        cache.cache_member(GuildId::new(1), member(user_id, GuildId::new(1)));
+
+        let mut updated = member(user_id, GuildId::new(3));
+        updated.user.name = "updated".to_owned();
+        cache.cache_member(GuildId::new(3), updated);
+
+        // Both members refer to the single, updated user.
+        assert_eq!(1, cache.0.users.len());
+        assert_eq!("updated", cache.user(user_id).unwrap().name);
+        assert_eq!(
+            user_id,
+            cache.member(GuildId::new(1), user_id).unwrap().user_id
+        );
+        assert_eq!(2, cache.0.users.get(&user_id).unwrap().1);
+    }
+
     #[test]
     fn test_voice_state_inserts_and_removes() {
         let cache = InMemoryCache::new();
@@ -1210,7 +1750,8 @@ mod tests {
         // User 1 joins guild 1's channel 11 (1 channel, 1 guild)
         {
             // Ids for this insert
-            let (guild_id, channel_id, user_id) = (GuildId(1), ChannelId(11), UserId(1));
+            let (guild_id, channel_id, user_id) =
+                (GuildId::new(1), ChannelId::new(11), UserId::new(1));
             cache.cache_voice_state(voice_state(guild_id, Some(channel_id), user_id));
 
             // The new user should show up in the global voice states
@@ -1230,7 +1771,8 @@ mod tests {
         // User 2 joins guild 2's channel 21 (2 channels, 2 guilds)
         {
             // Ids for this insert
-            let (guild_id, channel_id, user_id) = (GuildId(2), ChannelId(21), UserId(2));
+            let (guild_id, channel_id, user_id) =
+                (GuildId::new(2), ChannelId::new(21), UserId::new(2));
             cache.cache_voice_state(voice_state(guild_id, Some(channel_id), user_id));
 
             // The new voice state should show up in the global voice states
@@ -1250,7 +1792,8 @@ mod tests {
         // User 3 joins guild 1's channel 12  (3 channels, 2 guilds)
         {
             // Ids for this insert
-            let (guild_id, channel_id, user_id) = (GuildId(1), ChannelId(12), UserId(3));
+            let (guild_id, channel_id, user_id) =
+                (GuildId::new(1), ChannelId::new(12), UserId::new(3));
             cache.cache_voice_state(voice_state(guild_id, Some(channel_id), user_id));
 
             // The new voice state should show up in the global voice states
@@ -1271,7 +1814,8 @@ mod tests {
         // User 3 moves to guild 1's channel 11 (2 channels, 2 guilds)
         {
             // Ids for this insert
-            let (guild_id, channel_id, user_id) = (GuildId(1), ChannelId(11), UserId(3));
+            let (guild_id, channel_id, user_id) =
+                (GuildId::new(1), ChannelId::new(11), UserId::new(3));
             cache.cache_voice_state(voice_state(guild_id, Some(channel_id), user_id));
 
             // The new voice state should show up in the global voice states
@@ -1291,7 +1835,8 @@ mod tests {
 
         // User 3 dcs (2 channels, 2 guilds)
         {
-            let (guild_id, channel_id, user_id) = (GuildId(1), ChannelId(11), UserId(3));
+            let (guild_id, channel_id, user_id) =
+                (GuildId::new(1), ChannelId::new(11), UserId::new(3));
             cache.cache_voice_state(voice_state(guild_id, None, user_id));
 
             // Now that the user left, they should not show up in the voice states
@@ -1307,7 +1852,8 @@ mod tests {
 
         // User 2 dcs (1 channel, 1 guild)
         {
-            let (guild_id, channel_id, user_id) = (GuildId(2), ChannelId(21), UserId(2));
+            let (guild_id, channel_id, user_id) =
+                (GuildId::new(2), ChannelId::new(21), UserId::new(2));
             cache.cache_voice_state(voice_state(guild_id, None, user_id));
 
             // Now that the user left, they should not show up in the voice states
@@ -1325,7 +1871,8 @@ mod tests {
 
         // User 1 dcs (0 channels, 0 guilds)
         {
-            let (guild_id, _channel_id, user_id) = (GuildId(1), ChannelId(11), UserId(1));
+            let (guild_id, _channel_id, user_id) =
+                (GuildId::new(1), ChannelId::new(11), UserId::new(1));
             cache.cache_voice_state(voice_state(guild_id, None, user_id));
 
             // Since the last person has disconnected, the global voice states, guilds, and channels should all be gone
@@ -1338,14 +1885,61 @@ mod tests {
     #[test]
     fn test_voice_states() {
         let cache = InMemoryCache::new();
-        cache.cache_voice_state(voice_state(GuildId(1), Some(ChannelId(2)), UserId(3)));
-        cache.cache_voice_state(voice_state(GuildId(1), Some(ChannelId(2)), UserId(4)));
+        cache.cache_voice_state(voice_state(
+            GuildId::new(1),
+            Some(ChannelId::new(2)),
+            UserId::new(3),
+        ));
+        cache.cache_voice_state(voice_state(
+            GuildId::new(1),
+            Some(ChannelId::new(2)),
+            UserId::new(4),
+        ));
 
         // Returns both voice states for the channel that exists.
-        assert_eq!(2, cache.voice_channel_states(ChannelId(2)).unwrap().len());
+        assert_eq!(
+            2,
+            cache.voice_channel_states(ChannelId::new(2)).unwrap().len()
+        );
 
         // Returns None if the channel does not exist.
-        assert!(cache.voice_channel_states(ChannelId(0)).is_none());
+        assert!(cache.voice_channel_states(ChannelId::new(0)).is_none());
+    }
+
+    #[test]
+    fn test_own_voice_connection() {
+        let cache = InMemoryCache::new();
+        cache.cache_current_user(current_user(3));
+        assert!(cache.own_voice_connection(GuildId::new(1)).is_none());
+
+        cache.cache_voice_state(voice_state(
+            GuildId::new(1),
+            Some(ChannelId::new(2)),
+            UserId::new(3),
+        ));
+        cache.update(&VoiceServerUpdate {
+            channel_id: None,
+            endpoint: Some("endpoint".to_owned()),
+            guild_id: Some(GuildId::new(1)),
+            token: "token".to_owned(),
+        });
+
+        let connection = cache.own_voice_connection(GuildId::new(1)).unwrap();
+        assert_eq!(Some("endpoint"), connection.endpoint());
+        assert_eq!(Some("a"), connection.session_id());
+        assert_eq!(Some("token"), connection.token());
+
+        // Voice states of other users aren't considered.
+        cache.cache_voice_state(voice_state(
+            GuildId::new(4),
+            Some(ChannelId::new(5)),
+            UserId::new(6),
+        ));
+        assert!(cache.own_voice_connection(GuildId::new(4)).is_none());
+
+        // Disconnecting removes both the voice state and the voice server.
+        cache.cache_voice_state(voice_state(GuildId::new(1), None, UserId::new(3)));
+        assert!(cache.own_voice_connection(GuildId::new(1)).is_none());
     }
 
     #[test]
@@ -1355,7 +1949,7 @@ mod tests {
         // Single inserts
         {
             // The role ids for the guild with id 1
-            let guild_1_role_ids = (1..=10).map(RoleId).collect::<Vec<_>>();
+            let guild_1_role_ids = (1..=10).map(RoleId::new).collect::<Vec<_>>();
             // Map the role ids to a test role
             let guild_1_roles = guild_1_role_ids
                 .iter()
@@ -1364,11 +1958,11 @@ mod tests {
                 .collect::<Vec<_>>();
             // Cache all the roles using cache role
             for role in guild_1_roles.clone() {
-                cache.cache_role(GuildId(1), role);
+                cache.cache_role(GuildId::new(1), role);
             }
 
             // Check for the cached guild role ids
-            let cached_roles = cache.guild_roles(GuildId(1)).unwrap();
+            let cached_roles = cache.guild_roles(GuildId::new(1)).unwrap();
             assert_eq!(cached_roles.len(), guild_1_role_ids.len());
             assert!(guild_1_role_ids.iter().all(|id| cached_roles.contains(id)));
 
@@ -1381,7 +1975,7 @@ mod tests {
         // Bulk inserts
         {
             // The role ids for the guild with id 2
-            let guild_2_role_ids = (101..=110).map(RoleId).collect::<Vec<_>>();
+            let guild_2_role_ids = (101..=110).map(RoleId::new).collect::<Vec<_>>();
             // Map the role ids to a test role
             let guild_2_roles = guild_2_role_ids
                 .iter()
@@ -1389,10 +1983,10 @@ mod tests {
                 .map(role)
                 .collect::<Vec<_>>();
             // Cache all the roles using cache roles
-            cache.cache_roles(GuildId(2), guild_2_roles.clone());
+            cache.cache_roles(GuildId::new(2), guild_2_roles.clone());
 
             // Check for the cached guild role ids
-            let cached_roles = cache.guild_roles(GuildId(2)).unwrap();
+            let cached_roles = cache.guild_roles(GuildId::new(2)).unwrap();
             assert_eq!(cached_roles.len(), guild_2_role_ids.len());
             assert!(guild_2_role_ids.iter().all(|id| cached_roles.contains(id)));
 
@@ -1403,32 +1997,132 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_guild_roles_shared() {
+        let cache = InMemoryCache::new();
+        cache.cache_role(GuildId::new(1), role(RoleId::new(1)));
+
+        let first = cache.guild_roles(GuildId::new(1)).unwrap();
+        let second = cache.guild_roles(GuildId::new(1)).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+
+        // Updating the cache while a set is held must not change the held set.
+        cache.cache_role(GuildId::new(1), role(RoleId::new(2)));
+        assert_eq!(1, first.len());
+        assert_eq!(2, cache.guild_roles(GuildId::new(1)).unwrap().len());
+    }
+
+    #[test]
+    fn test_subscribe() {
+        let cache = InMemoryCache::new();
+        // Changes made before subscribing aren't sent.
+        cache.cache_member(GuildId::new(1), member(UserId::new(1), GuildId::new(1)));
+        let mut changes = cache.subscribe();
+
+        cache.cache_role(GuildId::new(1), role(RoleId::new(2)));
+        let created = cache.role(RoleId::new(2)).unwrap();
+        assert_eq!(
+            Some(Some(CacheChange::RoleCreated {
+                guild: GuildId::new(1),
+                role: Arc::clone(&created),
+            })),
+            changes.next().now_or_never(),
+        );
+
+        // Caching an unchanged role doesn't send a change.
+        cache.cache_role(GuildId::new(1), role(RoleId::new(2)));
+        assert!(changes.next().now_or_never().is_none());
+
+        let mut updated = role(RoleId::new(2));
+        updated.name = "updated".to_owned();
+        cache.cache_role(GuildId::new(1), updated);
+        assert_eq!(
+            Some(Some(CacheChange::RoleUpdated {
+                guild: GuildId::new(1),
+                old: created,
+                new: cache.role(RoleId::new(2)).unwrap(),
+            })),
+            changes.next().now_or_never(),
+        );
+
+        cache.update(&RoleDelete {
+            guild_id: GuildId::new(1),
+            role_id: RoleId::new(2),
+        });
+        assert_eq!(
+            Some(Some(CacheChange::RoleRemoved {
+                guild: GuildId::new(1),
+                role: RoleId::new(2),
+            })),
+            changes.next().now_or_never(),
+        );
+
+        let member_update = MemberUpdate {
+            guild_id: GuildId::new(1),
+            joined_at: "2021-01-01T00:00:00+00:00".to_owned(),
+            nick: None,
+            premium_since: None,
+            roles: Vec::new(),
+            user: user(UserId::new(1)),
+        };
+        let old = cache.member(GuildId::new(1), UserId::new(1)).unwrap();
+        cache.update(&member_update);
+        assert_eq!(
+            Some(Some(CacheChange::MemberUpdated {
+                old,
+                new: cache.member(GuildId::new(1), UserId::new(1)).unwrap(),
+            })),
+            changes.next().now_or_never(),
+        );
+
+        // Updating a member to the same value doesn't send a change.
+        cache.update(&member_update);
+        assert!(changes.next().now_or_never().is_none());
+
+        cache.update(&MemberRemove {
+            guild_id: GuildId::new(1),
+            user: user(UserId::new(1)),
+        });
+        assert_eq!(
+            Some(Some(CacheChange::MemberRemoved {
+                guild: GuildId::new(1),
+                user: UserId::new(1),
+            })),
+            changes.next().now_or_never(),
+        );
+
+        // Dropping every stream unsubscribes from the cache.
+        drop(changes);
+        cache.cache_role(GuildId::new(1), role(RoleId::new(3)));
+        assert!(!cache.is_subscribed());
+    }
+
     #[test]
     fn test_cache_guild_member() {
         let cache = InMemoryCache::new();
 
         // Single inserts
         {
-            let guild_1_user_ids = (1..=10).map(UserId).collect::<Vec<_>>();
+            let guild_1_user_ids = (1..=10).map(UserId::new).collect::<Vec<_>>();
             let guild_1_members = guild_1_user_ids
                 .iter()
                 .copied()
-                .map(|id| member(id, GuildId(1)))
+                .map(|id| member(id, GuildId::new(1)))
                 .collect::<Vec<_>>();
 
             for member in guild_1_members {
-                cache.cache_member(GuildId(1), member);
+                cache.cache_member(GuildId::new(1), member);
             }
 
             // Check for the cached guild members ids
-            let cached_roles = cache.guild_members(GuildId(1)).unwrap();
+            let cached_roles = cache.guild_members(GuildId::new(1)).unwrap();
             assert_eq!(cached_roles.len(), guild_1_user_ids.len());
             assert!(guild_1_user_ids.iter().all(|id| cached_roles.contains(id)));
 
             // Check for the cached members
             assert!(guild_1_user_ids
                 .iter()
-                .all(|id| cache.member(GuildId(1), *id).is_some()));
+                .all(|id| cache.member(GuildId::new(1), *id).is_some()));
 
             // Check for the cached users
             assert!(guild_1_user_ids.iter().all(|id| cache.user(*id).is_some()));
@@ -1436,16 +2130,16 @@ mod tests {
 
         // Bulk inserts
         {
-            let guild_2_user_ids = (1..=10).map(UserId).collect::<Vec<_>>();
+            let guild_2_user_ids = (1..=10).map(UserId::new).collect::<Vec<_>>();
             let guild_2_members = guild_2_user_ids
                 .iter()
                 .copied()
-                .map(|id| member(id, GuildId(2)))
+                .map(|id| member(id, GuildId::new(2)))
                 .collect::<Vec<_>>();
-            cache.cache_members(GuildId(2), guild_2_members);
+            cache.cache_members(GuildId::new(2), guild_2_members);
 
             // Check for the cached guild members ids
-            let cached_roles = cache.guild_members(GuildId(1)).unwrap();
+            let cached_roles = cache.guild_members(GuildId::new(1)).unwrap();
             assert_eq!(cached_roles.len(), guild_2_user_ids.len());
             assert!(guild_2_user_ids.iter().all(|id| cached_roles.contains(id)));
 
@@ -1453,7 +2147,7 @@ mod tests {
             assert!(guild_2_user_ids
                 .iter()
                 .copied()
-                .all(|id| cache.member(GuildId(1), id).is_some()));
+                .all(|id| cache.member(GuildId::new(1), id).is_some()));
 
             // Check for the cached users
             assert!(guild_2_user_ids.iter().all(|id| cache.user(*id).is_some()));
@@ -1466,9 +2160,9 @@ mod tests {
 
         // The user to do some of the inserts
         fn user_mod(id: EmojiId) -> Option<User> {
-            if id.0 % 2 == 0 {
+            if id.get() % 2 == 0 {
                 // Only use user for half
-                Some(user(UserId(1)))
+                Some(user(UserId::new(1)))
             } else {
                 None
             }
@@ -1476,7 +2170,7 @@ mod tests {
 
         // Single inserts
         {
-            let guild_1_emoji_ids = (1..=10).map(EmojiId).collect::<Vec<_>>();
+            let guild_1_emoji_ids = (1..=10).map(EmojiId::new).collect::<Vec<_>>();
             let guild_1_emoji = guild_1_emoji_ids
                 .iter()
                 .copied()
@@ -1484,7 +2178,7 @@ mod tests {
                 .collect::<Vec<_>>();
 
             for emoji in guild_1_emoji {
-                cache.cache_emoji(GuildId(1), emoji);
+                cache.cache_emoji(GuildId::new(1), emoji);
             }
 
             for id in guild_1_emoji_ids.iter().cloned() {
@@ -1494,7 +2188,7 @@ mod tests {
 
             // Ensure the emoji has been added to the per-guild lookup map to prevent
             // issues like #551 from returning
-            let guild_emojis = cache.guild_emojis(GuildId(1));
+            let guild_emojis = cache.guild_emojis(GuildId::new(1));
             assert!(guild_emojis.is_some());
             let guild_emojis = guild_emojis.unwrap();
 
@@ -1504,20 +2198,20 @@ mod tests {
 
         // Bulk inserts
         {
-            let guild_2_emoji_ids = (11..=20).map(EmojiId).collect::<Vec<_>>();
+            let guild_2_emoji_ids = (11..=20).map(EmojiId::new).collect::<Vec<_>>();
             let guild_2_emojis = guild_2_emoji_ids
                 .iter()
                 .copied()
                 .map(|id| emoji(id, user_mod(id)))
                 .collect::<Vec<_>>();
-            cache.cache_emojis(GuildId(2), guild_2_emojis);
+            cache.cache_emojis(GuildId::new(2), guild_2_emojis);
 
             for id in guild_2_emoji_ids.iter().cloned() {
                 let global_emoji = cache.emoji(id);
                 assert!(global_emoji.is_some());
             }
 
-            let guild_emojis = cache.guild_emojis(GuildId(2));
+            let guild_emojis = cache.guild_emojis(GuildId::new(2));
 
             assert!(guild_emojis.is_some());
             let guild_emojis = guild_emojis.unwrap();
@@ -1529,8 +2223,8 @@ mod tests {
     #[test]
     fn test_clear() {
         let cache = InMemoryCache::new();
-        cache.cache_emoji(GuildId(1), emoji(EmojiId(3), None));
-        cache.cache_member(GuildId(2), member(UserId(4), GuildId(2)));
+        cache.cache_emoji(GuildId::new(1), emoji(EmojiId::new(3), None));
+        cache.cache_member(GuildId::new(2), member(UserId::new(4), GuildId::new(2)));
         cache.clear();
         assert!(cache.0.emojis.is_empty());
         assert!(cache.0.members.is_empty());
@@ -1,9 +1,7 @@
 use serde::Serialize;
-use std::sync::Arc;
 use twilight_model::{
     guild::Emoji,
-    id::{EmojiId, RoleId},
-    user::User,
+    id::{EmojiId, RoleId, UserId},
 };
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
@@ -14,7 +12,7 @@ pub struct CachedEmoji {
     pub managed: bool,
     pub require_colons: bool,
     pub roles: Vec<RoleId>,
-    pub user: Option<Arc<User>>,
+    pub user_id: Option<UserId>,
     pub available: bool,
 }
 
@@ -39,7 +37,7 @@ mod tests {
     #[test]
     fn test_eq_emoji() {
         let emoji = Emoji {
-            id: EmojiId(123),
+            id: EmojiId::new(123),
             animated: true,
             name: "foo".to_owned(),
             managed: false,
@@ -49,13 +47,13 @@ mod tests {
             available: true,
         };
         let cached = CachedEmoji {
-            id: EmojiId(123),
+            id: EmojiId::new(123),
             animated: true,
             name: "foo".to_owned(),
             managed: false,
             require_colons: true,
             roles: vec![],
-            user: None,
+            user_id: None,
             available: true,
         };
 
@@ -71,7 +69,7 @@ mod tests {
             managed,
             require_colons,
             roles,
-            user
+            user_id
         );
     }
 
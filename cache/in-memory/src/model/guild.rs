@@ -1,10 +1,11 @@
 use serde::Serialize;
 use twilight_model::{
     guild::{
-        DefaultMessageNotificationLevel, ExplicitContentFilter, MfaLevel, Permissions, PremiumTier,
-        SystemChannelFlags, VerificationLevel,
+        DefaultMessageNotificationLevel, ExplicitContentFilter, GuildFeature, MfaLevel,
+        Permissions, PremiumTier, SystemChannelFlags, VerificationLevel,
     },
     id::{ApplicationId, ChannelId, GuildId, UserId},
+    util::{ImageHash, Locale},
 };
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
@@ -13,13 +14,13 @@ pub struct CachedGuild {
     pub afk_channel_id: Option<ChannelId>,
     pub afk_timeout: u64,
     pub application_id: Option<ApplicationId>,
-    pub banner: Option<String>,
+    pub banner: Option<ImageHash>,
     pub default_message_notifications: DefaultMessageNotificationLevel,
     pub description: Option<String>,
-    pub discovery_splash: Option<String>,
+    pub discovery_splash: Option<ImageHash>,
     pub explicit_content_filter: ExplicitContentFilter,
-    pub features: Vec<String>,
-    pub icon: Option<String>,
+    pub features: Vec<GuildFeature>,
+    pub icon: Option<ImageHash>,
     pub joined_at: Option<String>,
     pub large: bool,
     pub lazy: Option<bool>,
@@ -31,12 +32,12 @@ pub struct CachedGuild {
     pub owner: Option<bool>,
     pub owner_id: UserId,
     pub permissions: Option<Permissions>,
-    pub preferred_locale: String,
+    pub preferred_locale: Locale,
     pub premium_subscription_count: Option<u64>,
     pub premium_tier: PremiumTier,
     pub region: String,
     pub rules_channel_id: Option<ChannelId>,
-    pub splash: Option<String>,
+    pub splash: Option<ImageHash>,
     pub system_channel_id: Option<ChannelId>,
     pub system_channel_flags: SystemChannelFlags,
     pub unavailable: bool,
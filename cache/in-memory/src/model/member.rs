@@ -1,9 +1,7 @@
 use serde::Serialize;
-use std::sync::Arc;
 use twilight_model::{
     guild::{Member, PartialMember},
-    id::{GuildId, RoleId},
-    user::User,
+    id::{GuildId, RoleId, UserId},
 };
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
@@ -15,7 +13,7 @@ pub struct CachedMember {
     pub nick: Option<String>,
     pub premium_since: Option<String>,
     pub roles: Vec<RoleId>,
-    pub user: Arc<User>,
+    pub user_id: UserId,
 }
 
 impl PartialEq<Member> for CachedMember {
@@ -61,7 +59,6 @@ impl PartialEq<&PartialMember> for CachedMember {
 #[cfg(test)]
 mod tests {
     use super::CachedMember;
-    use std::sync::Arc;
     use twilight_model::{
         guild::{Member, PartialMember},
         id::{GuildId, RoleId, UserId},
@@ -71,13 +68,13 @@ mod tests {
     fn cached_member() -> CachedMember {
         CachedMember {
             deaf: false,
-            guild_id: GuildId(3),
+            guild_id: GuildId::new(3),
             joined_at: None,
             mute: true,
             nick: Some("member nick".to_owned()),
             premium_since: None,
             roles: Vec::new(),
-            user: Arc::new(user()),
+            user_id: UserId::new(1),
         }
     }
 
@@ -88,7 +85,7 @@ mod tests {
             discriminator: "0001".to_owned(),
             email: None,
             flags: None,
-            id: UserId(1),
+            id: UserId::new(1),
             locale: None,
             mfa_enabled: None,
             name: "bar".to_owned(),
@@ -103,8 +100,8 @@ mod tests {
     fn test_eq_member() {
         let member = Member {
             deaf: false,
-            guild_id: GuildId(3),
-            hoisted_role: Some(RoleId(4)),
+            guild_id: GuildId::new(3),
+            hoisted_role: Some(RoleId::new(4)),
             joined_at: None,
             mute: true,
             nick: Some("member nick".to_owned()),
@@ -3,8 +3,8 @@ use twilight_model::{
     channel::{
         embed::Embed,
         message::{
-            Message, MessageActivity, MessageApplication, MessageFlags, MessageReaction,
-            MessageReference, MessageType, Sticker,
+            Message, MessageActivity, MessageApplication, MessageFlags, MessageInteraction,
+            MessageReaction, MessageReference, MessageType, Sticker,
         },
         Attachment, ChannelMention,
     },
@@ -25,6 +25,7 @@ pub struct CachedMessage {
     pub embeds: Vec<Embed>,
     pub flags: Option<MessageFlags>,
     pub guild_id: Option<GuildId>,
+    pub interaction: Option<MessageInteraction>,
     pub kind: MessageType,
     pub member: Option<PartialMember>,
     pub mention_channels: Vec<ChannelMention>,
@@ -54,6 +55,7 @@ impl From<Message> for CachedMessage {
             embeds: msg.embeds,
             flags: msg.flags,
             guild_id: msg.guild_id,
+            interaction: msg.interaction,
             kind: msg.kind,
             member: msg.member,
             mention_channels: msg.mention_channels,
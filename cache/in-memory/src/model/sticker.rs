@@ -0,0 +1,27 @@
+use serde::Serialize;
+use twilight_model::{
+    channel::message::sticker::{Sticker, StickerFormatType, StickerId},
+    id::UserId,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct CachedSticker {
+    pub available: Option<bool>,
+    pub description: String,
+    pub format_type: StickerFormatType,
+    pub id: StickerId,
+    pub name: String,
+    pub tags: Option<String>,
+    pub user_id: Option<UserId>,
+}
+
+impl PartialEq<Sticker> for CachedSticker {
+    fn eq(&self, other: &Sticker) -> bool {
+        self.available == other.available
+            && self.description == other.description
+            && self.format_type == other.format_type
+            && self.id == other.id
+            && self.name == other.name
+            && self.tags == other.tags
+    }
+}
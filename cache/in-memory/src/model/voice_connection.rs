@@ -0,0 +1,36 @@
+use std::sync::Arc;
+use twilight_model::{gateway::payload::VoiceServerUpdate, voice::VoiceState};
+
+/// Voice connection information of the current user in a guild.
+///
+/// This contains the current user's voice state and the last voice server
+/// update received for the guild. Together they provide the session ID, token,
+/// and endpoint needed to connect to a voice server.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnVoiceConnection {
+    /// Last voice server update received for the guild, if any.
+    pub voice_server: Option<Arc<VoiceServerUpdate>>,
+    /// Voice state of the current user in the guild, if any.
+    pub voice_state: Option<Arc<VoiceState>>,
+}
+
+impl OwnVoiceConnection {
+    /// Returns the endpoint of the voice server, if known.
+    pub fn endpoint(&self) -> Option<&str> {
+        self.voice_server.as_ref()?.endpoint.as_deref()
+    }
+
+    /// Returns the session ID of the current user's voice state, if known.
+    pub fn session_id(&self) -> Option<&str> {
+        self.voice_state
+            .as_ref()
+            .map(|state| state.session_id.as_str())
+    }
+
+    /// Returns the token of the voice server, if known.
+    pub fn token(&self) -> Option<&str> {
+        self.voice_server
+            .as_ref()
+            .map(|server| server.token.as_str())
+    }
+}
@@ -0,0 +1,276 @@
+//! Metrics about cache operations.
+//!
+//! When the `metrics` feature is enabled these functions record counters via
+//! the `metrics` crate, otherwise they do nothing. Sizes of the cache are only
+//! calculated on demand via [`InMemoryCacheStats`].
+
+use crate::InMemoryCache;
+use std::hash::BuildHasher;
+
+/// Record that a resource was inserted into the cache.
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub fn insert(resource: &'static str) {
+    #[cfg(feature = "metrics")]
+    metrics::counter!("Cache-Insert", 1, "Resource" => resource);
+}
+
+/// Record that a cached resource was replaced with a newer version.
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub fn update(resource: &'static str) {
+    #[cfg(feature = "metrics")]
+    metrics::counter!("Cache-Update", 1, "Resource" => resource);
+}
+
+/// Record that a resource was inserted into the cache, or updated if it
+/// replaced a previous value.
+pub fn upsert<T>(resource: &'static str, previous: Option<T>) {
+    if previous.is_some() {
+        update(resource);
+    } else {
+        insert(resource);
+    }
+}
+
+/// Record that a resource was evicted from the cache, if anything was removed.
+///
+/// The removed value is returned.
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub fn evict<T>(resource: &'static str, removed: Option<T>) -> Option<T> {
+    #[cfg(feature = "metrics")]
+    if removed.is_some() {
+        metrics::counter!("Cache-Evict", 1, "Resource" => resource);
+    }
+
+    removed
+}
+
+/// Number of items of each resource type in an [`InMemoryCache`] at a point
+/// in time.
+///
+/// Refer to [`InMemoryCache::stats`].
+///
+/// [`InMemoryCache`]: crate::InMemoryCache
+/// [`InMemoryCache::stats`]: crate::InMemoryCache::stats
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct InMemoryCacheStats {
+    channels: usize,
+    emojis: usize,
+    groups: usize,
+    guilds: usize,
+    members: usize,
+    messages: usize,
+    presences: usize,
+    private_channels: usize,
+    roles: usize,
+    stickers: usize,
+    unavailable_guilds: usize,
+    users: usize,
+    voice_servers: usize,
+    voice_states: usize,
+}
+
+impl InMemoryCacheStats {
+    pub(crate) fn new<S: BuildHasher + Clone>(cache: &InMemoryCache<S>) -> Self {
+        let cache = &cache.0;
+
+        Self {
+            channels: cache.channels_guild.len(),
+            emojis: cache.emojis.len(),
+            groups: cache.groups.len(),
+            guilds: cache.guilds.len(),
+            members: cache.members.len(),
+            messages: cache.messages.iter().map(|c| c.value().len()).sum(),
+            presences: cache.presences.len(),
+            private_channels: cache.channels_private.len(),
+            roles: cache.roles.len(),
+            stickers: cache.stickers.len(),
+            unavailable_guilds: cache.unavailable_guilds.len(),
+            users: cache.users.len(),
+            voice_servers: cache.voice_servers.len(),
+            voice_states: cache.voice_states.len(),
+        }
+    }
+
+    /// Number of cached guild channels.
+    pub const fn channels(&self) -> usize {
+        self.channels
+    }
+
+    /// Number of cached emojis.
+    pub const fn emojis(&self) -> usize {
+        self.emojis
+    }
+
+    /// Number of cached groups.
+    pub const fn groups(&self) -> usize {
+        self.groups
+    }
+
+    /// Number of cached guilds.
+    pub const fn guilds(&self) -> usize {
+        self.guilds
+    }
+
+    /// Number of cached members across all guilds.
+    pub const fn members(&self) -> usize {
+        self.members
+    }
+
+    /// Number of cached messages across all channels.
+    pub const fn messages(&self) -> usize {
+        self.messages
+    }
+
+    /// Number of cached presences across all guilds.
+    pub const fn presences(&self) -> usize {
+        self.presences
+    }
+
+    /// Number of cached private channels.
+    pub const fn private_channels(&self) -> usize {
+        self.private_channels
+    }
+
+    /// Number of cached roles.
+    pub const fn roles(&self) -> usize {
+        self.roles
+    }
+
+    /// Number of cached stickers.
+    pub const fn stickers(&self) -> usize {
+        self.stickers
+    }
+
+    /// Number of guilds known to be unavailable.
+    pub const fn unavailable_guilds(&self) -> usize {
+        self.unavailable_guilds
+    }
+
+    /// Number of cached users.
+    pub const fn users(&self) -> usize {
+        self.users
+    }
+
+    /// Number of cached voice servers.
+    pub const fn voice_servers(&self) -> usize {
+        self.voice_servers
+    }
+
+    /// Number of cached voice states.
+    pub const fn voice_states(&self) -> usize {
+        self.voice_states
+    }
+
+    /// Record the number of items of each resource type as `Cache-Size`
+    /// gauges, labelled by the type of resource.
+    #[cfg(feature = "metrics")]
+    pub fn record(&self) {
+        use std::convert::TryInto;
+
+        for (resource, len) in [
+            ("Channel", self.channels),
+            ("Emoji", self.emojis),
+            ("Group", self.groups),
+            ("Guild", self.guilds),
+            ("Member", self.members),
+            ("Message", self.messages),
+            ("Presence", self.presences),
+            ("PrivateChannel", self.private_channels),
+            ("Role", self.roles),
+            ("Sticker", self.stickers),
+            ("UnavailableGuild", self.unavailable_guilds),
+            ("User", self.users),
+            ("VoiceServer", self.voice_servers),
+            ("VoiceState", self.voice_states),
+        ]
+        .iter()
+        {
+            metrics::gauge!("Cache-Size", (*len).try_into().unwrap_or(-1), "Resource" => *resource);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InMemoryCacheStats;
+    use crate::InMemoryCache;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        gateway::payload::{MemberRemove, RoleDelete},
+        guild::{Member, Permissions, Role},
+        id::{GuildId, RoleId, UserId},
+        user::User,
+    };
+
+    assert_impl_all!(InMemoryCacheStats: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+
+    fn user(id: UserId) -> User {
+        User {
+            avatar: None,
+            bot: false,
+            discriminator: "0001".to_owned(),
+            email: None,
+            flags: None,
+            id,
+            locale: None,
+            mfa_enabled: None,
+            name: "user".to_owned(),
+            premium_type: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let cache = InMemoryCache::new();
+        assert_eq!(InMemoryCacheStats::default(), cache.stats());
+
+        cache.cache_member(
+            GuildId::new(1),
+            Member {
+                deaf: false,
+                guild_id: GuildId::new(1),
+                hoisted_role: None,
+                joined_at: None,
+                mute: false,
+                nick: None,
+                premium_since: None,
+                roles: Vec::new(),
+                user: user(UserId::new(2)),
+            },
+        );
+        cache.cache_role(
+            GuildId::new(1),
+            Role {
+                color: 0,
+                hoist: false,
+                id: RoleId::new(3),
+                managed: false,
+                mentionable: false,
+                name: "role".to_owned(),
+                permissions: Permissions::empty(),
+                position: 0,
+                tags: None,
+            },
+        );
+
+        let stats = cache.stats();
+        assert_eq!(1, stats.members());
+        assert_eq!(1, stats.roles());
+        assert_eq!(1, stats.users());
+        assert_eq!(0, stats.guilds());
+
+        cache.update(&MemberRemove {
+            guild_id: GuildId::new(1),
+            user: user(UserId::new(2)),
+        });
+        cache.update(&RoleDelete {
+            guild_id: GuildId::new(1),
+            role_id: RoleId::new(3),
+        });
+        assert_eq!(InMemoryCacheStats::default(), cache.stats());
+    }
+}
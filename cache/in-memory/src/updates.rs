@@ -1,6 +1,12 @@
-use super::{config::ResourceType, InMemoryCache};
+use super::{config::ResourceType, stats, CacheChange, InMemoryCache};
 use dashmap::DashMap;
-use std::{borrow::Cow, collections::HashSet, hash::Hash, ops::Deref, sync::Arc};
+use std::{
+    borrow::Cow,
+    collections::{hash_map::RandomState, HashSet},
+    hash::{BuildHasher, Hash},
+    ops::Deref,
+    sync::Arc,
+};
 use twilight_model::{
     channel::{message::MessageReaction, Channel, GuildChannel, ReactionType},
     gateway::{event::Event, payload::*, presence::Presence},
@@ -8,18 +14,26 @@ use twilight_model::{
     id::GuildId,
 };
 
-pub trait UpdateCache {
+/// Update the cache with an event.
+///
+/// The cache's hasher is a parameter of the trait rather than of the method,
+/// so that the trait can be used as a trait object.
+pub trait UpdateCache<S = RandomState> {
     // Allow this for presentation purposes in documentation.
     #[allow(unused_variables)]
-    fn update(&self, cache: &InMemoryCache) {}
+    fn update(&self, cache: &InMemoryCache<S>) {}
 }
 
-impl UpdateCache for Event {
+impl<S: BuildHasher + Clone> UpdateCache<S> for Event {
     #[allow(clippy::cognitive_complexity)]
-    fn update(&self, c: &InMemoryCache) {
+    fn update(&self, c: &InMemoryCache<S>) {
         use Event::*;
 
         match self {
+            AutoModerationActionExecution(_) => {}
+            AutoModerationRuleCreate(_) => {}
+            AutoModerationRuleDelete(_) => {}
+            AutoModerationRuleUpdate(_) => {}
             BanAdd(_) => {}
             BanRemove(_) => {}
             ChannelCreate(v) => c.update(v),
@@ -36,6 +50,12 @@ impl UpdateCache for Event {
             GuildDelete(v) => c.update(v.deref()),
             GuildEmojisUpdate(v) => c.update(v),
             GuildIntegrationsUpdate(v) => c.update(v),
+            GuildScheduledEventCreate(v) => c.update(v.deref()),
+            GuildScheduledEventDelete(v) => c.update(v.deref()),
+            GuildScheduledEventUpdate(v) => c.update(v.deref()),
+            GuildScheduledEventUserAdd(v) => c.update(v),
+            GuildScheduledEventUserRemove(v) => c.update(v),
+            GuildStickersUpdate(v) => c.update(v),
             GuildUpdate(v) => c.update(v.deref()),
             InviteCreate(_) => {}
             InviteDelete(_) => {}
@@ -75,19 +95,27 @@ impl UpdateCache for Event {
     }
 }
 
-impl UpdateCache for BanAdd {}
+impl<S> UpdateCache<S> for AutoModerationActionExecution {}
+
+impl<S> UpdateCache<S> for AutoModerationRuleCreate {}
+
+impl<S> UpdateCache<S> for AutoModerationRuleDelete {}
 
-impl UpdateCache for BanRemove {}
+impl<S> UpdateCache<S> for AutoModerationRuleUpdate {}
 
-impl UpdateCache for ChannelCreate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S> UpdateCache<S> for BanAdd {}
+
+impl<S> UpdateCache<S> for BanRemove {}
+
+impl<S: BuildHasher + Clone> UpdateCache<S> for ChannelCreate {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if !cache.wants(ResourceType::CHANNEL) {
             return;
         }
 
         match &self.0 {
             Channel::Group(c) => {
-                super::upsert_item(&cache.0.groups, c.id, c.clone());
+                super::upsert_item(&cache.0.groups, "Group", c.id, c.clone());
             }
             Channel::Guild(c) => {
                 if let Some(gid) = c.guild_id() {
@@ -101,8 +129,8 @@ impl UpdateCache for ChannelCreate {
     }
 }
 
-impl UpdateCache for ChannelDelete {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S: BuildHasher + Clone> UpdateCache<S> for ChannelDelete {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if !cache.wants(ResourceType::CHANNEL) {
             return;
         }
@@ -115,14 +143,14 @@ impl UpdateCache for ChannelDelete {
                 cache.delete_guild_channel(c.id());
             }
             Channel::Private(ref c) => {
-                cache.0.channels_private.remove(&c.id);
+                stats::evict("PrivateChannel", cache.0.channels_private.remove(&c.id));
             }
         }
     }
 }
 
-impl UpdateCache for ChannelPinsUpdate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S: BuildHasher + Clone> UpdateCache<S> for ChannelPinsUpdate {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if !cache.wants(ResourceType::CHANNEL) {
             return;
         }
@@ -149,8 +177,8 @@ impl UpdateCache for ChannelPinsUpdate {
     }
 }
 
-impl UpdateCache for ChannelUpdate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S: BuildHasher + Clone> UpdateCache<S> for ChannelUpdate {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if !cache.wants(ResourceType::CHANNEL) {
             return;
         }
@@ -171,8 +199,8 @@ impl UpdateCache for ChannelUpdate {
     }
 }
 
-impl UpdateCache for GuildCreate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S: BuildHasher + Clone> UpdateCache<S> for GuildCreate {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if !cache.wants(ResourceType::GUILD) {
             return;
         }
@@ -181,16 +209,17 @@ impl UpdateCache for GuildCreate {
     }
 }
 
-impl UpdateCache for GuildDelete {
-    fn update(&self, cache: &InMemoryCache) {
-        fn remove_ids<T: Eq + Hash, U>(
-            guild_map: &DashMap<GuildId, HashSet<T>>,
-            container: &DashMap<T, U>,
+impl<S: BuildHasher + Clone> UpdateCache<S> for GuildDelete {
+    fn update(&self, cache: &InMemoryCache<S>) {
+        fn remove_ids<T: Eq + Hash, U, S: BuildHasher + Clone>(
+            guild_map: &DashMap<GuildId, Arc<HashSet<T>>, S>,
+            container: &DashMap<T, U, S>,
+            resource: &'static str,
             guild_id: GuildId,
         ) {
             if let Some((_, ids)) = guild_map.remove(&guild_id) {
-                for id in ids {
-                    container.remove(&id);
+                for id in ids.iter() {
+                    stats::evict(resource, container.remove(id));
                 }
             }
         }
@@ -201,63 +230,155 @@ impl UpdateCache for GuildDelete {
 
         let id = self.id;
 
-        cache.0.guilds.remove(&id);
+        if stats::evict("Guild", cache.0.guilds.remove(&id)).is_some() {
+            cache.emit(|| CacheChange::GuildRemoved { guild: id });
+        }
 
         if cache.wants(ResourceType::CHANNEL) {
-            remove_ids(&cache.0.guild_channels, &cache.0.channels_guild, id);
+            remove_ids(
+                &cache.0.guild_channels,
+                &cache.0.channels_guild,
+                "Channel",
+                id,
+            );
         }
 
         if cache.wants(ResourceType::EMOJI) {
-            remove_ids(&cache.0.guild_emojis, &cache.0.emojis, id);
+            if let Some((_, ids)) = cache.0.guild_emojis.remove(&id) {
+                for emoji_id in ids.iter() {
+                    let removed = stats::evict("Emoji", cache.0.emojis.remove(emoji_id));
+
+                    if let Some(user_id) = removed.and_then(|(_, emoji)| emoji.data.user_id) {
+                        cache.dereference_user(user_id);
+                    }
+                }
+            }
         }
 
         if cache.wants(ResourceType::ROLE) {
-            remove_ids(&cache.0.guild_roles, &cache.0.roles, id);
+            remove_ids(&cache.0.guild_roles, &cache.0.roles, "Role", id);
+        }
+
+        if cache.wants(ResourceType::STICKER) {
+            if let Some((_, ids)) = cache.0.guild_stickers.remove(&id) {
+                for sticker_id in ids.iter() {
+                    let removed = stats::evict("Sticker", cache.0.stickers.remove(sticker_id));
+
+                    if let Some(user_id) = removed.and_then(|(_, sticker)| sticker.data.user_id) {
+                        cache.dereference_user(user_id);
+                    }
+                }
+            }
         }
 
         if cache.wants(ResourceType::VOICE_STATE) {
             // Clear out a guilds voice states when a guild leaves
             cache.0.voice_state_guilds.remove(&id);
+            stats::evict("VoiceServer", cache.0.voice_servers.remove(&id));
         }
 
         if cache.wants(ResourceType::MEMBER) {
             if let Some((_, ids)) = cache.0.guild_members.remove(&id) {
-                for user_id in ids {
-                    cache.0.members.remove(&(id, user_id));
+                for user_id in ids.iter() {
+                    let removed = stats::evict("Member", cache.0.members.remove(&(id, *user_id)));
+
+                    if removed.is_some() {
+                        cache.dereference_user(*user_id);
+                    }
                 }
             }
         }
 
         if cache.wants(ResourceType::PRESENCE) {
             if let Some((_, ids)) = cache.0.guild_presences.remove(&id) {
-                for user_id in ids {
-                    cache.0.presences.remove(&(id, user_id));
+                for user_id in ids.iter() {
+                    stats::evict("Presence", cache.0.presences.remove(&(id, *user_id)));
                 }
             }
         }
     }
 }
 
-impl UpdateCache for GuildEmojisUpdate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S: BuildHasher + Clone> UpdateCache<S> for GuildEmojisUpdate {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if !cache.wants(ResourceType::EMOJI) {
             return;
         }
 
+        // Emojis that aren't in the update have been deleted.
+        if let Some(mut guild_emojis) = cache.0.guild_emojis.get_mut(&self.guild_id) {
+            Arc::make_mut(&mut guild_emojis).retain(|id| {
+                let keep = self.emojis.iter().any(|emoji| emoji.id == *id);
+
+                if !keep {
+                    let removed = stats::evict("Emoji", cache.0.emojis.remove(id));
+
+                    if let Some(user_id) = removed.and_then(|(_, emoji)| emoji.data.user_id) {
+                        cache.dereference_user(user_id);
+                    }
+                }
+
+                keep
+            });
+        }
+
         cache.cache_emojis(self.guild_id, self.emojis.clone());
     }
 }
 
-impl UpdateCache for GuildIntegrationsUpdate {}
+impl<S> UpdateCache<S> for GuildIntegrationsUpdate {}
+
+impl<S> UpdateCache<S> for GuildScheduledEventCreate {}
+
+impl<S> UpdateCache<S> for GuildScheduledEventDelete {}
+
+impl<S> UpdateCache<S> for GuildScheduledEventUpdate {}
+
+impl<S> UpdateCache<S> for GuildScheduledEventUserAdd {}
+
+impl<S> UpdateCache<S> for GuildScheduledEventUserRemove {}
 
-impl UpdateCache for GuildUpdate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S: BuildHasher + Clone> UpdateCache<S> for GuildStickersUpdate {
+    fn update(&self, cache: &InMemoryCache<S>) {
+        if !cache.wants(ResourceType::STICKER) {
+            return;
+        }
+
+        // Stickers that aren't in the update have been deleted.
+        if let Some(mut guild_stickers) = cache.0.guild_stickers.get_mut(&self.guild_id) {
+            Arc::make_mut(&mut guild_stickers).retain(|id| {
+                let keep = self.stickers.iter().any(|sticker| sticker.id == *id);
+
+                if !keep {
+                    let removed = stats::evict("Sticker", cache.0.stickers.remove(id));
+
+                    if let Some(user_id) = removed.and_then(|(_, sticker)| sticker.data.user_id) {
+                        cache.dereference_user(user_id);
+                    }
+                }
+
+                keep
+            });
+        }
+
+        cache.cache_stickers(self.guild_id, self.stickers.clone());
+    }
+}
+
+impl<S: BuildHasher + Clone> UpdateCache<S> for GuildUpdate {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if !cache.wants(ResourceType::GUILD) {
             return;
         }
 
-        if let Some(mut guild) = cache.0.guilds.get_mut(&self.0.id) {
-            let mut guild = Arc::make_mut(&mut guild);
+        if let Some(mut entry) = cache.0.guilds.get_mut(&self.0.id) {
+            let old = if cache.is_subscribed() {
+                Some(Arc::clone(&entry))
+            } else {
+                None
+            };
+
+            let mut guild = Arc::make_mut(&mut entry);
             guild.afk_channel_id = self.afk_channel_id;
             guild.afk_timeout = self.afk_timeout;
             guild.banner = self.banner.clone();
@@ -284,29 +405,32 @@ impl UpdateCache for GuildUpdate {
             guild.vanity_url_code = self.vanity_url_code.clone();
             guild.widget_channel_id = self.widget_channel_id;
             guild.widget_enabled = self.widget_enabled;
+
+            if let Some(old) = old {
+                cache.emit(|| CacheChange::GuildUpdated {
+                    old,
+                    new: Arc::clone(&entry),
+                });
+            }
         };
     }
 }
 
-impl UpdateCache for MemberAdd {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S: BuildHasher + Clone> UpdateCache<S> for MemberAdd {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if !cache.wants(ResourceType::MEMBER) {
             return;
         }
 
         cache.cache_member(self.guild_id, self.0.clone());
 
-        cache
-            .0
-            .guild_members
-            .entry(self.guild_id)
-            .or_default()
+        Arc::make_mut(&mut cache.0.guild_members.entry(self.guild_id).or_default())
             .insert(self.0.user.id);
     }
 }
 
-impl UpdateCache for MemberChunk {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S: BuildHasher + Clone> UpdateCache<S> for MemberChunk {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if !cache.wants(ResourceType::MEMBER) {
             return;
         }
@@ -317,61 +441,74 @@ impl UpdateCache for MemberChunk {
 
         cache.cache_members(self.guild_id, self.members.clone());
         let mut guild = cache.0.guild_members.entry(self.guild_id).or_default();
-        guild.extend(self.members.iter().map(|member| member.user.id));
+        Arc::make_mut(&mut guild).extend(self.members.iter().map(|member| member.user.id));
     }
 }
 
-impl UpdateCache for MemberRemove {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S: BuildHasher + Clone> UpdateCache<S> for MemberRemove {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if !cache.wants(ResourceType::MEMBER) {
             return;
         }
 
-        cache.0.members.remove(&(self.guild_id, self.user.id));
+        let removed = stats::evict(
+            "Member",
+            cache.0.members.remove(&(self.guild_id, self.user.id)),
+        );
 
-        if let Some(mut members) = cache.0.guild_members.get_mut(&self.guild_id) {
-            members.remove(&self.user.id);
+        if removed.is_some() {
+            cache.emit(|| CacheChange::MemberRemoved {
+                guild: self.guild_id,
+                user: self.user.id,
+            });
         }
 
-        // Avoid a deadlock by mutating the user, dropping the lock to the map,
-        // and then maybe conditionally removing the user later.
-        let mut maybe_remove_user = false;
-
-        if let Some(mut user_tuple) = cache.0.users.get_mut(&self.user.id) {
-            user_tuple.1.remove(&self.guild_id);
-
-            maybe_remove_user = true;
+        if let Some(mut members) = cache.0.guild_members.get_mut(&self.guild_id) {
+            Arc::make_mut(&mut members).remove(&self.user.id);
         }
 
-        if maybe_remove_user {
-            cache
-                .0
-                .users
-                .remove_if(&self.user.id, |_, guild_set| guild_set.1.is_empty());
+        // The user may still be referenced by other guilds' members or by
+        // cached messages, emojis, and stickers.
+        if removed.is_some() {
+            cache.dereference_user(self.user.id);
         }
     }
 }
 
-impl UpdateCache for MemberUpdate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S: BuildHasher + Clone> UpdateCache<S> for MemberUpdate {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if !cache.wants(ResourceType::MEMBER) {
             return;
         }
 
-        let mut member = match cache.0.members.get_mut(&(self.guild_id, self.user.id)) {
+        cache.cache_user(Cow::Borrowed(&self.user), false);
+
+        let mut entry = match cache.0.members.get_mut(&(self.guild_id, self.user.id)) {
             Some(member) => member,
             None => return,
         };
-        let mut member = Arc::make_mut(&mut member);
+        let old = if cache.is_subscribed() {
+            Some(Arc::clone(&entry))
+        } else {
+            None
+        };
+        let mut member = Arc::make_mut(&mut entry);
 
         member.nick = self.nick.clone();
         member.roles = self.roles.clone();
         member.joined_at.replace(self.joined_at.clone());
+
+        if let Some(old) = old.filter(|old| **old != **entry) {
+            cache.emit(|| CacheChange::MemberUpdated {
+                old,
+                new: Arc::clone(&entry),
+            });
+        }
     }
 }
 
-impl UpdateCache for MessageCreate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S: BuildHasher + Clone> UpdateCache<S> for MessageCreate {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if !cache.wants(ResourceType::MESSAGE) {
             return;
         }
@@ -380,33 +517,40 @@ impl UpdateCache for MessageCreate {
 
         if channel.len() > cache.0.config.message_cache_size() {
             if let Some(k) = channel.iter().next_back().map(|x| *x.0) {
-                channel.remove(&k);
+                if let Some(message) = stats::evict("Message", channel.remove(&k)) {
+                    cache.dereference_user(message.author);
+                }
             }
         }
 
-        channel.insert(self.0.id, Arc::new(From::from(self.0.clone())));
+        let previous = channel.insert(self.0.id, Arc::new(From::from(self.0.clone())));
 
-        let user = cache.cache_user(Cow::Borrowed(&self.author), self.guild_id);
+        // Authors are cached whether or not the message is in a guild.
+        cache.cache_user(Cow::Borrowed(&self.author), previous.is_none());
+        stats::upsert("Message", previous);
 
         if let (Some(member), Some(guild_id)) = (&self.member, self.guild_id) {
-            cache.cache_borrowed_partial_member(guild_id, member, user);
+            cache.cache_borrowed_partial_member(guild_id, member, &self.author);
         }
     }
 }
 
-impl UpdateCache for MessageDelete {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S: BuildHasher + Clone> UpdateCache<S> for MessageDelete {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if !cache.wants(ResourceType::MESSAGE) {
             return;
         }
 
         let mut channel = cache.0.messages.entry(self.channel_id).or_default();
-        channel.remove(&self.id);
+
+        if let Some(message) = stats::evict("Message", channel.remove(&self.id)) {
+            cache.dereference_user(message.author);
+        }
     }
 }
 
-impl UpdateCache for MessageDeleteBulk {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S: BuildHasher + Clone> UpdateCache<S> for MessageDeleteBulk {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if !cache.wants(ResourceType::MESSAGE) {
             return;
         }
@@ -414,13 +558,15 @@ impl UpdateCache for MessageDeleteBulk {
         let mut channel = cache.0.messages.entry(self.channel_id).or_default();
 
         for id in &self.ids {
-            channel.remove(id);
+            if let Some(message) = stats::evict("Message", channel.remove(id)) {
+                cache.dereference_user(message.author);
+            }
         }
     }
 }
 
-impl UpdateCache for MessageUpdate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S: BuildHasher + Clone> UpdateCache<S> for MessageUpdate {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if !cache.wants(ResourceType::MESSAGE) {
             return;
         }
@@ -473,8 +619,8 @@ impl UpdateCache for MessageUpdate {
     }
 }
 
-impl UpdateCache for PresenceUpdate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S: BuildHasher + Clone> UpdateCache<S> for PresenceUpdate {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if !cache.wants(ResourceType::PRESENCE) {
             return;
         }
@@ -491,8 +637,8 @@ impl UpdateCache for PresenceUpdate {
     }
 }
 
-impl UpdateCache for ReactionAdd {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S: BuildHasher + Clone> UpdateCache<S> for ReactionAdd {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if !cache.wants(ResourceType::REACTION) {
             return;
         }
@@ -531,8 +677,8 @@ impl UpdateCache for ReactionAdd {
     }
 }
 
-impl UpdateCache for ReactionRemove {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S: BuildHasher + Clone> UpdateCache<S> for ReactionRemove {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if !cache.wants(ResourceType::REACTION) {
             return;
         }
@@ -564,8 +710,8 @@ impl UpdateCache for ReactionRemove {
     }
 }
 
-impl UpdateCache for ReactionRemoveAll {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S: BuildHasher + Clone> UpdateCache<S> for ReactionRemoveAll {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if !cache.wants(ResourceType::REACTION) {
             return;
         }
@@ -582,8 +728,8 @@ impl UpdateCache for ReactionRemoveAll {
     }
 }
 
-impl UpdateCache for ReactionRemoveEmoji {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S: BuildHasher + Clone> UpdateCache<S> for ReactionRemoveEmoji {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if !cache.wants(ResourceType::REACTION) {
             return;
         }
@@ -610,8 +756,8 @@ impl UpdateCache for ReactionRemoveEmoji {
     }
 }
 
-impl UpdateCache for Ready {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S: BuildHasher + Clone> UpdateCache<S> for Ready {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if cache.wants(ResourceType::USER_CURRENT) {
             cache.cache_current_user(self.user.clone());
         }
@@ -631,23 +777,18 @@ impl UpdateCache for Ready {
     }
 }
 
-impl UpdateCache for RoleCreate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S: BuildHasher + Clone> UpdateCache<S> for RoleCreate {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if !cache.wants(ResourceType::ROLE) {
             return;
         }
 
-        super::upsert_guild_item(
-            &cache.0.roles,
-            self.guild_id,
-            self.role.id,
-            self.role.clone(),
-        );
+        cache.cache_role(self.guild_id, self.role.clone());
     }
 }
 
-impl UpdateCache for RoleDelete {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S: BuildHasher + Clone> UpdateCache<S> for RoleDelete {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if !cache.wants(ResourceType::ROLE) {
             return;
         }
@@ -656,8 +797,8 @@ impl UpdateCache for RoleDelete {
     }
 }
 
-impl UpdateCache for RoleUpdate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S: BuildHasher + Clone> UpdateCache<S> for RoleUpdate {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if !cache.wants(ResourceType::ROLE) {
             return;
         }
@@ -666,21 +807,20 @@ impl UpdateCache for RoleUpdate {
     }
 }
 
-impl UpdateCache for TypingStart {}
+impl<S> UpdateCache<S> for TypingStart {}
 
-impl UpdateCache for UnavailableGuild {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S: BuildHasher + Clone> UpdateCache<S> for UnavailableGuild {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if !cache.wants(ResourceType::GUILD) {
             return;
         }
 
-        cache.0.guilds.remove(&self.id);
-        cache.0.unavailable_guilds.insert(self.id);
+        cache.unavailable_guild(self.id);
     }
 }
 
-impl UpdateCache for UserUpdate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S: BuildHasher + Clone> UpdateCache<S> for UserUpdate {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if !cache.wants(ResourceType::USER_CURRENT) {
             return;
         }
@@ -689,12 +829,18 @@ impl UpdateCache for UserUpdate {
     }
 }
 
-impl UpdateCache for VoiceServerUpdate {
-    fn update(&self, _: &InMemoryCache) {}
+impl<S: BuildHasher + Clone> UpdateCache<S> for VoiceServerUpdate {
+    fn update(&self, cache: &InMemoryCache<S>) {
+        if !cache.wants(ResourceType::VOICE_STATE) {
+            return;
+        }
+
+        cache.cache_voice_server(self.clone());
+    }
 }
 
-impl UpdateCache for VoiceStateUpdate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<S: BuildHasher + Clone> UpdateCache<S> for VoiceStateUpdate {
+    fn update(&self, cache: &InMemoryCache<S>) {
         if !cache.wants(ResourceType::VOICE_STATE) {
             return;
         }
@@ -707,12 +853,13 @@ impl UpdateCache for VoiceStateUpdate {
     }
 }
 
-impl UpdateCache for WebhooksUpdate {}
+impl<S> UpdateCache<S> for WebhooksUpdate {}
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::config::ResourceType;
+    use static_assertions::assert_obj_safe;
     use twilight_model::{
         channel::{
             message::{MessageFlags, MessageType},
@@ -725,13 +872,20 @@ mod tests {
         },
         id::{ChannelId, GuildId, MessageId, UserId},
         user::User,
+        util::{ImageHash, Locale},
         voice::VoiceState,
     };
 
+    assert_obj_safe!(UpdateCache);
+
     fn guild_channel_text() -> (GuildId, ChannelId, GuildChannel) {
-        let guild_id = GuildId(1);
-        let channel_id = ChannelId(2);
+        let guild_id = GuildId::new(1);
+        let channel_id = ChannelId::new(2);
         let channel = GuildChannel::Text(TextChannel {
+            available_tags: Vec::new(),
+            default_forum_layout: None,
+            default_reaction_emoji: None,
+            default_sort_order: None,
             guild_id: Some(guild_id),
             id: channel_id,
             kind: ChannelType::GuildText,
@@ -749,6 +903,58 @@ mod tests {
         (guild_id, channel_id, channel)
     }
 
+    fn message(id: MessageId, guild_id: Option<GuildId>) -> Message {
+        Message {
+            activity: None,
+            application: None,
+            attachments: Vec::new(),
+            author: User {
+                avatar: None,
+                bot: false,
+                discriminator: "0001".to_owned(),
+                email: None,
+                flags: None,
+                id: UserId::new(3),
+                locale: None,
+                mfa_enabled: None,
+                name: "test".to_owned(),
+                premium_type: None,
+                public_flags: None,
+                system: None,
+                verified: None,
+            },
+            channel_id: ChannelId::new(2),
+            content: "ping".to_owned(),
+            edited_timestamp: None,
+            embeds: Vec::new(),
+            flags: Some(MessageFlags::empty()),
+            guild_id,
+            id,
+            interaction: None,
+            kind: MessageType::Regular,
+            member: guild_id.map(|_| PartialMember {
+                deaf: false,
+                joined_at: None,
+                mute: false,
+                nick: None,
+                premium_since: None,
+                roles: Vec::new(),
+            }),
+            mention_channels: Vec::new(),
+            mention_everyone: false,
+            mention_roles: Vec::new(),
+            mentions: Vec::new(),
+            pinned: false,
+            reactions: Vec::new(),
+            reference: None,
+            stickers: Vec::new(),
+            referenced_message: None,
+            timestamp: String::new(),
+            tts: false,
+            webhook_id: None,
+        }
+    }
+
     fn cache_with_message_and_reactions() -> InMemoryCache {
         let cache = InMemoryCache::new();
 
@@ -757,12 +963,12 @@ mod tests {
             application: None,
             attachments: Vec::new(),
             author: User {
-                avatar: Some("".to_owned()),
+                avatar: Some(ImageHash::parse("5bd5c7c3f2a4ab7d2e3a1b9c9a4d3e2f").unwrap()),
                 bot: false,
                 discriminator: "0001".to_owned(),
                 email: None,
                 flags: None,
-                id: UserId(3),
+                id: UserId::new(3),
                 locale: None,
                 mfa_enabled: None,
                 name: "test".to_owned(),
@@ -771,13 +977,14 @@ mod tests {
                 system: None,
                 verified: None,
             },
-            channel_id: ChannelId(2),
+            channel_id: ChannelId::new(2),
             content: "ping".to_owned(),
             edited_timestamp: None,
             embeds: Vec::new(),
             flags: Some(MessageFlags::empty()),
-            guild_id: Some(GuildId(1)),
-            id: MessageId(4),
+            guild_id: Some(GuildId::new(1)),
+            id: MessageId::new(4),
+            interaction: None,
             kind: MessageType::Regular,
             member: Some(PartialMember {
                 deaf: false,
@@ -804,14 +1011,14 @@ mod tests {
         cache.update(&MessageCreate(msg));
 
         let mut reaction = ReactionAdd(Reaction {
-            channel_id: ChannelId(2),
+            channel_id: ChannelId::new(2),
             emoji: ReactionType::Unicode {
                 name: "😀".to_owned(),
             },
-            guild_id: Some(GuildId(1)),
+            guild_id: Some(GuildId::new(1)),
             member: Some(Member {
                 deaf: false,
-                guild_id: GuildId(1),
+                guild_id: GuildId::new(1),
                 hoisted_role: None,
                 joined_at: None,
                 mute: false,
@@ -819,12 +1026,12 @@ mod tests {
                 premium_since: None,
                 roles: Vec::new(),
                 user: User {
-                    avatar: Some("".to_owned()),
+                    avatar: Some(ImageHash::parse("5bd5c7c3f2a4ab7d2e3a1b9c9a4d3e2f").unwrap()),
                     bot: false,
                     discriminator: "0001".to_owned(),
                     email: None,
                     flags: None,
-                    id: UserId(3),
+                    id: UserId::new(3),
                     locale: None,
                     mfa_enabled: None,
                     name: "test".to_owned(),
@@ -834,15 +1041,15 @@ mod tests {
                     verified: None,
                 },
             }),
-            message_id: MessageId(4),
-            user_id: UserId(3),
+            message_id: MessageId::new(4),
+            user_id: UserId::new(3),
         });
 
         cache.update(&reaction);
 
         reaction.member.replace(Member {
             deaf: false,
-            guild_id: GuildId(1),
+            guild_id: GuildId::new(1),
             hoisted_role: None,
             joined_at: None,
             mute: false,
@@ -850,12 +1057,12 @@ mod tests {
             premium_since: None,
             roles: Vec::new(),
             user: User {
-                avatar: Some("".to_owned()),
+                avatar: Some(ImageHash::parse("5bd5c7c3f2a4ab7d2e3a1b9c9a4d3e2f").unwrap()),
                 bot: false,
                 discriminator: "0002".to_owned(),
                 email: None,
                 flags: None,
-                id: UserId(5),
+                id: UserId::new(5),
                 locale: None,
                 mfa_enabled: None,
                 name: "test".to_owned(),
@@ -865,7 +1072,7 @@ mod tests {
                 verified: None,
             },
         });
-        reaction.user_id = UserId(5);
+        reaction.user_id = UserId::new(5);
 
         cache.update(&reaction);
 
@@ -896,7 +1103,7 @@ mod tests {
             explicit_content_filter: ExplicitContentFilter::None,
             features: Vec::new(),
             icon: None,
-            id: GuildId(1),
+            id: GuildId::new(1),
             joined_at: None,
             large: false,
             lazy: None,
@@ -907,10 +1114,10 @@ mod tests {
             members: Vec::new(),
             mfa_level: MfaLevel::None,
             name: "test".to_owned(),
-            owner_id: UserId(1),
+            owner_id: UserId::new(1),
             owner: None,
             permissions: None,
-            preferred_locale: "en_us".to_owned(),
+            preferred_locale: Locale::EnglishUs,
             premium_subscription_count: None,
             premium_tier: PremiumTier::None,
             presences: Vec::new(),
@@ -918,6 +1125,7 @@ mod tests {
             roles: Vec::new(),
             rules_channel_id: None,
             splash: None,
+            stickers: Vec::new(),
             system_channel_flags: SystemChannelFlags::empty(),
             system_channel_id: None,
             unavailable: false,
@@ -948,7 +1156,7 @@ mod tests {
             member_count: guild.member_count,
             mfa_level: guild.mfa_level,
             name: "test2222".to_owned(),
-            owner_id: UserId(2),
+            owner_id: UserId::new(2),
             owner: guild.owner,
             permissions: guild.permissions,
             preferred_locale: guild.preferred_locale,
@@ -992,6 +1200,78 @@ mod tests {
         assert!(cache.0.guild_channels.get(&guild_id).unwrap().is_empty());
     }
 
+    #[test]
+    fn test_guild_delete_dereferences_users() {
+        let cache = cache_with_message_and_reactions();
+        let (guild_id, _, channel) = guild_channel_text();
+        cache.cache_guild_channel(guild_id, channel);
+
+        // The user is referenced by both its message and its member.
+        assert_eq!(2, cache.0.users.get(&UserId::new(3)).unwrap().1);
+
+        cache.update(&GuildDelete {
+            id: guild_id,
+            unavailable: false,
+        });
+
+        // The member was removed, but the message still references the user.
+        assert!(cache.member(guild_id, UserId::new(3)).is_none());
+        assert_eq!(1, cache.0.users.get(&UserId::new(3)).unwrap().1);
+
+        cache.update(&MessageDelete {
+            channel_id: ChannelId::new(2),
+            guild_id: Some(guild_id),
+            id: MessageId::new(4),
+        });
+        assert!(cache.user(UserId::new(3)).is_none());
+    }
+
+    #[test]
+    fn test_message_create_private_author() {
+        let cache = InMemoryCache::new();
+        cache.update(&MessageCreate(message(MessageId::new(4), None)));
+
+        assert_eq!("test", cache.user(UserId::new(3)).unwrap().name);
+        assert_eq!(1, cache.0.users.get(&UserId::new(3)).unwrap().1);
+
+        // Creating the same message again doesn't add a reference.
+        cache.update(&MessageCreate(message(MessageId::new(4), None)));
+        assert_eq!(1, cache.0.users.get(&UserId::new(3)).unwrap().1);
+
+        cache.update(&MessageDelete {
+            channel_id: ChannelId::new(2),
+            guild_id: None,
+            id: MessageId::new(4),
+        });
+        assert!(cache.user(UserId::new(3)).is_none());
+    }
+
+    #[test]
+    fn test_member_remove_keeps_message_author() {
+        let cache = InMemoryCache::new();
+        cache.update(&MessageCreate(message(
+            MessageId::new(4),
+            Some(GuildId::new(1)),
+        )));
+        assert!(cache.member(GuildId::new(1), UserId::new(3)).is_some());
+
+        cache.update(&MemberRemove {
+            guild_id: GuildId::new(1),
+            user: cache.user(UserId::new(3)).unwrap().as_ref().clone(),
+        });
+
+        // The message still references its author.
+        assert!(cache.member(GuildId::new(1), UserId::new(3)).is_none());
+        assert_eq!("test", cache.user(UserId::new(3)).unwrap().name);
+
+        cache.update(&MessageDeleteBulk {
+            channel_id: ChannelId::new(2),
+            guild_id: Some(GuildId::new(1)),
+            ids: vec![MessageId::new(4)],
+        });
+        assert!(cache.user(UserId::new(3)).is_none());
+    }
+
     #[test]
     fn test_channel_update_guild() {
         let cache = InMemoryCache::new();
@@ -1016,7 +1296,7 @@ mod tests {
         cache.update(&VoiceStateUpdate(VoiceState {
             channel_id: None,
             deaf: false,
-            guild_id: Some(GuildId(1)),
+            guild_id: Some(GuildId::new(1)),
             member: None,
             mute: false,
             self_deaf: false,
@@ -1025,7 +1305,7 @@ mod tests {
             session_id: "38fj3jfkh3pfho3prh2".to_string(),
             suppress: false,
             token: None,
-            user_id: UserId(1),
+            user_id: UserId::new(1),
         }));
     }
 
@@ -1036,12 +1316,12 @@ mod tests {
         let cache = InMemoryCache::new();
 
         let mutation = VoiceStateUpdate(VoiceState {
-            channel_id: Some(ChannelId(4)),
+            channel_id: Some(ChannelId::new(4)),
             deaf: false,
-            guild_id: Some(GuildId(2)),
+            guild_id: Some(GuildId::new(2)),
             member: Some(Member {
                 deaf: false,
-                guild_id: GuildId(2),
+                guild_id: GuildId::new(2),
                 hoisted_role: None,
                 joined_at: None,
                 mute: false,
@@ -1049,12 +1329,12 @@ mod tests {
                 premium_since: None,
                 roles: Vec::new(),
                 user: User {
-                    avatar: Some("".to_owned()),
+                    avatar: Some(ImageHash::parse("5bd5c7c3f2a4ab7d2e3a1b9c9a4d3e2f").unwrap()),
                     bot: false,
                     discriminator: "0001".to_owned(),
                     email: None,
                     flags: None,
-                    id: UserId(3),
+                    id: UserId::new(3),
                     locale: None,
                     mfa_enabled: None,
                     name: "test".to_owned(),
@@ -1071,20 +1351,65 @@ mod tests {
             session_id: "".to_owned(),
             suppress: false,
             token: None,
-            user_id: UserId(3),
+            user_id: UserId::new(3),
         });
 
         cache.update(&mutation);
 
         assert_eq!(cache.0.members.len(), 1);
         {
-            let entry = cache.0.users.get(&UserId(3)).unwrap();
-            assert_eq!(entry.value().1.len(), 1);
+            let entry = cache.0.users.get(&UserId::new(3)).unwrap();
+            assert_eq!(entry.value().1, 1);
         }
         assert_eq!(
-            cache.member(GuildId(2), UserId(3)).unwrap().user.name,
-            "test"
+            cache
+                .member(GuildId::new(2), UserId::new(3))
+                .unwrap()
+                .user_id,
+            UserId::new(3)
         );
+        assert_eq!(cache.user(UserId::new(3)).unwrap().name, "test");
+    }
+
+    #[test]
+    fn test_guild_stickers_update() {
+        use twilight_model::channel::message::sticker::{Sticker, StickerFormatType, StickerId};
+
+        fn sticker(id: u64) -> Sticker {
+            Sticker {
+                asset: String::new(),
+                available: Some(true),
+                description: "a sticker".to_owned(),
+                format_type: StickerFormatType::Png,
+                guild_id: Some(GuildId::new(1)),
+                id: StickerId::new(id),
+                name: "sticker".to_owned(),
+                pack_id: None,
+                preview_asset: None,
+                tags: Some("tag".to_owned()),
+                user: None,
+            }
+        }
+
+        let cache = InMemoryCache::new();
+        cache.update(&GuildStickersUpdate {
+            guild_id: GuildId::new(1),
+            stickers: vec![sticker(2), sticker(3)],
+        });
+
+        assert_eq!(2, cache.guild_stickers(GuildId::new(1)).unwrap().len());
+        assert_eq!("sticker", cache.sticker(StickerId::new(2)).unwrap().name);
+
+        // Stickers missing from an update have been deleted.
+        cache.update(&GuildStickersUpdate {
+            guild_id: GuildId::new(1),
+            stickers: vec![sticker(3)],
+        });
+
+        let guild_stickers = cache.guild_stickers(GuildId::new(1)).unwrap();
+        assert_eq!(1, guild_stickers.len());
+        assert!(guild_stickers.contains(&StickerId::new(3)));
+        assert!(cache.sticker(StickerId::new(2)).is_none());
     }
 
     #[test]
@@ -1098,12 +1423,12 @@ mod tests {
             application: None,
             attachments: Vec::new(),
             author: User {
-                avatar: Some("".to_owned()),
+                avatar: Some(ImageHash::parse("5bd5c7c3f2a4ab7d2e3a1b9c9a4d3e2f").unwrap()),
                 bot: false,
                 discriminator: "0001".to_owned(),
                 email: None,
                 flags: None,
-                id: UserId(3),
+                id: UserId::new(3),
                 locale: None,
                 mfa_enabled: None,
                 name: "test".to_owned(),
@@ -1112,13 +1437,14 @@ mod tests {
                 system: None,
                 verified: None,
             },
-            channel_id: ChannelId(2),
+            channel_id: ChannelId::new(2),
             content: "ping".to_owned(),
             edited_timestamp: None,
             embeds: Vec::new(),
             flags: Some(MessageFlags::empty()),
-            guild_id: Some(GuildId(1)),
-            id: MessageId(4),
+            guild_id: Some(GuildId::new(1)),
+            id: MessageId::new(4),
+            interaction: None,
             kind: MessageType::Regular,
             member: Some(PartialMember {
                 deaf: false,
@@ -1145,15 +1471,20 @@ mod tests {
         cache.update(&MessageCreate(msg));
 
         {
-            let entry = cache.0.users.get(&UserId(3)).unwrap();
-            assert_eq!(entry.value().1.len(), 1);
+            // Referenced by both the message and its author's member.
+            let entry = cache.0.users.get(&UserId::new(3)).unwrap();
+            assert_eq!(entry.value().1, 2);
         }
         assert_eq!(
-            cache.member(GuildId(1), UserId(3)).unwrap().user.name,
-            "test"
+            cache
+                .member(GuildId::new(1), UserId::new(3))
+                .unwrap()
+                .user_id,
+            UserId::new(3)
         );
+        assert_eq!(cache.user(UserId::new(3)).unwrap().name, "test");
         {
-            let entry = cache.0.messages.get(&ChannelId(2)).unwrap();
+            let entry = cache.0.messages.get(&ChannelId::new(2)).unwrap();
             assert_eq!(entry.value().len(), 1);
         }
     }
@@ -1161,7 +1492,7 @@ mod tests {
     #[test]
     fn test_reaction_add() {
         let cache = cache_with_message_and_reactions();
-        let msg = cache.message(ChannelId(2), MessageId(4)).unwrap();
+        let msg = cache.message(ChannelId::new(2), MessageId::new(4)).unwrap();
 
         assert_eq!(msg.reactions.len(), 2);
 
@@ -1184,17 +1515,17 @@ mod tests {
     fn test_reaction_remove() {
         let cache = cache_with_message_and_reactions();
         cache.update(&ReactionRemove(Reaction {
-            channel_id: ChannelId(2),
+            channel_id: ChannelId::new(2),
             emoji: ReactionType::Unicode {
                 name: "😀".to_owned(),
             },
-            guild_id: Some(GuildId(1)),
+            guild_id: Some(GuildId::new(1)),
             member: None,
-            message_id: MessageId(4),
-            user_id: UserId(5),
+            message_id: MessageId::new(4),
+            user_id: UserId::new(5),
         }));
 
-        let msg = cache.message(ChannelId(2), MessageId(4)).unwrap();
+        let msg = cache.message(ChannelId::new(2), MessageId::new(4)).unwrap();
 
         assert_eq!(msg.reactions.len(), 2);
 
@@ -1217,16 +1548,16 @@ mod tests {
     fn test_reaction_remove_emoji() {
         let cache = cache_with_message_and_reactions();
         cache.update(&ReactionRemoveEmoji {
-            channel_id: ChannelId(2),
+            channel_id: ChannelId::new(2),
             emoji: PartialEmoji {
                 id: None,
                 name: "😀".to_owned(),
             },
-            guild_id: GuildId(1),
-            message_id: MessageId(4),
+            guild_id: GuildId::new(1),
+            message_id: MessageId::new(4),
         });
 
-        let msg = cache.message(ChannelId(2), MessageId(4)).unwrap();
+        let msg = cache.message(ChannelId::new(2), MessageId::new(4)).unwrap();
 
         assert_eq!(msg.reactions.len(), 1);
 
@@ -1248,12 +1579,12 @@ mod tests {
     fn test_reaction_remove_all() {
         let cache = cache_with_message_and_reactions();
         cache.update(&ReactionRemoveAll {
-            channel_id: ChannelId(2),
-            message_id: MessageId(4),
-            guild_id: Some(GuildId(1)),
+            channel_id: ChannelId::new(2),
+            message_id: MessageId::new(4),
+            guild_id: Some(GuildId::new(1)),
         });
 
-        let msg = cache.message(ChannelId(2), MessageId(4)).unwrap();
+        let msg = cache.message(ChannelId::new(2), MessageId::new(4)).unwrap();
 
         assert_eq!(msg.reactions.len(), 0);
     }
@@ -30,7 +30,11 @@ impl Display for EmbedAuthorNameError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
             Self::Empty { .. } => f.write_str("the author name is empty"),
-            Self::TooLong { .. } => f.write_str("the author name is too long"),
+            Self::TooLong { name } => f.write_fmt(format_args!(
+                "the author name is {} characters long, but the limit is {}",
+                name.chars().count(),
+                EmbedAuthorBuilder::NAME_LENGTH_LIMIT,
+            )),
         }
     }
 }
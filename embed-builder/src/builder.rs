@@ -1,6 +1,9 @@
 //! Create embeds.
 
-use super::image_source::ImageSource;
+use super::{
+    author::EmbedAuthorBuilder, field::EmbedFieldBuilder, footer::EmbedFooterBuilder,
+    image_source::ImageSource,
+};
 use std::{
     convert::TryFrom,
     error::Error,
@@ -13,9 +16,52 @@ use twilight_model::channel::embed::{
 /// Error building an embed.
 ///
 /// This is returned from [`EmbedBuilder::build`].
+///
+/// Builders created from an existing embed with [`EmbedBuilder::from`] may
+/// contain text that was never validated by the builder's methods, so each
+/// limit is checked again when building.
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum EmbedBuildError {
+    /// The author name is longer than
+    /// [`EmbedAuthorBuilder::NAME_LENGTH_LIMIT`].
+    AuthorNameTooLong {
+        /// The length of the author name.
+        length: usize,
+    },
+    /// The description is longer than
+    /// [`EmbedBuilder::DESCRIPTION_LENGTH_LIMIT`].
+    DescriptionTooLong {
+        /// The length of the description.
+        length: usize,
+    },
+    /// The name of a field is longer than
+    /// [`EmbedFieldBuilder::NAME_LENGTH_LIMIT`].
+    FieldNameTooLong {
+        /// The index of the field.
+        index: usize,
+        /// The length of the field's name.
+        length: usize,
+    },
+    /// The value of a field is longer than
+    /// [`EmbedFieldBuilder::VALUE_LENGTH_LIMIT`].
+    FieldValueTooLong {
+        /// The index of the field.
+        index: usize,
+        /// The length of the field's value.
+        length: usize,
+    },
+    /// The footer text is longer than
+    /// [`EmbedFooterBuilder::TEXT_LENGTH_LIMIT`].
+    FooterTextTooLong {
+        /// The length of the footer text.
+        length: usize,
+    },
+    /// The title is longer than [`EmbedBuilder::TITLE_LENGTH_LIMIT`].
+    TitleTooLong {
+        /// The length of the title.
+        length: usize,
+    },
     /// The total content of the embed is too large.
     ///
     /// Refer to [`EmbedBuilder::EMBED_LENGTH_LIMIT`] for more information about
@@ -37,8 +83,49 @@ pub enum EmbedBuildError {
 impl Display for EmbedBuildError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
-            Self::ContentTooLarge { .. } => f.write_str("the content of the embed is too large"),
-            Self::TooManyFields { .. } => f.write_str("more than 25 fields were provided"),
+            Self::AuthorNameTooLong { length } => f.write_fmt(format_args!(
+                "the author name is {} characters long, but the limit is {}",
+                length,
+                EmbedAuthorBuilder::NAME_LENGTH_LIMIT,
+            )),
+            Self::ContentTooLarge { length } => f.write_fmt(format_args!(
+                "the embed is {} characters long, but the author name, description, fields, \
+                footer text, and title can be at most {} characters combined",
+                length,
+                EmbedBuilder::EMBED_LENGTH_LIMIT,
+            )),
+            Self::DescriptionTooLong { length } => f.write_fmt(format_args!(
+                "the description is {} characters long, but the limit is {}",
+                length,
+                EmbedBuilder::DESCRIPTION_LENGTH_LIMIT,
+            )),
+            Self::FieldNameTooLong { index, length } => f.write_fmt(format_args!(
+                "the name of field {} is {} characters long, but the limit is {}",
+                index,
+                length,
+                EmbedFieldBuilder::NAME_LENGTH_LIMIT,
+            )),
+            Self::FieldValueTooLong { index, length } => f.write_fmt(format_args!(
+                "the value of field {} is {} characters long, but the limit is {}",
+                index,
+                length,
+                EmbedFieldBuilder::VALUE_LENGTH_LIMIT,
+            )),
+            Self::FooterTextTooLong { length } => f.write_fmt(format_args!(
+                "the footer text is {} characters long, but the limit is {}",
+                length,
+                EmbedFooterBuilder::TEXT_LENGTH_LIMIT,
+            )),
+            Self::TitleTooLong { length } => f.write_fmt(format_args!(
+                "the title is {} characters long, but the limit is {}",
+                length,
+                EmbedBuilder::TITLE_LENGTH_LIMIT,
+            )),
+            Self::TooManyFields { fields } => f.write_fmt(format_args!(
+                "{} fields were provided, but the limit is {}",
+                fields.len(),
+                EmbedBuilder::EMBED_FIELD_LIMIT,
+            )),
         }
     }
 }
@@ -84,7 +171,7 @@ pub enum EmbedDescriptionError {
         /// included.
         description: String,
     },
-    /// Description is longer than 2048 UTF-16 code points.
+    /// Description is longer than 4096 UTF-16 code points.
     TooLong {
         /// Provided description.
         description: String,
@@ -95,7 +182,11 @@ impl Display for EmbedDescriptionError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
             Self::Empty { .. } => f.write_str("the description is empty"),
-            Self::TooLong { .. } => f.write_str("the description is too long"),
+            Self::TooLong { description } => f.write_fmt(format_args!(
+                "the description is {} characters long, but the limit is {}",
+                description.chars().count(),
+                EmbedBuilder::DESCRIPTION_LENGTH_LIMIT,
+            )),
         }
     }
 }
@@ -124,7 +215,11 @@ impl Display for EmbedTitleError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
             Self::Empty { .. } => f.write_str("the title is empty"),
-            Self::TooLong { .. } => f.write_str("the title is too long"),
+            Self::TooLong { title } => f.write_fmt(format_args!(
+                "the title is {} characters long, but the limit is {}",
+                title.chars().count(),
+                EmbedBuilder::TITLE_LENGTH_LIMIT,
+            )),
         }
     }
 }
@@ -156,7 +251,7 @@ impl EmbedBuilder {
     /// This is used by [`description`].
     ///
     /// [`description`]: Self::description
-    pub const DESCRIPTION_LENGTH_LIMIT: usize = 2048;
+    pub const DESCRIPTION_LENGTH_LIMIT: usize = 4096;
 
     /// The maximum number of fields that can be in an embed.
     ///
@@ -199,6 +294,15 @@ impl EmbedBuilder {
     ///
     /// # Errors
     ///
+    /// Returns [`EmbedBuildError::AuthorNameTooLong`],
+    /// [`EmbedBuildError::DescriptionTooLong`],
+    /// [`EmbedBuildError::FieldNameTooLong`],
+    /// [`EmbedBuildError::FieldValueTooLong`],
+    /// [`EmbedBuildError::FooterTextTooLong`], or
+    /// [`EmbedBuildError::TitleTooLong`] if a piece of text is longer than its
+    /// limit. These can only occur for builders created from an existing
+    /// embed.
+    ///
     /// Returns [`EmbedBuildError::ContentTooLarge`] if the textual content of
     /// the embed is too large. Refer to [`EMBED_LENGTH_LIMIT`] for the limit
     /// value and what counts towards it.
@@ -224,23 +328,65 @@ impl EmbedBuilder {
             .as_ref()
             .and_then(|author| author.name.as_ref())
         {
-            total += name.chars().count();
+            let length = name.chars().count();
+
+            if length > EmbedAuthorBuilder::NAME_LENGTH_LIMIT {
+                return Err(EmbedBuildError::AuthorNameTooLong { length });
+            }
+
+            total += length;
         }
 
         if let Some(description) = self.0.description.as_ref() {
-            total += description.chars().count();
+            let length = description.chars().count();
+
+            if length > Self::DESCRIPTION_LENGTH_LIMIT {
+                return Err(EmbedBuildError::DescriptionTooLong { length });
+            }
+
+            total += length;
         }
 
         if let Some(footer) = self.0.footer.as_ref() {
-            total += footer.text.chars().count();
+            let length = footer.text.chars().count();
+
+            if length > EmbedFooterBuilder::TEXT_LENGTH_LIMIT {
+                return Err(EmbedBuildError::FooterTextTooLong { length });
+            }
+
+            total += length;
         }
 
-        for field in &self.0.fields {
-            total += field.name.chars().count() + field.value.chars().count();
+        for (index, field) in self.0.fields.iter().enumerate() {
+            let name_length = field.name.chars().count();
+
+            if name_length > EmbedFieldBuilder::NAME_LENGTH_LIMIT {
+                return Err(EmbedBuildError::FieldNameTooLong {
+                    index,
+                    length: name_length,
+                });
+            }
+
+            let value_length = field.value.chars().count();
+
+            if value_length > EmbedFieldBuilder::VALUE_LENGTH_LIMIT {
+                return Err(EmbedBuildError::FieldValueTooLong {
+                    index,
+                    length: value_length,
+                });
+            }
+
+            total += name_length + value_length;
         }
 
         if let Some(title) = self.0.title.as_ref() {
-            total += title.chars().count();
+            let length = title.chars().count();
+
+            if length > Self::TITLE_LENGTH_LIMIT {
+                return Err(EmbedBuildError::TitleTooLong { length });
+            }
+
+            total += length;
         }
 
         if total > Self::EMBED_LENGTH_LIMIT {
@@ -577,6 +723,31 @@ impl Default for EmbedBuilder {
     }
 }
 
+impl From<Embed> for EmbedBuilder {
+    /// Create an embed builder from an existing embed, such as one of a
+    /// fetched message, to modify it.
+    ///
+    /// The embed's text is validated when the builder is built.
+    ///
+    /// # Examples
+    ///
+    /// Change the title of an embed:
+    ///
+    /// ```rust
+    /// use twilight_embed_builder::EmbedBuilder;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let embed = EmbedBuilder::new().title("twilight")?.build()?;
+    ///
+    /// let edited = EmbedBuilder::from(embed).title("twilight-rs")?.build()?;
+    /// assert_eq!(Some("twilight-rs"), edited.title.as_deref());
+    /// # Ok(()) }
+    /// ```
+    fn from(embed: Embed) -> Self {
+        Self(embed)
+    }
+}
+
 impl TryFrom<EmbedBuilder> for Embed {
     type Error = EmbedBuildError;
 
@@ -607,7 +778,13 @@ mod tests {
         Send,
         Sync
     );
+    assert_fields!(EmbedBuildError::AuthorNameTooLong: length);
     assert_fields!(EmbedBuildError::ContentTooLarge: length);
+    assert_fields!(EmbedBuildError::DescriptionTooLong: length);
+    assert_fields!(EmbedBuildError::FieldNameTooLong: index, length);
+    assert_fields!(EmbedBuildError::FieldValueTooLong: index, length);
+    assert_fields!(EmbedBuildError::FooterTextTooLong: length);
+    assert_fields!(EmbedBuildError::TitleTooLong: length);
     assert_fields!(EmbedBuildError::TooManyFields: fields);
     assert_impl_all!(
         EmbedColorError: Clone,
@@ -646,12 +823,13 @@ mod tests {
         Debug,
         Default,
         Eq,
+        From<Embed>,
         PartialEq,
         Send,
         Sync
     );
     const_assert!(EmbedBuilder::COLOR_MAXIMUM == 0xff_ff_ff);
-    const_assert!(EmbedBuilder::DESCRIPTION_LENGTH_LIMIT == 2048);
+    const_assert!(EmbedBuilder::DESCRIPTION_LENGTH_LIMIT == 4096);
     const_assert!(EmbedBuilder::EMBED_FIELD_LIMIT == 25);
     const_assert!(EmbedBuilder::EMBED_LENGTH_LIMIT == 6000);
     const_assert!(EmbedBuilder::TITLE_LENGTH_LIMIT == 256);
@@ -702,6 +880,59 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_build_error() -> Result<(), Box<dyn Error>> {
+        let mut embed = EmbedBuilder::new().title("twilight")?.build()?;
+        embed.fields.push(EmbedField {
+            inline: false,
+            name: "name".to_owned(),
+            value: "a".repeat(EmbedFieldBuilder::VALUE_LENGTH_LIMIT + 1),
+        });
+
+        let error = EmbedBuilder::from(embed).build().unwrap_err();
+        assert_eq!(
+            EmbedBuildError::FieldValueTooLong {
+                index: 0,
+                length: 1025
+            },
+            error
+        );
+        assert_eq!(
+            "the value of field 0 is 1025 characters long, but the limit is 1024",
+            error.to_string()
+        );
+
+        let mut builder = EmbedBuilder::new().description("a".repeat(4000))?;
+
+        for _ in 0..2 {
+            builder = builder.field(EmbedFieldBuilder::new("a".repeat(256), "a".repeat(1000))?);
+        }
+
+        assert_eq!(
+            EmbedBuildError::ContentTooLarge { length: 6512 },
+            builder.build().unwrap_err()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_embed() -> Result<(), Box<dyn Error>> {
+        let embed = EmbedBuilder::new()
+            .title("twilight")?
+            .description("description")?
+            .build()?;
+
+        let edited = EmbedBuilder::from(embed.clone())
+            .title("twilight-rs")?
+            .build()?;
+
+        assert_eq!(Some("twilight-rs"), edited.title.as_deref());
+        assert_eq!(embed.description, edited.description);
+
+        Ok(())
+    }
+
     #[test]
     fn test_builder() -> Result<(), Box<dyn Error>> {
         let footer_image = ImageSource::url(
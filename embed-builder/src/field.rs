@@ -48,9 +48,17 @@ impl Display for EmbedFieldError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
             Self::NameEmpty { .. } => f.write_str("the field name is empty"),
-            Self::NameTooLong { .. } => f.write_str("the field name is too long"),
+            Self::NameTooLong { name, .. } => f.write_fmt(format_args!(
+                "the field name is {} characters long, but the limit is {}",
+                name.chars().count(),
+                EmbedFieldBuilder::NAME_LENGTH_LIMIT,
+            )),
             Self::ValueEmpty { .. } => f.write_str("the field value is empty"),
-            Self::ValueTooLong { .. } => f.write_str("the field value is too long"),
+            Self::ValueTooLong { value, .. } => f.write_fmt(format_args!(
+                "the field value is {} characters long, but the limit is {}",
+                value.chars().count(),
+                EmbedFieldBuilder::VALUE_LENGTH_LIMIT,
+            )),
         }
     }
 }
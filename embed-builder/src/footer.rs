@@ -30,7 +30,11 @@ impl Display for EmbedFooterTextError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
             Self::Empty { .. } => f.write_str("the footer text is empty"),
-            Self::TooLong { .. } => f.write_str("the footer text is too long"),
+            Self::TooLong { text } => f.write_fmt(format_args!(
+                "the footer text is {} characters long, but the limit is {}",
+                text.chars().count(),
+                EmbedFooterBuilder::TEXT_LENGTH_LIMIT,
+            )),
         }
     }
 }
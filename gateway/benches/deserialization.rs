@@ -0,0 +1,133 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use serde::de::DeserializeSeed;
+use twilight_model::gateway::event::{GatewayEvent, GatewayEventDeserializer};
+
+/// Create a `GUILD_CREATE` dispatch with the given number of members, like
+/// those received in bursts while shards identify.
+fn guild_create(members: usize) -> String {
+    let members = (1..=members)
+        .map(|id| {
+            format!(
+                r#"{{
+                    "deaf": false,
+                    "hoisted_role": null,
+                    "joined_at": "2020-04-04T04:04:04.000000+00:00",
+                    "mute": false,
+                    "nick": null,
+                    "roles": ["2"],
+                    "user": {{
+                        "avatar": "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                        "discriminator": "0001",
+                        "id": "{}",
+                        "username": "member {}"
+                    }}
+                }}"#,
+                id + 10,
+                id
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+
+    format!(
+        r#"{{
+            "d": {{
+                "afk_channel_id": null,
+                "afk_timeout": 300,
+                "application_id": null,
+                "banner": null,
+                "default_message_notifications": 0,
+                "description": null,
+                "discovery_splash": null,
+                "emojis": [],
+                "explicit_content_filter": 0,
+                "features": ["INVITE_SPLASH", "ANIMATED_ICON"],
+                "icon": null,
+                "id": "1",
+                "large": true,
+                "max_members": 250000,
+                "max_presences": null,
+                "member_count": 100000,
+                "members": [{}],
+                "mfa_level": 0,
+                "name": "guild",
+                "owner_id": "11",
+                "preferred_locale": "en-US",
+                "premium_subscription_count": 4,
+                "premium_tier": 1,
+                "region": "eu-central",
+                "roles": [{{
+                    "color": 0,
+                    "hoist": false,
+                    "id": "2",
+                    "managed": false,
+                    "mentionable": false,
+                    "name": "@everyone",
+                    "permissions": "104193601",
+                    "position": 0
+                }}],
+                "rules_channel_id": null,
+                "splash": null,
+                "system_channel_flags": 0,
+                "system_channel_id": null,
+                "vanity_url_code": null,
+                "verification_level": 0,
+                "widget_channel_id": null,
+                "widget_enabled": false
+            }},
+            "op": 0,
+            "s": 1,
+            "t": "GUILD_CREATE"
+        }}"#,
+        members
+    )
+}
+
+// Shards deserialize from their own buffer, so copy the input with both
+// libraries to compare them fairly.
+fn serde_json(input: &str) -> GatewayEvent {
+    let json = input.to_owned();
+    let gateway_deserializer = GatewayEventDeserializer::from_json(input).unwrap();
+    let mut json_deserializer = serde_json::Deserializer::from_str(&json);
+
+    gateway_deserializer
+        .deserialize(&mut json_deserializer)
+        .unwrap()
+}
+
+#[cfg(feature = "simd-json")]
+fn simd_json(input: &str) -> GatewayEvent {
+    let mut json = input.as_bytes().to_vec();
+    let gateway_deserializer = GatewayEventDeserializer::from_json(input).unwrap();
+    let mut json_deserializer = simd_json::Deserializer::from_slice(&mut json).unwrap();
+
+    gateway_deserializer
+        .deserialize(&mut json_deserializer)
+        .unwrap()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("guild create");
+
+    for members in &[10, 1000] {
+        let input = guild_create(*members);
+
+        group.bench_with_input(
+            BenchmarkId::new("serde_json", members),
+            &input,
+            |b, input| b.iter(|| serde_json(input)),
+        );
+
+        #[cfg(feature = "simd-json")]
+        group.bench_with_input(
+            BenchmarkId::new("simd-json", members),
+            &input,
+            |b, input| b.iter(|| simd_json(input)),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,95 @@
+use std::{sync::Mutex, time::Duration};
+use tokio::time::{self, Instant};
+
+/// Duration of the window over which identifies are counted.
+const DAY: Duration = Duration::from_secs(60 * 60 * 24);
+
+/// Local count of the identifies made within the current day.
+///
+/// Discord resets a bot's token once it exceeds its daily identify limit, so
+/// identifies that would exceed it are delayed until the window resets.
+#[derive(Debug)]
+pub(crate) struct IdentifyBudget(Mutex<IdentifyBudgetInner>);
+
+#[derive(Debug)]
+struct IdentifyBudgetInner {
+    limit: u64,
+    resets_at: Instant,
+    used: u64,
+}
+
+impl IdentifyBudget {
+    pub fn new(limit: u64) -> Self {
+        Self(Mutex::new(IdentifyBudgetInner {
+            limit,
+            resets_at: Instant::now() + DAY,
+            used: 0,
+        }))
+    }
+
+    /// Number of identifies remaining until the window resets.
+    pub fn remaining(&self) -> u64 {
+        let mut lock = self.0.lock().expect("identify budget poisoned");
+        lock.reset_if_elapsed();
+
+        lock.limit - lock.used
+    }
+
+    /// Use one identify from the budget, waiting for the window to reset if
+    /// the budget has been used up.
+    pub async fn acquire(&self) {
+        loop {
+            let resets_at = {
+                let mut lock = self.0.lock().expect("identify budget poisoned");
+                lock.reset_if_elapsed();
+
+                if lock.used < lock.limit {
+                    lock.used += 1;
+
+                    return;
+                }
+
+                lock.resets_at
+            };
+
+            tracing::warn!(
+                "daily identify limit reached, waiting {:.2?} for it to reset",
+                resets_at.saturating_duration_since(Instant::now()),
+            );
+
+            time::sleep_until(resets_at).await;
+        }
+    }
+}
+
+impl IdentifyBudgetInner {
+    fn reset_if_elapsed(&mut self) {
+        let now = Instant::now();
+
+        if now >= self.resets_at {
+            self.resets_at = now + DAY;
+            self.used = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IdentifyBudget;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(IdentifyBudget: Debug, Send, Sync);
+
+    #[tokio::test]
+    async fn test_acquire() {
+        let budget = IdentifyBudget::new(2);
+        assert_eq!(2, budget.remaining());
+
+        budget.acquire().await;
+        assert_eq!(1, budget.remaining());
+
+        budget.acquire().await;
+        assert_eq!(0, budget.remaining());
+    }
+}
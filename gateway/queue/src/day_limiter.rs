@@ -74,16 +74,26 @@ impl DayLimiter {
         })))
     }
 
+    pub async fn remaining(&self) -> u64 {
+        let lock = self.0.lock().await;
+
+        lock.total.saturating_sub(lock.current)
+    }
+
     pub async fn get(&self) {
         let mut lock = self.0.lock().await;
         if lock.current < lock.total {
             lock.current += 1;
         } else {
             let wait = lock.last_check + lock.next_reset;
+            tracing::warn!(
+                "daily identify limit reached, waiting {:.2?} for it to reset",
+                wait.saturating_duration_since(Instant::now()),
+            );
             time::sleep_until(wait).await;
             if let Ok(info) = lock.http.gateway().authed().await {
                 let last_check = Instant::now();
-                let next_reset = Duration::from_millis(info.session_start_limit.remaining);
+                let next_reset = Duration::from_millis(info.session_start_limit.reset_after);
                 tracing::info!("next session start limit reset in: {:.2?}", next_reset);
                 let total = info.session_start_limit.total;
                 let remaining = info.session_start_limit.remaining;
@@ -101,3 +111,33 @@ impl DayLimiter {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{DayLimiter, DayLimiterInner};
+    use std::time::Duration;
+    use tokio::{sync::Mutex, time::Instant};
+
+    fn limiter(total: u64, current: u64) -> DayLimiter {
+        DayLimiter(Mutex::new(DayLimiterInner {
+            http: twilight_http::Client::new("token"),
+            last_check: Instant::now(),
+            next_reset: Duration::from_secs(60),
+            total,
+            current,
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_remaining() {
+        assert_eq!(990, limiter(1000, 10).remaining().await);
+    }
+
+    #[tokio::test]
+    async fn test_remaining_exhausted() {
+        assert_eq!(0, limiter(1000, 1000).remaining().await);
+        // Refreshing after a reset counts the pending identify, so the
+        // current count can exceed the total.
+        assert_eq!(0, limiter(1000, 1001).remaining().await);
+    }
+}
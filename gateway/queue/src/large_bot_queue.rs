@@ -14,6 +14,10 @@ use tokio::time::sleep;
 /// cluster's ratelimit buckets. If you use multiple processes for clusters,
 /// then refer to the [module-level] documentation.
 ///
+/// The daily identify limit and the number of identifies remaining are
+/// retrieved from Discord, and identifies that would exceed the limit are
+/// delayed until it resets.
+///
 /// [Sharding for Very Large Bots]: https://discord.com/developers/docs/topics/gateway#sharding-for-very-large-bots
 /// [module-level]: crate
 #[derive(Debug)]
@@ -94,6 +98,10 @@ impl Queue for LargeBotQueue {
             let _ = rx.await;
         })
     }
+
+    fn remaining_identifies(&'_ self) -> Pin<Box<dyn Future<Output = Option<u64>> + Send + '_>> {
+        Box::pin(async move { Some(self.limiter.remaining().await) })
+    }
 }
 
 #[cfg(test)]
@@ -22,6 +22,14 @@
 //! can override this in the `ClusterBuilder::queue` and `ShardBuilder::queue`
 //! configuration methods.
 //!
+//! # Daily identify limit
+//!
+//! Discord limits how many sessions a bot may start per day, and resets the
+//! bot's token if the limit is exceeded. The provided queues track this limit
+//! and delay identifies that would exceed it until the limit resets. The
+//! number of identifies remaining can be retrieved with
+//! [`Queue::remaining_identifies`].
+//!
 //! # Advanced use cases
 //!
 //! Large bots, and smaller bots out of design, may need to implement their own
@@ -32,20 +40,27 @@
 //!
 //! [Sharding for Very Large Bots]: https://discord.com/developers/docs/topics/gateway#sharding-for-very-large-bots
 
+mod budget;
 mod day_limiter;
 mod large_bot_queue;
 
 pub use large_bot_queue::LargeBotQueue;
 
+use budget::IdentifyBudget;
 use day_limiter::DayLimiter;
 use futures_channel::{
     mpsc::{unbounded, UnboundedReceiver, UnboundedSender},
     oneshot::{self, Sender},
 };
 use futures_util::{sink::SinkExt, stream::StreamExt};
-use std::{fmt::Debug, future::Future, pin::Pin, time::Duration};
+use std::{fmt::Debug, future::Future, pin::Pin, sync::Arc, time::Duration};
 use tokio::time::sleep;
 
+/// Number of sessions most bots may start per day.
+///
+/// Very large bots may be granted a higher limit by Discord.
+pub const DEFAULT_DAILY_IDENTIFY_LIMIT: u64 = 1000;
+
 /// Queue for shards to request the ability to initialize new sessions with the
 /// gateway.
 ///
@@ -61,6 +76,17 @@ pub trait Queue: Debug + Send + Sync {
     /// The returned future must resolve only when the shard can initiate the
     /// session.
     fn request<'a>(&'a self, shard_id: [u64; 2]) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+
+    /// Number of sessions that may still be started before the daily
+    /// identify limit resets.
+    ///
+    /// Returns `None` if the queue doesn't track the daily limit, which is
+    /// the default.
+    fn remaining_identifies<'a>(
+        &'a self,
+    ) -> Pin<Box<dyn Future<Output = Option<u64>> + Send + 'a>> {
+        Box::pin(async { None })
+    }
 }
 
 /// A local, in-process implementation of a [`Queue`] which manages the
@@ -73,6 +99,12 @@ pub trait Queue: Debug + Send + Sync {
 /// You usually won't need to handle this yourself, because the `Cluster` will
 /// do that for you when managing multiple shards.
 ///
+/// Identifies are counted against a daily limit, which is
+/// [`DEFAULT_DAILY_IDENTIFY_LIMIT`] unless created via
+/// [`with_daily_limit`]. Once the limit is used up, requests are delayed until
+/// a day has passed since the count started. Since the count is local, it
+/// doesn't include sessions started before the queue was created.
+///
 /// # When not to use this
 ///
 /// This queue implementation is "local", meaning it's intended to be used if
@@ -88,8 +120,12 @@ pub trait Queue: Debug + Send + Sync {
 /// [`Queue`], such as the [`gateway-queue`] broker.
 ///
 /// [`gateway-queue`]: https://github.com/twilight-rs/gateway-queue
+/// [`with_daily_limit`]: Self::with_daily_limit
 #[derive(Clone, Debug)]
-pub struct LocalQueue(UnboundedSender<Sender<()>>);
+pub struct LocalQueue {
+    budget: Arc<IdentifyBudget>,
+    tx: UnboundedSender<Sender<()>>,
+}
 
 impl Default for LocalQueue {
     fn default() -> Self {
@@ -100,11 +136,21 @@ impl Default for LocalQueue {
 impl LocalQueue {
     /// Creates a new local queue.
     pub fn new() -> Self {
+        Self::with_daily_limit(DEFAULT_DAILY_IDENTIFY_LIMIT)
+    }
+
+    /// Creates a new local queue with a custom daily identify limit.
+    ///
+    /// This is useful for bots that Discord has granted a higher limit.
+    pub fn with_daily_limit(limit: u64) -> Self {
         let (tx, rx) = unbounded();
 
         tokio::spawn(waiter(rx));
 
-        Self(tx)
+        Self {
+            budget: Arc::new(IdentifyBudget::new(limit)),
+            tx,
+        }
     }
 }
 
@@ -124,9 +170,11 @@ impl Queue for LocalQueue {
     /// once the request has been completed.
     fn request(&'_ self, [id, total]: [u64; 2]) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
         Box::pin(async move {
+            self.budget.acquire().await;
+
             let (tx, rx) = oneshot::channel();
 
-            if let Err(err) = self.0.clone().send(tx).await {
+            if let Err(err) = self.tx.clone().send(tx).await {
                 tracing::warn!("skipping, send failed: {:?}", err);
                 return;
             }
@@ -136,6 +184,12 @@ impl Queue for LocalQueue {
             let _ = rx.await;
         })
     }
+
+    fn remaining_identifies(&'_ self) -> Pin<Box<dyn Future<Output = Option<u64>> + Send + '_>> {
+        let remaining = self.budget.remaining();
+
+        Box::pin(async move { Some(remaining) })
+    }
 }
 
 #[cfg(test)]
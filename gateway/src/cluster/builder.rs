@@ -2,7 +2,7 @@ use super::{
     config::Config as ClusterConfig,
     r#impl::{Cluster, ClusterStartError},
 };
-use crate::shard::{LargeThresholdError, ResumeSession, ShardBuilder};
+use crate::shard::{EventOverflow, LargeThresholdError, ResumeSession, ShardBuilder};
 use std::{
     collections::HashMap,
     convert::TryFrom,
@@ -182,11 +182,10 @@ impl ClusterBuilder {
             let gateway_url = (self.1)
                 .0
                 .http_client
-                .gateway()
-                .authed()
+                .gateway_info()
                 .await
                 .ok()
-                .map(|s| s.url);
+                .map(|info| info.url().to_owned());
 
             self = self.gateway_url(gateway_url);
         }
@@ -203,6 +202,27 @@ impl ClusterBuilder {
         self
     }
 
+    /// Set the maximum number of events queued in each shard's event streams.
+    ///
+    /// Refer to the shard's [`ShardBuilder::event_channel_capacity`] for more
+    /// information.
+    pub fn event_channel_capacity(mut self, event_channel_capacity: usize) -> Self {
+        self.1 = self.1.event_channel_capacity(event_channel_capacity);
+
+        self
+    }
+
+    /// Set the behavior of shards' event streams once their capacity is
+    /// reached.
+    ///
+    /// Refer to the shard's [`ShardBuilder::event_overflow`] for more
+    /// information.
+    pub fn event_overflow(mut self, event_overflow: EventOverflow) -> Self {
+        self.1 = self.1.event_overflow(event_overflow);
+
+        self
+    }
+
     /// Set the `twilight_http` Client used by the cluster and the shards it
     /// manages.
     ///
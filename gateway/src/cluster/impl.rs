@@ -186,12 +186,11 @@ impl Cluster {
                 let http = config.http_client();
 
                 let gateway = http
-                    .gateway()
-                    .authed()
+                    .gateway_info()
                     .await
                     .map_err(|source| ClusterStartError::RetrievingGatewayInfo { source })?;
 
-                [0, gateway.shards - 1, gateway.shards]
+                [0, gateway.shards() - 1, gateway.shards()]
             }
             ShardScheme::Range { from, to, total } => [*from, *to, *total],
         };
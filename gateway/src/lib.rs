@@ -44,6 +44,14 @@
 //! twilight-gateway = { default-features = false, features = ["rustls", "simd-json"], version = "0.2" }
 //! ```
 //!
+//! #### `unknown-fields`
+//!
+//! The `unknown-fields` feature logs fields of events that Discord sends but
+//! which aren't yet modelled by [`twilight-model`], which is useful for
+//! finding out about new API fields. Each unknown field is logged once per
+//! event type at the `WARN` level via [`tracing`]. It is not enabled by
+//! default.
+//!
 //! ### TLS
 //!
 //! `twilight-gateway` has features to enable [`async-tungstenite`] and
@@ -94,7 +102,9 @@
 //! [`rustls`]: https://crates.io/crates/rustls
 //! [`serde_json`]: https://crates.io/crates/serde_json
 //! [`simd-json`]: https://crates.io/crates/simd-json
+//! [`tracing`]: https://crates.io/crates/tracing
 //! [`twilight-http`]: https://twilight-rs.github.io/twilight/twilight_http/index.html
+//! [`twilight-model`]: https://twilight-rs.github.io/twilight/twilight_model/index.html
 //! [`zlib-ng`]: https://github.com/zlib-ng/zlib-ng
 //! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
 //! [discord link]: https://discord.gg/7jj8n7D
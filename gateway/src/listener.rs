@@ -1,15 +1,189 @@
-use crate::EventTypeFlags;
+use crate::{shard::EventOverflow, EventTypeFlags};
 use dashmap::DashMap;
-use futures_channel::mpsc::{self, UnboundedReceiver, UnboundedSender};
-use std::sync::{
-    atomic::{AtomicU64, Ordering},
-    Arc,
+use futures_util::{stream::Stream, task::AtomicWaker};
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll},
 };
 
+/// State shared between the sending and receiving halves of a listener's
+/// channel.
+#[derive(Debug)]
+struct Channel<T> {
+    /// Maximum number of queued items, or `None` if the channel is unbounded.
+    capacity: Option<usize>,
+    /// Number of items dropped to make room for newer ones.
+    dropped: AtomicU64,
+    /// What to do when the channel is full.
+    overflow: EventOverflow,
+    queue: Mutex<VecDeque<T>>,
+    /// Whether the receiver has been dropped.
+    rx_closed: AtomicBool,
+    rx_waker: AtomicWaker,
+    /// Whether the sender has been dropped.
+    tx_closed: AtomicBool,
+    tx_waker: AtomicWaker,
+}
+
+impl<T> Channel<T> {
+    fn len(&self) -> usize {
+        self.queue.lock().expect("queue poisoned").len()
+    }
+}
+
+/// Create a channel with an optional capacity and the behavior to use when
+/// the capacity is reached.
+pub fn channel<T>(
+    capacity: Option<usize>,
+    overflow: EventOverflow,
+) -> (ListenerSender<T>, ListenerReceiver<T>) {
+    let channel = Arc::new(Channel {
+        // A channel must be able to hold at least one item, otherwise nothing
+        // could ever be sent through it.
+        capacity: capacity.map(|capacity| capacity.max(1)),
+        dropped: AtomicU64::new(0),
+        overflow,
+        queue: Mutex::new(VecDeque::new()),
+        rx_closed: AtomicBool::new(false),
+        rx_waker: AtomicWaker::new(),
+        tx_closed: AtomicBool::new(false),
+        tx_waker: AtomicWaker::new(),
+    });
+
+    (
+        ListenerSender(Arc::clone(&channel)),
+        ListenerReceiver(channel),
+    )
+}
+
+/// Sending half of a listener's channel.
+#[derive(Debug)]
+pub struct ListenerSender<T>(Arc<Channel<T>>);
+
+impl<T> ListenerSender<T> {
+    /// Whether the receiver has been dropped.
+    pub fn is_closed(&self) -> bool {
+        self.0.rx_closed.load(Ordering::Acquire)
+    }
+
+    /// Poll for whether the channel has room for another item.
+    ///
+    /// This is always ready unless the channel is bounded and applies
+    /// backpressure, in which case it's ready once the receiver has made room
+    /// or has been dropped.
+    pub fn poll_ready(&self, cx: &mut Context<'_>) -> Poll<()> {
+        let capacity = match (self.0.capacity, self.0.overflow) {
+            (Some(capacity), EventOverflow::Backpressure) => capacity,
+            _ => return Poll::Ready(()),
+        };
+
+        self.0.tx_waker.register(cx.waker());
+
+        if self.is_closed() || self.0.len() < capacity {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+
+    /// Send an item over the channel.
+    ///
+    /// If the channel is full and drops the oldest items then the oldest item
+    /// is removed to make room. If it applies backpressure then the item is
+    /// queued regardless; callers should wait on [`poll_ready`] beforehand.
+    ///
+    /// # Errors
+    ///
+    /// Returns the item if the receiver has been dropped.
+    ///
+    /// [`poll_ready`]: Self::poll_ready
+    pub fn send(&self, item: T) -> Result<(), T> {
+        if self.is_closed() {
+            return Err(item);
+        }
+
+        {
+            let mut queue = self.0.queue.lock().expect("queue poisoned");
+
+            if let (Some(capacity), EventOverflow::DropOldest) = (self.0.capacity, self.0.overflow)
+            {
+                while queue.len() >= capacity {
+                    queue.pop_front();
+                    self.0.dropped.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+
+            queue.push_back(item);
+        }
+
+        self.0.rx_waker.wake();
+
+        Ok(())
+    }
+}
+
+impl<T> Drop for ListenerSender<T> {
+    fn drop(&mut self) {
+        self.0.tx_closed.store(true, Ordering::Release);
+        self.0.rx_waker.wake();
+    }
+}
+
+/// Receiving half of a listener's channel.
+///
+/// The stream ends once the sender has been dropped and all queued items have
+/// been received.
+#[derive(Debug)]
+pub struct ListenerReceiver<T>(Arc<Channel<T>>);
+
+impl<T> ListenerReceiver<T> {
+    /// Number of items that have been dropped to make room for newer ones.
+    pub fn dropped(&self) -> u64 {
+        self.0.dropped.load(Ordering::Relaxed)
+    }
+}
+
+impl<T> Drop for ListenerReceiver<T> {
+    fn drop(&mut self) {
+        self.0.rx_closed.store(true, Ordering::Release);
+        self.0.tx_waker.wake();
+    }
+}
+
+impl<T> Stream for ListenerReceiver<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.0.rx_waker.register(cx.waker());
+
+        let item = self.0.queue.lock().expect("queue poisoned").pop_front();
+
+        if let Some(item) = item {
+            self.0.tx_waker.wake();
+
+            return Poll::Ready(Some(item));
+        }
+
+        if self.0.tx_closed.load(Ordering::Acquire) {
+            // The sender may have queued an item right before being dropped.
+            let item = self.0.queue.lock().expect("queue poisoned").pop_front();
+
+            return Poll::Ready(item);
+        }
+
+        Poll::Pending
+    }
+}
+
 #[derive(Debug)]
 pub struct Listener<T> {
     pub events: EventTypeFlags,
-    pub tx: UnboundedSender<T>,
+    pub tx: ListenerSender<T>,
 }
 
 impl<T> Listener<T> {
@@ -44,9 +218,14 @@ impl<T> Default for ListenersRef<T> {
 pub struct Listeners<T>(Arc<ListenersRef<T>>);
 
 impl<T> Listeners<T> {
-    pub fn add(&self, events: EventTypeFlags) -> UnboundedReceiver<T> {
+    pub fn add(
+        &self,
+        events: EventTypeFlags,
+        capacity: Option<usize>,
+        overflow: EventOverflow,
+    ) -> ListenerReceiver<T> {
         let id = self.0.id.fetch_add(1, Ordering::Release) + 1;
-        let (tx, rx) = mpsc::unbounded();
+        let (tx, rx) = channel(capacity, overflow);
 
         self.0.listeners.insert(id, Listener { events, tx });
         self.recalculate_event_types();
@@ -101,9 +280,13 @@ impl<T> Default for Listeners<T> {
 
 #[cfg(test)]
 mod tests {
-    use super::{EventTypeFlags, Listener, Listeners};
+    use super::{channel, EventOverflow, EventTypeFlags, Listener, Listeners};
+    use futures_util::{future::FutureExt, stream::StreamExt, task::noop_waker_ref};
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{
+        fmt::Debug,
+        task::{Context, Poll},
+    };
 
     assert_impl_all!(Listeners<()>: Clone, Debug, Default, Send, Sync);
     assert_impl_all!(Listener<()>: Debug, Send, Sync);
@@ -111,9 +294,17 @@ mod tests {
     #[test]
     fn test_total_event_types() {
         let listeners: Listeners<()> = Listeners::default();
-        listeners.add(EventTypeFlags::MESSAGE_CREATE);
+        listeners.add(
+            EventTypeFlags::MESSAGE_CREATE,
+            None,
+            EventOverflow::Backpressure,
+        );
         assert_eq!(EventTypeFlags::MESSAGE_CREATE, listeners.event_types());
-        listeners.add(EventTypeFlags::MESSAGE_DELETE);
+        listeners.add(
+            EventTypeFlags::MESSAGE_DELETE,
+            None,
+            EventOverflow::Backpressure,
+        );
         assert_eq!(
             EventTypeFlags::MESSAGE_CREATE | EventTypeFlags::MESSAGE_DELETE,
             listeners.event_types(),
@@ -121,4 +312,40 @@ mod tests {
         listeners.remove_all();
         assert!(listeners.event_types().is_empty());
     }
+
+    #[test]
+    fn test_channel_backpressure() {
+        let mut cx = Context::from_waker(noop_waker_ref());
+        let (tx, mut rx) = channel(Some(1), EventOverflow::Backpressure);
+        assert_eq!(Poll::Ready(()), tx.poll_ready(&mut cx));
+        assert!(tx.send(1).is_ok());
+        assert_eq!(Poll::Pending, tx.poll_ready(&mut cx));
+
+        assert_eq!(Some(Some(1)), rx.next().now_or_never());
+        assert_eq!(Poll::Ready(()), tx.poll_ready(&mut cx));
+        assert_eq!(0, rx.dropped());
+    }
+
+    #[test]
+    fn test_channel_drop_oldest() {
+        let (tx, mut rx) = channel(Some(2), EventOverflow::DropOldest);
+        assert!(tx.send(1).is_ok());
+        assert!(tx.send(2).is_ok());
+        assert!(tx.send(3).is_ok());
+        assert_eq!(1, rx.dropped());
+        drop(tx);
+
+        assert_eq!(Some(Some(2)), rx.next().now_or_never());
+        assert_eq!(Some(Some(3)), rx.next().now_or_never());
+        assert_eq!(Some(None), rx.next().now_or_never());
+    }
+
+    #[test]
+    fn test_channel_closed() {
+        let (tx, rx) = channel(None, EventOverflow::Backpressure);
+        assert!(!tx.is_closed());
+        drop(rx);
+        assert!(tx.is_closed());
+        assert_eq!(Err(1), tx.send(1));
+    }
 }
@@ -1,4 +1,4 @@
-use super::{config::Config, Shard};
+use super::{config::Config, EventOverflow, Shard};
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
@@ -106,6 +106,8 @@ impl ShardBuilder {
         }
 
         Self(Config {
+            event_channel_capacity: None,
+            event_overflow: EventOverflow::default(),
             gateway_url: None,
             http_client: HttpClient::new(token.clone()),
             intents,
@@ -124,6 +126,37 @@ impl ShardBuilder {
         Shard::new_with_config(self.0)
     }
 
+    /// Set the maximum number of events queued in each event stream.
+    ///
+    /// Once a stream has this many events that haven't been received yet, the
+    /// shard handles new events according to its [`event_overflow`]. A
+    /// capacity of `0` is treated as `1`.
+    ///
+    /// Default is no capacity, meaning events are queued without limit and
+    /// a slow stream consumer may cause memory usage to grow unbounded.
+    ///
+    /// [`event_overflow`]: Self::event_overflow
+    pub fn event_channel_capacity(mut self, event_channel_capacity: usize) -> Self {
+        self.0
+            .event_channel_capacity
+            .replace(event_channel_capacity);
+
+        self
+    }
+
+    /// Set the behavior of event streams once their [capacity] is reached.
+    ///
+    /// This has no effect unless a capacity is set.
+    ///
+    /// Default is [`EventOverflow::Backpressure`].
+    ///
+    /// [capacity]: Self::event_channel_capacity
+    pub fn event_overflow(mut self, event_overflow: EventOverflow) -> Self {
+        self.0.event_overflow = event_overflow;
+
+        self
+    }
+
     /// Set the URL used for connecting to Discord's gateway
     pub fn gateway_url(mut self, gateway_url: Option<String>) -> Self {
         self.0.gateway_url = gateway_url.map(String::into_boxed_str);
@@ -1,3 +1,4 @@
+use super::EventOverflow;
 use std::sync::Arc;
 use twilight_gateway_queue::Queue;
 use twilight_http::Client;
@@ -11,6 +12,8 @@ use twilight_model::gateway::{payload::update_status::UpdateStatusInfo, Intents}
 /// [`Shard::builder`]: super::Shard::builder
 #[derive(Clone, Debug)]
 pub struct Config {
+    pub(super) event_channel_capacity: Option<usize>,
+    pub(super) event_overflow: EventOverflow,
     pub(crate) gateway_url: Option<Box<str>>,
     pub(crate) http_client: Client,
     pub(super) intents: Intents,
@@ -24,6 +27,17 @@ pub struct Config {
 }
 
 impl Config {
+    /// Return the maximum number of events queued in each event stream, if
+    /// any.
+    pub fn event_channel_capacity(&self) -> Option<usize> {
+        self.event_channel_capacity
+    }
+
+    /// Return the behavior of event streams once their capacity is reached.
+    pub fn event_overflow(&self) -> EventOverflow {
+        self.event_overflow
+    }
+
     /// Return an immutable reference to the url used to connect to the gateway.
     pub fn gateway_url(&self) -> Option<&str> {
         self.gateway_url.as_deref()
@@ -13,8 +13,7 @@
 //! [`EventType`]: ::twilight_model::gateway::event::EventType
 //! [`Shard::some_events`]: super::Shard::some_events
 
-use crate::EventTypeFlags;
-use futures_channel::mpsc::UnboundedReceiver;
+use crate::{listener::ListenerReceiver, EventTypeFlags};
 use futures_util::stream::{Stream, StreamExt};
 use std::{
     pin::Pin,
@@ -22,6 +21,32 @@ use std::{
 };
 use twilight_model::gateway::event::Event;
 
+/// Behavior of an event stream once its capacity has been reached.
+///
+/// Refer to [`ShardBuilder::event_channel_capacity`] for more information.
+///
+/// [`ShardBuilder::event_channel_capacity`]: super::ShardBuilder::event_channel_capacity
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum EventOverflow {
+    /// Stop processing gateway messages until the stream has room.
+    ///
+    /// No events are lost, but a stream that isn't read from for too long
+    /// delays the shard's handling of heartbeat acknowledgements and may cause
+    /// it to reconnect.
+    Backpressure,
+    /// Drop the oldest event in the stream to make room for the new one.
+    ///
+    /// The number of dropped events is available via [`Events::dropped`].
+    DropOldest,
+}
+
+impl Default for EventOverflow {
+    fn default() -> Self {
+        Self::Backpressure
+    }
+}
+
 /// A stream of events from a [`Shard`].
 ///
 /// The events of this stream may or may not be filtered. You can check the
@@ -40,14 +65,24 @@ use twilight_model::gateway::event::Event;
 /// [`futures::stream::Stream`]: https://docs.rs/futures/*/futures/stream/trait.Stream.html
 pub struct Events {
     event_types: EventTypeFlags,
-    rx: UnboundedReceiver<Event>,
+    rx: ListenerReceiver<Event>,
 }
 
 impl Events {
-    pub(super) fn new(event_types: EventTypeFlags, rx: UnboundedReceiver<Event>) -> Self {
+    pub(super) fn new(event_types: EventTypeFlags, rx: ListenerReceiver<Event>) -> Self {
         Self { event_types, rx }
     }
 
+    /// Number of events that have been dropped because the stream was full.
+    ///
+    /// This is only ever non-zero if the shard was configured with an
+    /// [event channel capacity] and [`EventOverflow::DropOldest`].
+    ///
+    /// [event channel capacity]: super::ShardBuilder::event_channel_capacity
+    pub fn dropped(&self) -> u64 {
+        self.rx.dropped()
+    }
+
     /// Returns the event types that can be passed to this stream.
     pub fn event_types(&self) -> EventTypeFlags {
         self.event_types
@@ -64,9 +99,21 @@ impl Stream for Events {
 
 #[cfg(test)]
 mod tests {
-    use super::Events;
+    use super::{EventOverflow, Events};
     use futures_util::stream::Stream;
     use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
 
+    assert_impl_all!(
+        EventOverflow: Clone,
+        Copy,
+        Debug,
+        Default,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
     assert_impl_all!(Events: Send, Stream, Sync);
 }
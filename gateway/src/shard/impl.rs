@@ -394,11 +394,11 @@ impl Shard {
             self.0
                 .config
                 .http_client()
-                .gateway()
-                .authed()
+                .gateway_info()
                 .await
                 .map_err(|source| ShardStartError::RetrievingGatewayUrl { source })?
-                .url
+                .url()
+                .to_owned()
         };
 
         let config = Arc::clone(&self.0.config);
@@ -482,7 +482,11 @@ impl Shard {
     ///
     /// [`futures::stream::Stream`]: https://docs.rs/futures/*/futures/stream/trait.Stream.html
     pub fn some_events(&self, event_types: EventTypeFlags) -> Events {
-        let rx = self.0.listeners.add(event_types);
+        let rx = self.0.listeners.add(
+            event_types,
+            self.0.config.event_channel_capacity(),
+            self.0.config.event_overflow(),
+        );
 
         Events::new(event_types, rx)
     }
@@ -3,6 +3,10 @@ pub use serde_json::{from_slice, from_str, to_string, to_vec, Error as JsonError
 #[cfg(feature = "simd-json")]
 pub use simd_json::{from_slice, from_str, to_string, to_vec, Error as JsonError};
 
+#[cfg(feature = "unknown-fields")]
+use once_cell::sync::Lazy;
+#[cfg(feature = "unknown-fields")]
+use std::{collections::HashSet, sync::Mutex};
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
@@ -66,13 +70,19 @@ pub fn parse_gateway_event(
     let gateway_deserializer = GatewayEventDeserializer::new(op, sequence, event_type);
     let mut json_deserializer = Deserializer::from_str(json);
 
-    gateway_deserializer
-        .deserialize(&mut json_deserializer)
-        .map_err(|source| {
-            tracing::debug!("invalid JSON: {}", json);
+    #[cfg(feature = "unknown-fields")]
+    let result = gateway_deserializer.deserialize(serde_ignored::Deserializer::new(
+        &mut json_deserializer,
+        |path| log_unknown_field(op, event_type, &path),
+    ));
+    #[cfg(not(feature = "unknown-fields"))]
+    let result = gateway_deserializer.deserialize(&mut json_deserializer);
 
-            GatewayEventParsingError::Deserializing { source }
-        })
+    result.map_err(|source| {
+        tracing::debug!("invalid JSON: {}", json);
+
+        GatewayEventParsingError::Deserializing { source }
+    })
 }
 
 /// Parse a gateway event from a string using `simd-json` with headers.
@@ -108,13 +118,100 @@ pub fn parse_gateway_event(
     let mut json_deserializer = Deserializer::from_slice(json_bytes)
         .map_err(|_| GatewayEventParsingError::PayloadInvalid)?;
 
-    gateway_deserializer
-        .deserialize(&mut json_deserializer)
-        .map_err(|source| {
-            tracing::debug!("invalid JSON: {}", json);
+    #[cfg(feature = "unknown-fields")]
+    let result = gateway_deserializer.deserialize(serde_ignored::Deserializer::new(
+        &mut json_deserializer,
+        |path| log_unknown_field(op, event_type, &path),
+    ));
+    #[cfg(not(feature = "unknown-fields"))]
+    let result = gateway_deserializer.deserialize(&mut json_deserializer);
+
+    result.map_err(|source| {
+        tracing::debug!("invalid JSON: {}", json);
+
+        GatewayEventParsingError::Deserializing { source }
+    })
+}
+
+/// Maximum number of unknown fields remembered before the record of which have
+/// been logged is cleared.
+#[cfg(feature = "unknown-fields")]
+const UNKNOWN_FIELDS_LIMIT: usize = 1024;
+
+/// Log a field that wasn't deserialized into the model of an event.
+///
+/// Each field is only logged the first time it's encountered for an opcode and
+/// event type combination to avoid flooding logs on busy shards.
+#[cfg(feature = "unknown-fields")]
+fn log_unknown_field(op: u8, event_type: Option<&str>, path: &serde_ignored::Path<'_>) {
+    static SEEN: Lazy<Mutex<UnknownFields>> = Lazy::new(Mutex::default);
+
+    let key = format!(
+        "{}:{}:{}",
+        op,
+        event_type.unwrap_or_default(),
+        normalize_path(path),
+    );
+
+    let first = SEEN.lock().map_or(false, |mut seen| seen.insert(key));
+
+    if first {
+        tracing::warn!(
+            op,
+            event_type,
+            "unknown field `{}` in gateway event; it isn't modelled yet",
+            path,
+        );
+    }
+}
+
+/// Format a path with sequence indices and IDs used as map keys replaced by
+/// `*`, so that the same field in different elements is only logged once.
+#[cfg(feature = "unknown-fields")]
+fn normalize_path(path: &serde_ignored::Path<'_>) -> String {
+    use serde_ignored::Path;
+
+    match path {
+        Path::Root => String::new(),
+        Path::Seq { parent, .. } => format!("{}.*", normalize_path(parent)),
+        Path::Map { parent, key } => {
+            let is_id = !key.is_empty() && key.bytes().all(|b| b.is_ascii_digit());
+
+            if is_id {
+                format!("{}.*", normalize_path(parent))
+            } else {
+                format!("{}.{}", normalize_path(parent), key)
+            }
+        }
+        Path::Some { parent }
+        | Path::NewtypeStruct { parent }
+        | Path::NewtypeVariant { parent } => normalize_path(parent),
+    }
+}
+
+/// Set of unknown fields that have been logged, bounded to
+/// [`UNKNOWN_FIELDS_LIMIT`] entries.
+///
+/// Once the limit is reached the set is cleared, so a field may be logged
+/// again rather than the set growing without bound.
+#[cfg(feature = "unknown-fields")]
+#[derive(Debug, Default)]
+struct UnknownFields(HashSet<String>);
+
+#[cfg(feature = "unknown-fields")]
+impl UnknownFields {
+    /// Record a field, returning whether it hadn't already been recorded.
+    fn insert(&mut self, key: String) -> bool {
+        if self.0.contains(&key) {
+            return false;
+        }
 
-            GatewayEventParsingError::Deserializing { source }
-        })
+        if self.0.len() >= UNKNOWN_FIELDS_LIMIT {
+            self.0.clear();
+        }
+
+        self.0.insert(key)
+    }
 }
 
 #[cfg(test)]
@@ -125,4 +222,47 @@ mod tests {
 
     assert_fields!(GatewayEventParsingError::Deserializing: source);
     assert_impl_all!(GatewayEventParsingError: Debug, Error, Send, Sync);
+
+    #[cfg(feature = "unknown-fields")]
+    #[test]
+    fn test_unknown_fields_normalized() {
+        use super::{normalize_path, UnknownFields, UNKNOWN_FIELDS_LIMIT};
+        use serde_ignored::Path;
+
+        let root = Path::Root;
+        let members = Path::Map {
+            parent: &root,
+            key: "members".to_owned(),
+        };
+        let mut seen = UnknownFields::default();
+
+        for index in 0..10 {
+            let member = Path::Seq {
+                parent: &members,
+                index,
+            };
+            let field = Path::Map {
+                parent: &member,
+                key: "new_field".to_owned(),
+            };
+
+            assert_eq!(".members.*.new_field", normalize_path(&field));
+            assert_eq!(index == 0, seen.insert(normalize_path(&field)));
+        }
+
+        let voice_states = Path::Map {
+            parent: &root,
+            key: "voice_states".to_owned(),
+        };
+        let id = Path::Map {
+            parent: &voice_states,
+            key: "123456789012345678".to_owned(),
+        };
+        assert_eq!(".voice_states.*", normalize_path(&id));
+
+        for index in 0..UNKNOWN_FIELDS_LIMIT {
+            seen.insert(index.to_string());
+        }
+        assert!(seen.0.len() <= UNKNOWN_FIELDS_LIMIT);
+    }
 }
@@ -33,7 +33,7 @@ mod sink;
 pub use self::{
     builder::{LargeThresholdError, ShardBuilder, ShardIdError},
     config::Config,
-    event::Events,
+    event::{EventOverflow, Events},
     processor::heartbeat::Latency,
     r#impl::{
         CommandError, Information, ResumeSession, SendError, SessionInactiveError, Shard,
@@ -1,9 +1,11 @@
 use super::super::json::{self, GatewayEventParsingError};
 use crate::{listener::Listeners, EventTypeFlags};
+use futures_util::future;
 use std::{
     convert::TryFrom,
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
+    task::Poll,
 };
 use twilight_model::gateway::event::{shard::Payload, Event};
 
@@ -68,6 +70,23 @@ impl Emitter {
         self.listeners.event_types().contains(event_type)
     }
 
+    /// Wait until every listener has room for another event.
+    ///
+    /// This resolves immediately unless a listener's channel is bounded and
+    /// applies backpressure.
+    pub async fn ready(&self) {
+        future::poll_fn(|cx| {
+            for listener in self.listeners.all().iter() {
+                if listener.tx.poll_ready(cx).is_pending() {
+                    return Poll::Pending;
+                }
+            }
+
+            Poll::Ready(())
+        })
+        .await
+    }
+
     /// Send some bytes to listeners that have subscribed to shard payloads.
     ///
     /// Shard payload events aren't subscribed to by default and must be opted in
@@ -166,7 +185,7 @@ impl Emitter {
                 return !listener.tx.is_closed();
             }
 
-            listener.tx.unbounded_send(f(idx)).is_ok()
+            listener.tx.send(f(idx)).is_ok()
         });
     }
 }
@@ -174,24 +193,29 @@ impl Emitter {
 #[cfg(test)]
 mod tests {
     use super::Emitter;
-    use crate::{listener::Listeners, Event, EventTypeFlags};
+    use crate::{listener::Listeners, shard::EventOverflow, Event, EventTypeFlags};
+    use futures_util::{future::FutureExt, stream::StreamExt};
 
     #[test]
     fn test_bytes_send() {
         let listeners = Listeners::default();
-        let mut rx = listeners.add(EventTypeFlags::SHARD_PAYLOAD);
+        let mut rx = listeners.add(
+            EventTypeFlags::SHARD_PAYLOAD,
+            None,
+            EventOverflow::Backpressure,
+        );
         let emitter = Emitter::new(listeners);
         emitter.bytes(&[1]);
         assert_eq!(1, emitter.listeners.len());
 
-        assert!(matches!(rx.try_next(), Ok(Some(_))));
-        assert!(rx.try_next().is_err());
+        assert!(matches!(rx.next().now_or_never(), Some(Some(_))));
+        assert!(rx.next().now_or_never().is_none());
     }
 
     #[test]
     fn test_event_removes_closed_channels() {
         let listeners = Listeners::default();
-        let _ = listeners.add(EventTypeFlags::default());
+        let _ = listeners.add(EventTypeFlags::default(), None, EventOverflow::Backpressure);
         let emitter = Emitter::new(listeners);
         emitter.event(Event::GatewayReconnect);
         assert!(emitter.listeners.all().is_empty());
@@ -200,17 +224,34 @@ mod tests {
     #[test]
     fn test_event_sends_to_rxs() {
         let listeners = Listeners::default();
-        let mut rx1 = listeners.add(EventTypeFlags::default());
-        let mut rx2 = listeners.add(EventTypeFlags::default());
+        let mut rx1 = listeners.add(EventTypeFlags::default(), None, EventOverflow::Backpressure);
+        let mut rx2 = listeners.add(EventTypeFlags::default(), None, EventOverflow::Backpressure);
         let emitter = Emitter::new(listeners);
         emitter.event(Event::GatewayReconnect);
         assert_eq!(2, emitter.listeners.len());
 
-        assert!(matches!(rx1.try_next(), Ok(Some(_))));
-        assert!(matches!(rx2.try_next(), Ok(Some(_))));
+        assert!(matches!(rx1.next().now_or_never(), Some(Some(_))));
+        assert!(matches!(rx2.next().now_or_never(), Some(Some(_))));
 
         // now check that they didn't send the event twice
-        assert!(rx1.try_next().is_err());
-        assert!(rx2.try_next().is_err());
+        assert!(rx1.next().now_or_never().is_none());
+        assert!(rx2.next().now_or_never().is_none());
+    }
+
+    #[test]
+    fn test_ready_waits_for_full_listeners() {
+        let listeners = Listeners::default();
+        let mut rx = listeners.add(
+            EventTypeFlags::default(),
+            Some(1),
+            EventOverflow::Backpressure,
+        );
+        let emitter = Emitter::new(listeners);
+        assert!(emitter.ready().now_or_never().is_some());
+        emitter.event(Event::GatewayReconnect);
+        assert!(emitter.ready().now_or_never().is_none());
+
+        assert!(matches!(rx.next().now_or_never(), Some(Some(_))));
+        assert!(emitter.ready().now_or_never().is_some());
     }
 }
@@ -21,6 +21,7 @@ use futures_util::stream::StreamExt;
 use serde::{Deserialize, Serialize};
 use std::{
     borrow::Cow,
+    convert::TryFrom,
     env::consts::OS,
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
@@ -41,7 +42,7 @@ use twilight_model::gateway::{
         resume::Resume,
         Ready,
     },
-    Intents, OpCode,
+    CloseCode as GatewayCloseCode, Intents, OpCode,
 };
 use url::{ParseError as UrlParseError, Url};
 
@@ -158,6 +159,17 @@ enum ReceivingEventError {
     },
     /// The event stream has ended, this is recoverable by resuming.
     EventStreamEnded,
+    /// Gateway closed the connection with a close code that the shard can't
+    /// recover from by reconnecting.
+    ///
+    /// Close codes with dedicated variants, such as for invalid intents, are
+    /// not included.
+    FatallyClosed {
+        /// Close code sent by the gateway.
+        close_code: GatewayCloseCode,
+        /// ID of the shard.
+        shard_id: u64,
+    },
     /// Current user isn't allowed to use at least one of the configured
     /// intents.
     ///
@@ -184,6 +196,7 @@ impl ReceivingEventError {
         matches!(
             self,
             ReceivingEventError::AuthorizationInvalid { .. }
+                | ReceivingEventError::FatallyClosed { .. }
                 | ReceivingEventError::IntentsDisallowed { .. }
                 | ReceivingEventError::IntentsInvalid { .. }
         )
@@ -206,6 +219,13 @@ impl Display for ReceivingEventError {
                 shard_id
             )),
             Self::Decompressing { .. } => f.write_str("a frame could not be decompressed"),
+            Self::FatallyClosed {
+                close_code,
+                shard_id,
+            } => f.write_fmt(format_args!(
+                "shard {} was closed with the unrecoverable code {:?}",
+                shard_id, close_code,
+            )),
             Self::IntentsDisallowed { intents, shard_id } => f.write_fmt(format_args!(
                 "at least one of the intents ({:?}) for shard {} are disallowed",
                 intents, shard_id
@@ -620,6 +640,10 @@ impl ShardProcessor {
         self.inflater.clear();
 
         loop {
+            // Don't read any further messages while an event stream applying
+            // backpressure is full.
+            self.emitter.ready().await;
+
             // Returns None when the socket forwarder has ended, meaning the
             // connection was dropped.
             let mut msg = self
@@ -691,28 +715,35 @@ impl ShardProcessor {
             shard_id: self.config.shard()[0],
         }));
 
-        if let Some(close_frame) = close_frame {
-            match close_frame.code {
-                CloseCode::Library(4004) => {
-                    return Err(ReceivingEventError::AuthorizationInvalid {
-                        shard_id: self.config.shard()[0],
-                        token: self.config.token().to_owned(),
-                    });
-                }
-                CloseCode::Library(4013) => {
-                    return Err(ReceivingEventError::IntentsInvalid {
-                        intents: self.config.intents(),
-                        shard_id: self.config.shard()[0],
-                    });
-                }
-                CloseCode::Library(4014) => {
-                    return Err(ReceivingEventError::IntentsDisallowed {
-                        intents: self.config.intents(),
-                        shard_id: self.config.shard()[0],
-                    });
-                }
-                _ => {}
+        let close_code =
+            close_frame.and_then(|frame| GatewayCloseCode::try_from(u16::from(frame.code)).ok());
+
+        match close_code {
+            Some(GatewayCloseCode::AuthenticationFailed) => {
+                return Err(ReceivingEventError::AuthorizationInvalid {
+                    shard_id: self.config.shard()[0],
+                    token: self.config.token().to_owned(),
+                });
+            }
+            Some(GatewayCloseCode::InvalidIntents) => {
+                return Err(ReceivingEventError::IntentsInvalid {
+                    intents: self.config.intents(),
+                    shard_id: self.config.shard()[0],
+                });
+            }
+            Some(GatewayCloseCode::DisallowedIntents) => {
+                return Err(ReceivingEventError::IntentsDisallowed {
+                    intents: self.config.intents(),
+                    shard_id: self.config.shard()[0],
+                });
+            }
+            Some(close_code) if close_code.is_fatal() => {
+                return Err(ReceivingEventError::FatallyClosed {
+                    close_code,
+                    shard_id: self.config.shard()[0],
+                });
             }
+            _ => {}
         }
 
         self.resume().await;
@@ -1,3 +1,5 @@
+//! Errors parsing ratelimit headers.
+
 use hyper::header::ToStrError;
 use std::{
     error::Error as StdError,
@@ -7,33 +9,54 @@ use std::{
     str::ParseBoolError,
 };
 
+/// Result of parsing ratelimit headers.
 pub type RatelimitResult<T> = StdResult<T, RatelimitError>;
 
+/// Parsing ratelimit headers failed.
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum RatelimitError {
+    /// No headers are present.
     NoHeaders,
+    /// A required header is missing.
     HeaderMissing {
+        /// Name of the header.
         name: &'static str,
     },
+    /// A header's value isn't valid UTF-8.
     HeaderNotUtf8 {
+        /// Name of the header.
         name: &'static str,
+        /// Reason for the error.
         source: ToStrError,
+        /// Value of the header.
         value: Vec<u8>,
     },
+    /// A header's value isn't a boolean.
     ParsingBoolText {
+        /// Name of the header.
         name: &'static str,
+        /// Reason for the error.
         source: ParseBoolError,
+        /// Value of the header.
         text: String,
     },
+    /// A header's value isn't a float.
     ParsingFloatText {
+        /// Name of the header.
         name: &'static str,
+        /// Reason for the error.
         source: ParseFloatError,
+        /// Value of the header.
         text: String,
     },
+    /// A header's value isn't an integer.
     ParsingIntText {
+        /// Name of the header.
         name: &'static str,
+        /// Reason for the error.
         source: ParseIntError,
+        /// Value of the header.
         text: String,
     },
 }
@@ -1,27 +1,39 @@
-use super::error::{RatelimitError, RatelimitResult};
+//! Parsing of the ratelimit headers of responses.
+
+use crate::error::{RatelimitError, RatelimitResult};
 use hyper::header::{HeaderMap, HeaderValue};
 use std::convert::TryFrom;
 
+/// Ratelimit information parsed from the headers of a response.
 #[derive(Clone, Debug)]
 #[non_exhaustive]
 pub enum RatelimitHeaders {
+    /// The request was globally ratelimited.
     GlobalLimited {
+        /// Number of milliseconds until the global ratelimit resets.
         reset_after: u64,
     },
+    /// The route isn't ratelimited.
     None,
+    /// Ratelimit information of the route's bucket.
     Present {
+        /// ID of the bucket, if any.
         bucket: Option<String>,
+        /// Whether the ratelimit is global.
         global: bool,
+        /// Total number of requests allowed during the bucket's period.
         limit: u64,
+        /// Number of requests remaining until the bucket resets.
         remaining: u64,
-        // when the bucket resets in unix ms
+        /// When the bucket resets as a Unix timestamp in milliseconds.
         reset: u64,
-        // how long until it resets in ms
+        /// Number of milliseconds until the bucket resets.
         reset_after: u64,
     },
 }
 
 impl RatelimitHeaders {
+    /// Whether the ratelimit is global.
     pub fn global(&self) -> bool {
         match self {
             Self::GlobalLimited { .. } => true,
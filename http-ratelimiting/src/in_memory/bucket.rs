@@ -1,5 +1,5 @@
-use super::{headers::RatelimitHeaders, GlobalLockPair};
-use crate::routing::Path;
+use super::GlobalLockPair;
+use crate::{headers::RatelimitHeaders, request::Path, Bucket as BucketInfo, TicketSender};
 use futures_channel::{
     mpsc::{self, UnboundedReceiver, UnboundedSender},
     oneshot::{self, Sender},
@@ -71,6 +71,15 @@ impl Bucket {
         TimeRemaining::Some(Duration::from_millis(reset_after) - elapsed)
     }
 
+    pub async fn to_bucket(&self) -> BucketInfo {
+        BucketInfo::new(
+            self.limit(),
+            self.remaining(),
+            Duration::from_millis(self.reset_after()),
+            *self.started_at.lock().await,
+        )
+    }
+
     pub async fn try_reset(&self) -> bool {
         if self.started_at.lock().await.is_none() {
             return false;
@@ -112,19 +121,16 @@ impl Bucket {
 
 #[derive(Debug)]
 pub struct BucketQueue {
-    rx: Mutex<UnboundedReceiver<Sender<Sender<Option<RatelimitHeaders>>>>>,
-    tx: UnboundedSender<Sender<Sender<Option<RatelimitHeaders>>>>,
+    rx: Mutex<UnboundedReceiver<Sender<TicketSender>>>,
+    tx: UnboundedSender<Sender<TicketSender>>,
 }
 
 impl BucketQueue {
-    pub fn push(&self, tx: Sender<Sender<Option<RatelimitHeaders>>>) {
+    pub fn push(&self, tx: Sender<TicketSender>) {
         let _ = self.tx.unbounded_send(tx);
     }
 
-    pub async fn pop(
-        &self,
-        timeout_duration: Duration,
-    ) -> Option<Sender<Sender<Option<RatelimitHeaders>>>> {
+    pub async fn pop(&self, timeout_duration: Duration) -> Option<Sender<TicketSender>> {
         let mut rx = self.rx.lock().await;
 
         match timeout(timeout_duration, StreamExt::next(&mut *rx))
@@ -240,7 +246,7 @@ impl BucketQueueTask {
         drop(lock);
     }
 
-    async fn next(&self) -> Option<Sender<Sender<Option<RatelimitHeaders>>>> {
+    async fn next(&self) -> Option<Sender<TicketSender>> {
         tracing::debug!(path=?self.path, "starting to get next in queue");
 
         self.wait_if_needed().await;
@@ -1,16 +1,13 @@
-pub mod error;
+//! In-memory implementation of a [`Ratelimiter`].
 
 mod bucket;
-mod headers;
 
-pub use self::{
-    error::{RatelimitError, RatelimitResult},
-    headers::RatelimitHeaders,
+use self::bucket::{Bucket, BucketQueueTask};
+use crate::{
+    request::Path, GetBucketFuture, GetTicketFuture, IsGloballyLockedFuture, Ratelimiter,
+    TicketReceiver, TicketSender,
 };
-
-use crate::routing::Path;
-use bucket::{Bucket, BucketQueueTask, TimeRemaining};
-use futures_channel::oneshot::{self, Receiver, Sender};
+use futures_channel::oneshot::{self, Sender};
 use futures_util::lock::Mutex;
 use std::{
     collections::hash_map::{Entry, HashMap},
@@ -18,7 +15,6 @@ use std::{
         atomic::{AtomicBool, Ordering},
         Arc,
     },
-    time::Duration,
 };
 
 /// Global lock. We use a pair to avoid actually locking the mutex every check.
@@ -42,14 +38,20 @@ impl GlobalLockPair {
     }
 }
 
+/// Ratelimiter storing its buckets in memory.
+///
+/// This is the default ratelimiter of `twilight-http`. Each bucket is
+/// processed by a background task, so a Tokio runtime is required.
+///
+/// Cloning the ratelimiter is cheap and clones share the same buckets.
 #[derive(Clone, Debug, Default)]
-pub struct Ratelimiter {
+pub struct InMemoryRatelimiter {
     buckets: Arc<Mutex<HashMap<Path, Arc<Bucket>>>>,
     global: Arc<GlobalLockPair>,
 }
 
-impl Ratelimiter {
-    /// Create a new ratelimiter.
+impl InMemoryRatelimiter {
+    /// Create a new in-memory ratelimiter.
     ///
     /// Most users won't need to use this directly. If you're creating your own
     /// HTTP proxy then this is good to use for your own ratelimiting.
@@ -57,7 +59,7 @@ impl Ratelimiter {
         Self::default()
     }
 
-    pub async fn get(&self, path: Path) -> Receiver<Sender<Option<RatelimitHeaders>>> {
+    async fn ticket_inner(&self, path: Path) -> TicketReceiver {
         tracing::debug!("getting bucket for path: {:?}", path);
 
         let (tx, rx) = oneshot::channel();
@@ -78,24 +80,7 @@ impl Ratelimiter {
         rx
     }
 
-    /// Provide an estimate for the time left until a path can be used
-    /// without being ratelimited.
-    ///
-    /// This method is not guaranteed to be accurate and may return
-    /// None if either no ratelimit is known or buckets are remaining.
-    pub async fn time_until_available(&self, path: &Path) -> Option<Duration> {
-        let buckets = self.buckets.lock().await;
-        match buckets.get(path)?.time_remaining().await {
-            TimeRemaining::Finished | TimeRemaining::NotStarted => None,
-            TimeRemaining::Some(duration) => Some(duration),
-        }
-    }
-
-    async fn entry(
-        &self,
-        path: Path,
-        tx: Sender<Sender<Option<RatelimitHeaders>>>,
-    ) -> (Arc<Bucket>, bool) {
+    async fn entry(&self, path: Path, tx: Sender<TicketSender>) -> (Arc<Bucket>, bool) {
         // nb: not realisically point of contention
         let mut buckets = self.buckets.lock().await;
 
@@ -122,3 +107,58 @@ impl Ratelimiter {
         }
     }
 }
+
+impl Ratelimiter for InMemoryRatelimiter {
+    fn bucket(&self, path: &Path) -> GetBucketFuture {
+        let buckets = Arc::clone(&self.buckets);
+        let path = path.clone();
+
+        Box::pin(async move {
+            let bucket = Arc::clone(buckets.lock().await.get(&path)?);
+
+            Some(bucket.to_bucket().await)
+        })
+    }
+
+    fn is_globally_locked(&self) -> IsGloballyLockedFuture {
+        Box::pin(futures_util::future::ready(self.global.is_locked()))
+    }
+
+    fn ticket(&self, path: Path) -> GetTicketFuture {
+        let ratelimiter = self.clone();
+
+        Box::pin(async move { ratelimiter.ticket_inner(path).await })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InMemoryRatelimiter;
+    use crate::{request::Path, Ratelimiter};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(
+        InMemoryRatelimiter: Clone,
+        Debug,
+        Default,
+        Ratelimiter,
+        Send,
+        Sync
+    );
+
+    #[tokio::test]
+    async fn test_bucket() {
+        let ratelimiter = InMemoryRatelimiter::new();
+        let path = Path::ChannelsIdMessages(123);
+        assert!(ratelimiter.bucket(&path).await.is_none());
+
+        let tx = ratelimiter.ticket(path.clone()).await.await.unwrap();
+        let bucket = ratelimiter.bucket(&path).await.unwrap();
+        assert_eq!(u64::max_value(), bucket.limit());
+        assert!(ratelimiter.time_until_available(&path).await.is_none());
+        assert!(!ratelimiter.is_globally_locked().await);
+
+        let _ = tx.send(None);
+    }
+}
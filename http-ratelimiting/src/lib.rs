@@ -0,0 +1,230 @@
+//! # twilight-http-ratelimiting
+//!
+//! [![discord badge][]][discord link] [![github badge][]][github link] [![license badge][]][license link] ![rust badge]
+//!
+//! Ratelimiting functionality for HTTP requests to the Discord API.
+//!
+//! This is the ratelimiter used by [`twilight-http`], split into its own
+//! crate so that HTTP proxies and custom clients can use exactly the same
+//! bucket semantics.
+//!
+//! Requests are grouped into buckets by their [`Path`]. A [`Ratelimiter`]
+//! hands out a ticket for a path once a request to it can be made, and the
+//! ratelimit headers of the response are then sent back through the ticket
+//! to update the bucket. [`InMemoryRatelimiter`] is the default
+//! implementation, storing buckets in memory.
+//!
+//! ## Examples
+//!
+//! Wait for a ticket to make a request to a channel's messages, and then send
+//! the response's ratelimit headers back to the ratelimiter:
+//!
+//! ```rust,no_run
+//! use std::convert::TryFrom;
+//! use twilight_http_ratelimiting::{
+//!     headers::RatelimitHeaders, request::Path, InMemoryRatelimiter, Ratelimiter,
+//! };
+//! # use hyper::HeaderMap;
+//!
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let ratelimiter = InMemoryRatelimiter::new();
+//!
+//! let ticket = ratelimiter.ticket(Path::ChannelsIdMessages(123)).await;
+//! let headers_tx = ticket.await?;
+//!
+//! // Make the request here.
+//! # let response_headers = HeaderMap::new();
+//!
+//! let headers = RatelimitHeaders::try_from(&response_headers).ok();
+//! let _ = headers_tx.send(headers);
+//! # Ok(()) }
+//! ```
+//!
+//! [`twilight-http`]: https://docs.rs/twilight-http
+//! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
+//! [discord link]: https://discord.gg/7jj8n7D
+//! [github badge]: https://img.shields.io/badge/github-twilight-6f42c1.svg?style=for-the-badge&logo=github
+//! [github link]: https://github.com/twilight-rs/twilight
+//! [license badge]: https://img.shields.io/badge/license-ISC-blue.svg?style=for-the-badge&logo=pastebin
+//! [license link]: https://github.com/twilight-rs/twilight/blob/trunk/LICENSE.md
+//! [rust badge]: https://img.shields.io/badge/rust-1.48+-93450a.svg?style=for-the-badge&logo=rust
+
+#![deny(
+    clippy::all,
+    clippy::pedantic,
+    future_incompatible,
+    nonstandard_style,
+    rust_2018_idioms,
+    broken_intra_doc_links,
+    unused,
+    warnings
+)]
+#![allow(
+    clippy::module_name_repetitions,
+    clippy::must_use_candidate,
+    clippy::missing_errors_doc
+)]
+
+pub mod error;
+pub mod headers;
+pub mod in_memory;
+pub mod request;
+
+pub use self::{
+    error::{RatelimitError, RatelimitResult},
+    headers::RatelimitHeaders,
+    in_memory::InMemoryRatelimiter,
+    request::{Path, PathParseError},
+};
+
+use futures_channel::oneshot::{Receiver, Sender};
+use std::{
+    fmt::Debug,
+    future::Future,
+    pin::Pin,
+    time::{Duration, Instant},
+};
+
+/// Sender of a ticket, used to send the ratelimit headers of a response back
+/// to the ratelimiter.
+///
+/// Send `None` if the request was aborted or its headers couldn't be parsed.
+pub type TicketSender = Sender<Option<RatelimitHeaders>>;
+
+/// Receiver of a ticket, resolving once a request may be made.
+pub type TicketReceiver = Receiver<TicketSender>;
+
+/// Future returned by [`Ratelimiter::bucket`].
+pub type GetBucketFuture = Pin<Box<dyn Future<Output = Option<Bucket>> + Send + 'static>>;
+
+/// Future returned by [`Ratelimiter::is_globally_locked`].
+pub type IsGloballyLockedFuture = Pin<Box<dyn Future<Output = bool> + Send + 'static>>;
+
+/// Future returned by [`Ratelimiter::ticket`].
+pub type GetTicketFuture = Pin<Box<dyn Future<Output = TicketReceiver> + Send + 'static>>;
+
+/// Future returned by [`Ratelimiter::time_until_available`].
+pub type TimeUntilAvailableFuture =
+    Pin<Box<dyn Future<Output = Option<Duration>> + Send + 'static>>;
+
+/// Ratelimiter for requests to the Discord API.
+///
+/// Implementations group requests into buckets by their [`Path`] and only
+/// hand out tickets for a bucket once a request can be made without being
+/// ratelimited.
+pub trait Ratelimiter: Debug + Send + Sync {
+    /// Retrieve information about the bucket of a path, if the bucket
+    /// exists.
+    fn bucket(&self, path: &Path) -> GetBucketFuture;
+
+    /// Whether the global ratelimit is currently in effect.
+    fn is_globally_locked(&self) -> IsGloballyLockedFuture;
+
+    /// Queue a request to a path, returning a receiver that resolves once
+    /// the request may be made.
+    ///
+    /// The resolved [`TicketSender`] must be used to send the response's
+    /// ratelimit headers back to the ratelimiter.
+    fn ticket(&self, path: Path) -> GetTicketFuture;
+
+    /// Provide an estimate for the time left until a path can be used
+    /// without being ratelimited.
+    ///
+    /// This method is not guaranteed to be accurate and may return `None` if
+    /// either no ratelimit is known or requests are remaining.
+    fn time_until_available(&self, path: &Path) -> TimeUntilAvailableFuture {
+        let bucket = self.bucket(path);
+
+        Box::pin(async move { bucket.await?.time_remaining() })
+    }
+}
+
+/// Information about a ratelimit bucket at a point in time.
+///
+/// This is returned from [`Ratelimiter::bucket`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bucket {
+    limit: u64,
+    remaining: u64,
+    reset_after: Duration,
+    started_at: Option<Instant>,
+}
+
+impl Bucket {
+    /// Create a representation of a bucket.
+    ///
+    /// This is useful for custom [`Ratelimiter`] implementations.
+    pub const fn new(
+        limit: u64,
+        remaining: u64,
+        reset_after: Duration,
+        started_at: Option<Instant>,
+    ) -> Self {
+        Self {
+            limit,
+            remaining,
+            reset_after,
+            started_at,
+        }
+    }
+
+    /// Total number of requests allowed during the bucket's period.
+    pub const fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Number of requests remaining until the bucket resets.
+    pub const fn remaining(&self) -> u64 {
+        self.remaining
+    }
+
+    /// Duration of the bucket's period, after which it resets.
+    pub const fn reset_after(&self) -> Duration {
+        self.reset_after
+    }
+
+    /// When the bucket's current period started, if it has started.
+    pub const fn started_at(&self) -> Option<Instant> {
+        self.started_at
+    }
+
+    /// How long until the bucket resets.
+    ///
+    /// Returns `None` if the bucket's period hasn't started or has already
+    /// finished.
+    pub fn time_remaining(&self) -> Option<Duration> {
+        let elapsed = self.started_at?.elapsed();
+
+        if elapsed > self.reset_after {
+            return None;
+        }
+
+        Some(self.reset_after - elapsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bucket, Ratelimiter};
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::{
+        fmt::Debug,
+        time::{Duration, Instant},
+    };
+
+    assert_impl_all!(Bucket: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_obj_safe!(Ratelimiter);
+
+    #[test]
+    fn test_bucket_time_remaining() {
+        let bucket = Bucket::new(5, 0, Duration::from_secs(60), None);
+        assert!(bucket.time_remaining().is_none());
+
+        let bucket = Bucket::new(5, 0, Duration::from_secs(60), Some(Instant::now()));
+        assert!(bucket.time_remaining().unwrap() <= Duration::from_secs(60));
+
+        let started_at = Instant::now() - Duration::from_secs(61);
+        let bucket = Bucket::new(5, 0, Duration::from_secs(60), Some(started_at));
+        assert!(bucket.time_remaining().is_none());
+    }
+}
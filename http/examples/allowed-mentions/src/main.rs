@@ -13,8 +13,8 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
         //add an empty allowed mentions, this will prevent any and all pings
         .default_allowed_mentions(AllowedMentionsBuilder::new().build_solo())
         .build();
-    let channel_id = ChannelId(381_926_291_785_383_946);
-    let user_id = UserId(77_469_400_222_932_992);
+    let channel_id = ChannelId::new(381_926_291_785_383_946);
+    let user_id = UserId::new(77_469_400_222_932_992);
 
     //here we want to warn a user about trying to ping everyone so we override to allow pinging them
     //but since we did not allow @everyone pings it will not ping everyone
@@ -22,7 +22,7 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
         .create_message(channel_id)
         .content(format!(
             "<@{}> you are not allowed to ping @everyone!",
-            user_id.0
+            user_id.get()
         ))?
         .allowed_mentions()
         .parse_specific_users(vec![user_id])
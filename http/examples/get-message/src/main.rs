@@ -9,7 +9,7 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     tracing_subscriber::fmt::init();
 
     let client = Client::new(env::var("DISCORD_TOKEN")?);
-    let channel_id = ChannelId(381_926_291_785_383_946);
+    let channel_id = ChannelId::new(381_926_291_785_383_946);
 
     future::join_all((1u8..=10).map(|x| {
         client
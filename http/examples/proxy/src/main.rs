@@ -12,7 +12,7 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
         .proxy("localhost:3000", true)
         .ratelimiter(None)
         .build();
-    let channel_id = ChannelId(620_980_184_606_048_278);
+    let channel_id = ChannelId::new(620_980_184_606_048_278);
 
     future::join_all((1u8..=10).map(|x| {
         client
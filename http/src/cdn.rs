@@ -0,0 +1,346 @@
+//! URLs of assets on Discord's CDN, such as avatars, emojis, and guild icons.
+//!
+//! Assets can be downloaded with [`Client::download`] and
+//! [`Client::download_to`], which reuse the client's connection pool.
+//!
+//! # Examples
+//!
+//! Create the URL of a user's avatar as a 256 pixel WebP image:
+//!
+//! ```rust
+//! use twilight_http::cdn::{CdnUrl, ImageFormat};
+//! use twilight_model::{id::UserId, util::ImageHash};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let hash = ImageHash::parse("b2a6536641da91a0b59bd66557c56c36")?;
+//! let url = CdnUrl::avatar(UserId::new(123), hash)
+//!     .format(ImageFormat::WebP)?
+//!     .size(256)?;
+//!
+//! assert_eq!(
+//!     "https://cdn.discordapp.com/avatars/123/b2a6536641da91a0b59bd66557c56c36.webp?size=256",
+//!     url.to_string(),
+//! );
+//! # Ok(()) }
+//! ```
+//!
+//! [`Client::download`]: crate::Client::download
+//! [`Client::download_to`]: crate::Client::download_to
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    id::{EmojiId, GuildId, UserId},
+    util::ImageHash,
+};
+
+/// Base URL of Discord's CDN.
+pub const CDN_URL: &str = "https://cdn.discordapp.com";
+
+/// Error building a [`CdnUrl`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CdnUrlError {
+    /// Format isn't supported by the asset.
+    ///
+    /// GIFs are only supported by animated assets, and default avatars are
+    /// only available as PNGs.
+    FormatUnsupported {
+        /// Provided format.
+        format: ImageFormat,
+    },
+    /// Size isn't a power of two between 16 and 4096.
+    SizeInvalid {
+        /// Provided size.
+        size: u16,
+    },
+}
+
+impl Display for CdnUrlError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::FormatUnsupported { format } => f.write_fmt(format_args!(
+                "the asset isn't available in the {} format",
+                format.extension(),
+            )),
+            Self::SizeInvalid { size } => f.write_fmt(format_args!(
+                "size is {} but must be a power of two between {} and {}",
+                size,
+                CdnUrl::SIZE_MINIMUM,
+                CdnUrl::SIZE_MAXIMUM,
+            )),
+        }
+    }
+}
+
+impl Error for CdnUrlError {}
+
+/// Format of an image on the CDN.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ImageFormat {
+    /// GIF, only available for animated assets.
+    Gif,
+    /// JPEG.
+    Jpeg,
+    /// PNG.
+    Png,
+    /// WebP.
+    WebP,
+}
+
+impl ImageFormat {
+    /// File extension of the format.
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Gif => "gif",
+            Self::Jpeg => "jpg",
+            Self::Png => "png",
+            Self::WebP => "webp",
+        }
+    }
+}
+
+/// URL of an asset on the CDN.
+///
+/// If a format isn't specified, then animated assets are GIFs and other
+/// assets are PNGs. If a size isn't specified, then the CDN's default size is
+/// used.
+///
+/// The URL is created with the type's [`Display`] implementation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CdnUrl {
+    animated: bool,
+    format: Option<ImageFormat>,
+    path: String,
+    png_only: bool,
+    size: Option<u16>,
+}
+
+impl CdnUrl {
+    /// Maximum size of an image.
+    pub const SIZE_MAXIMUM: u16 = 4096;
+
+    /// Minimum size of an image.
+    pub const SIZE_MINIMUM: u16 = 16;
+
+    /// URL of a user's avatar.
+    pub fn avatar(user_id: UserId, hash: ImageHash) -> Self {
+        Self::hashed(format!("avatars/{}", user_id), hash)
+    }
+
+    /// URL of a user's default avatar, used when they haven't set one.
+    ///
+    /// Default avatars are only available as PNGs.
+    pub fn default_avatar(discriminator: u16) -> Self {
+        Self {
+            animated: false,
+            format: None,
+            path: format!("embed/avatars/{}", discriminator % 5),
+            png_only: true,
+            size: None,
+        }
+    }
+
+    /// URL of a custom emoji.
+    pub fn emoji(emoji_id: EmojiId, animated: bool) -> Self {
+        Self {
+            animated,
+            format: None,
+            path: format!("emojis/{}", emoji_id),
+            png_only: false,
+            size: None,
+        }
+    }
+
+    /// URL of a guild's banner.
+    pub fn guild_banner(guild_id: GuildId, hash: ImageHash) -> Self {
+        Self::hashed(format!("banners/{}", guild_id), hash)
+    }
+
+    /// URL of a guild's discovery splash.
+    pub fn guild_discovery_splash(guild_id: GuildId, hash: ImageHash) -> Self {
+        Self::hashed(format!("discovery-splashes/{}", guild_id), hash)
+    }
+
+    /// URL of a guild's icon.
+    pub fn guild_icon(guild_id: GuildId, hash: ImageHash) -> Self {
+        Self::hashed(format!("icons/{}", guild_id), hash)
+    }
+
+    /// URL of a guild's invite splash.
+    pub fn guild_splash(guild_id: GuildId, hash: ImageHash) -> Self {
+        Self::hashed(format!("splashes/{}", guild_id), hash)
+    }
+
+    fn hashed(prefix: String, hash: ImageHash) -> Self {
+        Self {
+            animated: hash.is_animated(),
+            format: None,
+            path: format!("{}/{}", prefix, hash),
+            png_only: false,
+            size: None,
+        }
+    }
+
+    /// Set the format of the image.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CdnUrlError::FormatUnsupported`] if the asset isn't
+    /// available in the format.
+    pub fn format(mut self, format: ImageFormat) -> Result<Self, CdnUrlError> {
+        let supported = match format {
+            ImageFormat::Gif => self.animated,
+            ImageFormat::Png => true,
+            ImageFormat::Jpeg | ImageFormat::WebP => !self.png_only,
+        };
+
+        if !supported {
+            return Err(CdnUrlError::FormatUnsupported { format });
+        }
+
+        self.format.replace(format);
+
+        Ok(self)
+    }
+
+    /// Set the size of the image in pixels.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CdnUrlError::SizeInvalid`] if the size isn't a power of two
+    /// between [`SIZE_MINIMUM`] and [`SIZE_MAXIMUM`].
+    ///
+    /// [`SIZE_MAXIMUM`]: Self::SIZE_MAXIMUM
+    /// [`SIZE_MINIMUM`]: Self::SIZE_MINIMUM
+    pub fn size(mut self, size: u16) -> Result<Self, CdnUrlError> {
+        if !size.is_power_of_two() || size < Self::SIZE_MINIMUM || size > Self::SIZE_MAXIMUM {
+            return Err(CdnUrlError::SizeInvalid { size });
+        }
+
+        self.size.replace(size);
+
+        Ok(self)
+    }
+
+    /// Format of the image, taking the default into account.
+    pub fn image_format(&self) -> ImageFormat {
+        match self.format {
+            Some(format) => format,
+            None if self.animated => ImageFormat::Gif,
+            None => ImageFormat::Png,
+        }
+    }
+}
+
+impl Display for CdnUrl {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_fmt(format_args!(
+            "{}/{}.{}",
+            CDN_URL,
+            self.path,
+            self.image_format().extension(),
+        ))?;
+
+        if let Some(size) = self.size {
+            f.write_fmt(format_args!("?size={}", size))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CdnUrl, CdnUrlError, ImageFormat};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug, hash::Hash};
+    use twilight_model::{
+        id::{EmojiId, GuildId, UserId},
+        util::ImageHash,
+    };
+
+    assert_fields!(CdnUrlError::FormatUnsupported: format);
+    assert_fields!(CdnUrlError::SizeInvalid: size);
+    assert_impl_all!(CdnUrlError: Clone, Debug, Error, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(CdnUrl: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(ImageFormat: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+
+    const HASH: &str = "b2a6536641da91a0b59bd66557c56c36";
+
+    #[test]
+    fn test_format_negotiation() {
+        let hash = ImageHash::parse(HASH).unwrap();
+        let animated = ImageHash::parse(&format!("a_{}", HASH)).unwrap();
+
+        assert_eq!(
+            format!("https://cdn.discordapp.com/icons/1/{}.png", HASH),
+            CdnUrl::guild_icon(GuildId::new(1), hash).to_string(),
+        );
+        assert_eq!(
+            format!("https://cdn.discordapp.com/avatars/2/a_{}.gif", HASH),
+            CdnUrl::avatar(UserId::new(2), animated).to_string(),
+        );
+        assert_eq!(
+            "https://cdn.discordapp.com/emojis/3.gif",
+            CdnUrl::emoji(EmojiId::new(3), true).to_string(),
+        );
+        assert_eq!(
+            "https://cdn.discordapp.com/embed/avatars/4.png",
+            CdnUrl::default_avatar(1234).to_string(),
+        );
+    }
+
+    #[test]
+    fn test_format_unsupported() {
+        let hash = ImageHash::parse(HASH).unwrap();
+
+        assert_eq!(
+            CdnUrlError::FormatUnsupported {
+                format: ImageFormat::Gif
+            },
+            CdnUrl::avatar(UserId::new(1), hash)
+                .format(ImageFormat::Gif)
+                .unwrap_err(),
+        );
+        assert_eq!(
+            CdnUrlError::FormatUnsupported {
+                format: ImageFormat::WebP
+            },
+            CdnUrl::default_avatar(1)
+                .format(ImageFormat::WebP)
+                .unwrap_err(),
+        );
+    }
+
+    #[test]
+    fn test_size() {
+        let url = CdnUrl::emoji(EmojiId::new(1), false)
+            .format(ImageFormat::Jpeg)
+            .unwrap()
+            .size(64)
+            .unwrap();
+        assert_eq!(
+            "https://cdn.discordapp.com/emojis/1.jpg?size=64",
+            url.to_string()
+        );
+
+        assert_eq!(
+            CdnUrlError::SizeInvalid { size: 100 },
+            CdnUrl::emoji(EmojiId::new(1), false).size(100).unwrap_err(),
+        );
+        assert_eq!(
+            CdnUrlError::SizeInvalid { size: 8 },
+            CdnUrl::emoji(EmojiId::new(1), false).size(8).unwrap_err(),
+        );
+        assert_eq!(
+            CdnUrlError::SizeInvalid { size: 8192 },
+            CdnUrl::emoji(EmojiId::new(1), false)
+                .size(8192)
+                .unwrap_err(),
+        );
+    }
+}
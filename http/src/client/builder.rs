@@ -1,8 +1,11 @@
 use super::{Client, HttpsConnector, State};
-use crate::{ratelimiting::Ratelimiter, request::channel::allowed_mentions::AllowedMentions};
+use crate::{
+    ratelimiting::{InMemoryRatelimiter, Ratelimiter},
+    request::channel::allowed_mentions::AllowedMentions,
+};
 use hyper::client::{Client as HyperClient, HttpConnector};
 use std::{
-    sync::{atomic::AtomicBool, Arc},
+    sync::{atomic::AtomicBool, Arc, Mutex},
     time::Duration,
 };
 
@@ -11,7 +14,7 @@ use std::{
 pub struct ClientBuilder {
     pub(crate) default_allowed_mentions: Option<AllowedMentions>,
     pub(crate) proxy: Option<Box<str>>,
-    pub(crate) ratelimiter: Option<Ratelimiter>,
+    pub(crate) ratelimiter: Option<Box<dyn Ratelimiter>>,
     pub(crate) hyper_client: Option<HyperClient<HttpsConnector<HttpConnector>>>,
     pub(crate) timeout: Duration,
     pub(crate) token: Option<Box<str>>,
@@ -46,6 +49,7 @@ impl ClientBuilder {
 
         Client {
             state: Arc::new(State {
+                gateway_info: Mutex::new(None),
                 http,
                 proxy: self.proxy,
                 ratelimiter: self.ratelimiter,
@@ -112,8 +116,8 @@ impl ClientBuilder {
     ///
     /// If this method is not called at all then a default ratelimiter will be
     /// created by [`ClientBuilder::build`].
-    pub fn ratelimiter(mut self, ratelimiter: impl Into<Option<Ratelimiter>>) -> Self {
-        self.ratelimiter = ratelimiter.into();
+    pub fn ratelimiter(mut self, ratelimiter: Option<Box<dyn Ratelimiter>>) -> Self {
+        self.ratelimiter = ratelimiter;
 
         self
     }
@@ -152,7 +156,7 @@ impl Default for ClientBuilder {
             default_allowed_mentions: None,
             hyper_client: None,
             proxy: None,
-            ratelimiter: Some(Ratelimiter::new()),
+            ratelimiter: Some(Box::new(InMemoryRatelimiter::new())),
             timeout: Duration::from_secs(10),
             token: None,
             use_http: false,
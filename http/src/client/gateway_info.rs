@@ -0,0 +1,127 @@
+use std::time::{Duration, Instant};
+use twilight_model::gateway::connection_info::BotConnectionInfo;
+
+/// Information about connecting to the gateway as a bot, such as the
+/// recommended number of shards and how many sessions may be started.
+///
+/// This is returned from [`Client::gateway_info`], which caches it until the
+/// session start limit resets.
+///
+/// [`Client::gateway_info`]: super::Client::gateway_info
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GatewayInfo {
+    fetched_at: Instant,
+    max_concurrency: u64,
+    reset_after: Duration,
+    session_starts_remaining: u64,
+    session_starts_total: u64,
+    shards: u64,
+    url: String,
+}
+
+impl GatewayInfo {
+    pub(crate) fn new(info: BotConnectionInfo, fetched_at: Instant) -> Self {
+        Self {
+            fetched_at,
+            max_concurrency: info.session_start_limit.max_concurrency,
+            reset_after: Duration::from_millis(info.session_start_limit.reset_after),
+            session_starts_remaining: info.session_start_limit.remaining,
+            session_starts_total: info.session_start_limit.total,
+            shards: info.shards,
+            url: info.url,
+        }
+    }
+
+    /// When the information was retrieved from the API.
+    pub const fn fetched_at(&self) -> Instant {
+        self.fetched_at
+    }
+
+    /// Whether the session start limit has reset since the information was
+    /// retrieved, meaning it's out of date.
+    pub fn is_expired(&self) -> bool {
+        self.fetched_at.elapsed() >= self.reset_after
+    }
+
+    /// Maximum number of sessions that may be started concurrently.
+    pub const fn max_concurrency(&self) -> u64 {
+        self.max_concurrency
+    }
+
+    /// How long after the information was retrieved the session start limit
+    /// resets.
+    pub const fn reset_after(&self) -> Duration {
+        self.reset_after
+    }
+
+    /// Number of sessions that could still be started when the information
+    /// was retrieved.
+    ///
+    /// Sessions started since then aren't taken into account.
+    pub const fn session_starts_remaining(&self) -> u64 {
+        self.session_starts_remaining
+    }
+
+    /// Total number of sessions that may be started until the limit resets.
+    pub const fn session_starts_total(&self) -> u64 {
+        self.session_starts_total
+    }
+
+    /// Recommended number of shards to use.
+    pub const fn shards(&self) -> u64 {
+        self.shards
+    }
+
+    /// URL of the gateway.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GatewayInfo;
+    use static_assertions::assert_impl_all;
+    use std::{
+        fmt::Debug,
+        time::{Duration, Instant},
+    };
+    use twilight_model::gateway::{connection_info::BotConnectionInfo, SessionStartLimit};
+
+    assert_impl_all!(GatewayInfo: Clone, Debug, Eq, PartialEq, Send, Sync);
+
+    fn connection_info() -> BotConnectionInfo {
+        BotConnectionInfo {
+            session_start_limit: SessionStartLimit {
+                max_concurrency: 16,
+                remaining: 998,
+                reset_after: 60_000,
+                total: 1000,
+            },
+            shards: 48,
+            url: "wss://gateway.discord.gg".to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let info = GatewayInfo::new(connection_info(), Instant::now());
+
+        assert_eq!(16, info.max_concurrency());
+        assert_eq!(Duration::from_secs(60), info.reset_after());
+        assert_eq!(998, info.session_starts_remaining());
+        assert_eq!(1000, info.session_starts_total());
+        assert_eq!(48, info.shards());
+        assert_eq!("wss://gateway.discord.gg", info.url());
+    }
+
+    #[test]
+    fn test_is_expired() {
+        let info = GatewayInfo::new(connection_info(), Instant::now());
+        assert!(!info.is_expired());
+
+        let fetched_at = Instant::now() - Duration::from_secs(61);
+        let info = GatewayInfo::new(connection_info(), fetched_at);
+        assert!(info.is_expired());
+    }
+}
@@ -1,22 +1,28 @@
 mod builder;
+mod gateway_info;
+mod ticket;
 
-pub use self::builder::ClientBuilder;
+pub use self::{builder::ClientBuilder, gateway_info::GatewayInfo, ticket::Ticket};
 
 use crate::{
     api_error::{ApiError, ErrorCode},
     error::{Error, Result},
-    ratelimiting::{RatelimitHeaders, Ratelimiter},
+    ratelimiting::{InMemoryRatelimiter, RatelimitHeaders, Ratelimiter},
     request::{
         channel::allowed_mentions::AllowedMentions,
-        guild::{create_guild::CreateGuildError, create_guild_channel::CreateGuildChannelError},
+        guild::{
+            create_guild::CreateGuildError, create_guild_channel::CreateGuildChannelError,
+            sticker::create_guild_sticker::CreateGuildStickerError,
+        },
         prelude::*,
         GetUserApplicationInfo, Request,
     },
+    routing::Path,
     API_VERSION,
 };
 use bytes::Bytes;
 use hyper::{
-    body::{self, Buf},
+    body::{self, Buf, HttpBody},
     client::{Client as HyperClient, HttpConnector},
     header::{HeaderValue, AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, USER_AGENT},
     Body, Method, Response, StatusCode,
@@ -28,25 +34,38 @@ use std::{
     result::Result as StdResult,
     sync::{
         atomic::{AtomicBool, Ordering},
-        Arc,
+        Arc, Mutex,
     },
-    time::Duration,
+    time::{Duration, Instant},
+};
+use tokio::{
+    io::{AsyncWrite, AsyncWriteExt},
+    time,
 };
-use tokio::time;
 use twilight_model::{
-    guild::Permissions,
+    guild::{MfaLevel, Permissions},
     id::{ChannelId, EmojiId, GuildId, IntegrationId, MessageId, RoleId, UserId, WebhookId},
 };
 
+/// User agent sent with every request.
+const TWILIGHT_USER_AGENT: &str = concat!(
+    "DiscordBot (",
+    env!("CARGO_PKG_HOMEPAGE"),
+    ", ",
+    env!("CARGO_PKG_VERSION"),
+    ") Twilight-rs",
+);
+
 #[cfg(feature = "hyper-rustls")]
 type HttpsConnector<T> = hyper_rustls::HttpsConnector<T>;
 #[cfg(all(feature = "hyper-tls", not(feature = "hyper-rustls")))]
 type HttpsConnector<T> = hyper_tls::HttpsConnector<T>;
 
 struct State {
+    gateway_info: Mutex<Option<GatewayInfo>>,
     http: HyperClient<HttpsConnector<HttpConnector>, Body>,
     proxy: Option<Box<str>>,
-    ratelimiter: Option<Ratelimiter>,
+    ratelimiter: Option<Box<dyn Ratelimiter>>,
     timeout: Duration,
     token_invalid: AtomicBool,
     token: Option<Box<str>>,
@@ -57,6 +76,7 @@ struct State {
 impl Debug for State {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.debug_struct("State")
+            .field("gateway_info", &self.gateway_info)
             .field("http", &self.http)
             .field("proxy", &self.proxy)
             .field("ratelimiter", &self.ratelimiter)
@@ -141,9 +161,10 @@ impl Client {
 
         Self {
             state: Arc::new(State {
+                gateway_info: Mutex::new(None),
                 http: HyperClient::builder().build(connector),
                 proxy: None,
-                ratelimiter: Some(Ratelimiter::new()),
+                ratelimiter: Some(Box::new(InMemoryRatelimiter::new())),
                 timeout: Duration::from_secs(10),
                 token_invalid: AtomicBool::new(false),
                 token: Some(token.into_boxed_str()),
@@ -181,8 +202,8 @@ impl Client {
     ///
     /// This will return `None` only if ratelimit handling
     /// has been explicitly disabled in the [`ClientBuilder`].
-    pub fn ratelimiter(&self) -> Option<Ratelimiter> {
-        self.state.ratelimiter.clone()
+    pub fn ratelimiter(&self) -> Option<&dyn Ratelimiter> {
+        self.state.ratelimiter.as_deref()
     }
 
     /// Get the audit log for a guild.
@@ -196,7 +217,7 @@ impl Client {
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let client = Client::new("token");
-    /// let guild_id = GuildId(101);
+    /// let guild_id = GuildId::new(101);
     /// let audit_log = client
     /// // not done
     ///     .audit_log(guild_id)
@@ -221,7 +242,7 @@ impl Client {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     /// # let client = Client::new("my token");
     /// #
-    /// let guild_id = GuildId(1);
+    /// let guild_id = GuildId::new(1);
     ///
     /// let bans = client.bans(guild_id).await?;
     /// # Ok(()) }
@@ -253,8 +274,8 @@ impl Client {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     /// # let client = Client::new("my token");
     /// #
-    /// let guild_id = GuildId(100);
-    /// let user_id = UserId(200);
+    /// let guild_id = GuildId::new(100);
+    /// let user_id = UserId::new(200);
     /// client.create_ban(guild_id, user_id)
     ///     .delete_message_days(1)?
     ///     .reason("memes")?
@@ -279,8 +300,8 @@ impl Client {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     /// # let client = Client::new("my token");
     /// #
-    /// let guild_id = GuildId(100);
-    /// let user_id = UserId(200);
+    /// let guild_id = GuildId::new(100);
+    /// let user_id = UserId::new(200);
     ///
     /// client.delete_ban(guild_id, user_id).await?;
     /// # Ok(()) }
@@ -303,7 +324,7 @@ impl Client {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     /// # let client = Client::new("my token");
     /// #
-    /// let channel_id = ChannelId(100);
+    /// let channel_id = ChannelId::new(100);
     /// #
     /// let channel = client.channel(channel_id).await?;
     /// # Ok(()) }
@@ -376,8 +397,8 @@ impl Client {
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     /// let client = Client::new("my token");
-    /// let channel_id = ChannelId(123);
-    /// let message_id = MessageId(234);
+    /// let channel_id = ChannelId::new(123);
+    /// let message_id = MessageId::new(234);
     /// let limit: u64 = 6;
     ///
     /// let messages = client
@@ -422,10 +443,10 @@ impl Client {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     /// # let client = Client::new("my token");
     ///
-    /// let channel_id = ChannelId(123);
+    /// let channel_id = ChannelId::new(123);
     /// let allow = Permissions::VIEW_CHANNEL;
     /// let deny = Permissions::SEND_MESSAGES;
-    /// let role_id = RoleId(432);
+    /// let role_id = RoleId::new(432);
     ///
     /// client.update_channel_permission(channel_id, allow, deny)
     ///     .role(role_id)
@@ -486,8 +507,8 @@ impl Client {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     /// # let client = Client::new("my token");
     /// #
-    /// let after = GuildId(300);
-    /// let before = GuildId(400);
+    /// let after = GuildId::new(300);
+    /// let before = GuildId::new(400);
     /// let guilds = client.current_user_guilds()
     ///     .after(after)
     ///     .before(before)
@@ -534,7 +555,7 @@ impl Client {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     /// # let client = Client::new("my token");
     /// #
-    /// let guild_id = GuildId(100);
+    /// let guild_id = GuildId::new(100);
     ///
     /// client.emojis(guild_id).await?;
     /// # Ok(()) }
@@ -557,8 +578,8 @@ impl Client {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     /// # let client = Client::new("my token");
     /// #
-    /// let guild_id = GuildId(50);
-    /// let emoji_id = EmojiId(100);
+    /// let guild_id = GuildId::new(50);
+    /// let emoji_id = EmojiId::new(100);
     ///
     /// client.emoji(guild_id, emoji_id).await?;
     /// # Ok(()) }
@@ -593,6 +614,37 @@ impl Client {
         UpdateEmoji::new(self, guild_id, emoji_id)
     }
 
+    /// Create a sticker in a guild.
+    ///
+    /// The file is uploaded alongside the sticker's name and tags. Its format
+    /// is determined by the extension of `file_name`, which must be a PNG,
+    /// APNG, or Lottie JSON file. A description may be set on the returned
+    /// builder.
+    ///
+    /// Requires the [`MANAGE_EMOJIS`] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CreateGuildStickerError::NameInvalid`] when the name is
+    /// fewer than 2 characters or more than 30 characters.
+    ///
+    /// Returns a [`CreateGuildStickerError::TagsInvalid`] when the tags are
+    /// fewer than 2 characters or more than 200 characters.
+    ///
+    /// [`CreateGuildStickerError::NameInvalid`]: crate::request::guild::sticker::create_guild_sticker::CreateGuildStickerError::NameInvalid
+    /// [`CreateGuildStickerError::TagsInvalid`]: crate::request::guild::sticker::create_guild_sticker::CreateGuildStickerError::TagsInvalid
+    /// [`MANAGE_EMOJIS`]: twilight_model::guild::Permissions::MANAGE_EMOJIS
+    pub fn create_guild_sticker(
+        &self,
+        guild_id: GuildId,
+        name: impl Into<String>,
+        tags: impl Into<String>,
+        file_name: impl Into<String>,
+        file: impl Into<Vec<u8>>,
+    ) -> StdResult<CreateGuildSticker<'_>, CreateGuildStickerError> {
+        CreateGuildSticker::new(self, guild_id, name, tags, file_name, file)
+    }
+
     /// Get information about the gateway, optionally with additional information detailing the
     /// number of shards to use and sessions remaining.
     ///
@@ -631,6 +683,55 @@ impl Client {
         GetGateway::new(self)
     }
 
+    /// Get information about connecting to the gateway as a bot, such as the
+    /// recommended number of shards and the session start limit.
+    ///
+    /// The information is cached until the session start limit resets, so
+    /// repeated calls, such as by a cluster and each of its shards, only
+    /// result in one request. Refer to [`gateway`] to always make a request.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use twilight_http::Client;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// # let client = Client::new("my token");
+    /// #
+    /// let info = client.gateway_info().await?;
+    ///
+    /// println!("Recommended shards to use: {}", info.shards());
+    /// println!("Max concurrency: {}", info.max_concurrency());
+    /// println!("Session starts remaining: {}", info.session_starts_remaining());
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`gateway`]: Self::gateway
+    pub async fn gateway_info(&self) -> Result<GatewayInfo> {
+        let cached = self
+            .state
+            .gateway_info
+            .lock()
+            .expect("gateway info poisoned")
+            .clone()
+            .filter(|info| !info.is_expired());
+
+        if let Some(info) = cached {
+            return Ok(info);
+        }
+
+        let info = GatewayInfo::new(self.gateway().authed().await?, Instant::now());
+
+        self.state
+            .gateway_info
+            .lock()
+            .expect("gateway info poisoned")
+            .replace(info.clone());
+
+        Ok(info)
+    }
+
     /// Get information about a guild.
     pub fn guild(&self, guild_id: GuildId) -> GetGuild<'_> {
         GetGuild::new(self, guild_id)
@@ -725,6 +826,15 @@ impl Client {
         GetGuildWidget::new(self, guild_id)
     }
 
+    /// Modify a guild's MFA level.
+    ///
+    /// Requires that the current user is the owner of the guild and has
+    /// two-factor authentication enabled. Refer to [`UpdateGuildMfa`] for more
+    /// information.
+    pub fn update_guild_mfa(&self, guild_id: GuildId, level: MfaLevel) -> UpdateGuildMfa<'_> {
+        UpdateGuildMfa::new(self, guild_id, level)
+    }
+
     /// Modify the guild widget.
     pub fn update_guild_widget(&self, guild_id: GuildId) -> UpdateGuildWidget<'_> {
         UpdateGuildWidget::new(self, guild_id)
@@ -802,8 +912,8 @@ impl Client {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     /// # let client = Client::new("my token");
     /// #
-    /// let guild_id = GuildId(100);
-    /// let user_id = UserId(3000);
+    /// let guild_id = GuildId::new(100);
+    /// let user_id = UserId::new(3000);
     /// let members = client.guild_members(guild_id).after(user_id).await?;
     /// # Ok(()) }
     /// ```
@@ -880,9 +990,9 @@ impl Client {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     /// # let client = Client::new("my token");
     /// #
-    /// let guild_id = GuildId(1);
-    /// let role_id = RoleId(2);
-    /// let user_id = UserId(3);
+    /// let guild_id = GuildId::new(1);
+    /// let role_id = RoleId::new(2);
+    /// let user_id = UserId::new(3);
     ///
     /// client.add_guild_member_role(guild_id, user_id, role_id).reason("test")?.await?;
     /// # Ok(()) }
@@ -981,7 +1091,7 @@ impl Client {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     /// # let client = Client::new("my token");
     /// #
-    /// let channel_id = ChannelId(123);
+    /// let channel_id = ChannelId::new(123);
     /// let invite = client
     ///     .create_invite(channel_id)
     ///     .max_uses(3)
@@ -1014,7 +1124,7 @@ impl Client {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     /// # let client = Client::new("my token");
     /// #
-    /// let channel_id = ChannelId(123);
+    /// let channel_id = ChannelId::new(123);
     /// let message = client
     ///     .create_message(channel_id)
     ///     .content("Twilight is best pony")?
@@ -1082,7 +1192,7 @@ impl Client {
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     /// let client = Client::new("my token");
-    /// client.update_message(ChannelId(1), MessageId(2))
+    /// client.update_message(ChannelId::new(1), MessageId::new(2))
     ///     .content("test update".to_owned())?
     ///     .await?;
     /// # Ok(()) }
@@ -1097,7 +1207,7 @@ impl Client {
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     /// # let client = Client::new("my token");
-    /// client.update_message(ChannelId(1), MessageId(2))
+    /// client.update_message(ChannelId::new(1), MessageId::new(2))
     ///     .content(None)?
     ///     .await?;
     /// # Ok(()) }
@@ -1164,8 +1274,8 @@ impl Client {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     /// # let client = Client::new("my token");
     /// #
-    /// let channel_id = ChannelId(123);
-    /// let message_id = MessageId(456);
+    /// let channel_id = ChannelId::new(123);
+    /// let message_id = MessageId::new(456);
     /// let emoji = RequestReactionType::Unicode { name: String::from("🌃") };
     ///
     /// let reaction = client
@@ -1250,7 +1360,7 @@ impl Client {
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let client = Client::new("my token");
-    /// let guild_id = GuildId(234);
+    /// let guild_id = GuildId::new(234);
     ///
     /// client.create_role(guild_id)
     ///     .color(0xd90083)
@@ -1309,7 +1419,7 @@ impl Client {
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let client = Client::new("my token");
-    /// let channel_id = ChannelId(123);
+    /// let channel_id = ChannelId::new(123);
     ///
     /// let webhook = client
     ///     .create_webhook(channel_id, "Twily Bot")
@@ -1356,7 +1466,7 @@ impl Client {
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let client = Client::new("my token");
-    /// let id = WebhookId(432);
+    /// let id = WebhookId::new(432);
     /// #
     /// let webhook = client
     ///     .execute_webhook(id, "webhook token")
@@ -1387,7 +1497,7 @@ impl Client {
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let client = Client::new("token");
-    /// client.update_webhook_message(WebhookId(1), "token here", MessageId(2))
+    /// client.update_webhook_message(WebhookId::new(1), "token here", MessageId::new(2))
     ///     .content(Some("new message content".to_owned()))?
     ///     .await?;
     /// # Ok(()) }
@@ -1413,7 +1523,7 @@ impl Client {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let client = Client::new("token");
     /// client
-    ///     .delete_webhook_message(WebhookId(1), "token here", MessageId(2))
+    ///     .delete_webhook_message(WebhookId::new(1), "token here", MessageId::new(2))
     ///     .await?;
     /// # Ok(()) }
     /// ```
@@ -1426,18 +1536,106 @@ impl Client {
         DeleteWebhookMessage::new(self, webhook_id, token, message_id)
     }
 
+    /// Acquire a ticket to make a request to a ratelimit bucket, waiting
+    /// until the bucket is available.
+    ///
+    /// This allows scheduling work across buckets before the requests are
+    /// built. Use the ticket with [`raw_with_ticket`] or
+    /// [`request_with_ticket`]. To check how long a bucket will be
+    /// unavailable without waiting, use [`Ratelimiter::time_until_available`]
+    /// via [`ratelimiter`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use twilight_http::{request::Request, routing::Route, Client};
+    /// use twilight_model::{channel::Message, id::ChannelId};
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new("my token");
+    ///
+    /// let request = Request::from(Route::GetMessages {
+    ///     after: None,
+    ///     around: None,
+    ///     before: None,
+    ///     channel_id: 123,
+    ///     limit: None,
+    /// });
+    /// let ticket = client.ticket(request.path.clone()).await?;
+    ///
+    /// let messages: Vec<Message> = client.request_with_ticket(request, ticket).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::RequestCanceled`] if the ratelimiter dropped the
+    /// ticket before handing it out.
+    ///
+    /// [`Ratelimiter::time_until_available`]: crate::ratelimiting::Ratelimiter::time_until_available
+    /// [`raw_with_ticket`]: Self::raw_with_ticket
+    /// [`ratelimiter`]: Self::ratelimiter
+    /// [`request_with_ticket`]: Self::request_with_ticket
+    pub async fn ticket(&self, path: Path) -> Result<Ticket> {
+        let tx = match self.state.ratelimiter.as_ref() {
+            Some(ratelimiter) => Some(
+                ratelimiter
+                    .ticket(path.clone())
+                    .await
+                    .await
+                    .map_err(|source| Error::RequestCanceled { source })?,
+            ),
+            None => None,
+        };
+
+        Ok(Ticket { path, tx })
+    }
+
     /// Execute a request, returning the response.
     ///
     /// # Errors
     ///
     /// Returns [`Error::Unauthorized`] if the configured token has become
     /// invalid due to expiration, revokation, etc.
-    #[allow(clippy::too_many_lines)]
     pub async fn raw(&self, request: Request) -> Result<Response<Body>> {
+        self.raw_inner(request, None).await
+    }
+
+    /// Execute a request with a ticket acquired via [`ticket`], returning
+    /// the response.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::TicketMismatch`] if the ticket is for a different
+    /// bucket than the request.
+    ///
+    /// Returns [`Error::Unauthorized`] if the configured token has become
+    /// invalid due to expiration, revokation, etc.
+    ///
+    /// [`ticket`]: Self::ticket
+    pub async fn raw_with_ticket(
+        &self,
+        request: Request,
+        ticket: Ticket,
+    ) -> Result<Response<Body>> {
+        self.raw_inner(request, Some(ticket)).await
+    }
+
+    #[allow(clippy::too_many_lines)]
+    async fn raw_inner(&self, request: Request, ticket: Option<Ticket>) -> Result<Response<Body>> {
         if self.state.token_invalid.load(Ordering::Relaxed) {
             return Err(Error::Unauthorized);
         }
 
+        if let Some(ticket) = ticket.as_ref() {
+            if ticket.path != request.path {
+                return Err(Error::TicketMismatch {
+                    request: request.path,
+                    ticket: ticket.path.clone(),
+                });
+            }
+        }
+
         let Request {
             body,
             form,
@@ -1468,13 +1666,7 @@ impl Client {
             }
         }
 
-        let user_agent = HeaderValue::from_static(concat!(
-            "DiscordBot (",
-            env!("CARGO_PKG_HOMEPAGE"),
-            ", ",
-            env!("CARGO_PKG_VERSION"),
-            ") Twilight-rs",
-        ));
+        let user_agent = HeaderValue::from_static(TWILIGHT_USER_AGENT);
 
         if let Some(headers) = builder.headers_mut() {
             headers.insert(USER_AGENT, user_agent);
@@ -1529,8 +1721,13 @@ impl Client {
         let inner = self.state.http.request(req);
         let fut = time::timeout(self.state.timeout, inner);
 
-        let ratelimiter = match self.state.ratelimiter.as_ref() {
-            Some(ratelimiter) => ratelimiter,
+        let tx = match ticket {
+            Some(ticket) => ticket.tx,
+            None => self.ticket(bucket).await?.tx,
+        };
+
+        let tx = match tx {
+            Some(tx) => tx,
             None => {
                 return fut
                     .await
@@ -1539,11 +1736,6 @@ impl Client {
             }
         };
 
-        let rx = ratelimiter.get(bucket).await;
-        let tx = rx
-            .await
-            .map_err(|source| Error::RequestCanceled { source })?;
-
         let resp = fut
             .await
             .map_err(|source| Error::RequestTimedOut { source })?
@@ -1577,7 +1769,35 @@ impl Client {
     /// Returns [`Error::Unauthorized`] if the configured token has become
     /// invalid due to expiration, revokation, etc.
     pub async fn request<T: DeserializeOwned>(&self, request: Request) -> Result<T> {
-        let resp = self.make_request(request).await?;
+        self.request_inner(request, None).await
+    }
+
+    /// Execute a request with a ticket acquired via [`ticket`], chunking and
+    /// deserializing the response.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::TicketMismatch`] if the ticket is for a different
+    /// bucket than the request.
+    ///
+    /// Returns [`Error::Unauthorized`] if the configured token has become
+    /// invalid due to expiration, revokation, etc.
+    ///
+    /// [`ticket`]: Self::ticket
+    pub async fn request_with_ticket<T: DeserializeOwned>(
+        &self,
+        request: Request,
+        ticket: Ticket,
+    ) -> Result<T> {
+        self.request_inner(request, Some(ticket)).await
+    }
+
+    async fn request_inner<T: DeserializeOwned>(
+        &self,
+        request: Request,
+        ticket: Option<Ticket>,
+    ) -> Result<T> {
+        let resp = self.make_request(request, ticket).await?;
 
         let mut buf = body::aggregate(resp.into_body())
             .await
@@ -1595,7 +1815,7 @@ impl Client {
     }
 
     pub(crate) async fn request_bytes(&self, request: Request) -> Result<Bytes> {
-        let resp = self.make_request(request).await?;
+        let resp = self.make_request(request, None).await?;
 
         hyper::body::to_bytes(resp.into_body())
             .await
@@ -1611,13 +1831,137 @@ impl Client {
     /// Returns [`Error::Unauthorized`] if the configured token has become
     /// invalid due to expiration, revokation, etc.
     pub async fn verify(&self, request: Request) -> Result<()> {
-        self.make_request(request).await?;
+        self.make_request(request, None).await?;
 
         Ok(())
     }
 
-    async fn make_request(&self, request: Request) -> Result<Response<Body>> {
-        let resp = self.raw(request).await?;
+    /// Download an asset, such as a [`CdnUrl`] or the URL of an attachment.
+    ///
+    /// The request reuses the client's connection pool and timeout, but it
+    /// isn't ratelimited and doesn't include the client's token.
+    ///
+    /// # Examples
+    ///
+    /// Download a user's avatar:
+    ///
+    /// ```rust,no_run
+    /// use twilight_http::{cdn::CdnUrl, Client};
+    /// use twilight_model::id::UserId;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new("my token");
+    ///
+    /// let user = client.user(UserId::new(123)).await?.expect("user exists");
+    ///
+    /// if let Some(hash) = user.avatar {
+    ///     let url = CdnUrl::avatar(user.id, hash).size(1024)?;
+    ///     let avatar = client.download(&url.to_string()).await?;
+    ///     println!("avatar is {} bytes", avatar.len());
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::AssetUnavailable`] if the response's status code isn't
+    /// a success, such as when the asset doesn't exist.
+    ///
+    /// [`CdnUrl`]: crate::cdn::CdnUrl
+    pub async fn download(&self, url: &str) -> Result<Bytes> {
+        let resp = self.download_response(url).await?;
+
+        hyper::body::to_bytes(resp.into_body())
+            .await
+            .map_err(|source| Error::ChunkingResponse { source })
+    }
+
+    /// Download an asset, streaming it into a writer such as a file.
+    ///
+    /// Returns the number of bytes written. Refer to [`download`] for more
+    /// information.
+    ///
+    /// # Examples
+    ///
+    /// Save an attachment to a file:
+    ///
+    /// ```rust,no_run
+    /// use tokio::fs::File;
+    /// use twilight_http::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new("my token");
+    ///
+    /// let mut file = File::create("attachment.png").await?;
+    /// client
+    ///     .download_to("https://cdn.discordapp.com/attachments/1/2/attachment.png", &mut file)
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::AssetUnavailable`] if the response's status code isn't
+    /// a success, such as when the asset doesn't exist.
+    ///
+    /// Returns [`Error::WritingAsset`] if writing to the writer failed.
+    ///
+    /// [`download`]: Self::download
+    pub async fn download_to<W: AsyncWrite + Unpin + ?Sized>(
+        &self,
+        url: &str,
+        writer: &mut W,
+    ) -> Result<u64> {
+        let mut body = self.download_response(url).await?.into_body();
+        let mut written = 0;
+
+        while let Some(chunk) = body.data().await {
+            let chunk = chunk.map_err(|source| Error::ChunkingResponse { source })?;
+
+            writer
+                .write_all(&chunk)
+                .await
+                .map_err(|source| Error::WritingAsset { source })?;
+
+            written += chunk.len() as u64;
+        }
+
+        writer
+            .flush()
+            .await
+            .map_err(|source| Error::WritingAsset { source })?;
+
+        Ok(written)
+    }
+
+    async fn download_response(&self, url: &str) -> Result<Response<Body>> {
+        let req = hyper::Request::builder()
+            .method(Method::GET)
+            .uri(url)
+            .header(USER_AGENT, HeaderValue::from_static(TWILIGHT_USER_AGENT))
+            .body(Body::empty())
+            .map_err(|source| Error::BuildingRequest { source })?;
+
+        let resp = time::timeout(self.state.timeout, self.state.http.request(req))
+            .await
+            .map_err(|source| Error::RequestTimedOut { source })?
+            .map_err(|source| Error::RequestError { source })?;
+
+        let status = resp.status();
+
+        if !status.is_success() {
+            return Err(Error::AssetUnavailable { status });
+        }
+
+        Ok(resp)
+    }
+
+    async fn make_request(
+        &self,
+        request: Request,
+        ticket: Option<Ticket>,
+    ) -> Result<Response<Body>> {
+        let resp = self.raw_inner(request, ticket).await?;
         let status = resp.status();
 
         if status.is_success() {
@@ -1667,9 +2011,10 @@ impl From<HyperClient<HttpsConnector<HttpConnector>>> for Client {
     fn from(hyper_client: HyperClient<HttpsConnector<HttpConnector>>) -> Self {
         Self {
             state: Arc::new(State {
+                gateway_info: Mutex::new(None),
                 http: hyper_client,
                 proxy: None,
-                ratelimiter: Some(Ratelimiter::new()),
+                ratelimiter: Some(Box::new(InMemoryRatelimiter::new())),
                 timeout: Duration::from_secs(10),
                 token_invalid: AtomicBool::new(false),
                 token: None,
@@ -0,0 +1,38 @@
+use crate::{ratelimiting::TicketSender, routing::Path};
+
+/// Permission to make a request to a ratelimit bucket, acquired before the
+/// request is made.
+///
+/// Tickets are acquired with [`Client::ticket`] and used with
+/// [`Client::raw_with_ticket`] or [`Client::request_with_ticket`].
+///
+/// A ticket holds its bucket until it's used or dropped, so other requests to
+/// the bucket wait for it. Dropping a ticket without using it releases the
+/// bucket.
+///
+/// [`Client::raw_with_ticket`]: super::Client::raw_with_ticket
+/// [`Client::request_with_ticket`]: super::Client::request_with_ticket
+/// [`Client::ticket`]: super::Client::ticket
+#[derive(Debug)]
+pub struct Ticket {
+    pub(super) path: Path,
+    /// Sender for the response's ratelimit headers, or `None` if the client's
+    /// ratelimiter is disabled.
+    pub(super) tx: Option<TicketSender>,
+}
+
+impl Ticket {
+    /// Path of the bucket that the ticket is for.
+    pub const fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ticket;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(Ticket: Debug, Send, Sync);
+}
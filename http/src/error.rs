@@ -1,4 +1,4 @@
-use crate::{api_error::ApiError, ratelimiting::RatelimitError};
+use crate::{api_error::ApiError, ratelimiting::RatelimitError, routing::Path};
 use futures_channel::oneshot::Canceled;
 use hyper::{
     header::InvalidHeaderValue, http::Error as HttpError, Body, Error as HyperError, Response,
@@ -7,6 +7,7 @@ use hyper::{
 use std::{
     error::Error as StdError,
     fmt::{Display, Error as FmtError, Formatter, Result as FmtResult},
+    io::Error as IoError,
     result::Result as StdResult,
 };
 use tokio::time::error::Elapsed;
@@ -21,6 +22,12 @@ pub type Result<T, E = Error> = StdResult<T, E>;
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum Error {
+    /// Downloading an asset failed because the response's status code isn't
+    /// a success.
+    AssetUnavailable {
+        /// Status code of the response.
+        status: StatusCode,
+    },
     BuildingRequest {
         source: HttpError,
     },
@@ -66,11 +73,24 @@ pub enum Error {
     ServiceUnavailable {
         response: Response<Body>,
     },
+    /// Ticket used to make a request is for a different ratelimit bucket
+    /// than the request.
+    TicketMismatch {
+        /// Path of the request's bucket.
+        request: Path,
+        /// Path of the ticket's bucket.
+        ticket: Path,
+    },
     /// Token in use has become revoked or is otherwise invalid.
     ///
     /// This can occur if a bot token is invalidated or an access token expires
     /// or is revoked. Recreate the client to configure a new token.
     Unauthorized,
+    /// Writing a downloaded asset failed.
+    WritingAsset {
+        /// Reason for the error.
+        source: IoError,
+    },
 }
 
 impl From<FmtError> for Error {
@@ -88,6 +108,13 @@ impl From<JsonError> for Error {
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
+            Self::AssetUnavailable { status } => {
+                write!(
+                    f,
+                    "downloading the asset failed with status code {}",
+                    status
+                )
+            }
             Self::BuildingRequest { .. } => f.write_str("failed to build the request"),
             Self::ChunkingResponse { .. } => f.write_str("Chunking the response failed"),
             Self::CreatingHeader { name, .. } => {
@@ -112,7 +139,13 @@ impl Display for Error {
             Self::ServiceUnavailable { .. } => {
                 f.write_str("api may be temporarily unavailable (received a 503)")
             }
+            Self::TicketMismatch { request, ticket } => write!(
+                f,
+                "ticket is for the bucket {:?}, but the request is for the bucket {:?}",
+                ticket, request
+            ),
             Self::Unauthorized => f.write_str("token in use is invalid, expired, or is revoked"),
+            Self::WritingAsset { .. } => f.write_str("writing the downloaded asset failed"),
         }
     }
 }
@@ -128,7 +161,12 @@ impl StdError for Error {
             Self::RequestCanceled { source } => Some(source),
             Self::ChunkingResponse { source } | Self::RequestError { source } => Some(source),
             Self::RequestTimedOut { source } => Some(source),
-            Self::Response { .. } | Self::ServiceUnavailable { .. } | Self::Unauthorized => None,
+            Self::WritingAsset { source } => Some(source),
+            Self::AssetUnavailable { .. }
+            | Self::Response { .. }
+            | Self::ServiceUnavailable { .. }
+            | Self::TicketMismatch { .. }
+            | Self::Unauthorized => None,
         }
     }
 }
@@ -92,6 +92,7 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 pub mod api_error;
+pub mod cdn;
 pub mod client;
 pub mod error;
 pub mod ratelimiting;
@@ -0,0 +1,11 @@
+//! Ratelimiting functionality for requests.
+//!
+//! This re-exports [`twilight_http_ratelimiting`], which contains the
+//! [`Ratelimiter`] trait and the default [`InMemoryRatelimiter`]
+//! implementation.
+
+pub use twilight_http_ratelimiting::{
+    error, headers, in_memory, Bucket, GetBucketFuture, GetTicketFuture, InMemoryRatelimiter,
+    IsGloballyLockedFuture, RatelimitError, RatelimitHeaders, RatelimitResult, Ratelimiter,
+    TicketReceiver, TicketSender, TimeUntilAvailableFuture,
+};
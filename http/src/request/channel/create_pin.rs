@@ -27,14 +27,14 @@ impl<'a> CreatePin<'a> {
             Request::from((
                 headers,
                 Route::PinMessage {
-                    channel_id: self.channel_id.0,
-                    message_id: self.message_id.0,
+                    channel_id: self.channel_id.get(),
+                    message_id: self.message_id.get(),
                 },
             ))
         } else {
             Request::from(Route::PinMessage {
-                channel_id: self.channel_id.0,
-                message_id: self.message_id.0,
+                channel_id: self.channel_id.get(),
+                message_id: self.message_id.get(),
             })
         };
 
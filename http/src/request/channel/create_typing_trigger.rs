@@ -20,7 +20,7 @@ impl<'a> CreateTypingTrigger<'a> {
     fn start(&mut self) -> Result<()> {
         self.fut.replace(Box::pin(self.http.verify(Request::from(
             Route::CreateTypingTrigger {
-                channel_id: self.channel_id.0,
+                channel_id: self.channel_id.get(),
             },
         ))));
 
@@ -25,12 +25,12 @@ impl<'a> DeleteChannel<'a> {
             Request::from((
                 headers,
                 Route::DeleteChannel {
-                    channel_id: self.channel_id.0,
+                    channel_id: self.channel_id.get(),
                 },
             ))
         } else {
             Request::from(Route::DeleteChannel {
-                channel_id: self.channel_id.0,
+                channel_id: self.channel_id.get(),
             })
         };
 
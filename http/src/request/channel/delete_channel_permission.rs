@@ -17,12 +17,12 @@ impl<'a> DeleteChannelPermission<'a> {
 
     /// Delete an override for an member.
     pub fn member(self, user_id: impl Into<UserId>) -> DeleteChannelPermissionConfigured<'a> {
-        self.configure(user_id.into().0)
+        self.configure(user_id.into().get())
     }
 
     /// Delete an override for an role.
     pub fn role(self, role_id: impl Into<RoleId>) -> DeleteChannelPermissionConfigured<'a> {
-        self.configure(role_id.into().0)
+        self.configure(role_id.into().get())
     }
 
     fn configure(self, target_id: u64) -> DeleteChannelPermissionConfigured<'a> {
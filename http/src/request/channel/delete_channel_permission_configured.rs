@@ -29,13 +29,13 @@ impl<'a> DeleteChannelPermissionConfigured<'a> {
             Request::from((
                 headers,
                 Route::DeletePermissionOverwrite {
-                    channel_id: self.channel_id.0,
+                    channel_id: self.channel_id.get(),
                     target_id: self.target_id,
                 },
             ))
         } else {
             Request::from(Route::DeletePermissionOverwrite {
-                channel_id: self.channel_id.0,
+                channel_id: self.channel_id.get(),
                 target_id: self.target_id,
             })
         };
@@ -33,7 +33,7 @@ impl<'a> FollowNewsChannel<'a> {
         let request = Request::from((
             crate::json_to_vec(&self.fields)?,
             Route::FollowNewsChannel {
-                channel_id: self.channel_id.0,
+                channel_id: self.channel_id.get(),
             },
         ));
 
@@ -15,7 +15,7 @@ use twilight_model::{channel::Channel, id::ChannelId};
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 /// let client = Client::new("my token");
 ///
-/// let channel_id = ChannelId(100);
+/// let channel_id = ChannelId::new(100);
 ///
 /// let channel = client.channel(channel_id).await?;
 /// # Ok(()) }
@@ -39,7 +39,7 @@ impl<'a> GetChannel<'a> {
         self.fut
             .replace(Box::pin(self.http.request_bytes(Request::from(
                 Route::GetChannel {
-                    channel_id: self.channel_id.0,
+                    channel_id: self.channel_id.get(),
                 },
             ))));
 
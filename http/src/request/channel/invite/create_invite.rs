@@ -32,7 +32,7 @@ struct CreateInviteFields {
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 /// let client = Client::new("my token");
 ///
-/// let channel_id = ChannelId(123);
+/// let channel_id = ChannelId::new(123);
 /// let invite = client
 ///     .create_invite(channel_id)
 ///     .max_uses(3)
@@ -98,7 +98,7 @@ impl<'a> CreateInvite<'a> {
 
     /// Set the target user for this invite.
     pub fn target_user(mut self, target_user: UserId) -> Self {
-        self.fields.target_user.replace(target_user.0.to_string());
+        self.fields.target_user.replace(target_user.to_string());
 
         self
     }
@@ -117,14 +117,14 @@ impl<'a> CreateInvite<'a> {
                 crate::json_to_vec(&self.fields)?,
                 headers,
                 Route::CreateInvite {
-                    channel_id: self.channel_id.0,
+                    channel_id: self.channel_id.get(),
                 },
             ))
         } else {
             Request::from((
                 crate::json_to_vec(&self.fields)?,
                 Route::CreateInvite {
-                    channel_id: self.channel_id.0,
+                    channel_id: self.channel_id.get(),
                 },
             ))
         };
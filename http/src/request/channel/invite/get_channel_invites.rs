@@ -22,7 +22,7 @@ impl<'a> GetChannelInvites<'a> {
     fn start(&mut self) -> Result<()> {
         self.fut.replace(Box::pin(self.http.request(Request::from(
             Route::GetChannelInvites {
-                channel_id: self.channel_id.0,
+                channel_id: self.channel_id.get(),
             },
         ))));
 
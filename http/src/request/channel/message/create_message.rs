@@ -6,9 +6,14 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::{
-    channel::{embed::Embed, message::MessageReference, Message},
+    channel::{
+        embed::Embed,
+        message::{MessageFlags, MessageReference},
+        Message,
+    },
     id::{ChannelId, MessageId},
 };
+use twilight_validate::message::{self as validate_message, MessageValidationError};
 
 /// The error created when a messsage can not be created as configured.
 #[derive(Clone, Debug)]
@@ -18,6 +23,8 @@ pub enum CreateMessageError {
     ContentInvalid {
         /// Provided content.
         content: String,
+        /// The source of the error.
+        source: MessageValidationError,
     },
     /// Returned when the length of the embed is over 6000 characters.
     EmbedTooLarge {
@@ -26,6 +33,13 @@ pub enum CreateMessageError {
         /// The source of the error.
         source: EmbedValidationError,
     },
+    /// Returned when flags other than [`MessageFlags::IS_VOICE_MESSAGE`],
+    /// [`MessageFlags::SUPPRESS_EMBEDS`], and
+    /// [`MessageFlags::SUPPRESS_NOTIFICATIONS`] are set.
+    FlagsInvalid {
+        /// Provided flags.
+        flags: MessageFlags,
+    },
 }
 
 impl Display for CreateMessageError {
@@ -33,6 +47,9 @@ impl Display for CreateMessageError {
         match self {
             Self::ContentInvalid { .. } => f.write_str("the message content is invalid"),
             Self::EmbedTooLarge { .. } => f.write_str("the embed's contents are too long"),
+            Self::FlagsInvalid { .. } => {
+                f.write_str("only the voice message and suppress flags can be set")
+            }
         }
     }
 }
@@ -40,8 +57,9 @@ impl Display for CreateMessageError {
 impl Error for CreateMessageError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
-            Self::ContentInvalid { .. } => None,
+            Self::ContentInvalid { source, .. } => Some(source),
             Self::EmbedTooLarge { source, .. } => Some(source),
+            Self::FlagsInvalid { .. } => None,
         }
     }
 }
@@ -53,6 +71,8 @@ pub(crate) struct CreateMessageFields {
     #[serde(skip_serializing_if = "Option::is_none")]
     embed: Option<Embed>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    flags: Option<MessageFlags>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     message_reference: Option<MessageReference>,
     #[serde(skip_serializing_if = "Option::is_none")]
     nonce: Option<u64>,
@@ -76,7 +96,7 @@ pub(crate) struct CreateMessageFields {
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 /// let client = Client::new("my token");
 ///
-/// let channel_id = ChannelId(123);
+/// let channel_id = ChannelId::new(123);
 /// let message = client
 ///     .create_message(channel_id)
 ///     .content("Twilight is best pony")?
@@ -147,8 +167,8 @@ impl<'a> CreateMessage<'a> {
     }
 
     fn _content(mut self, content: String) -> Result<Self, CreateMessageError> {
-        if !validate::content_limit(&content) {
-            return Err(CreateMessageError::ContentInvalid { content });
+        if let Err(source) = validate_message::content(&content) {
+            return Err(CreateMessageError::ContentInvalid { content, source });
         }
 
         self.fields.content.replace(content);
@@ -184,6 +204,27 @@ impl<'a> CreateMessage<'a> {
         Ok(self)
     }
 
+    /// Set the message's flags.
+    ///
+    /// Only [`MessageFlags::IS_VOICE_MESSAGE`],
+    /// [`MessageFlags::SUPPRESS_EMBEDS`], and
+    /// [`MessageFlags::SUPPRESS_NOTIFICATIONS`] can be set when creating a
+    /// message.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CreateMessageError::FlagsInvalid`] if any other flags are
+    /// set.
+    pub fn flags(mut self, flags: MessageFlags) -> Result<Self, CreateMessageError> {
+        if !validate::message_flags(flags) {
+            return Err(CreateMessageError::FlagsInvalid { flags });
+        }
+
+        self.fields.flags.replace(flags);
+
+        Ok(self)
+    }
+
     /// Attach a nonce to the message, for optimistic message sending.
     pub fn nonce(mut self, nonce: u64) -> Self {
         self.fields.nonce.replace(nonce);
@@ -227,7 +268,7 @@ impl<'a> CreateMessage<'a> {
                 Request::from((
                     crate::json_to_vec(&self.fields)?,
                     Route::CreateMessage {
-                        channel_id: self.channel_id.0,
+                        channel_id: self.channel_id.get(),
                     },
                 ))
             } else {
@@ -243,7 +284,7 @@ impl<'a> CreateMessage<'a> {
                 Request::from((
                     multipart,
                     Route::CreateMessage {
-                        channel_id: self.channel_id.0,
+                        channel_id: self.channel_id.get(),
                     },
                 ))
             },
@@ -24,8 +24,8 @@ impl<'a> CrosspostMessage<'a> {
 
     fn start(&mut self) -> Result<()> {
         let request = Request::from(Route::CrosspostMessage {
-            channel_id: self.channel_id.0,
-            message_id: self.message_id.0,
+            channel_id: self.channel_id.get(),
+            message_id: self.message_id.get(),
         });
 
         self.fut.replace(Box::pin(self.http.request(request)));
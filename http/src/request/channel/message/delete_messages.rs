@@ -45,14 +45,14 @@ impl<'a> DeleteMessages<'a> {
                 crate::json_to_vec(&self.fields)?,
                 headers,
                 Route::DeleteMessages {
-                    channel_id: self.channel_id.0,
+                    channel_id: self.channel_id.get(),
                 },
             ))
         } else {
             Request::from((
                 crate::json_to_vec(&self.fields)?,
                 Route::DeleteMessages {
-                    channel_id: self.channel_id.0,
+                    channel_id: self.channel_id.get(),
                 },
             ))
         };
@@ -91,10 +91,10 @@ impl<'a> GetChannelMessagesConfigured<'a> {
     fn start(&mut self) -> Result<()> {
         self.fut.replace(Box::pin(self.http.request(Request::from(
             Route::GetMessages {
-                after: self.after.map(|x| x.0),
-                around: self.around.map(|x| x.0),
-                before: self.before.map(|x| x.0),
-                channel_id: self.channel_id.0,
+                after: self.after.map(|x| x.get()),
+                around: self.around.map(|x| x.get()),
+                before: self.before.map(|x| x.get()),
+                channel_id: self.channel_id.get(),
                 limit: self.fields.limit,
             },
         ))));
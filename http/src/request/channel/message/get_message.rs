@@ -26,8 +26,8 @@ impl<'a> GetMessage<'a> {
         self.fut
             .replace(Box::pin(self.http.request_bytes(Request::from(
                 Route::GetMessage {
-                    channel_id: self.channel_id.0,
-                    message_id: self.message_id.0,
+                    channel_id: self.channel_id.get(),
+                    message_id: self.message_id.get(),
                 },
             ))));
 
@@ -4,9 +4,10 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::{
-    channel::{embed::Embed, message::MessageFlags, Message},
+    channel::{embed::Embed, message::MessageFlags, Attachment, Message},
     id::{ChannelId, MessageId},
 };
+use twilight_validate::message::{self as validate_message, MessageValidationError};
 
 /// The error created when a message can not be updated as configured.
 #[derive(Clone, Debug)]
@@ -16,6 +17,8 @@ pub enum UpdateMessageError {
     ContentInvalid {
         /// Provided content.
         content: String,
+        /// The source of the error.
+        source: MessageValidationError,
     },
     /// Returned when the length of the embed is over 6000 characters.
     EmbedTooLarge {
@@ -38,7 +41,7 @@ impl Display for UpdateMessageError {
 impl Error for UpdateMessageError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
-            Self::ContentInvalid { .. } => None,
+            Self::ContentInvalid { source, .. } => Some(source),
             Self::EmbedTooLarge { source, .. } => Some(source),
         }
     }
@@ -48,6 +51,8 @@ impl Error for UpdateMessageError {
 struct UpdateMessageFields {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) allowed_mentions: Option<AllowedMentions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attachments: Option<Vec<Attachment>>,
     // We don't serialize if this is Option::None, to avoid overwriting the
     // field without meaning to.
     //
@@ -84,7 +89,7 @@ struct UpdateMessageFields {
 /// # #[tokio::main]
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 /// let client = Client::new("my token");
-/// client.update_message(ChannelId(1), MessageId(2))
+/// client.update_message(ChannelId::new(1), MessageId::new(2))
 ///     .content("test update".to_owned())?
 ///     .await?;
 /// # Ok(()) }
@@ -99,7 +104,7 @@ struct UpdateMessageFields {
 /// # #[tokio::main]
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 /// # let client = Client::new("my token");
-/// client.update_message(ChannelId(1), MessageId(2))
+/// client.update_message(ChannelId::new(1), MessageId::new(2))
 ///     .content(None)?
 ///     .await?;
 /// # Ok(()) }
@@ -123,6 +128,17 @@ impl<'a> UpdateMessage<'a> {
         }
     }
 
+    /// Set the attachments to keep on the message.
+    ///
+    /// Attachments that are not included are removed from the message. Pass
+    /// the message's existing attachments to modify them, such as to change
+    /// their descriptions.
+    pub fn attachments(mut self, attachments: Vec<Attachment>) -> Self {
+        self.fields.attachments.replace(attachments);
+
+        self
+    }
+
     /// Set the content of the message.
     ///
     /// Pass `None` if you want to remove the message content.
@@ -142,9 +158,10 @@ impl<'a> UpdateMessage<'a> {
 
     fn _content(mut self, content: Option<String>) -> Result<Self, UpdateMessageError> {
         if let Some(content_ref) = content.as_ref() {
-            if !validate::content_limit(content_ref) {
+            if let Err(source) = validate_message::content(content_ref) {
                 return Err(UpdateMessageError::ContentInvalid {
                     content: content.expect("content is known to be some"),
+                    source,
                 });
             }
         }
@@ -208,8 +225,8 @@ impl<'a> UpdateMessage<'a> {
         self.fut.replace(Box::pin(self.http.request(Request::from((
             crate::json_to_vec(&self.fields)?,
             Route::UpdateMessage {
-                channel_id: self.channel_id.0,
-                message_id: self.message_id.0,
+                channel_id: self.channel_id.get(),
+                message_id: self.message_id.get(),
             },
         )))));
 
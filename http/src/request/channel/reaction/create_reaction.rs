@@ -16,8 +16,8 @@ use twilight_model::id::{ChannelId, MessageId};
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 /// let client = Client::new("my token");
 ///
-/// let channel_id = ChannelId(123);
-/// let message_id = MessageId(456);
+/// let channel_id = ChannelId::new(123);
+/// let message_id = MessageId::new(456);
 /// let emoji = RequestReactionType::Unicode { name: String::from("🌃") };
 ///
 /// let reaction = client
@@ -51,9 +51,9 @@ impl<'a> CreateReaction<'a> {
 
     fn request(&self) -> Request {
         Request::from(Route::CreateReaction {
-            channel_id: self.channel_id.0,
+            channel_id: self.channel_id.get(),
             emoji: self.emoji.clone(),
-            message_id: self.message_id.0,
+            message_id: self.message_id.get(),
         })
     }
 
@@ -87,7 +87,7 @@ mod tests {
             name: String::from("\u{1f303}"),
         };
 
-        let builder = CreateReaction::new(&client, ChannelId(123), MessageId(456), emoji);
+        let builder = CreateReaction::new(&client, ChannelId::new(123), MessageId::new(456), emoji);
         let actual = builder.request();
 
         let expected = Request::from(Route::CreateReaction {
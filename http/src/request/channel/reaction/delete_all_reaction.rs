@@ -29,8 +29,8 @@ impl<'a> DeleteAllReaction<'a> {
     fn start(&mut self) -> Result<()> {
         self.fut.replace(Box::pin(self.http.verify(Request::from(
             Route::DeleteMessageSpecficReaction {
-                channel_id: self.channel_id.0,
-                message_id: self.message_id.0,
+                channel_id: self.channel_id.get(),
+                message_id: self.message_id.get(),
                 emoji: self.emoji.clone(),
             },
         ))));
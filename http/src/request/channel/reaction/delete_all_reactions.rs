@@ -22,8 +22,8 @@ impl<'a> DeleteAllReactions<'a> {
     fn start(&mut self) -> Result<()> {
         self.fut.replace(Box::pin(self.http.verify(Request::from(
             Route::DeleteMessageReactions {
-                channel_id: self.channel_id.0,
-                message_id: self.message_id.0,
+                channel_id: self.channel_id.get(),
+                message_id: self.message_id.get(),
             },
         ))));
 
@@ -32,9 +32,9 @@ impl<'a> DeleteReaction<'a> {
     fn start(&mut self) -> Result<()> {
         self.fut.replace(Box::pin(self.http.verify(Request::from(
             Route::DeleteReaction {
-                channel_id: self.channel_id.0,
+                channel_id: self.channel_id.get(),
                 emoji: self.emoji.clone(),
-                message_id: self.message_id.0,
+                message_id: self.message_id.get(),
                 user: self.target_user.clone(),
             },
         ))));
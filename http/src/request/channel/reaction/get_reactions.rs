@@ -101,12 +101,12 @@ impl<'a> GetReactions<'a> {
     fn start(&mut self) -> Result<()> {
         self.fut.replace(Box::pin(self.http.request(Request::from(
             Route::GetReactionUsers {
-                after: self.fields.after.map(|x| x.0),
-                before: self.fields.before.map(|x| x.0),
-                channel_id: self.channel_id.0,
+                after: self.fields.after.map(|x| x.get()),
+                before: self.fields.before.map(|x| x.get()),
+                channel_id: self.channel_id.get(),
                 emoji: self.emoji.to_owned(),
                 limit: self.fields.limit,
-                message_id: self.message_id.0,
+                message_id: self.message_id.get(),
             },
         ))));
 
@@ -4,9 +4,10 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::{
-    channel::{permission_overwrite::PermissionOverwrite, Channel, ChannelType},
+    channel::{permission_overwrite::PermissionOverwrite, Channel, ChannelType, VideoQualityMode},
     id::ChannelId,
 };
+use twilight_validate::channel::{self as validate_channel, ChannelValidationError};
 
 /// Returned when the channel can not be updated as configured.
 #[derive(Clone, Debug)]
@@ -17,6 +18,8 @@ pub enum UpdateChannelError {
     NameInvalid {
         /// Provided name.
         name: String,
+        /// The source of the error.
+        source: ChannelValidationError,
     },
     /// The seconds of the rate limit per user is more than 21600.
     RateLimitPerUserInvalid {
@@ -28,6 +31,11 @@ pub enum UpdateChannelError {
         /// Provided topic.
         topic: String,
     },
+    /// The user limit is more than 99.
+    UserLimitInvalid {
+        /// Provided user limit.
+        user_limit: u64,
+    },
 }
 
 impl Display for UpdateChannelError {
@@ -38,11 +46,21 @@ impl Display for UpdateChannelError {
                 f.write_str("the rate limit per user is invalid")
             }
             Self::TopicInvalid { .. } => f.write_str("the topic is invalid"),
+            Self::UserLimitInvalid { .. } => f.write_str("the user limit is invalid"),
         }
     }
 }
 
-impl Error for UpdateChannelError {}
+impl Error for UpdateChannelError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::NameInvalid { source, .. } => Some(source),
+            Self::RateLimitPerUserInvalid { .. }
+            | Self::TopicInvalid { .. }
+            | Self::UserLimitInvalid { .. } => None,
+        }
+    }
+}
 
 // The Discord API doesn't require the `name` and `kind` fields to be present,
 // but it does require them to be non-null.
@@ -63,10 +81,15 @@ struct UpdateChannelFields {
     position: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     rate_limit_per_user: Option<u64>,
+    #[allow(clippy::option_option)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rtc_region: Option<Option<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     topic: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     user_limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    video_quality_mode: Option<VideoQualityMode>,
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
     kind: Option<ChannelType>,
@@ -87,6 +110,8 @@ struct UpdateChannelFields {
 ///
 /// Returns a [`UpdateChannelError::TopicInvalid`] when the length of the topic is more than
 /// 1024 UTF-16 characters.
+///
+/// Returns a [`UpdateChannelError::UserLimitInvalid`] when the user limit is more than 99.
 pub struct UpdateChannel<'a> {
     channel_id: ChannelId,
     fields: UpdateChannelFields,
@@ -127,8 +152,8 @@ impl<'a> UpdateChannel<'a> {
     }
 
     fn _name(mut self, name: String) -> Result<Self, UpdateChannelError> {
-        if !validate::channel_name(&name) {
-            return Err(UpdateChannelError::NameInvalid { name });
+        if let Err(source) = validate_channel::name(&name) {
+            return Err(UpdateChannelError::NameInvalid { name, source });
         }
 
         self.fields.name.replace(name);
@@ -201,6 +226,15 @@ impl<'a> UpdateChannel<'a> {
         Ok(self)
     }
 
+    /// For voice channels, set the voice region of the channel.
+    ///
+    /// Set to `None` to have the region chosen automatically.
+    pub fn rtc_region(mut self, rtc_region: Option<String>) -> Self {
+        self.fields.rtc_region.replace(rtc_region);
+
+        self
+    }
+
     /// Set the topic.
     ///
     /// The maximum length is 1024 UTF-16 characters. Refer to [the discord docs] for more details.
@@ -230,10 +264,25 @@ impl<'a> UpdateChannel<'a> {
     /// Set to 0 for no limit. Limit can otherwise be between 1 and 99 inclusive. Refer to [the
     /// discord docs] for more details.
     ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateChannelError::UserLimitInvalid`] if the limit is greater than 99.
+    ///
     /// [the discord docs]: https://discord.com/developers/docs/resources/channel#modify-channel-json-params
-    pub fn user_limit(mut self, user_limit: u64) -> Self {
+    pub fn user_limit(mut self, user_limit: u64) -> Result<Self, UpdateChannelError> {
+        if user_limit > 99 {
+            return Err(UpdateChannelError::UserLimitInvalid { user_limit });
+        }
+
         self.fields.user_limit.replace(user_limit);
 
+        Ok(self)
+    }
+
+    /// For voice channels, set the camera video quality mode.
+    pub fn video_quality_mode(mut self, video_quality_mode: VideoQualityMode) -> Self {
+        self.fields.video_quality_mode.replace(video_quality_mode);
+
         self
     }
 
@@ -257,14 +306,14 @@ impl<'a> UpdateChannel<'a> {
                 crate::json_to_vec(&self.fields)?,
                 headers,
                 Route::UpdateChannel {
-                    channel_id: self.channel_id.0,
+                    channel_id: self.channel_id.get(),
                 },
             ))
         } else {
             Request::from((
                 crate::json_to_vec(&self.fields)?,
                 Route::UpdateChannel {
-                    channel_id: self.channel_id.0,
+                    channel_id: self.channel_id.get(),
                 },
             ))
         };
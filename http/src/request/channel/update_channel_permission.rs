@@ -21,10 +21,10 @@ use twilight_model::{
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 /// let client = Client::new("my token");
 ///
-/// let channel_id = ChannelId(123);
+/// let channel_id = ChannelId::new(123);
 /// let allow = Permissions::VIEW_CHANNEL;
 /// let deny = Permissions::SEND_MESSAGES;
-/// let role_id = RoleId(432);
+/// let role_id = RoleId::new(432);
 ///
 /// client.update_channel_permission(channel_id, allow, deny)
 ///     .role(role_id)
@@ -33,10 +33,10 @@ impl<'a> UpdateChannelPermissionConfigured<'a> {
     ) -> Self {
         let (name, target_id) = match target {
             PermissionOverwriteType::Member(user_id) => {
-                (PermissionOverwriteTargetType::Member, user_id.0)
+                (PermissionOverwriteTargetType::Member, user_id.get())
             }
             PermissionOverwriteType::Role(role_id) => {
-                (PermissionOverwriteTargetType::Role, role_id.0)
+                (PermissionOverwriteTargetType::Role, role_id.get())
             }
         };
 
@@ -61,7 +61,7 @@ impl<'a> UpdateChannelPermissionConfigured<'a> {
                 crate::json_to_vec(&self.fields)?,
                 headers,
                 Route::UpdatePermissionOverwrite {
-                    channel_id: self.channel_id.0,
+                    channel_id: self.channel_id.get(),
                     target_id: self.target_id,
                 },
             ))
@@ -69,7 +69,7 @@ impl<'a> UpdateChannelPermissionConfigured<'a> {
             Request::from((
                 crate::json_to_vec(&self.fields)?,
                 Route::UpdatePermissionOverwrite {
-                    channel_id: self.channel_id.0,
+                    channel_id: self.channel_id.get(),
                     target_id: self.target_id,
                 },
             ))
@@ -111,10 +111,10 @@ mod tests {
         let client = Client::new("foo");
         let builder = UpdateChannelPermissionConfigured::new(
             &client,
-            ChannelId(1),
+            ChannelId::new(1),
             Permissions::empty(),
             Permissions::SEND_MESSAGES,
-            &PermissionOverwriteType::Member(UserId(2)),
+            &PermissionOverwriteType::Member(UserId::new(2)),
         );
         let actual = builder.request().expect("failed to create request");
 
@@ -19,7 +19,7 @@ struct CreateWebhookFields {
 /// # #[tokio::main]
 /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// let client = Client::new("my token");
-/// let channel_id = ChannelId(123);
+/// let channel_id = ChannelId::new(123);
 ///
 /// let webhook = client
 ///     .create_webhook(channel_id, "Twily Bot")
@@ -68,14 +68,14 @@ impl<'a> CreateWebhook<'a> {
                 crate::json_to_vec(&self.fields)?,
                 headers,
                 Route::CreateWebhook {
-                    channel_id: self.channel_id.0,
+                    channel_id: self.channel_id.get(),
                 },
             ))
         } else {
             Request::from((
                 crate::json_to_vec(&self.fields)?,
                 Route::CreateWebhook {
-                    channel_id: self.channel_id.0,
+                    channel_id: self.channel_id.get(),
                 },
             ))
         };
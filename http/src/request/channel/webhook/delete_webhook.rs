@@ -38,13 +38,13 @@ impl<'a> DeleteWebhook<'a> {
             Request::from((
                 headers,
                 Route::DeleteWebhook {
-                    webhook_id: self.id.0,
+                    webhook_id: self.id.get(),
                     token: self.fields.token.clone(),
                 },
             ))
         } else {
             Request::from(Route::DeleteWebhook {
-                webhook_id: self.id.0,
+                webhook_id: self.id.get(),
                 token: self.fields.token.clone(),
             })
         };
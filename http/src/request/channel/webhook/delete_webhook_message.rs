@@ -19,7 +19,7 @@ use twilight_model::id::{MessageId, WebhookId};
 /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// # let client = Client::new("token");
 /// client
-///     .delete_webhook_message(WebhookId(1), "token here", MessageId(2))
+///     .delete_webhook_message(WebhookId::new(1), "token here", MessageId::new(2))
 ///     .reason("reason here")?
 ///     .await?;
 /// # Ok(()) }
@@ -52,9 +52,9 @@ impl<'a> DeleteWebhookMessage<'a> {
 
     fn request(&self) -> Result<Request> {
         let route = Route::DeleteWebhookMessage {
-            message_id: self.message_id.0,
+            message_id: self.message_id.get(),
             token: self.token.clone(),
-            webhook_id: self.webhook_id.0,
+            webhook_id: self.webhook_id.get(),
         };
 
         Ok(if let Some(reason) = &self.reason {
@@ -93,7 +93,8 @@ mod tests {
     #[test]
     fn test_request() {
         let client = Client::new("token");
-        let builder = DeleteWebhookMessage::new(&client, WebhookId(1), "token", MessageId(2));
+        let builder =
+            DeleteWebhookMessage::new(&client, WebhookId::new(1), "token", MessageId::new(2));
         let actual = builder.request().expect("failed to create request");
 
         let route = Route::DeleteWebhookMessage {
@@ -1,10 +1,38 @@
+//! Execute a webhook, sending a message to its channel.
+
 use crate::request::prelude::*;
 use futures_util::future::TryFutureExt;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
 use twilight_model::{
-    channel::{embed::Embed, Message},
+    channel::{embed::Embed, message::MessageFlags, Message},
     id::WebhookId,
 };
 
+/// A webhook can not be executed as configured.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum ExecuteWebhookError {
+    /// Returned when flags other than [`MessageFlags::SUPPRESS_EMBEDS`] and
+    /// [`MessageFlags::SUPPRESS_NOTIFICATIONS`] are set.
+    FlagsInvalid {
+        /// Provided flags.
+        flags: MessageFlags,
+    },
+}
+
+impl Display for ExecuteWebhookError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::FlagsInvalid { .. } => f.write_str("only the suppress flags can be set"),
+        }
+    }
+}
+
+impl Error for ExecuteWebhookError {}
+
 #[derive(Default, Serialize)]
 struct ExecuteWebhookFields {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -16,6 +44,8 @@ struct ExecuteWebhookFields {
     #[serde(skip_serializing_if = "Option::is_none")]
     file: Option<Vec<u8>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    flags: Option<MessageFlags>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     payload_json: Option<Vec<u8>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     tts: Option<bool>,
@@ -38,7 +68,7 @@ struct ExecuteWebhookFields {
 /// # #[tokio::main]
 /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// let client = Client::new("my token");
-/// let id = WebhookId(432);
+/// let id = WebhookId::new(432);
 ///
 /// let webhook = client
 ///     .execute_webhook(id, "webhook token")
@@ -99,6 +129,26 @@ impl<'a> ExecuteWebhook<'a> {
         self
     }
 
+    /// Set the message's flags.
+    ///
+    /// Only [`MessageFlags::SUPPRESS_EMBEDS`] and
+    /// [`MessageFlags::SUPPRESS_NOTIFICATIONS`] can be set when executing a
+    /// webhook. Webhooks can't send voice messages.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExecuteWebhookError::FlagsInvalid`] if any other flags are
+    /// set.
+    pub fn flags(mut self, flags: MessageFlags) -> Result<Self, ExecuteWebhookError> {
+        if !validate::webhook_message_flags(flags) {
+            return Err(ExecuteWebhookError::FlagsInvalid { flags });
+        }
+
+        self.fields.flags.replace(flags);
+
+        Ok(self)
+    }
+
     /// JSON encoded body of any additional request fields. See [Discord Docs/Create Message]
     ///
     /// [Discord Docs/Create Message]: https://discord.com/developers/docs/resources/channel#create-message-params
@@ -138,7 +188,7 @@ impl<'a> ExecuteWebhook<'a> {
             Route::ExecuteWebhook {
                 token: self.token.to_owned(),
                 wait: self.fields.wait,
-                webhook_id: self.webhook_id.0,
+                webhook_id: self.webhook_id.get(),
             },
         ));
 
@@ -157,3 +207,26 @@ impl<'a> ExecuteWebhook<'a> {
 }
 
 poll_req!(ExecuteWebhook<'_>, Option<Message>);
+
+#[cfg(test)]
+mod tests {
+    use super::{ExecuteWebhook, ExecuteWebhookError};
+    use crate::client::Client;
+    use twilight_model::{channel::message::MessageFlags, id::WebhookId};
+
+    #[test]
+    fn test_flags() {
+        let client = Client::new("token");
+        let builder = ExecuteWebhook::new(&client, WebhookId::new(1), "token");
+
+        assert!(builder.flags(MessageFlags::SUPPRESS_NOTIFICATIONS).is_ok());
+
+        let builder = ExecuteWebhook::new(&client, WebhookId::new(1), "token");
+
+        assert!(matches!(
+            builder.flags(MessageFlags::IS_VOICE_MESSAGE),
+            Err(ExecuteWebhookError::FlagsInvalid { flags })
+            if flags == MessageFlags::IS_VOICE_MESSAGE
+        ));
+    }
+}
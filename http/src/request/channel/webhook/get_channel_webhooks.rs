@@ -20,7 +20,7 @@ impl<'a> GetChannelWebhooks<'a> {
     fn start(&mut self) -> Result<()> {
         self.fut.replace(Box::pin(self.http.request(Request::from(
             Route::GetChannelWebhooks {
-                channel_id: self.channel_id.0,
+                channel_id: self.channel_id.get(),
             },
         ))));
 
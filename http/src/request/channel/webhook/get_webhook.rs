@@ -36,7 +36,7 @@ impl<'a> GetWebhook<'a> {
             .replace(Box::pin(self.http.request_bytes(Request::from(
                 Route::GetWebhook {
                     token: self.fields.token.clone(),
-                    webhook_id: self.id.0,
+                    webhook_id: self.id.get(),
                 },
             ))));
 
@@ -73,7 +73,7 @@ impl<'a> UpdateWebhook<'a> {
                 headers,
                 Route::UpdateWebhook {
                     token: None,
-                    webhook_id: self.webhook_id.0,
+                    webhook_id: self.webhook_id.get(),
                 },
             ))
         } else {
@@ -81,7 +81,7 @@ impl<'a> UpdateWebhook<'a> {
                 crate::json_to_vec(&self.fields)?,
                 Route::UpdateWebhook {
                     token: None,
-                    webhook_id: self.webhook_id.0,
+                    webhook_id: self.webhook_id.get(),
                 },
             ))
         };
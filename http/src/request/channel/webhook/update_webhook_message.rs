@@ -17,9 +17,10 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::{
-    channel::embed::Embed,
+    channel::{embed::Embed, Attachment},
     id::{MessageId, WebhookId},
 };
+use twilight_validate::message::{self as validate_message, MessageValidationError};
 
 /// A webhook's message can not be updated as configured.
 #[derive(Clone, Debug)]
@@ -29,6 +30,8 @@ pub enum UpdateWebhookMessageError {
     ContentInvalid {
         /// Provided content.
         content: String,
+        /// Source of the error.
+        source: MessageValidationError,
     },
     /// Length of one of the embeds is over 6000 characters.
     EmbedTooLarge {
@@ -68,8 +71,9 @@ impl Display for UpdateWebhookMessageError {
 impl Error for UpdateWebhookMessageError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
+            Self::ContentInvalid { source, .. } => Some(source),
             Self::EmbedTooLarge { source, .. } => Some(source),
-            Self::ContentInvalid { .. } | Self::TooManyEmbeds { .. } => None,
+            Self::TooManyEmbeds { .. } => None,
         }
     }
 }
@@ -78,6 +82,8 @@ impl Error for UpdateWebhookMessageError {
 struct UpdateWebhookMessageFields {
     #[serde(skip_serializing_if = "Option::is_none")]
     allowed_mentions: Option<AllowedMentions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attachments: Option<Vec<Attachment>>,
     #[allow(clippy::option_option)]
     #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<Option<String>>,
@@ -106,7 +112,7 @@ struct UpdateWebhookMessageFields {
 /// # #[tokio::main]
 /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// # let client = Client::new("token");
-/// client.update_webhook_message(WebhookId(1), "token here", MessageId(2))
+/// client.update_webhook_message(WebhookId::new(1), "token here", MessageId::new(2))
 ///     // By creating a default set of allowed mentions, no entity can be
 ///     // mentioned.
 ///     .allowed_mentions(AllowedMentions::default())
@@ -157,6 +163,17 @@ impl<'a> UpdateWebhookMessage<'a> {
         self
     }
 
+    /// Set the attachments to keep on the message.
+    ///
+    /// Attachments that are not included are removed from the message. Pass
+    /// the message's existing attachments to modify them, such as to change
+    /// their descriptions.
+    pub fn attachments(mut self, attachments: Vec<Attachment>) -> Self {
+        self.fields.attachments.replace(attachments);
+
+        self
+    }
+
     /// Set the content of the message.
     ///
     /// Pass `None` if you want to remove the message content.
@@ -172,9 +189,10 @@ impl<'a> UpdateWebhookMessage<'a> {
     /// length is too long.
     pub fn content(mut self, content: Option<String>) -> Result<Self, UpdateWebhookMessageError> {
         if let Some(content_ref) = content.as_ref() {
-            if !validate::content_limit(content_ref) {
+            if let Err(source) = validate_message::content(content_ref) {
                 return Err(UpdateWebhookMessageError::ContentInvalid {
                     content: content.expect("content is known to be some"),
+                    source,
                 });
             }
         }
@@ -214,7 +232,7 @@ impl<'a> UpdateWebhookMessage<'a> {
     ///     .url("https://twilight.rs")
     ///     .build()?;
     ///
-    /// client.update_webhook_message(WebhookId(1), "token", MessageId(2))
+    /// client.update_webhook_message(WebhookId::new(1), "token", MessageId::new(2))
     ///     .embeds(Some(vec![embed]))?
     ///     .await?;
     /// # Ok(()) }
@@ -257,9 +275,9 @@ impl<'a> UpdateWebhookMessage<'a> {
     fn request(&self) -> Result<Request> {
         let body = crate::json_to_vec(&self.fields)?;
         let route = Route::UpdateWebhookMessage {
-            message_id: self.message_id.0,
+            message_id: self.message_id.get(),
             token: self.token.clone(),
-            webhook_id: self.webhook_id.0,
+            webhook_id: self.webhook_id.get(),
         };
 
         Ok(if let Some(reason) = &self.reason {
@@ -302,15 +320,17 @@ mod tests {
     #[test]
     fn test_request() {
         let client = Client::new("token");
-        let builder = UpdateWebhookMessage::new(&client, WebhookId(1), "token", MessageId(2))
-            .content(Some("test".to_owned()))
-            .expect("'test' content couldn't be set")
-            .reason("reason")
-            .expect("'reason' is not a valid reason");
+        let builder =
+            UpdateWebhookMessage::new(&client, WebhookId::new(1), "token", MessageId::new(2))
+                .content(Some("test".to_owned()))
+                .expect("'test' content couldn't be set")
+                .reason("reason")
+                .expect("'reason' is not a valid reason");
         let actual = builder.request().expect("failed to create request");
 
         let body = crate::json_to_vec(&UpdateWebhookMessageFields {
             allowed_mentions: None,
+            attachments: None,
             content: Some(Some("test".to_owned())),
             embeds: None,
         })
@@ -56,7 +56,7 @@ impl<'a> UpdateWebhookWithToken<'a> {
             crate::json_to_vec(&self.fields)?,
             Route::UpdateWebhook {
                 token: Some(self.token.clone()),
-                webhook_id: self.webhook_id.0,
+                webhook_id: self.webhook_id.get(),
             },
         )))));
 
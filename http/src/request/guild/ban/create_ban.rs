@@ -50,8 +50,8 @@ struct CreateBanFields {
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 /// let client = Client::new("my token");
 ///
-/// let guild_id = GuildId(100);
-/// let user_id = UserId(200);
+/// let guild_id = GuildId::new(100);
+/// let user_id = UserId::new(200);
 /// client.create_ban(guild_id, user_id)
 ///     .delete_message_days(1)?
 ///     .reason("memes")?
@@ -99,9 +99,9 @@ impl<'a> CreateBan<'a> {
         self.fut.replace(Box::pin(self.http.verify(Request::from(
             Route::CreateBan {
                 delete_message_days: self.fields.delete_message_days,
-                guild_id: self.guild_id.0,
+                guild_id: self.guild_id.get(),
                 reason: self.fields.reason.clone(),
-                user_id: self.user_id.0,
+                user_id: self.user_id.get(),
             },
         ))));
 
@@ -15,8 +15,8 @@ use twilight_model::id::{GuildId, UserId};
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 /// let client = Client::new("my token");
 ///
-/// let guild_id = GuildId(100);
-/// let user_id = UserId(200);
+/// let guild_id = GuildId::new(100);
+/// let user_id = UserId::new(200);
 ///
 /// client.delete_ban(guild_id, user_id).await?;
 /// # Ok(()) }
@@ -46,14 +46,14 @@ impl<'a> DeleteBan<'a> {
             Request::from((
                 headers,
                 Route::DeleteBan {
-                    guild_id: self.guild_id.0,
-                    user_id: self.user_id.0,
+                    guild_id: self.guild_id.get(),
+                    user_id: self.user_id.get(),
                 },
             ))
         } else {
             Request::from(Route::DeleteBan {
-                guild_id: self.guild_id.0,
-                user_id: self.user_id.0,
+                guild_id: self.guild_id.get(),
+                user_id: self.user_id.get(),
             })
         };
 
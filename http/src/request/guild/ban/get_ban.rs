@@ -28,8 +28,8 @@ impl<'a> GetBan<'a> {
         self.fut
             .replace(Box::pin(self.http.request_bytes(Request::from(
                 Route::GetBan {
-                    guild_id: self.guild_id.0,
-                    user_id: self.user_id.0,
+                    guild_id: self.guild_id.get(),
+                    user_id: self.user_id.get(),
                 },
             ))));
 
@@ -15,7 +15,7 @@ use twilight_model::{guild::Ban, id::GuildId};
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 /// let client = Client::new("my token");
 ///
-/// let guild_id = GuildId(1);
+/// let guild_id = GuildId::new(1);
 ///
 /// let bans = client.bans(guild_id).await?;
 /// # Ok(()) }
@@ -38,7 +38,7 @@ impl<'a> GetBans<'a> {
     fn start(&mut self) -> Result<()> {
         self.fut
             .replace(Box::pin(self.http.request(Request::from(Route::GetBans {
-                guild_id: self.guild_id.0,
+                guild_id: self.guild_id.get(),
             }))));
 
         Ok(())
@@ -48,7 +48,7 @@ impl RoleFieldsBuilder {
     /// [`color`]: Self::color
     pub const COLOR_MAXIMUM: u32 = 0xff_ff_ff;
 
-    const ROLE_ID: RoleId = RoleId(1);
+    const ROLE_ID: RoleId = RoleId::new(1);
 
     /// Create a new default role field builder.
     pub fn new(name: impl Into<String>) -> Self {
@@ -231,7 +231,7 @@ impl TextFieldsBuilder {
         }
 
         Ok(Self(TextFields {
-            id: ChannelId(1),
+            id: ChannelId::new(1),
             kind: ChannelType::GuildText,
             name,
             nsfw: None,
@@ -369,7 +369,7 @@ impl VoiceFieldsBuilder {
 
         Ok(Self(VoiceFields {
             bitrate: None,
-            id: ChannelId(1),
+            id: ChannelId::new(1),
             kind: ChannelType::GuildVoice,
             name,
             permission_overwrites: None,
@@ -481,7 +481,7 @@ impl CategoryFieldsBuilder {
 
         Ok(Self {
             fields: CategoryFields {
-                id: ChannelId(1),
+                id: ChannelId::new(1),
                 name,
                 kind: ChannelType::GuildCategory,
                 permission_overwrites: None,
@@ -560,9 +560,9 @@ impl GuildChannelFieldsBuilder {
             .iter()
             .rev()
             .find(|c| matches!(c, GuildChannelFields::Category(_)))
-            .map_or(ChannelId(1), |c| c.to_owned().id());
+            .map_or(ChannelId::new(1), |c| c.to_owned().id());
 
-        let mut channels = channel.build(ChannelId(last_id.0 + 1));
+        let mut channels = channel.build(ChannelId::new(last_id.get() + 1));
 
         self.0.append(&mut channels);
 
@@ -594,7 +594,7 @@ mod tests {
         PermissionOverwrite {
             allow: perms(),
             deny: Permissions::empty(),
-            kind: PermissionOverwriteType::Role(RoleId(2)),
+            kind: PermissionOverwriteType::Role(RoleId::new(2)),
         }
     }
 
@@ -619,7 +619,7 @@ mod tests {
             .color(0x12_34_56)
             .unwrap()
             .hoist()
-            .id(RoleId(2))
+            .id(RoleId::new(2))
             .unwrap()
             .mentionable()
             .permissions(Permissions::empty())
@@ -630,7 +630,7 @@ mod tests {
             RoleFields {
                 color: Some(0x12_34_56),
                 hoist: Some(true),
-                id: RoleId(2),
+                id: RoleId::new(2),
                 mentionable: Some(true),
                 name: String::from("rolename"),
                 permissions: Some(Permissions::empty()),
@@ -654,13 +654,13 @@ mod tests {
             fields.build(),
             VoiceFields {
                 bitrate: Some(96_000),
-                id: ChannelId(1),
+                id: ChannelId::new(1),
                 kind: ChannelType::GuildVoice,
                 name: String::from("voicename"),
                 permission_overwrites: Some(vec![PermissionOverwrite {
                     allow: perms(),
                     deny: Permissions::empty(),
-                    kind: PermissionOverwriteType::Role(RoleId(2)),
+                    kind: PermissionOverwriteType::Role(RoleId::new(2)),
                 }]),
                 parent_id: None,
                 user_limit: Some(40),
@@ -693,14 +693,14 @@ mod tests {
         assert_eq!(
             fields.build(),
             TextFields {
-                id: ChannelId(1),
+                id: ChannelId::new(1),
                 kind: ChannelType::GuildText,
                 name: String::from("textname"),
                 nsfw: Some(true),
                 permission_overwrites: Some(vec![PermissionOverwrite {
                     allow: perms(),
                     deny: Permissions::empty(),
-                    kind: PermissionOverwriteType::Role(RoleId(2)),
+                    kind: PermissionOverwriteType::Role(RoleId::new(2)),
                 }]),
                 parent_id: None,
                 rate_limit_per_user: Some(4_000),
@@ -732,13 +732,13 @@ mod tests {
             channels.build(),
             vec![
                 GuildChannelFields::Category(CategoryFields {
-                    id: ChannelId(2),
+                    id: ChannelId::new(2),
                     kind: ChannelType::GuildCategory,
                     name: String::from("category"),
                     permission_overwrites: None,
                 }),
                 GuildChannelFields::Text(TextFields {
-                    id: ChannelId(1),
+                    id: ChannelId::new(1),
                     kind: ChannelType::GuildText,
                     name: String::from("textname"),
                     nsfw: Some(true),
@@ -747,15 +747,15 @@ mod tests {
                             | Permissions::SPEAK
                             | Permissions::SEND_TTS_MESSAGES,
                         deny: Permissions::empty(),
-                        kind: PermissionOverwriteType::Role(RoleId(2)),
+                        kind: PermissionOverwriteType::Role(RoleId::new(2)),
                     }]),
-                    parent_id: Some(ChannelId(2)),
+                    parent_id: Some(ChannelId::new(2)),
                     rate_limit_per_user: Some(4_000),
                     topic: Some(String::from("a topic")),
                 }),
                 GuildChannelFields::Voice(VoiceFields {
                     bitrate: Some(96_000),
-                    id: ChannelId(1),
+                    id: ChannelId::new(1),
                     kind: ChannelType::GuildVoice,
                     name: String::from("voicename"),
                     permission_overwrites: Some(vec![PermissionOverwrite {
@@ -763,9 +763,9 @@ mod tests {
                             | Permissions::SPEAK
                             | Permissions::SEND_TTS_MESSAGES,
                         deny: Permissions::empty(),
-                        kind: PermissionOverwriteType::Role(RoleId(2)),
+                        kind: PermissionOverwriteType::Role(RoleId::new(2)),
                     }]),
-                    parent_id: Some(ChannelId(2)),
+                    parent_id: Some(ChannelId::new(2)),
                     user_limit: Some(40),
                 }),
             ]
@@ -782,7 +782,7 @@ mod tests {
             channels.build(),
             vec![
                 GuildChannelFields::Text(TextFields {
-                    id: ChannelId(1),
+                    id: ChannelId::new(1),
                     kind: ChannelType::GuildText,
                     name: String::from("textname"),
                     nsfw: Some(true),
@@ -791,7 +791,7 @@ mod tests {
                             | Permissions::SPEAK
                             | Permissions::SEND_TTS_MESSAGES,
                         deny: Permissions::empty(),
-                        kind: PermissionOverwriteType::Role(RoleId(2)),
+                        kind: PermissionOverwriteType::Role(RoleId::new(2)),
                     }]),
                     parent_id: None,
                     rate_limit_per_user: Some(4_000),
@@ -799,7 +799,7 @@ mod tests {
                 }),
                 GuildChannelFields::Voice(VoiceFields {
                     bitrate: Some(96_000),
-                    id: ChannelId(1),
+                    id: ChannelId::new(1),
                     kind: ChannelType::GuildVoice,
                     name: String::from("voicename"),
                     permission_overwrites: Some(vec![PermissionOverwrite {
@@ -807,7 +807,7 @@ mod tests {
                             | Permissions::SPEAK
                             | Permissions::SEND_TTS_MESSAGES,
                         deny: Permissions::empty(),
-                        kind: PermissionOverwriteType::Role(RoleId(2)),
+                        kind: PermissionOverwriteType::Role(RoleId::new(2)),
                     }]),
                     parent_id: None,
                     user_limit: Some(40),
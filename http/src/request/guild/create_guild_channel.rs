@@ -4,9 +4,12 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::{
-    channel::{permission_overwrite::PermissionOverwrite, ChannelType, GuildChannel},
+    channel::{
+        permission_overwrite::PermissionOverwrite, ChannelType, GuildChannel, VideoQualityMode,
+    },
     id::{ChannelId, GuildId},
 };
+use twilight_validate::channel::{self as validate_channel, ChannelValidationError};
 
 /// Returned when the channel can not be created as configured.
 #[derive(Clone, Debug)]
@@ -17,6 +20,8 @@ pub enum CreateGuildChannelError {
     NameInvalid {
         /// Provided name.
         name: String,
+        /// The source of the error.
+        source: ChannelValidationError,
     },
     /// The seconds of the rate limit per user is more than 21600.
     RateLimitPerUserInvalid {
@@ -28,6 +33,11 @@ pub enum CreateGuildChannelError {
         /// Provided topic.
         topic: String,
     },
+    /// The user limit is more than 99.
+    UserLimitInvalid {
+        /// Provided user limit.
+        user_limit: u64,
+    },
 }
 
 impl Display for CreateGuildChannelError {
@@ -38,11 +48,21 @@ impl Display for CreateGuildChannelError {
                 f.write_str("the rate limit per user is invalid")
             }
             Self::TopicInvalid { .. } => f.write_str("the topic is invalid"),
+            Self::UserLimitInvalid { .. } => f.write_str("the user limit is invalid"),
         }
     }
 }
 
-impl Error for CreateGuildChannelError {}
+impl Error for CreateGuildChannelError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::NameInvalid { source, .. } => Some(source),
+            Self::RateLimitPerUserInvalid { .. }
+            | Self::TopicInvalid { .. }
+            | Self::UserLimitInvalid { .. } => None,
+        }
+    }
+}
 
 #[derive(Serialize)]
 struct CreateGuildChannelFields {
@@ -62,9 +82,13 @@ struct CreateGuildChannelFields {
     #[serde(skip_serializing_if = "Option::is_none")]
     rate_limit_per_user: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    rtc_region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     topic: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     user_limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    video_quality_mode: Option<VideoQualityMode>,
 }
 
 /// Create a new request to create a guild channel.
@@ -82,6 +106,8 @@ struct CreateGuildChannelFields {
 ///
 /// Returns a [`CreateGuildChannelError::TopicInvalid`] when the length of the topic is more than
 /// 1024 UTF-16 characters.
+///
+/// Returns a [`CreateGuildChannelError::UserLimitInvalid`] when the user limit is more than 99.
 pub struct CreateGuildChannel<'a> {
     fields: CreateGuildChannelFields,
     fut: Option<Pending<'a, GuildChannel>>,
@@ -104,8 +130,8 @@ impl<'a> CreateGuildChannel<'a> {
         guild_id: GuildId,
         name: String,
     ) -> Result<Self, CreateGuildChannelError> {
-        if !validate::channel_name(&name) {
-            return Err(CreateGuildChannelError::NameInvalid { name });
+        if let Err(source) = validate_channel::name(&name) {
+            return Err(CreateGuildChannelError::NameInvalid { name, source });
         }
 
         Ok(Self {
@@ -118,8 +144,10 @@ impl<'a> CreateGuildChannel<'a> {
                 permission_overwrites: None,
                 position: None,
                 rate_limit_per_user: None,
+                rtc_region: None,
                 topic: None,
                 user_limit: None,
+                video_quality_mode: None,
             },
             fut: None,
             guild_id,
@@ -206,6 +234,15 @@ impl<'a> CreateGuildChannel<'a> {
         Ok(self)
     }
 
+    /// For voice channels, set the voice region of the channel.
+    ///
+    /// Defaults to the region being chosen automatically.
+    pub fn rtc_region(mut self, rtc_region: impl Into<String>) -> Self {
+        self.fields.rtc_region.replace(rtc_region.into());
+
+        self
+    }
+
     /// Set the topic.
     ///
     /// The maximum length is 1024 UTF-16 characters. Refer to [the discord docs] for more details.
@@ -235,10 +272,25 @@ impl<'a> CreateGuildChannel<'a> {
     /// Set to 0 for no limit. Limit can otherwise be between 1 and 99 inclusive. Refer to [the
     /// discord docs] for more details.
     ///
+    /// # Errors
+    ///
+    /// Returns [`CreateGuildChannelError::UserLimitInvalid`] if the limit is greater than 99.
+    ///
     /// [the discord docs]: https://discord.com/developers/docs/resources/channel#modify-channel-json-params
-    pub fn user_limit(mut self, user_limit: u64) -> Self {
+    pub fn user_limit(mut self, user_limit: u64) -> Result<Self, CreateGuildChannelError> {
+        if user_limit > 99 {
+            return Err(CreateGuildChannelError::UserLimitInvalid { user_limit });
+        }
+
         self.fields.user_limit.replace(user_limit);
 
+        Ok(self)
+    }
+
+    /// For voice channels, set the camera video quality mode.
+    pub fn video_quality_mode(mut self, video_quality_mode: VideoQualityMode) -> Self {
+        self.fields.video_quality_mode.replace(video_quality_mode);
+
         self
     }
 
@@ -249,14 +301,14 @@ impl<'a> CreateGuildChannel<'a> {
                 crate::json_to_vec(&self.fields)?,
                 headers,
                 Route::CreateChannel {
-                    guild_id: self.guild_id.0,
+                    guild_id: self.guild_id.get(),
                 },
             ))
         } else {
             Request::from((
                 crate::json_to_vec(&self.fields)?,
                 Route::CreateChannel {
-                    guild_id: self.guild_id.0,
+                    guild_id: self.guild_id.get(),
                 },
             ))
         };
@@ -59,7 +59,7 @@ impl<'a> CreateGuildPrune<'a> {
 
     /// List of roles to include when pruning.
     pub fn include_roles(mut self, roles: impl Iterator<Item = RoleId>) -> Self {
-        let roles = roles.map(|e| e.0).collect::<Vec<_>>();
+        let roles = roles.map(|e| e.get()).collect::<Vec<_>>();
 
         self.fields.include_roles = roles;
 
@@ -98,7 +98,7 @@ impl<'a> CreateGuildPrune<'a> {
                 Route::CreateGuildPrune {
                     compute_prune_count: self.fields.compute_prune_count,
                     days: self.fields.days,
-                    guild_id: self.guild_id.0,
+                    guild_id: self.guild_id.get(),
                     include_roles: self.fields.include_roles.clone(),
                 },
             ))
@@ -106,7 +106,7 @@ impl<'a> CreateGuildPrune<'a> {
             Request::from(Route::CreateGuildPrune {
                 compute_prune_count: self.fields.compute_prune_count,
                 days: self.fields.days,
-                guild_id: self.guild_id.0,
+                guild_id: self.guild_id.get(),
                 include_roles: self.fields.include_roles.clone(),
             })
         };
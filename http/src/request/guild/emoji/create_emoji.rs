@@ -65,14 +65,14 @@ impl<'a> CreateEmoji<'a> {
                 crate::json_to_vec(&self.fields)?,
                 headers,
                 Route::CreateEmoji {
-                    guild_id: self.guild_id.0,
+                    guild_id: self.guild_id.get(),
                 },
             ))
         } else {
             Request::from((
                 crate::json_to_vec(&self.fields)?,
                 Route::CreateEmoji {
-                    guild_id: self.guild_id.0,
+                    guild_id: self.guild_id.get(),
                 },
             ))
         };
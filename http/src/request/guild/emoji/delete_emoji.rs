@@ -27,14 +27,14 @@ impl<'a> DeleteEmoji<'a> {
             Request::from((
                 headers,
                 Route::DeleteEmoji {
-                    emoji_id: self.emoji_id.0,
-                    guild_id: self.guild_id.0,
+                    emoji_id: self.emoji_id.get(),
+                    guild_id: self.guild_id.get(),
                 },
             ))
         } else {
             Request::from(Route::DeleteEmoji {
-                emoji_id: self.emoji_id.0,
-                guild_id: self.guild_id.0,
+                emoji_id: self.emoji_id.get(),
+                guild_id: self.guild_id.get(),
             })
         };
 
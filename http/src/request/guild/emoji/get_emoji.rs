@@ -18,8 +18,8 @@ use twilight_model::{
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 /// let client = Client::new("my token");
 ///
-/// let guild_id = GuildId(50);
-/// let emoji_id = EmojiId(100);
+/// let guild_id = GuildId::new(50);
+/// let emoji_id = EmojiId::new(100);
 ///
 /// client.emoji(guild_id, emoji_id).await?;
 /// # Ok(()) }
@@ -45,8 +45,8 @@ impl<'a> GetEmoji<'a> {
         self.fut
             .replace(Box::pin(self.http.request_bytes(Request::from(
                 Route::GetEmoji {
-                    emoji_id: self.emoji_id.0,
-                    guild_id: self.guild_id.0,
+                    emoji_id: self.emoji_id.get(),
+                    guild_id: self.guild_id.get(),
                 },
             ))));
 
@@ -55,16 +55,16 @@ impl<'a> UpdateEmoji<'a> {
                 crate::json_to_vec(&self.fields)?,
                 headers,
                 Route::UpdateEmoji {
-                    emoji_id: self.emoji_id.0,
-                    guild_id: self.guild_id.0,
+                    emoji_id: self.emoji_id.get(),
+                    guild_id: self.guild_id.get(),
                 },
             ))
         } else {
             Request::from((
                 crate::json_to_vec(&self.fields)?,
                 Route::UpdateEmoji {
-                    emoji_id: self.emoji_id.0,
-                    guild_id: self.guild_id.0,
+                    emoji_id: self.emoji_id.get(),
+                    guild_id: self.guild_id.get(),
                 },
             ))
         };
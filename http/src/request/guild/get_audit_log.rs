@@ -1,10 +1,15 @@
 use crate::request::prelude::*;
+use futures_util::stream::Stream;
 use std::{
+    collections::VecDeque,
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
 };
 use twilight_model::{
-    guild::audit_log::{AuditLog, AuditLogEvent},
+    guild::audit_log::{AuditLog, AuditLogEntry, AuditLogEvent},
     id::{GuildId, UserId},
 };
 
@@ -29,7 +34,7 @@ impl Display for GetAuditLogError {
 
 impl Error for GetAuditLogError {}
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 struct GetAuditLogFields {
     action_type: Option<AuditLogEvent>,
     before: Option<u64>,
@@ -49,7 +54,7 @@ struct GetAuditLogFields {
 /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// let client = Client::new("token");
 ///
-/// let guild_id = GuildId(101);
+/// let guild_id = GuildId::new(101);
 /// let audit_log = client
 /// // not done
 ///     .audit_log(guild_id)
@@ -105,6 +110,21 @@ impl<'a> GetAuditLog<'a> {
         Ok(self)
     }
 
+    /// Consume the request, returning a stream over all of the matching
+    /// entries from newest to oldest.
+    ///
+    /// Refer to [`AuditLogStream`] for more information.
+    pub fn paginate(self) -> AuditLogStream<'a> {
+        AuditLogStream {
+            entries: VecDeque::new(),
+            fields: self.fields,
+            finished: false,
+            fut: None,
+            guild_id: self.guild_id,
+            http: self.http,
+        }
+    }
+
     /// Filter audit log for entries from a user.
     ///
     /// This is the user who did the auditable action, not the target of the auditable action.
@@ -119,9 +139,9 @@ impl<'a> GetAuditLog<'a> {
             Route::GetAuditLogs {
                 action_type: self.fields.action_type.map(|x| x as u64),
                 before: self.fields.before,
-                guild_id: self.guild_id.0,
+                guild_id: self.guild_id.get(),
                 limit: self.fields.limit,
-                user_id: self.fields.user_id.map(|x| x.0),
+                user_id: self.fields.user_id.map(|x| x.get()),
             },
         ))));
 
@@ -130,3 +150,181 @@ impl<'a> GetAuditLog<'a> {
 }
 
 poll_req!(GetAuditLog<'_>, Option<AuditLog>);
+
+/// Stream over the entries of a guild's audit log, paginating backwards
+/// through them from newest to oldest.
+///
+/// Pages are requested one at a time as the stream is polled, passing the ID
+/// of the oldest entry received so far as `before`. Any filters and the limit
+/// set on the [`GetAuditLog`] the stream was created from apply to every page,
+/// with the limit being the size of each page. The stream ends once a page
+/// has fewer entries than the limit, or after the first error.
+///
+/// Created via [`GetAuditLog::paginate`].
+///
+/// # Examples
+///
+/// Print the reasons of all of the bans in a guild's audit log:
+///
+/// ```rust,no_run
+/// use futures_util::StreamExt;
+/// use twilight_http::Client;
+/// use twilight_model::{guild::audit_log::AuditLogEvent, id::GuildId};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("token");
+///
+/// let mut entries = client
+///     .audit_log(GuildId::new(101))
+///     .action_type(AuditLogEvent::MemberBanAdd)
+///     .paginate();
+///
+/// while let Some(entry) = entries.next().await {
+///     println!("{:?}", entry?.reason);
+/// }
+/// # Ok(()) }
+/// ```
+pub struct AuditLogStream<'a> {
+    entries: VecDeque<AuditLogEntry>,
+    fields: GetAuditLogFields,
+    finished: bool,
+    fut: Option<GetAuditLog<'a>>,
+    guild_id: GuildId,
+    http: &'a Client,
+}
+
+impl AuditLogStream<'_> {
+    /// Default number of entries in a page, used if no limit is set.
+    const DEFAULT_LIMIT: u64 = 50;
+
+    /// Queue the entries of a page, either requesting the next page from
+    /// before the oldest entry or finishing if the page isn't full.
+    fn page(&mut self, entries: Vec<AuditLogEntry>) {
+        let limit = self.fields.limit.unwrap_or(Self::DEFAULT_LIMIT);
+
+        // Entries are usually ordered from newest to oldest, but the
+        // oldest is found explicitly so that no entry is ever skipped.
+        match entries.iter().map(|entry| entry.id).min() {
+            Some(oldest) if entries.len() as u64 >= limit => {
+                self.fields.before.replace(oldest.get());
+            }
+            _ => self.finished = true,
+        }
+
+        self.entries.extend(entries);
+    }
+}
+
+impl Stream for AuditLogStream<'_> {
+    type Item = Result<AuditLogEntry>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if let Some(entry) = this.entries.pop_front() {
+                return Poll::Ready(Some(Ok(entry)));
+            }
+
+            if this.finished {
+                return Poll::Ready(None);
+            }
+
+            let (fields, guild_id, http) = (&this.fields, this.guild_id, this.http);
+            let fut = this.fut.get_or_insert_with(|| GetAuditLog {
+                fields: fields.clone(),
+                fut: None,
+                guild_id,
+                http,
+            });
+
+            let result = match Pin::new(fut).poll(cx) {
+                Poll::Ready(result) => result,
+                Poll::Pending => return Poll::Pending,
+            };
+
+            this.fut.take();
+
+            let entries = match result {
+                Ok(audit_log) => audit_log.map_or_else(Vec::new, |log| log.audit_log_entries),
+                Err(source) => {
+                    this.finished = true;
+
+                    return Poll::Ready(Some(Err(source)));
+                }
+            };
+
+            this.page(entries);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Client;
+    use twilight_model::{
+        guild::audit_log::{AuditLogEntry, AuditLogEvent},
+        id::{AuditLogEntryId, GuildId, UserId},
+    };
+
+    fn entries(ids: &[u64]) -> Vec<AuditLogEntry> {
+        ids.iter()
+            .map(|id| AuditLogEntry {
+                action_type: AuditLogEvent::MemberBanAdd,
+                changes: None,
+                id: AuditLogEntryId::new(*id),
+                options: None,
+                reason: None,
+                target_id: None,
+                user_id: UserId::new(1),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_paginate_full_page() {
+        let client = Client::new("token");
+        let mut stream = client
+            .audit_log(GuildId::new(1))
+            .limit(3)
+            .unwrap()
+            .paginate();
+
+        stream.page(entries(&[30, 20, 10]));
+        assert_eq!(Some(10), stream.fields.before);
+        assert!(!stream.finished);
+        assert_eq!(3, stream.entries.len());
+
+        stream.page(entries(&[9, 8, 7]));
+        assert_eq!(Some(7), stream.fields.before);
+        assert!(!stream.finished);
+        assert_eq!(6, stream.entries.len());
+    }
+
+    #[test]
+    fn test_paginate_short_page() {
+        let client = Client::new("token");
+        let mut stream = client
+            .audit_log(GuildId::new(1))
+            .limit(3)
+            .unwrap()
+            .paginate();
+
+        stream.page(entries(&[30, 20]));
+        assert!(stream.finished);
+        assert!(stream.fields.before.is_none());
+        assert_eq!(2, stream.entries.len());
+    }
+
+    #[test]
+    fn test_paginate_empty_page() {
+        let client = Client::new("token");
+        let mut stream = client.audit_log(GuildId::new(1)).before(5).paginate();
+
+        stream.page(Vec::new());
+        assert!(stream.finished);
+        assert_eq!(Some(5), stream.fields.before);
+        assert!(stream.entries.is_empty());
+    }
+}
@@ -35,7 +35,7 @@ impl<'a> GetGuild<'a> {
     fn start(&mut self) -> Result<()> {
         self.fut.replace(Box::pin(self.http.request(Request::from(
             Route::GetGuild {
-                guild_id: self.guild_id.0,
+                guild_id: self.guild_id.get(),
                 with_counts: self.fields.with_counts,
             },
         ))));
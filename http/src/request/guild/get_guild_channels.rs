@@ -20,7 +20,7 @@ impl<'a> GetGuildChannels<'a> {
     fn start(&mut self) -> Result<()> {
         self.fut.replace(Box::pin(self.http.request(Request::from(
             Route::GetChannels {
-                guild_id: self.guild_id.0,
+                guild_id: self.guild_id.get(),
             },
         ))));
 
@@ -22,7 +22,7 @@ impl<'a> GetGuildPreview<'a> {
     fn start(&mut self) -> Result<()> {
         self.fut.replace(Box::pin(self.http.request(Request::from(
             Route::GetGuildPreview {
-                guild_id: self.guild_id.0,
+                guild_id: self.guild_id.get(),
             },
         ))));
 
@@ -71,7 +71,7 @@ impl<'a> GetGuildPruneCount<'a> {
 
     /// List of roles to include when calculating prune count
     pub fn include_roles(mut self, roles: impl Iterator<Item = RoleId>) -> Self {
-        let roles = roles.map(|e| e.0).collect::<Vec<_>>();
+        let roles = roles.map(|e| e.get()).collect::<Vec<_>>();
 
         self.fields.include_roles = roles;
 
@@ -82,7 +82,7 @@ impl<'a> GetGuildPruneCount<'a> {
         self.fut.replace(Box::pin(self.http.request(Request::from(
             Route::GetGuildPruneCount {
                 days: self.fields.days,
-                guild_id: self.guild_id.0,
+                guild_id: self.guild_id.get(),
                 include_roles: self.fields.include_roles.clone(),
             },
         ))));
@@ -33,7 +33,7 @@ impl<'a> GetGuildVanityUrl<'a> {
         let fut = self
             .http
             .request_bytes(Request::from(Route::GetGuildVanityUrl {
-                guild_id: self.guild_id.0,
+                guild_id: self.guild_id.get(),
             }));
         self.fut.replace(Box::pin(fut));
 
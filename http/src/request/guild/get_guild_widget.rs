@@ -25,7 +25,7 @@ impl<'a> GetGuildWidget<'a> {
         self.fut
             .replace(Box::pin(self.http.request_bytes(Request::from(
                 Route::GetGuildWidget {
-                    guild_id: self.guild_id.0,
+                    guild_id: self.guild_id.get(),
                 },
             ))));
 
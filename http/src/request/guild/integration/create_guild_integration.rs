@@ -47,14 +47,14 @@ impl<'a> CreateGuildIntegration<'a> {
                 crate::json_to_vec(&self.fields)?,
                 headers,
                 Route::CreateGuildIntegration {
-                    guild_id: self.guild_id.0,
+                    guild_id: self.guild_id.get(),
                 },
             ))
         } else {
             Request::from((
                 crate::json_to_vec(&self.fields)?,
                 Route::CreateGuildIntegration {
-                    guild_id: self.guild_id.0,
+                    guild_id: self.guild_id.get(),
                 },
             ))
         };
@@ -27,14 +27,14 @@ impl<'a> DeleteGuildIntegration<'a> {
             Request::from((
                 headers,
                 Route::DeleteGuildIntegration {
-                    guild_id: self.guild_id.0,
-                    integration_id: self.integration_id.0,
+                    guild_id: self.guild_id.get(),
+                    integration_id: self.integration_id.get(),
                 },
             ))
         } else {
             Request::from(Route::DeleteGuildIntegration {
-                guild_id: self.guild_id.0,
-                integration_id: self.integration_id.0,
+                guild_id: self.guild_id.get(),
+                integration_id: self.integration_id.get(),
             })
         };
 
@@ -22,8 +22,8 @@ impl<'a> SyncGuildIntegration<'a> {
     fn start(&mut self) -> Result<()> {
         self.fut.replace(Box::pin(self.http.verify(Request::from(
             Route::SyncGuildIntegration {
-                guild_id: self.guild_id.0,
-                integration_id: self.integration_id.0,
+                guild_id: self.guild_id.get(),
+                integration_id: self.integration_id.get(),
             },
         ))));
 
@@ -71,16 +71,16 @@ impl<'a> UpdateGuildIntegration<'a> {
                 crate::json_to_vec(&self.fields)?,
                 headers,
                 Route::UpdateGuildIntegration {
-                    guild_id: self.guild_id.0,
-                    integration_id: self.integration_id.0,
+                    guild_id: self.guild_id.get(),
+                    integration_id: self.integration_id.get(),
                 },
             ))
         } else {
             Request::from((
                 crate::json_to_vec(&self.fields)?,
                 Route::UpdateGuildIntegration {
-                    guild_id: self.guild_id.0,
-                    integration_id: self.integration_id.0,
+                    guild_id: self.guild_id.get(),
+                    integration_id: self.integration_id.get(),
                 },
             ))
         };
@@ -10,6 +10,7 @@ use twilight_model::{
     guild::PartialMember,
     id::{GuildId, RoleId, UserId},
 };
+use twilight_validate::user::{self as validate_user, UserValidationError};
 
 /// Member cannot be added as configured.
 #[derive(Clone, Debug)]
@@ -17,7 +18,12 @@ use twilight_model::{
 pub enum AddGuildMemberError {
     /// Nickname is either empty or the length is more than 32 UTF-16
     /// characters.
-    NicknameInvalid { nickname: String },
+    NicknameInvalid {
+        /// Provided nickname.
+        nickname: String,
+        /// The source of the error.
+        source: UserValidationError,
+    },
 }
 
 impl Display for AddGuildMemberError {
@@ -28,7 +34,13 @@ impl Display for AddGuildMemberError {
     }
 }
 
-impl Error for AddGuildMemberError {}
+impl Error for AddGuildMemberError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::NicknameInvalid { source, .. } => Some(source),
+        }
+    }
+}
 
 #[derive(Serialize)]
 struct AddGuildMemberFields {
@@ -117,8 +129,11 @@ impl<'a> AddGuildMember<'a> {
     }
 
     fn _nick(mut self, nick: String) -> Result<Self, AddGuildMemberError> {
-        if !validate::nickname(&nick) {
-            return Err(AddGuildMemberError::NicknameInvalid { nickname: nick });
+        if let Err(source) = validate_user::nickname(&nick) {
+            return Err(AddGuildMemberError::NicknameInvalid {
+                nickname: nick,
+                source,
+            });
         }
 
         self.fields.nick.replace(nick);
@@ -137,8 +152,8 @@ impl<'a> AddGuildMember<'a> {
         let request = Request::from((
             crate::json_to_vec(&self.fields)?,
             Route::AddGuildMember {
-                guild_id: self.guild_id.0,
-                user_id: self.user_id.0,
+                guild_id: self.guild_id.get(),
+                user_id: self.user_id.get(),
             },
         ));
 
@@ -15,9 +15,9 @@ use twilight_model::id::{GuildId, RoleId, UserId};
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 /// let client = Client::new("my token");
 ///
-/// let guild_id = GuildId(1);
-/// let role_id = RoleId(2);
-/// let user_id = UserId(3);
+/// let guild_id = GuildId::new(1);
+/// let role_id = RoleId::new(2);
+/// let user_id = UserId::new(3);
 ///
 /// client.add_guild_member_role(guild_id, user_id, role_id).reason("test")?.await?;
 /// # Ok(()) }
@@ -54,16 +54,16 @@ impl<'a> AddRoleToMember<'a> {
             Request::from((
                 headers,
                 Route::AddMemberRole {
-                    guild_id: self.guild_id.0,
-                    role_id: self.role_id.0,
-                    user_id: self.user_id.0,
+                    guild_id: self.guild_id.get(),
+                    role_id: self.role_id.get(),
+                    user_id: self.user_id.get(),
                 },
             ))
         } else {
             Request::from(Route::AddMemberRole {
-                guild_id: self.guild_id.0,
-                role_id: self.role_id.0,
-                user_id: self.user_id.0,
+                guild_id: self.guild_id.get(),
+                role_id: self.role_id.get(),
+                user_id: self.user_id.get(),
             })
         };
 
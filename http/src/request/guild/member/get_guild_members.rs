@@ -63,8 +63,8 @@ struct GetGuildMembersFields {
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 /// let client = Client::new("my token");
 ///
-/// let guild_id = GuildId(100);
-/// let user_id = UserId(3000);
+/// let guild_id = GuildId::new(100);
+/// let user_id = UserId::new(3000);
 /// let members = client.guild_members(guild_id).after(user_id).await?;
 /// # Ok(()) }
 /// ```
@@ -125,8 +125,8 @@ impl<'a> GetGuildMembers<'a> {
         self.fut
             .replace(Box::pin(self.http.request_bytes(Request::from(
                 Route::GetGuildMembers {
-                    after: self.fields.after.map(|x| x.0),
-                    guild_id: self.guild_id.0,
+                    after: self.fields.after.map(|x| x.get()),
+                    guild_id: self.guild_id.get(),
                     limit: self.fields.limit,
                     presences: self.fields.presences,
                 },
@@ -38,8 +38,8 @@ impl<'a> GetMember<'a> {
         self.fut
             .replace(Box::pin(self.http.request_bytes(Request::from(
                 Route::GetMember {
-                    guild_id: self.guild_id.0,
-                    user_id: self.user_id.0,
+                    guild_id: self.guild_id.get(),
+                    user_id: self.user_id.get(),
                 },
             ))));
 
@@ -27,14 +27,14 @@ impl<'a> RemoveMember<'a> {
             Request::from((
                 headers,
                 Route::RemoveMember {
-                    guild_id: self.guild_id.0,
-                    user_id: self.user_id.0,
+                    guild_id: self.guild_id.get(),
+                    user_id: self.user_id.get(),
                 },
             ))
         } else {
             Request::from(Route::RemoveMember {
-                guild_id: self.guild_id.0,
-                user_id: self.user_id.0,
+                guild_id: self.guild_id.get(),
+                user_id: self.user_id.get(),
             })
         };
 
@@ -34,16 +34,16 @@ impl<'a> RemoveRoleFromMember<'a> {
             Request::from((
                 headers,
                 Route::RemoveMemberRole {
-                    guild_id: self.guild_id.0,
-                    role_id: self.role_id.0,
-                    user_id: self.user_id.0,
+                    guild_id: self.guild_id.get(),
+                    role_id: self.role_id.get(),
+                    user_id: self.user_id.get(),
                 },
             ))
         } else {
             Request::from(Route::RemoveMemberRole {
-                guild_id: self.guild_id.0,
-                role_id: self.role_id.0,
-                user_id: self.user_id.0,
+                guild_id: self.guild_id.get(),
+                role_id: self.role_id.get(),
+                user_id: self.user_id.get(),
             })
         };
 
@@ -4,13 +4,19 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::id::{ChannelId, GuildId, RoleId, UserId};
+use twilight_validate::user::{self as validate_user, UserValidationError};
 
 /// The error created when the member can not be updated as configured.
 #[derive(Clone, Debug)]
 #[non_exhaustive]
 pub enum UpdateGuildMemberError {
     /// The nickname is either empty or the length is more than 32 UTF-16 characters.
-    NicknameInvalid { nickname: String },
+    NicknameInvalid {
+        /// Provided nickname.
+        nickname: String,
+        /// The source of the error.
+        source: UserValidationError,
+    },
 }
 
 impl Display for UpdateGuildMemberError {
@@ -21,7 +27,13 @@ impl Display for UpdateGuildMemberError {
     }
 }
 
-impl Error for UpdateGuildMemberError {}
+impl Error for UpdateGuildMemberError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::NicknameInvalid { source, .. } => Some(source),
+        }
+    }
+}
 
 #[derive(Default, Serialize)]
 struct UpdateGuildMemberFields {
@@ -105,9 +117,10 @@ impl<'a> UpdateGuildMember<'a> {
 
     fn _nick(mut self, nick: Option<String>) -> Result<Self, UpdateGuildMemberError> {
         if let Some(nick) = nick.as_ref() {
-            if !validate::nickname(&nick) {
+            if let Err(source) = validate_user::nickname(nick) {
                 return Err(UpdateGuildMemberError::NicknameInvalid {
                     nickname: nick.to_owned(),
+                    source,
                 });
             }
         }
@@ -131,16 +144,16 @@ impl<'a> UpdateGuildMember<'a> {
                 crate::json_to_vec(&self.fields)?,
                 headers,
                 Route::UpdateMember {
-                    guild_id: self.guild_id.0,
-                    user_id: self.user_id.0,
+                    guild_id: self.guild_id.get(),
+                    user_id: self.user_id.get(),
                 },
             ))
         } else {
             Request::from((
                 crate::json_to_vec(&self.fields)?,
                 Route::UpdateMember {
-                    guild_id: self.guild_id.0,
-                    user_id: self.user_id.0,
+                    guild_id: self.guild_id.get(),
+                    user_id: self.user_id.get(),
                 },
             ))
         };
@@ -8,6 +8,7 @@ pub mod get_guild_prune_count;
 pub mod integration;
 pub mod member;
 pub mod role;
+pub mod sticker;
 pub mod update_guild;
 
 mod delete_guild;
@@ -21,16 +22,27 @@ mod get_guild_webhooks;
 mod get_guild_widget;
 mod update_current_user_nick;
 mod update_guild_channel_positions;
+mod update_guild_mfa;
 mod update_guild_widget;
 
 pub use self::{
-    create_guild::CreateGuild, create_guild_channel::CreateGuildChannel,
-    create_guild_prune::CreateGuildPrune, delete_guild::DeleteGuild, get_audit_log::GetAuditLog,
-    get_guild::GetGuild, get_guild_channels::GetGuildChannels, get_guild_invites::GetGuildInvites,
-    get_guild_preview::GetGuildPreview, get_guild_prune_count::GetGuildPruneCount,
-    get_guild_vanity_url::GetGuildVanityUrl, get_guild_voice_regions::GetGuildVoiceRegions,
-    get_guild_webhooks::GetGuildWebhooks, get_guild_widget::GetGuildWidget,
-    update_current_user_nick::UpdateCurrentUserNick, update_guild::UpdateGuild,
+    create_guild::CreateGuild,
+    create_guild_channel::CreateGuildChannel,
+    create_guild_prune::CreateGuildPrune,
+    delete_guild::DeleteGuild,
+    get_audit_log::{AuditLogStream, GetAuditLog},
+    get_guild::GetGuild,
+    get_guild_channels::GetGuildChannels,
+    get_guild_invites::GetGuildInvites,
+    get_guild_preview::GetGuildPreview,
+    get_guild_prune_count::GetGuildPruneCount,
+    get_guild_vanity_url::GetGuildVanityUrl,
+    get_guild_voice_regions::GetGuildVoiceRegions,
+    get_guild_webhooks::GetGuildWebhooks,
+    get_guild_widget::GetGuildWidget,
+    update_current_user_nick::UpdateCurrentUserNick,
+    update_guild::UpdateGuild,
     update_guild_channel_positions::UpdateGuildChannelPositions,
+    update_guild_mfa::UpdateGuildMfa,
     update_guild_widget::UpdateGuildWidget,
 };
@@ -29,7 +29,7 @@ struct CreateRoleFields {
 /// # #[tokio::main]
 /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// let client = Client::new("my token");
-/// let guild_id = GuildId(234);
+/// let guild_id = GuildId::new(234);
 ///
 /// client.create_role(guild_id)
 ///     .color(0xd90083)
@@ -100,14 +100,14 @@ impl<'a> CreateRole<'a> {
                 crate::json_to_vec(&self.fields)?,
                 headers,
                 Route::CreateRole {
-                    guild_id: self.guild_id.0,
+                    guild_id: self.guild_id.get(),
                 },
             ))
         } else {
             Request::from((
                 crate::json_to_vec(&self.fields)?,
                 Route::CreateRole {
-                    guild_id: self.guild_id.0,
+                    guild_id: self.guild_id.get(),
                 },
             ))
         };
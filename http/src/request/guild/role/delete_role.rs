@@ -27,14 +27,14 @@ impl<'a> DeleteRole<'a> {
             Request::from((
                 headers,
                 Route::DeleteRole {
-                    guild_id: self.guild_id.0,
-                    role_id: self.role_id.0,
+                    guild_id: self.guild_id.get(),
+                    role_id: self.role_id.get(),
                 },
             ))
         } else {
             Request::from(Route::DeleteRole {
-                guild_id: self.guild_id.0,
-                role_id: self.role_id.0,
+                guild_id: self.guild_id.get(),
+                role_id: self.role_id.get(),
             })
         };
 
@@ -84,16 +84,16 @@ impl<'a> UpdateRole<'a> {
                 crate::json_to_vec(&self.fields)?,
                 headers,
                 Route::UpdateRole {
-                    guild_id: self.guild_id.0,
-                    role_id: self.role_id.0,
+                    guild_id: self.guild_id.get(),
+                    role_id: self.role_id.get(),
                 },
             ))
         } else {
             Request::from((
                 crate::json_to_vec(&self.fields)?,
                 Route::UpdateRole {
-                    guild_id: self.guild_id.0,
-                    role_id: self.role_id.0,
+                    guild_id: self.guild_id.get(),
+                    role_id: self.role_id.get(),
                 },
             ))
         };
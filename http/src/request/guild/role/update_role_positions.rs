@@ -32,7 +32,7 @@ impl<'a> UpdateRolePositions<'a> {
         self.fut.replace(Box::pin(self.http.request(Request::from((
             crate::json_to_vec(&self.roles)?,
             Route::UpdateRolePositions {
-                guild_id: self.guild_id.0,
+                guild_id: self.guild_id.get(),
             },
         )))));
 
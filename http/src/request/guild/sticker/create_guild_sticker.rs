@@ -0,0 +1,230 @@
+use crate::request::{multipart::Form, prelude::*};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{channel::message::sticker::Sticker, id::GuildId};
+use twilight_validate::sticker::{self as validate_sticker, StickerValidationError};
+
+/// Returned when the sticker can not be created as configured.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum CreateGuildStickerError {
+    /// The description is neither empty nor between 2 and 100 characters.
+    DescriptionInvalid {
+        /// Provided description.
+        description: String,
+        /// The source of the error.
+        source: StickerValidationError,
+    },
+    /// The name is fewer than 2 characters or more than 30 characters.
+    NameInvalid {
+        /// Provided name.
+        name: String,
+        /// The source of the error.
+        source: StickerValidationError,
+    },
+    /// The tags are fewer than 2 characters or more than 200 characters.
+    TagsInvalid {
+        /// Provided tags.
+        tags: String,
+        /// The source of the error.
+        source: StickerValidationError,
+    },
+}
+
+impl Display for CreateGuildStickerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::DescriptionInvalid { .. } => f.write_str("the description is invalid"),
+            Self::NameInvalid { .. } => f.write_str("the name is invalid"),
+            Self::TagsInvalid { .. } => f.write_str("the tags are invalid"),
+        }
+    }
+}
+
+impl Error for CreateGuildStickerError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::DescriptionInvalid { source, .. }
+            | Self::NameInvalid { source, .. }
+            | Self::TagsInvalid { source, .. } => Some(source),
+        }
+    }
+}
+
+struct CreateGuildStickerFields {
+    description: String,
+    file: Vec<u8>,
+    file_name: String,
+    name: String,
+    tags: String,
+}
+
+/// Create a sticker in a guild.
+///
+/// The sticker is uploaded as a multipart form containing the file along with
+/// its name, description, and tags. The file must be a PNG, APNG, or Lottie
+/// JSON file no larger than 500 KB, and its format is determined from the file
+/// name's extension.
+///
+/// Requires the [`MANAGE_EMOJIS`] permission.
+///
+/// [`MANAGE_EMOJIS`]: twilight_model::guild::Permissions::MANAGE_EMOJIS
+pub struct CreateGuildSticker<'a> {
+    fields: CreateGuildStickerFields,
+    fut: Option<Pending<'a, Sticker>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    reason: Option<String>,
+}
+
+impl<'a> CreateGuildSticker<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        name: impl Into<String>,
+        tags: impl Into<String>,
+        file_name: impl Into<String>,
+        file: impl Into<Vec<u8>>,
+    ) -> Result<Self, CreateGuildStickerError> {
+        Self::_new(
+            http,
+            guild_id,
+            name.into(),
+            tags.into(),
+            file_name.into(),
+            file.into(),
+        )
+    }
+
+    fn _new(
+        http: &'a Client,
+        guild_id: GuildId,
+        name: String,
+        tags: String,
+        file_name: String,
+        file: Vec<u8>,
+    ) -> Result<Self, CreateGuildStickerError> {
+        if let Err(source) = validate_sticker::name(&name) {
+            return Err(CreateGuildStickerError::NameInvalid { name, source });
+        }
+
+        if let Err(source) = validate_sticker::tags(&tags) {
+            return Err(CreateGuildStickerError::TagsInvalid { tags, source });
+        }
+
+        Ok(Self {
+            fields: CreateGuildStickerFields {
+                description: String::new(),
+                file,
+                file_name,
+                name,
+                tags,
+            },
+            fut: None,
+            guild_id,
+            http,
+            reason: None,
+        })
+    }
+
+    /// Set the description of the sticker.
+    ///
+    /// Defaults to an empty description.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CreateGuildStickerError::DescriptionInvalid`] error type if
+    /// the description is neither empty nor between 2 and 100 characters.
+    pub fn description(
+        self,
+        description: impl Into<String>,
+    ) -> Result<Self, CreateGuildStickerError> {
+        self._description(description.into())
+    }
+
+    fn _description(mut self, description: String) -> Result<Self, CreateGuildStickerError> {
+        if let Err(source) = validate_sticker::description(&description) {
+            return Err(CreateGuildStickerError::DescriptionInvalid {
+                description,
+                source,
+            });
+        }
+
+        self.fields.description = description;
+
+        Ok(self)
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let mut form = Form::new();
+        form.part(b"name", self.fields.name.as_bytes())
+            .part(b"description", self.fields.description.as_bytes())
+            .part(b"tags", self.fields.tags.as_bytes())
+            .file(b"file", self.fields.file_name.as_bytes(), &self.fields.file);
+
+        let route = Route::CreateGuildSticker {
+            guild_id: self.guild_id.get(),
+        };
+
+        let request = if let Some(reason) = &self.reason {
+            let headers = audit_header(&reason)?;
+
+            Request::from((form, headers, route))
+        } else {
+            Request::from((form, route))
+        };
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+impl<'a> AuditLogReason for CreateGuildSticker<'a> {
+    fn reason(mut self, reason: impl Into<String>) -> Result<Self, AuditLogReasonError> {
+        self.reason
+            .replace(AuditLogReasonError::validate(reason.into())?);
+
+        Ok(self)
+    }
+}
+
+poll_req!(CreateGuildSticker<'_>, Sticker);
+
+#[cfg(test)]
+mod tests {
+    use super::{CreateGuildSticker, CreateGuildStickerError};
+    use crate::Client;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::id::GuildId;
+
+    assert_fields!(CreateGuildStickerError::DescriptionInvalid: description, source);
+    assert_fields!(CreateGuildStickerError::NameInvalid: name, source);
+    assert_fields!(CreateGuildStickerError::TagsInvalid: tags, source);
+    assert_impl_all!(CreateGuildStickerError: Clone, Debug, Error, Send, Sync);
+
+    #[test]
+    fn test_validation() {
+        let client = Client::new("token");
+        let guild_id = GuildId::new(1);
+
+        assert!(matches!(
+            CreateGuildSticker::new(&client, guild_id, "a", "tag", "a.png", vec![]),
+            Err(CreateGuildStickerError::NameInvalid { .. })
+        ));
+        assert!(matches!(
+            CreateGuildSticker::new(&client, guild_id, "name", "", "a.png", vec![]),
+            Err(CreateGuildStickerError::TagsInvalid { .. })
+        ));
+
+        let builder =
+            CreateGuildSticker::new(&client, guild_id, "name", "tag", "a.png", vec![]).unwrap();
+        assert!(matches!(
+            builder.description("a"),
+            Err(CreateGuildStickerError::DescriptionInvalid { .. })
+        ));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod create_guild_sticker;
+
+pub use self::create_guild_sticker::CreateGuildSticker;
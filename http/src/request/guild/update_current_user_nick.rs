@@ -28,7 +28,7 @@ impl<'a> UpdateCurrentUserNick<'a> {
         self.fut.replace(Box::pin(self.http.verify(Request::from((
             crate::json_to_vec(&self.fields)?,
             Route::UpdateNickname {
-                guild_id: self.guild_id.0,
+                guild_id: self.guild_id.get(),
             },
         )))));
 
@@ -8,6 +8,7 @@ use twilight_model::{
         DefaultMessageNotificationLevel, ExplicitContentFilter, PartialGuild, VerificationLevel,
     },
     id::{ChannelId, GuildId, UserId},
+    util::Locale,
 };
 
 /// The error returned when the guild can not be updated as configured.
@@ -75,7 +76,7 @@ struct UpdateGuildFields {
     public_updates_channel_id: Option<Option<ChannelId>>,
     #[allow(clippy::option_option)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    preferred_locale: Option<Option<String>>,
+    preferred_locale: Option<Option<Locale>>,
 }
 
 /// Update a guild.
@@ -258,7 +259,7 @@ impl<'a> UpdateGuild<'a> {
     /// Set the preferred locale for the guild.
     ///
     /// Defaults to `en-US`. Requires the guild to be `PUBLIC`.
-    pub fn preferred_locale(mut self, preferred_locale: impl Into<Option<String>>) -> Self {
+    pub fn preferred_locale(mut self, preferred_locale: impl Into<Option<Locale>>) -> Self {
         self.fields
             .preferred_locale
             .replace(preferred_locale.into());
@@ -287,14 +288,14 @@ impl<'a> UpdateGuild<'a> {
                 crate::json_to_vec(&self.fields)?,
                 headers,
                 Route::UpdateGuild {
-                    guild_id: self.guild_id.0,
+                    guild_id: self.guild_id.get(),
                 },
             ))
         } else {
             Request::from((
                 crate::json_to_vec(&self.fields)?,
                 Route::UpdateGuild {
-                    guild_id: self.guild_id.0,
+                    guild_id: self.guild_id.get(),
                 },
             ))
         };
@@ -39,7 +39,7 @@ impl<'a> UpdateGuildChannelPositions<'a> {
         self.fut.replace(Box::pin(self.http.verify(Request::from((
             crate::json_to_vec(&self.positions)?,
             Route::UpdateGuildChannels {
-                guild_id: self.guild_id.0,
+                guild_id: self.guild_id.get(),
             },
         )))));
 
@@ -0,0 +1,69 @@
+use crate::request::prelude::*;
+use twilight_model::{
+    guild::{GuildMfa, MfaLevel},
+    id::GuildId,
+};
+
+#[derive(Serialize)]
+struct UpdateGuildMfaFields {
+    level: MfaLevel,
+}
+
+/// Modify a guild's MFA level, which determines whether moderators must have
+/// two-factor authentication enabled to perform moderation actions.
+///
+/// Requires that the current user is the owner of the guild and has
+/// two-factor authentication enabled on their account. Bots can only use this
+/// if they own the guild, as they can't enable two-factor authentication.
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [the discord docs]: https://discord.com/developers/docs/resources/guild#modify-guild-mfa-level
+pub struct UpdateGuildMfa<'a> {
+    fields: UpdateGuildMfaFields,
+    fut: Option<Pending<'a, GuildMfa>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    reason: Option<String>,
+}
+
+impl<'a> UpdateGuildMfa<'a> {
+    pub(crate) fn new(http: &'a Client, guild_id: GuildId, level: MfaLevel) -> Self {
+        Self {
+            fields: UpdateGuildMfaFields { level },
+            fut: None,
+            guild_id,
+            http,
+            reason: None,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let route = Route::UpdateGuildMfa {
+            guild_id: self.guild_id.get(),
+        };
+
+        let request = if let Some(reason) = &self.reason {
+            let headers = audit_header(&reason)?;
+
+            Request::from((crate::json_to_vec(&self.fields)?, headers, route))
+        } else {
+            Request::from((crate::json_to_vec(&self.fields)?, route))
+        };
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+impl<'a> AuditLogReason for UpdateGuildMfa<'a> {
+    fn reason(mut self, reason: impl Into<String>) -> Result<Self, AuditLogReasonError> {
+        self.reason
+            .replace(AuditLogReasonError::validate(reason.into())?);
+
+        Ok(self)
+    }
+}
+
+poll_req!(UpdateGuildMfa<'_>, GuildMfa);
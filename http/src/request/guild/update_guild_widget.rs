@@ -49,7 +49,7 @@ impl<'a> UpdateGuildWidget<'a> {
         self.fut.replace(Box::pin(self.http.request(Request::from((
             crate::json_to_vec(&self.fields)?,
             Route::UpdateGuildWidget {
-                guild_id: self.guild_id.0,
+                guild_id: self.guild_id.get(),
             },
         )))));
 
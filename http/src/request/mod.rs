@@ -66,6 +66,7 @@ pub mod channel;
 pub mod guild;
 pub mod prelude;
 pub mod user;
+pub mod validate;
 
 mod audit_reason;
 mod get_gateway;
@@ -73,7 +74,6 @@ mod get_gateway_authed;
 mod get_user_application;
 mod get_voice_regions;
 mod multipart;
-mod validate;
 
 pub use self::{
     audit_reason::{AuditLogReason, AuditLogReasonError},
@@ -214,6 +214,21 @@ impl From<(Vec<u8>, Form, Route)> for Request {
     }
 }
 
+impl From<(Form, HeaderMap<HeaderValue>, Route)> for Request {
+    fn from((form, headers, route): (Form, HeaderMap<HeaderValue>, Route)) -> Self {
+        let (method, path, path_str) = route.into_parts();
+
+        Self {
+            body: None,
+            form: Some(form),
+            headers: Some(headers),
+            method,
+            path,
+            path_str,
+        }
+    }
+}
+
 impl From<(HeaderMap<HeaderValue>, Route)> for Request {
     fn from((headers, route): (HeaderMap<HeaderValue>, Route)) -> Self {
         let (method, path, path_str) = route.into_parts();
@@ -5,7 +5,7 @@ pub use super::{
     get_gateway::GetGateway,
     get_gateway_authed::GetGatewayAuthed,
     get_voice_regions::GetVoiceRegions,
-    guild::{ban::*, emoji::*, integration::*, member::*, role::*, *},
+    guild::{ban::*, emoji::*, integration::*, member::*, role::*, sticker::*, *},
     user::*,
 };
 pub(super) use crate::{client::Client, error::Result, routing::Route};
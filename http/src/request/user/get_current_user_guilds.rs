@@ -47,8 +47,8 @@ struct GetCurrentUserGuildsFields {
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 /// let client = Client::new("my token");
 ///
-/// let after = GuildId(300);
-/// let before = GuildId(400);
+/// let after = GuildId::new(300);
+/// let before = GuildId::new(400);
 /// let guilds = client.current_user_guilds()
 ///     .after(after)
 ///     .before(before)
@@ -112,8 +112,8 @@ impl<'a> GetCurrentUserGuilds<'a> {
     fn start(&mut self) -> Result<()> {
         self.fut.replace(Box::pin(self.http.request(Request::from(
             Route::GetGuilds {
-                after: self.fields.after.map(|x| x.0),
-                before: self.fields.before.map(|x| x.0),
+                after: self.fields.after.map(|x| x.get()),
+                before: self.fields.before.map(|x| x.get()),
                 limit: self.fields.limit,
             },
         ))));
@@ -4,28 +4,37 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::user::User;
+use twilight_validate::user::{self as validate_user, UserValidationError};
 
 /// The error created when the user can not be updated as configured.
 #[derive(Clone, Debug)]
 #[non_exhaustive]
 pub enum UpdateCurrentUserError {
     /// The length of the username is either fewer than 2 UTF-16 characters or more than 32 UTF-16
-    /// characters.
+    /// characters, or the username contains disallowed content.
     UsernameInvalid {
         /// Provided username.
         username: String,
+        /// The source of the error.
+        source: UserValidationError,
     },
 }
 
 impl Display for UpdateCurrentUserError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
-            Self::UsernameInvalid { .. } => f.write_str("the username length is invalid"),
+            Self::UsernameInvalid { .. } => f.write_str("the username is invalid"),
         }
     }
 }
 
-impl Error for UpdateCurrentUserError {}
+impl Error for UpdateCurrentUserError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::UsernameInvalid { source, .. } => Some(source),
+        }
+    }
+}
 
 #[derive(Default, Serialize)]
 struct UpdateCurrentUserFields {
@@ -81,8 +90,8 @@ impl<'a> UpdateCurrentUser<'a> {
     }
 
     fn _username(mut self, username: String) -> Result<Self, UpdateCurrentUserError> {
-        if !validate::username(&username) {
-            return Err(UpdateCurrentUserError::UsernameInvalid { username });
+        if let Err(source) = validate_user::username(&username) {
+            return Err(UpdateCurrentUserError::UsernameInvalid { username, source });
         }
 
         self.fields.username.replace(username);
@@ -1,245 +1,7 @@
-use hyper::Method;
-use std::{
-    borrow::Cow,
-    convert::TryFrom,
-    error::Error as StdError,
-    fmt::{Display, Formatter, Result as FmtResult, Write},
-    num::ParseIntError,
-    str::FromStr,
-};
-
-#[derive(Clone, Debug, Eq, PartialEq)]
-#[non_exhaustive]
-pub enum PathParseError {
-    /// The ID couldn't be parsed as an integer.
-    IntegerParsing {
-        /// Additional information about the parsing failure.
-        source: ParseIntError,
-    },
-    /// When parsing into a [`Path::ChannelsIdMessagesId`] variant, the method
-    /// must also be specified via its `TryFrom` impl.
-    MessageIdWithoutMethod {
-        /// The ID of the channel.
-        channel_id: u64,
-    },
-    /// A static path for the provided path string wasn't found.
-    NoMatch,
-}
-
-impl From<ParseIntError> for PathParseError {
-    fn from(source: ParseIntError) -> Self {
-        Self::IntegerParsing { source }
-    }
-}
-
-impl Display for PathParseError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::IntegerParsing { .. } => f.write_str("An ID in a segment was invalid"),
-            Self::MessageIdWithoutMethod { .. } => {
-                f.write_str("A message path was detected but the method wasn't given")
-            }
-            Self::NoMatch => f.write_str("There was no matched path"),
-        }
-    }
-}
-
-impl StdError for PathParseError {
-    fn source(&self) -> Option<&(dyn StdError + 'static)> {
-        match self {
-            Self::IntegerParsing { source } => Some(source),
-            Self::MessageIdWithoutMethod { .. } | Self::NoMatch => None,
-        }
-    }
-}
-
-/// An enum representing a path, most useful for ratelimiting implementations.
-// If adding to this enum, be sure to add to the `TryFrom` impl.
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-#[non_exhaustive]
-pub enum Path {
-    /// Operating on a channel.
-    ChannelsId(u64),
-    /// Operating on a channel's invites.
-    ChannelsIdInvites(u64),
-    /// Operating on a channel's messages.
-    ChannelsIdMessages(u64),
-    /// Operating on a channel's messages by bulk deleting.
-    ChannelsIdMessagesBulkDelete(u64),
-    /// Operating on an individual channel's message.
-    ChannelsIdMessagesId(Method, u64),
-    /// Crossposting an individual channel's message.
-    ChannelsIdMessagesIdCrosspost(u64),
-    /// Operating on an individual channel's message's reactions.
-    ChannelsIdMessagesIdReactions(u64),
-    /// Operating on an individual channel's message's reactions while
-    /// specifying the user ID and emoji type.
-    ChannelsIdMessagesIdReactionsUserIdType(u64),
-    /// Operating on a channel's permission overwrites by ID.
-    ChannelsIdPermissionsOverwriteId(u64),
-    /// Operating on a channel's pins.
-    ChannelsIdPins(u64),
-    /// Operating on a channel's individual pinned message.
-    ChannelsIdPinsMessageId(u64),
-    /// Operating on a channel's typing indicator.
-    ChannelsIdTyping(u64),
-    /// Operating on a channel's webhooks.
-    ChannelsIdWebhooks(u64),
-    /// Operating on a channel's followers.
-    ChannelsIdFollowers(u64),
-    /// Operating with the gateway information.
-    Gateway,
-    /// Operating with the gateway information tailored to the current user.
-    GatewayBot,
-    /// Operating on the guild resource.
-    Guilds,
-    /// Operating on one of user's guilds.
-    GuildsId(u64),
-    GuildsIdBans(u64),
-    GuildsIdBansId(u64),
-    GuildsIdAuditLogs(u64),
-    GuildsIdBansUserId(u64),
-    GuildsIdChannels(u64),
-    GuildsIdWidget(u64),
-    GuildsIdEmojis(u64),
-    GuildsIdEmojisId(u64),
-    GuildsIdIntegrations(u64),
-    GuildsIdIntegrationsId(u64),
-    GuildsIdIntegrationsIdSync(u64),
-    GuildsIdInvites(u64),
-    GuildsIdMembers(u64),
-    GuildsIdMembersId(u64),
-    GuildsIdMembersIdRolesId(u64),
-    GuildsIdMembersMeNick(u64),
-    GuildsIdPreview(u64),
-    GuildsIdPrune(u64),
-    GuildsIdRegions(u64),
-    GuildsIdRoles(u64),
-    GuildsIdRolesId(u64),
-    GuildsIdVanityUrl(u64),
-    GuildsIdWebhooks(u64),
-    InvitesCode,
-    UsersId,
-    OauthApplicationsMe,
-    UsersIdConnections,
-    UsersIdChannels,
-    /// Operating on the state of a guild that the user is in.
-    UsersIdGuilds,
-    /// Operating on the state of a guild that the user is in.
-    UsersIdGuildsId,
-    /// Operating on the voice regions available to the current user.
-    VoiceRegions,
-    /// Operating on a message created by a webhook.
-    WebhooksIdTokenMessageId(u64),
-    /// Operating on a webhook.
-    WebhooksId(u64),
-}
-
-impl FromStr for Path {
-    type Err = PathParseError;
-
-    /// Parses a string into a path.
-    ///
-    /// The string *may* start with a slash (`/`), which will be ignored.
-    ///
-    /// # Examples
-    ///
-    /// ```rust
-    /// use twilight_http::routing::Path;
-    /// use std::str::FromStr;
-    ///
-    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// assert_eq!(Path::VoiceRegions, Path::from_str("/voice/regions")?);
-    /// assert_eq!(
-    ///     Path::ChannelsIdMessages(123),
-    ///     Path::from_str("channels/123/messages")?,
-    /// );
-    /// # Ok(()) }
-    /// ```
-    #[allow(clippy::enum_glob_use)]
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        use Path::*;
-
-        let skip = usize::from(s.starts_with('/'));
-
-        let parts = s.split('/').skip(skip).collect::<Vec<&str>>();
-
-        Ok(match parts.as_slice() {
-            ["channels", id] => ChannelsId(id.parse()?),
-            ["channels", id, "followers"] => ChannelsIdFollowers(id.parse()?),
-            ["channels", id, "invites"] => ChannelsIdInvites(id.parse()?),
-            ["channels", id, "messages"] => ChannelsIdMessages(id.parse()?),
-            ["channels", id, "messages", _] => {
-                return Err(PathParseError::MessageIdWithoutMethod {
-                    channel_id: id.parse()?,
-                });
-            }
-            ["channels", id, "messages", _, "crosspost"] => {
-                ChannelsIdMessagesIdCrosspost(id.parse()?)
-            }
-            ["channels", id, "messages", _, "reactions"] => {
-                ChannelsIdMessagesIdReactions(id.parse()?)
-            }
-            ["channels", id, "messages", _, "reactions", _, _] => {
-                ChannelsIdMessagesIdReactionsUserIdType(id.parse()?)
-            }
-            ["channels", id, "permissions", _] => ChannelsIdPermissionsOverwriteId(id.parse()?),
-            ["channels", id, "pins"] => ChannelsIdPins(id.parse()?),
-            ["channels", id, "pins", _] => ChannelsIdPinsMessageId(id.parse()?),
-            ["channels", id, "typing"] => ChannelsIdTyping(id.parse()?),
-            ["channels", id, "webhooks"] => ChannelsIdWebhooks(id.parse()?),
-            ["gateway"] => Gateway,
-            ["gateway", "bot"] => GatewayBot,
-            ["guilds"] => Guilds,
-            ["guilds", id] => GuildsId(id.parse()?),
-            ["guilds", id, "bans"] => GuildsIdBans(id.parse()?),
-            ["guilds", id, "bans", _] => GuildsIdBansUserId(id.parse()?),
-            ["guilds", id, "channels"] => GuildsIdChannels(id.parse()?),
-            ["guilds", id, "widget"] => GuildsIdWidget(id.parse()?),
-            ["guilds", id, "emojis"] => GuildsIdEmojis(id.parse()?),
-            ["guilds", id, "emojis", _] => GuildsIdEmojisId(id.parse()?),
-            ["guilds", id, "integrations"] => GuildsIdIntegrations(id.parse()?),
-            ["guilds", id, "integrations", _] => GuildsIdIntegrationsId(id.parse()?),
-            ["guilds", id, "integrations", _, "sync"] => GuildsIdIntegrationsIdSync(id.parse()?),
-            ["guilds", id, "invites"] => GuildsIdInvites(id.parse()?),
-            ["guilds", id, "members"] => GuildsIdMembers(id.parse()?),
-            ["guilds", id, "members", _] => GuildsIdMembersId(id.parse()?),
-            ["guilds", id, "members", _, "roles", _] => GuildsIdMembersIdRolesId(id.parse()?),
-            ["guilds", id, "members", "@me", "nick"] => GuildsIdMembersMeNick(id.parse()?),
-            ["guilds", id, "preview"] => GuildsIdPreview(id.parse()?),
-            ["guilds", id, "prune"] => GuildsIdPrune(id.parse()?),
-            ["guilds", id, "regions"] => GuildsIdRegions(id.parse()?),
-            ["guilds", id, "roles"] => GuildsIdRoles(id.parse()?),
-            ["guilds", id, "roles", _] => GuildsIdRolesId(id.parse()?),
-            ["guilds", id, "vanity-url"] => GuildsIdVanityUrl(id.parse()?),
-            ["guilds", id, "webhooks"] => GuildsIdWebhooks(id.parse()?),
-            ["invites", _] => InvitesCode,
-            ["oauth2", "applications", "@me"] => OauthApplicationsMe,
-            ["users", _] => UsersId,
-            ["users", _, "connections"] => UsersIdConnections,
-            ["users", _, "channels"] => UsersIdChannels,
-            ["users", _, "guilds"] => UsersIdGuilds,
-            ["users", _, "guilds", _] => UsersIdGuildsId,
-            ["voice", "regions"] => VoiceRegions,
-            ["webhooks", id] | ["webhooks", id, _] => WebhooksId(id.parse()?),
-            _ => return Err(PathParseError::NoMatch),
-        })
-    }
-}
-
-impl TryFrom<(Method, &str)> for Path {
-    type Error = PathParseError;
+pub use twilight_http_ratelimiting::request::{Path, PathParseError};
 
-    fn try_from((method, s): (Method, &str)) -> Result<Self, Self::Error> {
-        match Self::from_str(s) {
-            Ok(v) => Ok(v),
-            Err(PathParseError::MessageIdWithoutMethod { channel_id }) => {
-                Ok(Self::ChannelsIdMessagesId(method, channel_id))
-            }
-            Err(why) => Err(why),
-        }
-    }
-}
+use hyper::Method;
+use std::{borrow::Cow, fmt::Write};
 
 #[derive(Clone, Debug)]
 #[non_exhaustive]
@@ -299,6 +61,11 @@ pub enum Route {
         /// pruned.
         include_roles: Vec<u64>,
     },
+    /// Route information to create a sticker in a guild.
+    CreateGuildSticker {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to create an invite to a channel.
     CreateInvite {
         /// The ID of the channel.
@@ -747,6 +514,11 @@ pub enum Route {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to update a guild's MFA level.
+    UpdateGuildMfa {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to update a guild's widget.
     UpdateGuildWidget {
         /// The ID of the guild.
@@ -905,6 +677,11 @@ impl Route {
 
                 (Method::POST, Path::GuildsIdPrune(guild_id), path.into())
             }
+            Self::CreateGuildSticker { guild_id } => (
+                Method::POST,
+                Path::GuildsIdStickers(guild_id),
+                format!("guilds/{}/stickers", guild_id).into(),
+            ),
             Self::CreateInvite { channel_id } => (
                 Method::POST,
                 Path::ChannelsIdInvites(channel_id),
@@ -1454,6 +1231,11 @@ impl Route {
                 Path::GuildsIdChannels(guild_id),
                 format!("guilds/{}/channels", guild_id).into(),
             ),
+            Self::UpdateGuildMfa { guild_id } => (
+                Method::POST,
+                Path::GuildsIdMfa(guild_id),
+                format!("guilds/{}/mfa", guild_id).into(),
+            ),
             Self::UpdateGuildWidget { guild_id } => (
                 Method::PATCH,
                 Path::GuildsIdWidget(guild_id),
@@ -1525,41 +1307,3 @@ impl Route {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::{Path, PathParseError};
-    use hyper::Method;
-    use std::{convert::TryFrom, error::Error, str::FromStr};
-
-    #[test]
-    fn test_path_prefix_unimportant() -> Result<(), Box<dyn Error>> {
-        assert_eq!(Path::Guilds, Path::from_str("guilds")?);
-        assert_eq!(Path::Guilds, Path::from_str("/guilds")?);
-
-        Ok(())
-    }
-
-    #[test]
-    fn test_path_from_str() -> Result<(), Box<dyn Error>> {
-        assert_eq!(Path::ChannelsId(123), Path::from_str("/channels/123")?);
-        assert_eq!(Path::WebhooksId(123), Path::from_str("/webhooks/123")?);
-        assert_eq!(Path::InvitesCode, Path::from_str("/invites/abc")?);
-
-        Ok(())
-    }
-
-    #[test]
-    fn test_path_message_id() -> Result<(), Box<dyn Error>> {
-        assert_eq!(
-            PathParseError::MessageIdWithoutMethod { channel_id: 123 },
-            Path::from_str("channels/123/messages/456").unwrap_err()
-        );
-        assert_eq!(
-            Path::ChannelsIdMessagesId(Method::GET, 123),
-            Path::try_from((Method::GET, "/channels/123/messages/456"))?,
-        );
-
-        Ok(())
-    }
-}
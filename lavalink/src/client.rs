@@ -1,17 +1,18 @@
 //! Client to manage nodes and players.
 
 use crate::{
-    model::{IncomingEvent, OutgoingEvent, VoiceUpdate},
-    node::{Node, NodeConfig, NodeError, Resume},
+    model::{IncomingEvent, OutgoingEvent, Stats, VoiceUpdate},
+    node::{Node, NodeConfig, NodeError, ProtocolVersion, Resume},
     player::{Player, PlayerManager},
 };
 use dashmap::{mapref::one::Ref, DashMap};
 use futures_channel::mpsc::{TrySendError, UnboundedReceiver};
 use std::{
+    collections::HashMap,
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
     net::SocketAddr,
-    sync::Arc,
+    sync::{Arc, Weak},
 };
 use twilight_model::{
     gateway::{
@@ -67,9 +68,82 @@ struct LavalinkRef {
     resume: Option<Resume>,
     shard_count: u64,
     user_id: UserId,
+    version: ProtocolVersion,
     waiting: DashMap<GuildId, VoiceStateHalf>,
 }
 
+/// Builder to configure and construct a [`Lavalink`] client.
+///
+/// # Examples
+///
+/// Create a client that connects to nodes using version 4 of the Lavalink
+/// protocol:
+///
+/// ```
+/// use twilight_lavalink::{node::ProtocolVersion, Lavalink};
+/// use twilight_model::id::UserId;
+///
+/// let user_id = UserId::new(123);
+/// let lavalink = Lavalink::builder(user_id, 1)
+///     .protocol_version(ProtocolVersion::V4)
+///     .build();
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "has no effect if not built"]
+pub struct LavalinkBuilder {
+    resume: Option<Resume>,
+    shard_count: u64,
+    user_id: UserId,
+    version: ProtocolVersion,
+}
+
+impl LavalinkBuilder {
+    /// Create a new builder for a client with the user ID of the bot and the
+    /// total number of shards.
+    pub fn new(user_id: UserId, shard_count: u64) -> Self {
+        Self {
+            resume: None,
+            shard_count,
+            user_id,
+            version: ProtocolVersion::default(),
+        }
+    }
+
+    /// Consume the builder, returning the configured client.
+    #[must_use = "has no effect if not used"]
+    pub fn build(self) -> Lavalink {
+        Lavalink(Arc::new(LavalinkRef {
+            guilds: DashMap::new(),
+            nodes: DashMap::new(),
+            players: PlayerManager::new(),
+            resume: self.resume,
+            shard_count: self.shard_count,
+            user_id: self.user_id,
+            version: self.version,
+            waiting: DashMap::new(),
+        }))
+    }
+
+    /// Set the version of the Lavalink protocol used by nodes.
+    ///
+    /// Defaults to [`ProtocolVersion::V3`].
+    pub fn protocol_version(mut self, version: ProtocolVersion) -> Self {
+        self.version = version;
+
+        self
+    }
+
+    /// Set the session resume capability of nodes.
+    ///
+    /// Session resuming is disabled by default. See the [`Resume`]
+    /// documentation for defaults.
+    pub fn resume(mut self, resume: impl Into<Option<Resume>>) -> Self {
+        self.resume = resume.into();
+
+        self
+    }
+}
+
 /// The lavalink client that manages nodes, players, and processes events from
 /// Discord to tie it all together.
 ///
@@ -106,7 +180,13 @@ impl Lavalink {
     /// [`add`]: Self::add
     /// [`new_with_resume`]: Self::new_with_resume
     pub fn new(user_id: UserId, shard_count: u64) -> Self {
-        Self::_new_with_resume(user_id, shard_count, None)
+        Self::builder(user_id, shard_count).build()
+    }
+
+    /// Create a builder to configure a new client, such as the version of
+    /// the Lavalink protocol to use.
+    pub fn builder(user_id: UserId, shard_count: u64) -> LavalinkBuilder {
+        LavalinkBuilder::new(user_id, shard_count)
     }
 
     /// Like [`new`], but allows you to specify resume capability (if any).
@@ -121,19 +201,7 @@ impl Lavalink {
         shard_count: u64,
         resume: impl Into<Option<Resume>>,
     ) -> Self {
-        Self::_new_with_resume(user_id, shard_count, resume.into())
-    }
-
-    fn _new_with_resume(user_id: UserId, shard_count: u64, resume: Option<Resume>) -> Self {
-        Self(Arc::new(LavalinkRef {
-            guilds: DashMap::new(),
-            nodes: DashMap::new(),
-            players: PlayerManager::new(),
-            resume,
-            shard_count,
-            user_id,
-            waiting: DashMap::new(),
-        }))
+        Self::builder(user_id, shard_count).resume(resume).build()
     }
 
     /// Process an event into the Lavalink client.
@@ -282,11 +350,23 @@ impl Lavalink {
             resume: self.0.resume.clone(),
             shard_count: self.0.shard_count,
             user_id: self.0.user_id,
+            version: self.0.version,
         };
 
         let (node, rx) = Node::connect(config, self.0.players.clone()).await?;
         self.0.nodes.insert(address, node.clone());
 
+        let closed = node.closed();
+        let client = Arc::downgrade(&self.0);
+
+        tokio::spawn(async move {
+            closed.await;
+
+            if let Some(client) = Weak::upgrade(&client) {
+                Lavalink(client).failover().await;
+            }
+        });
+
         Ok((node, rx))
     }
 
@@ -301,7 +381,21 @@ impl Lavalink {
     /// Determine the "best" node for new players according to available nodes'
     /// penalty scores.
     ///
-    /// Refer to [`Node::penalty`] for how this is calculated.
+    /// # Errors
+    ///
+    /// Returns [`ClientError::NodesUnconfigured`] if there are no configured
+    /// nodes available in the client.
+    #[deprecated(note = "Use `best_node`", since = "0.3.0")]
+    pub async fn best(&self) -> Result<Node, ClientError> {
+        self.best_node().await
+    }
+
+    /// Determine the "best" node for new players according to available nodes'
+    /// penalty scores.
+    ///
+    /// Refer to [`Node::penalty`] for how this is calculated from the
+    /// statistics that nodes periodically send. Nodes that have closed are
+    /// never chosen.
     ///
     /// # Errors
     ///
@@ -309,22 +403,92 @@ impl Lavalink {
     /// nodes available in the client.
     ///
     /// [`Node::penalty`]: crate::node::Node::penalty
-    pub async fn best(&self) -> Result<Node, ClientError> {
+    pub async fn best_node(&self) -> Result<Node, ClientError> {
+        // Collect the nodes first so that the map isn't locked while waiting
+        // for their stats.
+        let nodes = self
+            .0
+            .nodes
+            .iter()
+            .map(|node| node.value().clone())
+            .filter(|node| !node.is_closed())
+            .collect::<Vec<_>>();
+
         let mut lowest = i32::MAX;
         let mut best = None;
 
-        for node in self.0.nodes.iter() {
-            let penalty = node.value().penalty().await;
+        for node in nodes {
+            let penalty = node.penalty().await;
 
-            if penalty < lowest {
+            if best.is_none() || penalty < lowest {
                 lowest = penalty;
-                best.replace(node.clone());
+                best.replace(node);
             }
         }
 
         best.ok_or(ClientError::NodesUnconfigured)
     }
 
+    /// Retrieve the latest statistics of every node, such as their number of
+    /// players and CPU and memory usage.
+    ///
+    /// This can be used to make decisions such as whether more nodes are
+    /// needed. Refer to [`Node::stats`] for the statistics of a single node.
+    ///
+    /// [`Node::stats`]: crate::node::Node::stats
+    pub async fn stats(&self) -> HashMap<SocketAddr, Stats> {
+        // Collect the nodes first so that the map isn't locked while waiting
+        // for their stats.
+        let nodes = self
+            .0
+            .nodes
+            .iter()
+            .map(|node| (*node.key(), node.value().clone()))
+            .collect::<Vec<_>>();
+
+        let mut stats = HashMap::with_capacity(nodes.len());
+
+        for (address, node) in nodes {
+            stats.insert(address, node.stats().await);
+        }
+
+        stats
+    }
+
+    /// Remove closed nodes and move their players to the best available node.
+    async fn failover(&self) {
+        self.0.nodes.retain(|address, node| {
+            if node.is_closed() {
+                tracing::warn!("removing closed node {}", address);
+            }
+
+            !node.is_closed()
+        });
+
+        let node = match self.best_node().await {
+            Ok(node) => node,
+            Err(_) => {
+                tracing::warn!("no node is available to move players to");
+
+                return;
+            }
+        };
+
+        for mut player in self.0.players.players.iter_mut() {
+            if !player.node().is_closed() {
+                continue;
+            }
+
+            if let Err(source) = player.value_mut().migrate(node.clone()) {
+                tracing::warn!(
+                    "failed to move player for guild {}: {:?}",
+                    player.key(),
+                    source,
+                );
+            }
+        }
+    }
+
     /// Retrieve an immutable reference to the player manager.
     pub fn players(&self) -> &PlayerManager {
         &self.0.players
@@ -348,7 +512,7 @@ impl Lavalink {
             return Ok(player);
         }
 
-        let node = self.best().await?;
+        let node = self.best_node().await?;
 
         Ok(self.players().get_or_insert(guild_id, node).downgrade())
     }
@@ -367,7 +531,7 @@ impl Lavalink {
         for r in self.0.waiting.iter() {
             let guild_id = r.key();
 
-            if (guild_id.0 >> 22) % shard_count == shard_id {
+            if (guild_id.get() >> 22) % shard_count == shard_id {
                 self.0.waiting.remove(guild_id);
             }
         }
@@ -376,12 +540,13 @@ impl Lavalink {
 
 #[cfg(test)]
 mod tests {
-    use super::{ClientError, Lavalink, VoiceStateHalf};
+    use super::{ClientError, Lavalink, LavalinkBuilder, VoiceStateHalf};
     use static_assertions::{assert_fields, assert_impl_all};
     use std::{error::Error, fmt::Debug};
 
     assert_fields!(ClientError::SendingVoiceUpdate: source);
     assert_impl_all!(ClientError: Clone, Debug, Error, PartialEq, Send, Sync);
     assert_impl_all!(Lavalink: Clone, Debug, Send, Sync);
+    assert_impl_all!(LavalinkBuilder: Clone, Debug, Send, Sync);
     assert_impl_all!(VoiceStateHalf: Debug, Send, Sync);
 }
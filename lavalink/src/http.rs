@@ -1,28 +1,76 @@
 //! Models to deserialize responses into and functions to create `http` crate
 //! requests.
 
+use base64::DecodeError;
 use http::{
     header::{HeaderValue, AUTHORIZATION},
     Error as HttpError, Request,
 };
 use percent_encoding::NON_ALPHANUMERIC;
-use serde::{Deserialize, Serialize};
-use std::net::{IpAddr, SocketAddr};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::{
+    convert::TryInto,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    net::{IpAddr, SocketAddr},
+};
+
+/// Decoding a track's information from its base64 string failed.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TrackDecodeError {
+    /// The track isn't valid base64.
+    Base64 {
+        /// The source of the error.
+        source: DecodeError,
+    },
+    /// The track contains a string that isn't valid.
+    InvalidString,
+    /// The track ended before all of its information was read.
+    UnexpectedEnd,
+}
+
+impl Display for TrackDecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Base64 { .. } => f.write_str("track is not valid base64"),
+            Self::InvalidString => f.write_str("track contains an invalid string"),
+            Self::UnexpectedEnd => f.write_str("track ended unexpectedly"),
+        }
+    }
+}
+
+impl Error for TrackDecodeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Base64 { source } => Some(source),
+            Self::InvalidString | Self::UnexpectedEnd => None,
+        }
+    }
+}
 
 /// The type of search result given.
+///
+/// Both the values of version 3 of the protocol, such as `SEARCH_RESULT`, and
+/// version 4, such as `search`, are accepted.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[non_exhaustive]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum LoadType {
     /// Loading the results failed.
+    #[serde(alias = "error")]
     LoadFailed,
     /// There were no matches.
+    #[serde(alias = "empty")]
     NoMatches,
     /// A playlist was found.
+    #[serde(alias = "playlist")]
     PlaylistLoaded,
     /// Some results were found.
+    #[serde(alias = "search")]
     SearchResult,
     /// A single track was found.
+    #[serde(alias = "track")]
     TrackLoaded,
 }
 
@@ -36,6 +84,7 @@ pub struct Track {
     /// The base64 track string that you use in the [`Play`] event.
     ///
     /// [`Play`]: crate::model::outgoing::Play
+    #[serde(alias = "encoded")]
     pub track: String,
 }
 
@@ -62,6 +111,171 @@ pub struct TrackInfo {
     pub uri: String,
 }
 
+impl TrackInfo {
+    /// Decode the information of a track from its base64 string, without
+    /// making a request to a node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_lavalink::http::TrackInfo;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let track = "QAAAjQIAJVJpY2sgQXN0bGV5IC0gTmV2ZXIgR29ubmEgR2l2ZSBZb3UgVXAADlJpY2tBc3RsZXlWRVZPAAAAAAADPCAAC2RRdzR3OVdnWGNRAAEAK2h0dHBzOi8vd3d3LnlvdXR1YmUuY29tL3dhdGNoP3Y9ZFF3NHc5V2dYY1EAB3lvdXR1YmUAAAAAAAAAAA==";
+    /// let info = TrackInfo::decode(track)?;
+    ///
+    /// assert_eq!(Some("Rick Astley - Never Gonna Give You Up"), info.title.as_deref());
+    /// assert_eq!(212_000, info.length);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TrackDecodeError::Base64`] if the track isn't valid base64.
+    ///
+    /// Returns [`TrackDecodeError::InvalidString`] if the track contains a
+    /// string that isn't valid.
+    ///
+    /// Returns [`TrackDecodeError::UnexpectedEnd`] if the track ends before all
+    /// of its information could be read.
+    pub fn decode(track: impl AsRef<str>) -> Result<Self, TrackDecodeError> {
+        /// Flag in the header denoting that the track has a version byte.
+        const VERSIONED: u32 = 1;
+
+        let bytes =
+            base64::decode(track.as_ref()).map_err(|source| TrackDecodeError::Base64 { source })?;
+        let mut reader = TrackReader(&bytes);
+
+        let header = reader.u32()?;
+        let flags = header >> 30;
+        let size = (header & 0x3FFF_FFFF) as usize;
+
+        // The position is the last field of the message, after any data
+        // specific to the track's source.
+        let message = bytes
+            .get(4..4 + size)
+            .ok_or(TrackDecodeError::UnexpectedEnd)?;
+        let position = message
+            .len()
+            .checked_sub(8)
+            .and_then(|start| TrackReader(&message[start..]).u64().ok())
+            .ok_or(TrackDecodeError::UnexpectedEnd)?;
+
+        let version = if flags & VERSIONED == VERSIONED {
+            reader.u8()?
+        } else {
+            1
+        };
+
+        let title = reader.string()?;
+        let author = reader.string()?;
+        let length = reader.u64()?;
+        let identifier = reader.string()?;
+        let is_stream = reader.u8()? != 0;
+        let uri = if version >= 2 {
+            reader.optional_string()?
+        } else {
+            None
+        };
+
+        Ok(Self {
+            author: Some(author),
+            identifier,
+            is_seekable: !is_stream,
+            is_stream,
+            length,
+            position,
+            title: Some(title),
+            uri: uri.unwrap_or_default(),
+        })
+    }
+}
+
+/// Reader of the binary format of tracks, which is big endian and uses Java's
+/// modified UTF-8 for strings.
+struct TrackReader<'a>(&'a [u8]);
+
+impl TrackReader<'_> {
+    fn bytes(&mut self, len: usize) -> Result<&[u8], TrackDecodeError> {
+        if self.0.len() < len {
+            return Err(TrackDecodeError::UnexpectedEnd);
+        }
+
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8, TrackDecodeError> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, TrackDecodeError> {
+        let bytes = self.bytes(2)?.try_into().expect("length is 2");
+
+        Ok(u16::from_be_bytes(bytes))
+    }
+
+    fn u32(&mut self) -> Result<u32, TrackDecodeError> {
+        let bytes = self.bytes(4)?.try_into().expect("length is 4");
+
+        Ok(u32::from_be_bytes(bytes))
+    }
+
+    fn u64(&mut self) -> Result<u64, TrackDecodeError> {
+        let bytes = self.bytes(8)?.try_into().expect("length is 8");
+
+        Ok(u64::from_be_bytes(bytes))
+    }
+
+    fn optional_string(&mut self) -> Result<Option<String>, TrackDecodeError> {
+        if self.u8()? == 0 {
+            return Ok(None);
+        }
+
+        self.string().map(Some)
+    }
+
+    /// Read a string, which is prefixed by its length and encoded in Java's
+    /// modified UTF-8, where characters outside of the basic multilingual
+    /// plane are encoded as surrogate pairs.
+    fn string(&mut self) -> Result<String, TrackDecodeError> {
+        let len = self.u16()?;
+        let bytes = self.bytes(len.into())?;
+        let mut units = Vec::with_capacity(bytes.len());
+        let mut index = 0;
+
+        while let Some(&first) = bytes.get(index) {
+            let first = u16::from(first);
+            let continuation = |offset: usize| {
+                bytes
+                    .get(index + offset)
+                    .map(|byte| u16::from(*byte) & 0x3F)
+                    .ok_or(TrackDecodeError::InvalidString)
+            };
+
+            let (unit, len) = if first & 0x80 == 0 {
+                (first, 1)
+            } else if first & 0xE0 == 0xC0 {
+                (((first & 0x1F) << 6) | continuation(1)?, 2)
+            } else if first & 0xF0 == 0xE0 {
+                (
+                    ((first & 0x0F) << 12) | (continuation(1)? << 6) | continuation(2)?,
+                    3,
+                )
+            } else {
+                return Err(TrackDecodeError::InvalidString);
+            };
+
+            units.push(unit);
+            index += len;
+        }
+
+        String::from_utf16(&units).map_err(|_| TrackDecodeError::InvalidString)
+    }
+}
+
 /// Information about a playlist from a search result.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[non_exhaustive]
@@ -70,14 +284,28 @@ pub struct PlaylistInfo {
     /// The name of the playlist, if available.
     pub name: Option<String>,
     /// The selected track, if one was selected.
+    #[serde(default, deserialize_with = "selected_track")]
     pub selected_track: Option<u64>,
 }
 
+/// Deserialize the index of a playlist's selected track, which Lavalink sends
+/// as `-1` if no track was selected.
+fn selected_track<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<u64>, D::Error> {
+    let index = Option::<i64>::deserialize(deserializer)?;
+
+    Ok(index.and_then(|index| index.try_into().ok()))
+}
+
 /// Possible track results for a query.
+///
+/// Refer to [`LoadResult`] for a typed version of the results.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[non_exhaustive]
 #[serde(rename_all = "camelCase")]
 pub struct LoadedTracks {
+    /// The exception that caused loading to fail, if it did.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exception: Option<LoadException>,
     /// The type of search result, such as a list of tracks or a playlist.
     pub load_type: LoadType,
     /// Information about the playlist, if provided.
@@ -86,6 +314,174 @@ pub struct LoadedTracks {
     pub tracks: Vec<Track>,
 }
 
+/// The severity of an exception that caused loading tracks to fail.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ExceptionSeverity {
+    /// The cause is known and expected, such as a track being unavailable.
+    #[serde(alias = "common")]
+    Common,
+    /// The cause might not be exactly known, but is possibly caused by
+    /// outside factors.
+    #[serde(alias = "fault")]
+    Fault,
+    /// The cause is unknown and may be a bug in Lavalink.
+    #[serde(alias = "suspicious")]
+    Suspicious,
+}
+
+/// An exception that caused loading tracks to fail.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub struct LoadException {
+    /// The message of the exception, if any.
+    pub message: Option<String>,
+    /// The severity of the exception.
+    pub severity: ExceptionSeverity,
+}
+
+/// Typed result of loading tracks for a query, based on the type of the
+/// result.
+///
+/// Deserialize the response of a [`load_track`] or [`search_tracks`] request
+/// into this instead of [`LoadedTracks`] to not need to check the
+/// [`LoadType`]. Responses of version 4 of the protocol, such as from a
+/// [`load_track_v4`] request, can also be deserialized into this.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(from = "RawLoadResult", into = "LoadedTracks")]
+pub enum LoadResult {
+    /// Loading the results failed, with the exception if Lavalink provided
+    /// one.
+    LoadFailed(Option<LoadException>),
+    /// There were no matches.
+    NoMatches,
+    /// A playlist was found.
+    PlaylistLoaded {
+        /// Information about the playlist.
+        info: PlaylistInfo,
+        /// The tracks of the playlist.
+        tracks: Vec<Track>,
+    },
+    /// Tracks matching a search were found.
+    SearchResult(Vec<Track>),
+    /// A single track was found.
+    TrackLoaded(Track),
+}
+
+impl From<LoadedTracks> for LoadResult {
+    fn from(loaded: LoadedTracks) -> Self {
+        match loaded.load_type {
+            LoadType::LoadFailed => Self::LoadFailed(loaded.exception),
+            LoadType::NoMatches => Self::NoMatches,
+            LoadType::PlaylistLoaded => Self::PlaylistLoaded {
+                info: loaded.playlist_info,
+                tracks: loaded.tracks,
+            },
+            LoadType::SearchResult => Self::SearchResult(loaded.tracks),
+            LoadType::TrackLoaded => match loaded.tracks.into_iter().next() {
+                Some(track) => Self::TrackLoaded(track),
+                None => Self::NoMatches,
+            },
+        }
+    }
+}
+
+/// Result of loading tracks in version 4 of the protocol, where the
+/// `loadType` determines the shape of the `data`.
+#[derive(Deserialize)]
+#[serde(tag = "loadType", content = "data", rename_all = "camelCase")]
+enum LoadResultV4 {
+    Empty {},
+    Error(LoadException),
+    Playlist {
+        info: PlaylistInfo,
+        tracks: Vec<Track>,
+    },
+    Search(Vec<Track>),
+    Track(Track),
+}
+
+impl From<LoadResultV4> for LoadResult {
+    fn from(result: LoadResultV4) -> Self {
+        match result {
+            LoadResultV4::Empty {} => Self::NoMatches,
+            LoadResultV4::Error(exception) => Self::LoadFailed(Some(exception)),
+            LoadResultV4::Playlist { info, tracks } => Self::PlaylistLoaded { info, tracks },
+            LoadResultV4::Search(tracks) => Self::SearchResult(tracks),
+            LoadResultV4::Track(track) => Self::TrackLoaded(track),
+        }
+    }
+}
+
+/// Result of loading tracks in either version of the protocol.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawLoadResult {
+    V4(LoadResultV4),
+    V3(LoadedTracks),
+}
+
+impl From<RawLoadResult> for LoadResult {
+    fn from(result: RawLoadResult) -> Self {
+        match result {
+            RawLoadResult::V4(result) => result.into(),
+            RawLoadResult::V3(loaded) => loaded.into(),
+        }
+    }
+}
+
+impl From<LoadResult> for LoadedTracks {
+    fn from(result: LoadResult) -> Self {
+        let (load_type, playlist_info, tracks, exception) = match result {
+            LoadResult::LoadFailed(exception) => {
+                (LoadType::LoadFailed, None, Vec::new(), exception)
+            }
+            LoadResult::NoMatches => (LoadType::NoMatches, None, Vec::new(), None),
+            LoadResult::PlaylistLoaded { info, tracks } => {
+                (LoadType::PlaylistLoaded, Some(info), tracks, None)
+            }
+            LoadResult::SearchResult(tracks) => (LoadType::SearchResult, None, tracks, None),
+            LoadResult::TrackLoaded(track) => (LoadType::TrackLoaded, None, vec![track], None),
+        };
+
+        Self {
+            exception,
+            load_type,
+            playlist_info: playlist_info.unwrap_or(PlaylistInfo {
+                name: None,
+                selected_track: None,
+            }),
+            tracks,
+        }
+    }
+}
+
+/// Source to search for tracks on.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum SearchSource {
+    /// Search SoundCloud.
+    SoundCloud,
+    /// Search YouTube.
+    YouTube,
+    /// Search YouTube Music.
+    YouTubeMusic,
+}
+
+impl SearchSource {
+    /// Prefix of search identifiers for the source, such as `ytsearch:`.
+    pub const fn prefix(self) -> &'static str {
+        match self {
+            Self::SoundCloud => "scsearch:",
+            Self::YouTube => "ytsearch:",
+            Self::YouTubeMusic => "ytmsearch:",
+        }
+    }
+}
+
 /// A failing IP address within the planner.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[non_exhaustive]
@@ -240,9 +636,51 @@ pub fn load_track(
     identifier: impl AsRef<str>,
     authorization: impl AsRef<str>,
 ) -> Result<Request<&'static [u8]>, HttpError> {
-    let identifier =
-        percent_encoding::percent_encode(identifier.as_ref().as_bytes(), NON_ALPHANUMERIC);
-    let url = format!("http://{}/loadtracks?identifier={}", address, identifier);
+    load_tracks_request(address, "/loadtracks", identifier.as_ref(), authorization)
+}
+
+/// Get a list of tracks that match an identifier from a node using version 4
+/// of the protocol.
+///
+/// The response will include a body which can be deserialized into a
+/// [`LoadResult`].
+///
+/// # Examples
+///
+/// ```
+/// use twilight_lavalink::http;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let address = "127.0.0.1:2333".parse()?;
+/// let request = http::load_track_v4(address, "dQw4w9WgXcQ", "youshallnotpass")?;
+///
+/// assert_eq!(
+///     "http://127.0.0.1:2333/v4/loadtracks?identifier=dQw4w9WgXcQ",
+///     request.uri().to_string(),
+/// );
+/// # Ok(()) }
+/// ```
+pub fn load_track_v4(
+    address: SocketAddr,
+    identifier: impl AsRef<str>,
+    authorization: impl AsRef<str>,
+) -> Result<Request<&'static [u8]>, HttpError> {
+    load_tracks_request(
+        address,
+        "/v4/loadtracks",
+        identifier.as_ref(),
+        authorization,
+    )
+}
+
+fn load_tracks_request(
+    address: SocketAddr,
+    path: &str,
+    identifier: &str,
+    authorization: impl AsRef<str>,
+) -> Result<Request<&'static [u8]>, HttpError> {
+    let identifier = percent_encoding::percent_encode(identifier.as_bytes(), NON_ALPHANUMERIC);
+    let url = format!("http://{}{}?identifier={}", address, path, identifier);
 
     let mut req = Request::get(url);
 
@@ -252,6 +690,69 @@ pub fn load_track(
     req.body(b"")
 }
 
+/// Search for tracks on a source.
+///
+/// The response will include a body which can be deserialized into a
+/// [`LoadResult`].
+///
+/// # Examples
+///
+/// ```
+/// use twilight_lavalink::http::{self, SearchSource};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let address = "127.0.0.1:2333".parse()?;
+/// let request = http::search_tracks(address, SearchSource::YouTube, "lofi", "youshallnotpass")?;
+///
+/// assert_eq!(
+///     "http://127.0.0.1:2333/loadtracks?identifier=ytsearch%3Alofi",
+///     request.uri().to_string(),
+/// );
+/// # Ok(()) }
+/// ```
+pub fn search_tracks(
+    address: SocketAddr,
+    source: SearchSource,
+    query: impl AsRef<str>,
+    authorization: impl AsRef<str>,
+) -> Result<Request<&'static [u8]>, HttpError> {
+    let identifier = format!("{}{}", source.prefix(), query.as_ref());
+
+    load_track(address, identifier, authorization)
+}
+
+/// Search for tracks on a source using a node using version 4 of the
+/// protocol.
+///
+/// The response will include a body which can be deserialized into a
+/// [`LoadResult`].
+///
+/// # Examples
+///
+/// ```
+/// use twilight_lavalink::http::{self, SearchSource};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let address = "127.0.0.1:2333".parse()?;
+/// let request = http::search_tracks_v4(address, SearchSource::YouTube, "lofi", "youshallnotpass")?;
+///
+/// assert_eq!(
+///     "http://127.0.0.1:2333/v4/loadtracks?identifier=ytsearch%3Alofi",
+///     request.uri().to_string(),
+/// );
+/// # Ok(()) }
+/// ```
+pub fn search_tracks_v4(
+    address: SocketAddr,
+    source: SearchSource,
+    query: impl AsRef<str>,
+    authorization: impl AsRef<str>,
+) -> Result<Request<&'static [u8]>, HttpError> {
+    let identifier = format!("{}{}", source.prefix(), query.as_ref());
+
+    load_track_v4(address, identifier, authorization)
+}
+
 /// Get the configured route planner for a node by address.
 ///
 /// The response will include a body which can be deserialized into a
@@ -292,14 +793,15 @@ pub fn unmark_failed_address(
 #[cfg(test)]
 mod tests {
     use super::{
-        FailingAddress, IpBlock, IpBlockType, LoadType, LoadedTracks, NanoIpDetails,
-        NanoIpRoutePlanner, PlaylistInfo, RotatingIpDetails, RotatingIpRoutePlanner,
-        RotatingNanoIpDetails, RotatingNanoIpRoutePlanner, RoutePlanner, RoutePlannerType, Track,
-        TrackInfo,
+        ExceptionSeverity, FailingAddress, IpBlock, IpBlockType, LoadException, LoadResult,
+        LoadType, LoadedTracks, NanoIpDetails, NanoIpRoutePlanner, PlaylistInfo, RotatingIpDetails,
+        RotatingIpRoutePlanner, RotatingNanoIpDetails, RotatingNanoIpRoutePlanner, RoutePlanner,
+        RoutePlannerType, SearchSource, Track, TrackDecodeError, TrackInfo,
     };
     use serde::{Deserialize, Serialize};
+    use serde_test::Token;
     use static_assertions::{assert_fields, assert_impl_all};
-    use std::fmt::Debug;
+    use std::{error::Error, fmt::Debug, hash::Hash};
 
     assert_fields!(FailingAddress: address, failing_timestamp, failing_time);
     assert_impl_all!(
@@ -312,6 +814,42 @@ mod tests {
         Serialize,
         Sync,
     );
+    assert_impl_all!(TrackDecodeError: Debug, Error, Send, Sync);
+    assert_impl_all!(
+        ExceptionSeverity: Clone,
+        Copy,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_fields!(LoadException: message, severity);
+    assert_impl_all!(
+        LoadException: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_impl_all!(
+        LoadResult: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        From<LoadedTracks>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_impl_all!(SearchSource: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
     assert_impl_all!(
         IpBlockType: Clone,
         Debug,
@@ -343,7 +881,7 @@ mod tests {
         Serialize,
         Sync,
     );
-    assert_fields!(LoadedTracks: load_type, playlist_info, tracks);
+    assert_fields!(LoadedTracks: exception, load_type, playlist_info, tracks);
     assert_impl_all!(
         LoadedTracks: Clone,
         Debug,
@@ -497,4 +1035,300 @@ mod tests {
         Serialize,
         Sync
     );
+
+    #[test]
+    fn test_track_info_decode() {
+        let track = "QAAAjQIAJVJpY2sgQXN0bGV5IC0gTmV2ZXIgR29ubmEgR2l2ZSBZb3UgVXAADlJpY2tBc3RsZXlWRVZPAAAAAAADPCAAC2RRdzR3OVdnWGNRAAEAK2h0dHBzOi8vd3d3LnlvdXR1YmUuY29tL3dhdGNoP3Y9ZFF3NHc5V2dYY1EAB3lvdXR1YmUAAAAAAAAAAA==";
+
+        assert_eq!(
+            TrackInfo {
+                author: Some("RickAstleyVEVO".to_owned()),
+                identifier: "dQw4w9WgXcQ".to_owned(),
+                is_seekable: true,
+                is_stream: false,
+                length: 212_000,
+                position: 0,
+                title: Some("Rick Astley - Never Gonna Give You Up".to_owned()),
+                uri: "https://www.youtube.com/watch?v=dQw4w9WgXcQ".to_owned(),
+            },
+            TrackInfo::decode(track).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_track_info_decode_invalid() {
+        assert!(matches!(
+            TrackInfo::decode("not base64!"),
+            Err(TrackDecodeError::Base64 { .. })
+        ));
+        assert!(matches!(
+            TrackInfo::decode("QAAAjQIAJVJpY2sg"),
+            Err(TrackDecodeError::UnexpectedEnd)
+        ));
+    }
+
+    #[test]
+    fn test_load_result() {
+        let search = r#"{
+            "loadType": "SEARCH_RESULT",
+            "playlistInfo": {},
+            "tracks": [
+                {
+                    "track": "QAAAjQIAJVJpY2sgQXN0bGV5",
+                    "info": {
+                        "author": "RickAstleyVEVO",
+                        "identifier": "dQw4w9WgXcQ",
+                        "isSeekable": true,
+                        "isStream": false,
+                        "length": 212000,
+                        "position": 0,
+                        "title": "Rick Astley - Never Gonna Give You Up",
+                        "uri": "https://www.youtube.com/watch?v=dQw4w9WgXcQ"
+                    }
+                }
+            ]
+        }"#;
+
+        assert_eq!(
+            LoadResult::SearchResult(vec![Track {
+                info: TrackInfo {
+                    author: Some("RickAstleyVEVO".to_owned()),
+                    identifier: "dQw4w9WgXcQ".to_owned(),
+                    is_seekable: true,
+                    is_stream: false,
+                    length: 212_000,
+                    position: 0,
+                    title: Some("Rick Astley - Never Gonna Give You Up".to_owned()),
+                    uri: "https://www.youtube.com/watch?v=dQw4w9WgXcQ".to_owned(),
+                },
+                track: "QAAAjQIAJVJpY2sgQXN0bGV5".to_owned(),
+            }]),
+            serde_json::from_str(search).unwrap(),
+        );
+
+        let failed = r#"{
+            "loadType": "LOAD_FAILED",
+            "playlistInfo": {},
+            "tracks": [],
+            "exception": {
+                "message": "The uploader has not made this video available in your country.",
+                "severity": "COMMON"
+            }
+        }"#;
+
+        assert_eq!(
+            LoadResult::LoadFailed(Some(LoadException {
+                message: Some(
+                    "The uploader has not made this video available in your country.".to_owned()
+                ),
+                severity: ExceptionSeverity::Common,
+            })),
+            serde_json::from_str(failed).unwrap(),
+        );
+
+        let no_matches = r#"{"loadType": "NO_MATCHES", "playlistInfo": {}, "tracks": []}"#;
+
+        assert_eq!(
+            LoadResult::NoMatches,
+            serde_json::from_str(no_matches).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_load_type() {
+        let values = [
+            (LoadType::LoadFailed, "LOAD_FAILED", "error"),
+            (LoadType::NoMatches, "NO_MATCHES", "empty"),
+            (LoadType::PlaylistLoaded, "PLAYLIST_LOADED", "playlist"),
+            (LoadType::SearchResult, "SEARCH_RESULT", "search"),
+            (LoadType::TrackLoaded, "TRACK_LOADED", "track"),
+        ];
+
+        for (kind, v3, v4) in values.iter() {
+            serde_test::assert_tokens(
+                kind,
+                &[Token::UnitVariant {
+                    name: "LoadType",
+                    variant: *v3,
+                }],
+            );
+            serde_test::assert_de_tokens(
+                kind,
+                &[Token::UnitVariant {
+                    name: "LoadType",
+                    variant: *v4,
+                }],
+            );
+        }
+    }
+
+    #[test]
+    fn test_load_result_playlist() {
+        let tracks = vec![Track {
+            info: TrackInfo {
+                author: Some("RickAstleyVEVO".to_owned()),
+                identifier: "dQw4w9WgXcQ".to_owned(),
+                is_seekable: true,
+                is_stream: false,
+                length: 212_000,
+                position: 0,
+                title: Some("Rick Astley - Never Gonna Give You Up".to_owned()),
+                uri: "https://www.youtube.com/watch?v=dQw4w9WgXcQ".to_owned(),
+            },
+            track: "QAAAjQIAJVJpY2sgQXN0bGV5".to_owned(),
+        }];
+        let expected = LoadResult::PlaylistLoaded {
+            info: PlaylistInfo {
+                name: Some("Rick".to_owned()),
+                selected_track: None,
+            },
+            tracks,
+        };
+        let info = r#"{
+            "author": "RickAstleyVEVO",
+            "identifier": "dQw4w9WgXcQ",
+            "isSeekable": true,
+            "isStream": false,
+            "length": 212000,
+            "position": 0,
+            "title": "Rick Astley - Never Gonna Give You Up",
+            "uri": "https://www.youtube.com/watch?v=dQw4w9WgXcQ"
+        }"#;
+
+        let v3 = format!(
+            r#"{{
+                "loadType": "PLAYLIST_LOADED",
+                "playlistInfo": {{ "name": "Rick", "selectedTrack": -1 }},
+                "tracks": [{{ "track": "QAAAjQIAJVJpY2sgQXN0bGV5", "info": {} }}]
+            }}"#,
+            info,
+        );
+        assert_eq!(expected, serde_json::from_str(&v3).unwrap());
+
+        let v4 = format!(
+            r#"{{
+                "loadType": "playlist",
+                "data": {{
+                    "info": {{ "name": "Rick", "selectedTrack": -1 }},
+                    "pluginInfo": {{}},
+                    "tracks": [{{ "encoded": "QAAAjQIAJVJpY2sgQXN0bGV5", "info": {} }}]
+                }}
+            }}"#,
+            info,
+        );
+        assert_eq!(expected, serde_json::from_str(&v4).unwrap());
+    }
+
+    #[test]
+    fn test_load_result_v4() {
+        let track = r#"{
+            "loadType": "track",
+            "data": {
+                "encoded": "QAAAjQIAJVJpY2sgQXN0bGV5",
+                "info": {
+                    "identifier": "dQw4w9WgXcQ",
+                    "isSeekable": true,
+                    "author": "RickAstleyVEVO",
+                    "length": 212000,
+                    "isStream": false,
+                    "position": 0,
+                    "title": "Rick Astley - Never Gonna Give You Up",
+                    "uri": "https://www.youtube.com/watch?v=dQw4w9WgXcQ",
+                    "artworkUrl": null,
+                    "isrc": null,
+                    "sourceName": "youtube"
+                },
+                "pluginInfo": {}
+            }
+        }"#;
+
+        assert_eq!(
+            LoadResult::TrackLoaded(Track {
+                info: TrackInfo {
+                    author: Some("RickAstleyVEVO".to_owned()),
+                    identifier: "dQw4w9WgXcQ".to_owned(),
+                    is_seekable: true,
+                    is_stream: false,
+                    length: 212_000,
+                    position: 0,
+                    title: Some("Rick Astley - Never Gonna Give You Up".to_owned()),
+                    uri: "https://www.youtube.com/watch?v=dQw4w9WgXcQ".to_owned(),
+                },
+                track: "QAAAjQIAJVJpY2sgQXN0bGV5".to_owned(),
+            }),
+            serde_json::from_str(track).unwrap(),
+        );
+
+        let error = r#"{
+            "loadType": "error",
+            "data": {
+                "message": "Something went wrong",
+                "severity": "fault",
+                "cause": "..."
+            }
+        }"#;
+
+        assert_eq!(
+            LoadResult::LoadFailed(Some(LoadException {
+                message: Some("Something went wrong".to_owned()),
+                severity: ExceptionSeverity::Fault,
+            })),
+            serde_json::from_str(error).unwrap(),
+        );
+
+        let empty = r#"{"loadType": "empty", "data": {}}"#;
+
+        assert_eq!(LoadResult::NoMatches, serde_json::from_str(empty).unwrap());
+
+        let search = r#"{"loadType": "search", "data": []}"#;
+
+        assert_eq!(
+            LoadResult::SearchResult(Vec::new()),
+            serde_json::from_str(search).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_load_track_v4() {
+        let request = super::load_track_v4(
+            ([127, 0, 0, 1], 2333).into(),
+            "never gonna",
+            "youshallnotpass",
+        )
+        .unwrap();
+
+        assert_eq!(
+            "http://127.0.0.1:2333/v4/loadtracks?identifier=never%20gonna",
+            request.uri().to_string(),
+        );
+    }
+
+    #[test]
+    fn test_search_tracks() {
+        let request = super::search_tracks(
+            ([127, 0, 0, 1], 2333).into(),
+            SearchSource::SoundCloud,
+            "never gonna",
+            "youshallnotpass",
+        )
+        .unwrap();
+
+        assert_eq!(
+            "http://127.0.0.1:2333/loadtracks?identifier=scsearch%3Anever%20gonna",
+            request.uri().to_string(),
+        );
+
+        let request = super::search_tracks_v4(
+            ([127, 0, 0, 1], 2333).into(),
+            SearchSource::YouTubeMusic,
+            "never gonna",
+            "youshallnotpass",
+        )
+        .unwrap();
+
+        assert_eq!(
+            "http://127.0.0.1:2333/v4/loadtracks?identifier=ytmsearch%3Anever%20gonna",
+            request.uri().to_string(),
+        );
+    }
 }
@@ -18,7 +18,14 @@
 //! ### `http-support`
 //!
 //! The `http-support` feature adds support for the `http` module to return
-//! request types from the [`http`] crate. This is enabled by default.
+//! request types from the [`http`] crate and to decode track information. This
+//! is enabled by default.
+//!
+//! ### `v4-support`
+//!
+//! The `v4-support` feature adds support for version 4 of the Lavalink
+//! protocol via [`ProtocolVersion::V4`], which controls players over REST
+//! using [`hyper`]. This is enabled by default.
 //!
 //! ### TLS
 //!
@@ -98,6 +105,8 @@
 //! [Lavalink]: https://github.com/Frederikam/Lavalink
 //! [`async-tungstenite`]: https://crates.io/crates/async-tungstenite
 //! [`http`]: https://crates.io/crates/http
+//! [`hyper`]: https://crates.io/crates/hyper
+//! [`ProtocolVersion::V4`]: node::ProtocolVersion
 //! [`rustls`]: https://crates.io/crates/rustls
 //! [client]: Lavalink
 //! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
@@ -126,7 +135,11 @@ pub mod model;
 pub mod node;
 pub mod player;
 
-#[cfg(feature = "http")]
+#[cfg(feature = "http-support")]
 pub mod http;
 
-pub use self::{client::Lavalink, node::Node, player::PlayerManager};
+pub use self::{
+    client::{Lavalink, LavalinkBuilder},
+    node::Node,
+    player::PlayerManager,
+};
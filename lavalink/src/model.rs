@@ -14,12 +14,18 @@ pub enum Opcode {
     Equalizer,
     /// Meta information about a track starting or ending.
     Event,
+    /// Set the audio filters of a player.
+    Filters,
     /// Pause a player.
     Pause,
     /// Play a track.
     Play,
     /// An update about a player's current track.
     PlayerUpdate,
+    /// The connection to a node is ready.
+    ///
+    /// Only sent by nodes using version 4 of the protocol.
+    Ready,
     /// Seek a player's active track to a new position.
     Seek,
     /// Updated statistics about a node.
@@ -48,6 +54,8 @@ pub mod outgoing {
         Destroy(Destroy),
         /// Equalize a player.
         Equalizer(Equalizer),
+        /// Set the audio filters of a player.
+        Filters(Filters),
         /// Pause or unpause a player.
         Pause(Pause),
         /// Play a track.
@@ -74,6 +82,12 @@ pub mod outgoing {
         }
     }
 
+    impl From<Filters> for OutgoingEvent {
+        fn from(event: Filters) -> OutgoingEvent {
+            Self::Filters(event)
+        }
+    }
+
     impl From<Pause> for OutgoingEvent {
         fn from(event: Pause) -> OutgoingEvent {
             Self::Pause(event)
@@ -194,6 +208,200 @@ pub mod outgoing {
         }
     }
 
+    /// Set the audio filters of a player.
+    ///
+    /// Filters that aren't set are disabled, so sending filters replaces all
+    /// of the filters that were previously set.
+    ///
+    /// # Examples
+    ///
+    /// Speed up and raise the pitch of a track, commonly known as "nightcore":
+    ///
+    /// ```
+    /// use twilight_lavalink::model::{Filters, Timescale};
+    /// use twilight_model::id::GuildId;
+    ///
+    /// let mut filters = Filters::new(GuildId::new(1));
+    /// filters.timescale = Some(Timescale::new(1.2, 1.2, 1.0));
+    /// ```
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Filters {
+        /// The bands of the equalizer to adjust.
+        ///
+        /// There are 15 bands, from 0 to 14, which each have a gain from -0.25
+        /// to 1.0.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub equalizer: Option<Vec<EqualizerBand>>,
+        /// The guild ID of the player.
+        pub guild_id: GuildId,
+        /// Eliminate part of a band, usually targeting vocals.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub karaoke: Option<Karaoke>,
+        /// Suppress higher frequencies, letting lower frequencies pass through.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub low_pass: Option<LowPass>,
+        /// The opcode of the event.
+        pub op: Opcode,
+        /// Rotate the audio around the stereo channels.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub rotation: Option<Rotation>,
+        /// Change the speed, pitch, and rate of the audio.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub timescale: Option<Timescale>,
+        /// Oscillate the volume of the audio.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub tremolo: Option<Tremolo>,
+        /// Oscillate the pitch of the audio.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub vibrato: Option<Vibrato>,
+        /// Volume of the player as a multiplier, from 0.0 to 5.0.
+        ///
+        /// Unlike the [`Volume`] event, this is applied as a filter.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub volume: Option<f64>,
+    }
+
+    impl Filters {
+        /// Create a new filters event with every filter disabled.
+        pub fn new(guild_id: GuildId) -> Self {
+            Self::from(guild_id)
+        }
+    }
+
+    impl From<GuildId> for Filters {
+        fn from(guild_id: GuildId) -> Self {
+            Self {
+                equalizer: None,
+                guild_id,
+                karaoke: None,
+                low_pass: None,
+                op: Opcode::Filters,
+                rotation: None,
+                timescale: None,
+                tremolo: None,
+                vibrato: None,
+                volume: None,
+            }
+        }
+    }
+
+    /// Eliminate part of a band, usually targeting vocals.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Karaoke {
+        /// The band to filter, in hertz.
+        pub filter_band: f64,
+        /// The width of the filter.
+        pub filter_width: f64,
+        /// The level of the effect, from 0.0 to 1.0.
+        pub level: f64,
+        /// The level of the mono part of the effect, from 0.0 to 1.0.
+        pub mono_level: f64,
+    }
+
+    impl Karaoke {
+        /// Create a new karaoke filter.
+        pub fn new(level: f64, mono_level: f64, filter_band: f64, filter_width: f64) -> Self {
+            Self {
+                filter_band,
+                filter_width,
+                level,
+                mono_level,
+            }
+        }
+    }
+
+    /// Suppress higher frequencies, letting lower frequencies pass through.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct LowPass {
+        /// The amount of smoothing. Values of 1.0 or less disable the filter.
+        pub smoothing: f64,
+    }
+
+    impl LowPass {
+        /// Create a new low pass filter.
+        pub fn new(smoothing: f64) -> Self {
+            Self { smoothing }
+        }
+    }
+
+    /// Rotate the audio around the stereo channels.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Rotation {
+        /// The frequency of the rotation, in hertz.
+        pub rotation_hz: f64,
+    }
+
+    impl Rotation {
+        /// Create a new rotation filter.
+        pub fn new(rotation_hz: f64) -> Self {
+            Self { rotation_hz }
+        }
+    }
+
+    /// Change the speed, pitch, and rate of the audio.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Timescale {
+        /// The pitch as a multiplier.
+        pub pitch: f64,
+        /// The rate as a multiplier, changing both the speed and pitch.
+        pub rate: f64,
+        /// The speed as a multiplier.
+        pub speed: f64,
+    }
+
+    impl Timescale {
+        /// Create a new timescale filter.
+        pub fn new(speed: f64, pitch: f64, rate: f64) -> Self {
+            Self { pitch, rate, speed }
+        }
+    }
+
+    /// Oscillate the volume of the audio.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Tremolo {
+        /// The depth of the oscillation, from 0.0 to 1.0.
+        pub depth: f64,
+        /// The frequency of the oscillation, in hertz.
+        pub frequency: f64,
+    }
+
+    impl Tremolo {
+        /// Create a new tremolo filter.
+        pub fn new(frequency: f64, depth: f64) -> Self {
+            Self { depth, frequency }
+        }
+    }
+
+    /// Oscillate the pitch of the audio.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Vibrato {
+        /// The depth of the oscillation, from 0.0 to 1.0.
+        pub depth: f64,
+        /// The frequency of the oscillation, in hertz, up to 14.0.
+        pub frequency: f64,
+    }
+
+    impl Vibrato {
+        /// Create a new vibrato filter.
+        pub fn new(frequency: f64, depth: f64) -> Self {
+            Self { depth, frequency }
+        }
+    }
+
     /// Pause or unpause a player.
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     #[non_exhaustive]
@@ -462,9 +670,39 @@ pub mod incoming {
     //! Events that Lavalink sends to clients.
 
     use super::Opcode;
-    use serde::{Deserialize, Serialize};
+    use serde::{Deserialize, Deserializer, Serialize};
     use twilight_model::id::GuildId;
 
+    /// Deserialize a base64 track, which version 4 of the protocol nests in
+    /// an object alongside its information.
+    fn deserialize_track<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum EncodedTrack {
+            Object { encoded: String },
+            String(String),
+        }
+
+        Ok(match EncodedTrack::deserialize(deserializer)? {
+            EncodedTrack::Object { encoded } | EncodedTrack::String(encoded) => encoded,
+        })
+    }
+
+    /// Deserialize the error of a track exception, which version 4 of the
+    /// protocol sends as an object with the message and its severity.
+    fn deserialize_error<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Exception {
+            Object { message: String },
+            String(String),
+        }
+
+        Ok(match Exception::deserialize(deserializer)? {
+            Exception::Object { message } | Exception::String(message) => message,
+        })
+    }
+
     /// An incoming event from a Lavalink node.
     #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
     #[non_exhaustive]
@@ -472,20 +710,50 @@ pub mod incoming {
     pub enum IncomingEvent {
         /// An update about the information of a player.
         PlayerUpdate(PlayerUpdate),
+        /// The connection to a node is ready.
+        Ready(Ready),
         /// New statistics about a node and its host.
         Stats(Stats),
         /// A track ended.
         TrackEnd(TrackEnd),
+        /// A track threw an exception.
+        TrackException(TrackException),
+        /// A track got stuck while playing.
+        TrackStuck(TrackStuck),
         /// A track started.
+        ///
+        /// This must be last because its fields are a subset of the other
+        /// track events' fields.
         TrackStart(TrackStart),
     }
 
+    impl IncomingEvent {
+        /// The ID of the guild of the player that the event is about, if the
+        /// event is about a player.
+        pub fn guild_id(&self) -> Option<GuildId> {
+            match self {
+                Self::PlayerUpdate(event) => Some(event.guild_id),
+                Self::TrackEnd(event) => Some(event.guild_id),
+                Self::TrackException(event) => Some(event.guild_id),
+                Self::TrackStart(event) => Some(event.guild_id),
+                Self::TrackStuck(event) => Some(event.guild_id),
+                Self::Ready(_) | Self::Stats(_) => None,
+            }
+        }
+    }
+
     impl From<PlayerUpdate> for IncomingEvent {
         fn from(event: PlayerUpdate) -> IncomingEvent {
             Self::PlayerUpdate(event)
         }
     }
 
+    impl From<Ready> for IncomingEvent {
+        fn from(event: Ready) -> IncomingEvent {
+            Self::Ready(event)
+        }
+    }
+
     impl From<Stats> for IncomingEvent {
         fn from(event: Stats) -> IncomingEvent {
             Self::Stats(event)
@@ -505,6 +773,21 @@ pub mod incoming {
         pub state: PlayerUpdateState,
     }
 
+    /// The connection to a node is ready.
+    ///
+    /// Only sent by nodes using version 4 of the protocol.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Ready {
+        /// The opcode of the event.
+        pub op: Opcode,
+        /// Whether a previous session was resumed.
+        pub resumed: bool,
+        /// The ID of the session, used to control players.
+        pub session_id: String,
+    }
+
     /// New statistics about a node and its host.
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     #[non_exhaustive]
@@ -587,9 +870,15 @@ pub mod incoming {
         /// A track for a player ended.
         #[serde(rename = "TrackEndEvent")]
         End,
+        /// A track for a player threw an exception.
+        #[serde(rename = "TrackExceptionEvent")]
+        Exception,
         /// A track for a player started.
         #[serde(rename = "TrackStartEvent")]
         Start,
+        /// A track for a player got stuck.
+        #[serde(rename = "TrackStuckEvent")]
+        Stuck,
     }
 
     /// A track ended.
@@ -609,6 +898,46 @@ pub mod incoming {
         /// For example, this may be `"FINISHED"`.
         pub reason: String,
         /// The base64 track that was affected.
+        #[serde(deserialize_with = "deserialize_track")]
+        pub track: String,
+    }
+
+    /// A track threw an exception.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct TrackException {
+        /// The message of the exception.
+        #[serde(alias = "exception", deserialize_with = "deserialize_error")]
+        pub error: String,
+        /// The guild ID of the player.
+        pub guild_id: GuildId,
+        /// The type of track event.
+        #[serde(rename = "type")]
+        pub kind: TrackEventType,
+        /// The opcode of the event.
+        pub op: Opcode,
+        /// The base64 track that was affected.
+        #[serde(deserialize_with = "deserialize_track")]
+        pub track: String,
+    }
+
+    /// A track got stuck while playing.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct TrackStuck {
+        /// The guild ID of the player.
+        pub guild_id: GuildId,
+        /// The type of track event.
+        #[serde(rename = "type")]
+        pub kind: TrackEventType,
+        /// The opcode of the event.
+        pub op: Opcode,
+        /// The threshold in milliseconds that was exceeded.
+        pub threshold_ms: u64,
+        /// The base64 track that was affected.
+        #[serde(deserialize_with = "deserialize_track")]
         pub track: String,
     }
 
@@ -625,18 +954,20 @@ pub mod incoming {
         /// The opcode of the event.
         pub op: Opcode,
         /// The base64 track that was affected.
+        #[serde(deserialize_with = "deserialize_track")]
         pub track: String,
     }
 }
 
 pub use self::{
     incoming::{
-        IncomingEvent, PlayerUpdate, PlayerUpdateState, Stats, StatsCpu, StatsFrames, StatsMemory,
-        TrackEnd, TrackEventType, TrackStart,
+        IncomingEvent, PlayerUpdate, PlayerUpdateState, Ready, Stats, StatsCpu, StatsFrames,
+        StatsMemory, TrackEnd, TrackEventType, TrackException, TrackStart, TrackStuck,
     },
     outgoing::{
-        Destroy, Equalizer, EqualizerBand, OutgoingEvent, Pause, Play, Seek, SlimVoiceServerUpdate,
-        Stop, VoiceUpdate, Volume,
+        Destroy, Equalizer, EqualizerBand, Filters, Karaoke, LowPass, OutgoingEvent, Pause, Play,
+        Rotation, Seek, SlimVoiceServerUpdate, Stop, Timescale, Tremolo, Vibrato, VoiceUpdate,
+        Volume,
     },
 };
 
@@ -644,12 +975,13 @@ pub use self::{
 mod tests {
     use super::{
         incoming::{
-            IncomingEvent, PlayerUpdate, PlayerUpdateState, Stats, StatsCpu, StatsFrames,
-            StatsMemory, TrackEnd, TrackEventType, TrackStart,
+            IncomingEvent, PlayerUpdate, PlayerUpdateState, Ready, Stats, StatsCpu, StatsFrames,
+            StatsMemory, TrackEnd, TrackEventType, TrackException, TrackStart, TrackStuck,
         },
         outgoing::{
-            Destroy, Equalizer, EqualizerBand, OutgoingEvent, Pause, Play, Seek,
-            SlimVoiceServerUpdate, Stop, VoiceUpdate, Volume,
+            Destroy, Equalizer, EqualizerBand, Filters, Karaoke, LowPass, OutgoingEvent, Pause,
+            Play, Rotation, Seek, SlimVoiceServerUpdate, Stop, Timescale, Tremolo, Vibrato,
+            VoiceUpdate, Volume,
         },
         Opcode,
     };
@@ -693,11 +1025,34 @@ mod tests {
         Serialize,
         Sync,
     );
+    assert_fields!(
+        Filters: equalizer,
+        guild_id,
+        karaoke,
+        low_pass,
+        op,
+        rotation,
+        timescale,
+        tremolo,
+        vibrato,
+        volume
+    );
+    assert_impl_all!(
+        Filters: Clone,
+        Debug,
+        Deserialize<'static>,
+        From<GuildId>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
     assert_impl_all!(
         IncomingEvent: Clone,
         Debug,
         Deserialize<'static>,
         From<PlayerUpdate>,
+        From<Ready>,
         From<Stats>,
         PartialEq,
         Send,
@@ -710,6 +1065,7 @@ mod tests {
         Deserialize<'static>,
         From<Destroy>,
         From<Equalizer>,
+        From<Filters>,
         From<Pause>,
         From<Play>,
         From<Seek>,
@@ -721,6 +1077,26 @@ mod tests {
         Serialize,
         Sync,
     );
+    assert_fields!(Karaoke: filter_band, filter_width, level, mono_level);
+    assert_impl_all!(
+        Karaoke: Clone,
+        Debug,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_fields!(LowPass: smoothing);
+    assert_impl_all!(
+        LowPass: Clone,
+        Debug,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
     assert_fields!(Pause: guild_id, op, pause);
     assert_impl_all!(
         Pause: Clone,
@@ -777,6 +1153,27 @@ mod tests {
         Serialize,
         Sync,
     );
+    assert_fields!(Ready: op, resumed, session_id);
+    assert_impl_all!(
+        Ready: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_fields!(Rotation: rotation_hz);
+    assert_impl_all!(
+        Rotation: Clone,
+        Debug,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
     assert_fields!(Seek: guild_id, op, position);
     assert_impl_all!(
         Seek: Clone,
@@ -860,6 +1257,16 @@ mod tests {
         Serialize,
         Sync,
     );
+    assert_fields!(Timescale: pitch, rate, speed);
+    assert_impl_all!(
+        Timescale: Clone,
+        Debug,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
     assert_fields!(TrackEnd: guild_id, kind, op, reason, track);
     assert_impl_all!(
         TrackEnd: Clone,
@@ -870,6 +1277,16 @@ mod tests {
         Serialize,
         Sync,
     );
+    assert_fields!(TrackException: error, guild_id, kind, op, track);
+    assert_impl_all!(
+        TrackException: Clone,
+        Debug,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
     assert_impl_all!(
         TrackEventType: Clone,
         Copy,
@@ -890,6 +1307,36 @@ mod tests {
         Serialize,
         Sync,
     );
+    assert_fields!(TrackStuck: guild_id, kind, op, threshold_ms, track);
+    assert_impl_all!(
+        TrackStuck: Clone,
+        Debug,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_fields!(Tremolo: depth, frequency);
+    assert_impl_all!(
+        Tremolo: Clone,
+        Debug,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_fields!(Vibrato: depth, frequency);
+    assert_impl_all!(
+        Vibrato: Clone,
+        Debug,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
     assert_fields!(VoiceUpdate: event, guild_id, op, session_id);
     assert_impl_all!(
         VoiceUpdate: Clone,
@@ -983,4 +1430,133 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_ready() {
+        let json = r#"{"op":"ready","resumed":false,"sessionId":"la3kfsdf5eafe848"}"#;
+
+        assert_eq!(
+            IncomingEvent::Ready(Ready {
+                op: Opcode::Ready,
+                resumed: false,
+                session_id: "la3kfsdf5eafe848".to_owned(),
+            }),
+            serde_json::from_str::<IncomingEvent>(json).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_track_start_v4() {
+        let json = r#"{
+            "op": "event",
+            "type": "TrackStartEvent",
+            "guildId": "1",
+            "track": {
+                "encoded": "QAAAjQIAJVJpY2sgQXN0bGV5",
+                "info": {
+                    "identifier": "dQw4w9WgXcQ",
+                    "title": "Never Gonna Give You Up"
+                }
+            }
+        }"#;
+
+        assert_eq!(
+            IncomingEvent::TrackStart(TrackStart {
+                guild_id: GuildId::new(1),
+                kind: TrackEventType::Start,
+                op: Opcode::Event,
+                track: "QAAAjQIAJVJpY2sgQXN0bGV5".to_owned(),
+            }),
+            serde_json::from_str::<IncomingEvent>(json).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_track_exception_and_stuck() {
+        let exception = r#"{
+            "op": "event",
+            "type": "TrackExceptionEvent",
+            "guildId": "1",
+            "track": "QAAAjQIAJVJpY2sgQXN0bGV5",
+            "exception": {
+                "message": "Something broke",
+                "severity": "COMMON",
+                "cause": "Something"
+            }
+        }"#;
+
+        assert_eq!(
+            IncomingEvent::TrackException(TrackException {
+                error: "Something broke".to_owned(),
+                guild_id: GuildId::new(1),
+                kind: TrackEventType::Exception,
+                op: Opcode::Event,
+                track: "QAAAjQIAJVJpY2sgQXN0bGV5".to_owned(),
+            }),
+            serde_json::from_str::<IncomingEvent>(exception).unwrap(),
+        );
+
+        let stuck = r#"{
+            "op": "event",
+            "type": "TrackStuckEvent",
+            "guildId": "1",
+            "track": "QAAAjQIAJVJpY2sgQXN0bGV5",
+            "thresholdMs": 10000
+        }"#;
+        let event = serde_json::from_str::<IncomingEvent>(stuck).unwrap();
+
+        assert_eq!(Some(GuildId::new(1)), event.guild_id());
+        assert_eq!(
+            IncomingEvent::TrackStuck(TrackStuck {
+                guild_id: GuildId::new(1),
+                kind: TrackEventType::Stuck,
+                op: Opcode::Event,
+                threshold_ms: 10_000,
+                track: "QAAAjQIAJVJpY2sgQXN0bGV5".to_owned(),
+            }),
+            event,
+        );
+    }
+
+    #[test]
+    fn test_filters() {
+        let mut filters = Filters::new(GuildId::new(1));
+        filters.timescale = Some(Timescale::new(1.2, 1.1, 1.0));
+        filters.volume = Some(0.5);
+
+        serde_test::assert_ser_tokens(
+            &filters,
+            &[
+                Token::Struct {
+                    name: "Filters",
+                    len: 4,
+                },
+                Token::Str("guildId"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("op"),
+                Token::UnitVariant {
+                    name: "Opcode",
+                    variant: "filters",
+                },
+                Token::Str("timescale"),
+                Token::Some,
+                Token::Struct {
+                    name: "Timescale",
+                    len: 3,
+                },
+                Token::Str("pitch"),
+                Token::F64(1.1),
+                Token::Str("rate"),
+                Token::F64(1.0),
+                Token::Str("speed"),
+                Token::F64(1.2),
+                Token::StructEnd,
+                Token::Str("volume"),
+                Token::Some,
+                Token::F64(0.5),
+                Token::StructEnd,
+            ],
+        );
+    }
 }
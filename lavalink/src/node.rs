@@ -18,7 +18,10 @@
 //! [`Lavalink`]: crate::client::Lavalink
 
 use crate::{
-    model::{IncomingEvent, Opcode, OutgoingEvent, PlayerUpdate, Stats, StatsCpu, StatsMemory},
+    model::{
+        IncomingEvent, Opcode, OutgoingEvent, PlayerUpdate, Ready, Stats, StatsCpu, StatsMemory,
+        TrackEnd,
+    },
     player::PlayerManager,
 };
 use async_tungstenite::{
@@ -33,16 +36,32 @@ use futures_util::{
     sink::SinkExt,
     stream::StreamExt,
 };
-use http::{header::HeaderName, Error as HttpError, Request, Response, StatusCode};
+use http::{
+    header::{HeaderMap, HeaderName},
+    Error as HttpError, Request, Response, StatusCode,
+};
+#[cfg(feature = "v4-support")]
+use http::{
+    header::{AUTHORIZATION, CONTENT_TYPE},
+    Method,
+};
+#[cfg(feature = "v4-support")]
+use hyper::{client::Client as HyperClient, Body};
 use serde_json::Error as JsonError;
+#[cfg(feature = "v4-support")]
+use serde_json::{json, Value};
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
+    future::Future,
     net::SocketAddr,
-    sync::Arc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
     time::Duration,
 };
-use tokio::time as tokio_time;
+use tokio::{sync::Notify, time as tokio_time};
 use twilight_model::id::UserId;
 
 /// An error occurred while either initializing a connection or while running
@@ -106,6 +125,29 @@ impl Error for NodeError {
     }
 }
 
+/// Version of the Lavalink protocol to use with a node.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ProtocolVersion {
+    /// Version 3, where players are controlled over the websocket.
+    ///
+    /// This is the default so that existing nodes keep working.
+    V3,
+    /// Version 4, where players are controlled over REST using the session ID
+    /// received when connecting.
+    ///
+    /// This is the version used by current Lavalink servers. Requires the
+    /// `v4-support` feature.
+    #[cfg(feature = "v4-support")]
+    V4,
+}
+
+impl Default for ProtocolVersion {
+    fn default() -> Self {
+        Self::V3
+    }
+}
+
 /// The configuration that a [`Node`] uses to connect to a Lavalink server.
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
@@ -122,12 +164,19 @@ pub struct NodeConfig {
     pub shard_count: u64,
     /// The user ID of the bot.
     pub user_id: UserId,
+    /// The version of the Lavalink protocol to use.
+    pub version: ProtocolVersion,
 }
 
 /// Configuration for a session which can be resumed.
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub struct Resume {
+    /// The key to resume the session with.
+    ///
+    /// Only used by [`ProtocolVersion::V3`]; version 4 resumes using the
+    /// session ID sent by the node. The default is the address of the node.
+    pub key: Option<String>,
     /// The number of seconds that the Lavalink server will allow the session to
     /// be resumed for after a disconnect.
     ///
@@ -139,13 +188,25 @@ impl Resume {
     /// Configure resume capability, providing the number of seconds that the
     /// Lavalink server should queue events for when the connection is resumed.
     pub fn new(seconds: u64) -> Self {
-        Self { timeout: seconds }
+        Self {
+            key: None,
+            timeout: seconds,
+        }
+    }
+
+    /// Set the key to resume the session with.
+    ///
+    /// Defaults to the address of the node.
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.key.replace(key.into());
+
+        self
     }
 }
 
 impl Default for Resume {
     fn default() -> Self {
-        Self { timeout: 60 }
+        Self::new(60)
     }
 }
 
@@ -186,15 +247,60 @@ impl NodeConfig {
             resume,
             shard_count,
             user_id,
+            version: ProtocolVersion::default(),
+        }
+    }
+
+    /// Set the version of the Lavalink protocol to use.
+    ///
+    /// Defaults to [`ProtocolVersion::V3`].
+    pub fn version(mut self, version: ProtocolVersion) -> Self {
+        self.version = version;
+
+        self
+    }
+}
+
+/// Whether a node's connection has closed for good, such as after failing to
+/// reconnect.
+#[derive(Debug, Default)]
+struct Closed {
+    closed: AtomicBool,
+    notify: Notify,
+}
+
+impl Closed {
+    fn close(&self) {
+        self.closed.store(true, Ordering::Release);
+        self.notify.notify_waiters();
+    }
+
+    fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Acquire)
+    }
+
+    async fn wait(&self) {
+        loop {
+            // Create the future before checking so that a close in between
+            // isn't missed.
+            let notified = self.notify.notified();
+
+            if self.is_closed() {
+                return;
+            }
+
+            notified.await;
         }
     }
 }
 
 #[derive(Debug)]
 struct NodeRef {
+    closed: Arc<Closed>,
     config: NodeConfig,
     lavalink_tx: UnboundedSender<OutgoingEvent>,
     players: PlayerManager,
+    session_id: Arc<Mutex<Option<String>>>,
     stats: BiLock<Stats>,
 }
 
@@ -239,18 +345,36 @@ impl Node {
             op: Opcode::Stats,
             uptime: 0,
         });
+        let session_id = Arc::new(Mutex::new(None));
         tracing::debug!("starting connection to {}", config.address);
-        let (conn_loop, lavalink_tx, lavalink_rx) =
-            Connection::connect(config.clone(), players.clone(), bilock_right).await?;
+        let (conn_loop, lavalink_tx, lavalink_rx) = Connection::connect(
+            config.clone(),
+            players.clone(),
+            Arc::clone(&session_id),
+            bilock_right,
+        )
+        .await?;
         tracing::debug!("started connection to {}", config.address);
 
-        tokio::spawn(conn_loop.run());
+        let closed = Arc::new(Closed::default());
+        let address = config.address;
+        let conn_closed = Arc::clone(&closed);
+
+        tokio::spawn(async move {
+            if let Err(source) = conn_loop.run().await {
+                tracing::warn!("connection to node {} failed: {:?}", address, source);
+            }
+
+            conn_closed.close();
+        });
 
         Ok((
             Self(Arc::new(NodeRef {
+                closed,
                 config,
                 lavalink_tx,
                 players,
+                session_id,
                 stats: bilock_left,
             })),
             lavalink_rx,
@@ -262,6 +386,25 @@ impl Node {
         &self.0.config
     }
 
+    /// Return whether the node's connection has closed for good.
+    ///
+    /// Nodes close when they fail to reconnect after a disconnect. Closed
+    /// nodes can no longer be used to send events.
+    pub fn is_closed(&self) -> bool {
+        self.0.closed.is_closed()
+    }
+
+    /// Wait for the node's connection to close for good.
+    ///
+    /// The returned future doesn't borrow the node, so it can be spawned
+    /// without keeping the node alive. It resolves immediately if the node has
+    /// already closed.
+    pub fn closed(&self) -> impl Future<Output = ()> + Send + 'static {
+        let closed = Arc::clone(&self.0.closed);
+
+        async move { closed.wait().await }
+    }
+
     /// Retrieve an immutable reference to the player manager used by the node.
     pub async fn players(&self) -> &PlayerManager {
         &self.0.players
@@ -283,7 +426,24 @@ impl Node {
         self.0.lavalink_tx.clone()
     }
 
+    /// Retrieve a copy of the ID of the node's current session.
+    ///
+    /// Only nodes using version 4 of the protocol have sessions, and only
+    /// once the node has sent its ready event.
+    pub fn session_id(&self) -> Option<String> {
+        self.0
+            .session_id
+            .lock()
+            .expect("session id poisoned")
+            .clone()
+    }
+
     /// Retrieve a copy of the node's stats.
+    ///
+    /// Nodes send their statistics periodically, about once a minute, and
+    /// these are the latest statistics received. They include the number of
+    /// players, CPU and memory usage, and audio frame statistics. All values
+    /// are zero until the first statistics are received.
     pub async fn stats(&self) -> Stats {
         (*self.0.stats.lock().await).clone()
     }
@@ -292,15 +452,23 @@ impl Node {
     ///
     /// This score can be used to calculate how loaded the server is. A higher
     /// number means it is more heavily loaded.
+    ///
+    /// The score is made up of the number of playing players, the CPU load,
+    /// and the number of deficit and nulled audio frames, in the same way as
+    /// other Lavalink clients. Closed nodes have the highest possible score.
     pub async fn penalty(&self) -> i32 {
+        if self.is_closed() {
+            return i32::MAX;
+        }
+
         let stats = self.0.stats.lock().await;
         let cpu = 1.05f64.powf(100f64 * stats.cpu.system_load) * 10f64 - 10f64;
 
         let (deficit_frame, null_frame) = (
             1.03f64
                 .powf(500f64 * (stats.frames.as_ref().map_or(0, |f| f.deficit) as f64 / 3000f64))
-                * 300f64
-                - 300f64,
+                * 600f64
+                - 600f64,
             (1.03f64
                 .powf(500f64 * (stats.frames.as_ref().map_or(0, |f| f.nulled) as f64 / 3000f64))
                 * 300f64
@@ -317,7 +485,19 @@ struct Connection {
     connection: WebSocketStream<ConnectStream>,
     node_from: UnboundedReceiver<OutgoingEvent>,
     node_to: UnboundedSender<IncomingEvent>,
+    /// Events that couldn't be sent yet because the node hasn't sent its
+    /// ready event with a session ID.
+    #[cfg(feature = "v4-support")]
+    pending: Vec<OutgoingEvent>,
     players: PlayerManager,
+    /// Events that couldn't be sent because the connection was lost, which
+    /// are sent again once reconnected.
+    queued: Vec<OutgoingEvent>,
+    /// Queue of requests to the node's REST API, sent in order by a separate
+    /// task so that slow requests don't block the connection.
+    #[cfg(feature = "v4-support")]
+    rest: UnboundedSender<RestRequest>,
+    session_id: Arc<Mutex<Option<String>>>,
     stats: BiLock<Stats>,
 }
 
@@ -325,6 +505,7 @@ impl Connection {
     async fn connect(
         config: NodeConfig,
         players: PlayerManager,
+        session_id: Arc<Mutex<Option<String>>>,
         stats: BiLock<Stats>,
     ) -> Result<
         (
@@ -334,18 +515,33 @@ impl Connection {
         ),
         NodeError,
     > {
-        let connection = reconnect(&config).await?;
+        let connection = reconnect(&config, None).await?;
 
         let (to_node, from_lavalink) = mpsc::unbounded();
         let (to_lavalink, from_node) = mpsc::unbounded();
 
+        // The task ends once the connection, and so the sender, is dropped.
+        #[cfg(feature = "v4-support")]
+        let rest = {
+            let (rest, requests) = mpsc::unbounded();
+            tokio::spawn(send_rest_requests(config.clone(), requests));
+
+            rest
+        };
+
         Ok((
             Self {
                 config,
                 connection,
                 node_from: from_node,
                 node_to: to_node,
+                #[cfg(feature = "v4-support")]
+                pending: Vec::new(),
                 players,
+                queued: Vec::new(),
+                #[cfg(feature = "v4-support")]
+                rest,
+                session_id,
                 stats,
             },
             to_lavalink,
@@ -364,7 +560,7 @@ impl Connection {
                 }
                 Either::Left((_, _)) => {
                     tracing::debug!("connection to {} closed, reconnecting", self.config.address);
-                    self.connection = reconnect(&self.config).await?;
+                    self.reconnect().await?;
                 }
                 Either::Right((Some(outgoing), _)) => {
                     tracing::debug!(
@@ -373,14 +569,16 @@ impl Connection {
                         outgoing
                     );
 
-                    let payload = serde_json::to_string(&outgoing).map_err(|source| {
-                        NodeError::SerializingMessage {
-                            message: outgoing,
-                            source,
-                        }
-                    })?;
-                    let msg = Message::Text(payload);
-                    self.connection.send(msg).await.unwrap();
+                    #[cfg(feature = "v4-support")]
+                    if self.config.version == ProtocolVersion::V4 {
+                        self.outgoing_rest(outgoing)?;
+
+                        continue;
+                    }
+
+                    if !self.forward(outgoing).await? {
+                        self.reconnect().await?;
+                    }
                 }
                 Either::Right((_, _)) => {
                     tracing::debug!("node {} closed, ending connection", self.config.address);
@@ -393,6 +591,69 @@ impl Connection {
         Ok(())
     }
 
+    /// Send an event over the websocket.
+    ///
+    /// If the connection was lost then the event is queued to be sent again
+    /// once reconnected, and `false` is returned.
+    async fn forward(&mut self, outgoing: OutgoingEvent) -> Result<bool, NodeError> {
+        let payload =
+            serde_json::to_string(&outgoing).map_err(|source| NodeError::SerializingMessage {
+                message: outgoing.clone(),
+                source,
+            })?;
+
+        if let Err(source) = self.connection.send(Message::Text(payload)).await {
+            tracing::debug!(
+                "failed to send event to {}, queueing until reconnected: {:?}",
+                self.config.address,
+                source,
+            );
+            self.queued.push(outgoing);
+
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+
+    /// Reconnect to the node and send the events that were queued while the
+    /// connection was lost.
+    ///
+    /// If the session is resumed then the node keeps its players, so sending
+    /// the queued events means they aren't lost during a short disconnect.
+    async fn reconnect(&mut self) -> Result<(), NodeError> {
+        loop {
+            let session_id = self.session_id();
+            self.connection = reconnect(&self.config, session_id.as_deref()).await?;
+
+            let mut queued = std::mem::take(&mut self.queued).into_iter();
+
+            tracing::debug!(
+                "reconnected to {}, sending {} queued events",
+                self.config.address,
+                queued.len(),
+            );
+
+            let mut sent_all = true;
+
+            for event in &mut queued {
+                if !self.forward(event).await? {
+                    sent_all = false;
+
+                    break;
+                }
+            }
+
+            if sent_all {
+                return Ok(());
+            }
+
+            // Keep the events that weren't sent in order, after the one that
+            // failed and was queued again.
+            self.queued.extend(queued);
+        }
+    }
+
     async fn incoming(&mut self, incoming: Message) -> Result<bool, NodeError> {
         tracing::debug!(
             "received message from {}: {:?}",
@@ -435,10 +696,19 @@ impl Connection {
 
         match event {
             IncomingEvent::PlayerUpdate(ref update) => self.player_update(update).await?,
+            IncomingEvent::Ready(ref ready) => self.ready(ready)?,
             IncomingEvent::Stats(ref stats) => self.stats(stats).await?,
+            IncomingEvent::TrackEnd(ref end) => self.track_end(end),
             _ => {}
         }
 
+        if let Some(player) = event
+            .guild_id()
+            .and_then(|guild_id| self.players.get(&guild_id))
+        {
+            player.value().dispatch(&event);
+        }
+
         // It's fine if the rx end dropped, often users don't need to care about
         // these events.
         if !self.node_to.is_closed() {
@@ -468,21 +738,264 @@ impl Connection {
         Ok(())
     }
 
+    fn track_end(&self, end: &TrackEnd) {
+        if let Some(player) = self.players.get(&end.guild_id) {
+            player.value().track_ended(&end.track);
+        }
+    }
+
     async fn stats(&self, stats: &Stats) -> Result<(), NodeError> {
         *self.stats.lock().await = stats.clone();
 
         Ok(())
     }
+
+    /// Store the session ID of a ready node, configure resuming, and send the
+    /// events that were waiting for a session.
+    fn ready(&mut self, ready: &Ready) -> Result<(), NodeError> {
+        tracing::debug!(
+            "node {} is ready with session {} (resumed: {})",
+            self.config.address,
+            ready.session_id,
+            ready.resumed,
+        );
+
+        self.session_id
+            .lock()
+            .expect("session id poisoned")
+            .replace(ready.session_id.clone());
+
+        #[cfg(feature = "v4-support")]
+        {
+            if let Some(resume) = self.config.resume.as_ref() {
+                if !ready.resumed {
+                    self.request(RestRequest {
+                        body: Some(json!({
+                            "resuming": true,
+                            "timeout": resume.timeout,
+                        })),
+                        method: Method::PATCH,
+                        path: format!("/v4/sessions/{}", ready.session_id),
+                    });
+                }
+            }
+
+            for event in std::mem::take(&mut self.pending) {
+                self.outgoing_rest(event)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn session_id(&self) -> Option<String> {
+        self.session_id.lock().expect("session id poisoned").clone()
+    }
+
+    /// Send an outgoing event to a node using version 4 of the protocol,
+    /// where players are controlled over REST.
+    #[cfg(feature = "v4-support")]
+    fn outgoing_rest(&mut self, event: OutgoingEvent) -> Result<(), NodeError> {
+        let session_id = match self.session_id() {
+            Some(session_id) => session_id,
+            None => {
+                tracing::debug!(
+                    "node {} has no session yet, queueing event",
+                    self.config.address
+                );
+                self.pending.push(event);
+
+                return Ok(());
+            }
+        };
+
+        self.request(rest_request(&session_id, &event)?);
+
+        Ok(())
+    }
+
+    /// Queue a request to the node's REST API.
+    #[cfg(feature = "v4-support")]
+    fn request(&self, request: RestRequest) {
+        // The task only ends once the connection is dropped.
+        let _ = self.rest.unbounded_send(request);
+    }
+}
+
+/// Request to a node's REST API.
+#[cfg(feature = "v4-support")]
+#[derive(Debug, PartialEq)]
+struct RestRequest {
+    body: Option<Value>,
+    method: Method,
+    path: String,
+}
+
+/// Send queued requests to a node's REST API in order.
+///
+/// Failures are logged rather than returned so that a single bad request
+/// doesn't end the connection.
+#[cfg(feature = "v4-support")]
+async fn send_rest_requests(config: NodeConfig, mut requests: UnboundedReceiver<RestRequest>) {
+    let http = HyperClient::new();
+
+    while let Some(RestRequest { body, method, path }) = requests.next().await {
+        let body = body.map_or_else(Vec::new, |body| body.to_string().into_bytes());
+        let request = Request::builder()
+            .method(method)
+            .uri(format!("http://{}{}", config.address, path))
+            .header(AUTHORIZATION, &config.authorization)
+            .header(CONTENT_TYPE, "application/json")
+            .body(Body::from(body));
+
+        let request = match request {
+            Ok(request) => request,
+            Err(source) => {
+                tracing::warn!("failed to build request to {}: {:?}", path, source);
+
+                continue;
+            }
+        };
+
+        match http.request(request).await {
+            Ok(response) if !response.status().is_success() => {
+                tracing::warn!(
+                    "request to {} on node {} failed with status {}",
+                    path,
+                    config.address,
+                    response.status(),
+                );
+            }
+            Ok(_) => {}
+            Err(source) => {
+                tracing::warn!(
+                    "request to {} on node {} failed: {:?}",
+                    path,
+                    config.address,
+                    source,
+                );
+            }
+        }
+    }
 }
 
-fn connect_request(state: &NodeConfig) -> Result<Request<()>, NodeError> {
-    let mut builder = Request::get(format!("ws://{}", state.address));
+/// Create the REST request for an outgoing event in version 4 of the
+/// protocol.
+///
+/// Returns [`NodeError::SerializingMessage`] if the event's filters couldn't
+/// be serialized.
+#[cfg(feature = "v4-support")]
+fn rest_request(session_id: &str, event: &OutgoingEvent) -> Result<RestRequest, NodeError> {
+    let guild_id = match event {
+        OutgoingEvent::Destroy(event) => event.guild_id,
+        OutgoingEvent::Equalizer(event) => event.guild_id,
+        OutgoingEvent::Filters(event) => event.guild_id,
+        OutgoingEvent::Pause(event) => event.guild_id,
+        OutgoingEvent::Play(event) => event.guild_id,
+        OutgoingEvent::Seek(event) => event.guild_id,
+        OutgoingEvent::Stop(event) => event.guild_id,
+        OutgoingEvent::VoiceUpdate(event) => event.guild_id,
+        OutgoingEvent::Volume(event) => event.guild_id,
+    };
+    let path = format!("/v4/sessions/{}/players/{}", session_id, guild_id);
+
+    let body = match event {
+        OutgoingEvent::Destroy(_) => {
+            return Ok(RestRequest {
+                body: None,
+                method: Method::DELETE,
+                path,
+            })
+        }
+        OutgoingEvent::Equalizer(event) => json!({
+            "filters": {
+                "equalizer": event.bands,
+            },
+        }),
+        OutgoingEvent::Filters(event) => {
+            let mut filters =
+                serde_json::to_value(event).map_err(|source| NodeError::SerializingMessage {
+                    message: OutgoingEvent::Filters(event.clone()),
+                    source,
+                })?;
+
+            if let Some(filters) = filters.as_object_mut() {
+                filters.remove("guildId");
+                filters.remove("op");
+            }
+
+            json!({ "filters": filters })
+        }
+        OutgoingEvent::Pause(event) => json!({ "paused": event.pause }),
+        OutgoingEvent::Play(event) => {
+            let mut body = json!({
+                "track": {
+                    "encoded": event.track,
+                },
+            });
+
+            if let Some(start_time) = event.start_time {
+                body["position"] = json!(start_time);
+            }
+
+            if let Some(end_time) = event.end_time {
+                body["endTime"] = json!(end_time);
+            }
+
+            return Ok(RestRequest {
+                body: Some(body),
+                method: Method::PATCH,
+                path: format!("{}?noReplace={}", path, event.no_replace),
+            });
+        }
+        OutgoingEvent::Seek(event) => json!({ "position": event.position }),
+        OutgoingEvent::Stop(_) => json!({
+            "track": {
+                "encoded": null,
+            },
+        }),
+        OutgoingEvent::VoiceUpdate(event) => json!({
+            "voice": {
+                "endpoint": event.event.endpoint,
+                "sessionId": event.session_id,
+                "token": event.event.token,
+            },
+        }),
+        OutgoingEvent::Volume(event) => json!({ "volume": event.volume }),
+    };
+
+    Ok(RestRequest {
+        body: Some(body),
+        method: Method::PATCH,
+        path,
+    })
+}
+
+#[cfg_attr(not(feature = "v4-support"), allow(unused_variables))]
+fn connect_request(state: &NodeConfig, session_id: Option<&str>) -> Result<Request<()>, NodeError> {
+    let mut builder = match state.version {
+        ProtocolVersion::V3 => Request::get(format!("ws://{}", state.address)),
+        #[cfg(feature = "v4-support")]
+        ProtocolVersion::V4 => Request::get(format!("ws://{}/v4/websocket", state.address)),
+    };
     builder = builder.header("Authorization", &state.authorization);
     builder = builder.header("Num-Shards", state.shard_count);
-    builder = builder.header("User-Id", state.user_id.0);
+    builder = builder.header("User-Id", state.user_id.get());
+
+    match state.version {
+        ProtocolVersion::V3 => {
+            if let Some(resume) = state.resume.as_ref() {
+                builder = builder.header("Resume-Key", resume_key(state, resume));
+            }
+        }
+        #[cfg(feature = "v4-support")]
+        ProtocolVersion::V4 => {
+            builder = builder.header("Client-Name", "twilight-lavalink");
 
-    if state.resume.is_some() {
-        builder = builder.header("Resume-Key", state.address.to_string());
+            if let (Some(_), Some(session_id)) = (state.resume.as_ref(), session_id) {
+                builder = builder.header("Session-Id", session_id);
+            }
+        }
     }
 
     builder
@@ -490,11 +1003,28 @@ fn connect_request(state: &NodeConfig) -> Result<Request<()>, NodeError> {
         .map_err(|source| NodeError::BuildingConnectionRequest { source })
 }
 
-async fn reconnect(config: &NodeConfig) -> Result<WebSocketStream<ConnectStream>, NodeError> {
-    let (mut stream, res) = backoff(config).await?;
+async fn reconnect(
+    config: &NodeConfig,
+    session_id: Option<&str>,
+) -> Result<WebSocketStream<ConnectStream>, NodeError> {
+    let (mut stream, res) = backoff(config, session_id).await?;
 
     let headers = res.headers();
 
+    // Resuming is configured over REST once the node is ready in version 4 of
+    // the protocol.
+    if config.version == ProtocolVersion::V3 {
+        configure_resuming(config, &mut stream, headers).await;
+    }
+
+    Ok(stream)
+}
+
+async fn configure_resuming(
+    config: &NodeConfig,
+    stream: &mut WebSocketStream<ConnectStream>,
+    headers: &HeaderMap,
+) {
     if let Some(resume) = config.resume.as_ref() {
         let header = HeaderName::from_static("session-resumed");
 
@@ -504,7 +1034,7 @@ async fn reconnect(config: &NodeConfig) -> Result<WebSocketStream<ConnectStream>
 
                 let payload = serde_json::json!({
                     "op": "configureResuming",
-                    "key": config.address,
+                    "key": resume_key(config, resume),
                     "timeout": resume.timeout,
                 });
                 let msg = Message::Text(serde_json::to_string(&payload).unwrap());
@@ -515,17 +1045,25 @@ async fn reconnect(config: &NodeConfig) -> Result<WebSocketStream<ConnectStream>
             }
         }
     }
+}
 
-    Ok(stream)
+/// Key to resume a session with in version 3 of the protocol, defaulting to the
+/// address of the node.
+fn resume_key(config: &NodeConfig, resume: &Resume) -> String {
+    resume
+        .key
+        .clone()
+        .unwrap_or_else(|| config.address.to_string())
 }
 
 async fn backoff(
     config: &NodeConfig,
+    session_id: Option<&str>,
 ) -> Result<(WebSocketStream<ConnectStream>, Response<()>), NodeError> {
     let mut seconds = 1;
 
     loop {
-        let req = connect_request(config)?;
+        let req = connect_request(config, session_id)?;
 
         match async_tungstenite::tokio::connect_async(req).await {
             Ok((stream, res)) => return Ok((stream, res)),
@@ -563,16 +1101,18 @@ async fn backoff(
 
 #[cfg(test)]
 mod tests {
-    use super::{Node, NodeConfig, NodeError, Resume};
+    use super::{Closed, Node, NodeConfig, NodeError, ProtocolVersion, Resume};
     use static_assertions::{assert_fields, assert_impl_all};
-    use std::{error::Error, fmt::Debug};
+    use std::{error::Error, fmt::Debug, hash::Hash, sync::Arc};
+    use twilight_model::id::UserId;
 
     assert_fields!(
         NodeConfig: address,
         authorization,
         resume,
         shard_count,
-        user_id
+        user_id,
+        version
     );
     assert_impl_all!(NodeConfig: Clone, Debug, Send, Sync);
     assert_fields!(NodeError::BuildingConnectionRequest: source);
@@ -581,6 +1121,121 @@ mod tests {
     assert_fields!(NodeError::Unauthorized: address, authorization);
     assert_impl_all!(NodeError: Debug, Error, Send, Sync);
     assert_impl_all!(Node: Clone, Debug, Send, Sync);
-    assert_fields!(Resume: timeout);
+    assert_impl_all!(
+        ProtocolVersion: Clone,
+        Copy,
+        Debug,
+        Default,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
+    assert_fields!(Resume: key, timeout);
     assert_impl_all!(Resume: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+
+    #[cfg(feature = "v4-support")]
+    #[test]
+    fn test_rest_request() -> Result<(), Box<dyn Error>> {
+        use super::RestRequest;
+        use crate::model::{Filters, Pause, Play, Stop, Timescale};
+        use http::Method;
+        use serde_json::json;
+        use twilight_model::id::GuildId;
+
+        let guild_id = GuildId::new(1);
+
+        assert_eq!(
+            RestRequest {
+                body: Some(json!({
+                    "track": {
+                        "encoded": "track",
+                    },
+                    "position": 5000,
+                })),
+                method: Method::PATCH,
+                path: "/v4/sessions/abc/players/1?noReplace=false".to_owned(),
+            },
+            super::rest_request(
+                "abc",
+                &Play::new(guild_id, "track", 5000, None::<u64>, false).into()
+            )?,
+        );
+        assert_eq!(
+            RestRequest {
+                body: Some(json!({ "paused": true })),
+                method: Method::PATCH,
+                path: "/v4/sessions/abc/players/1".to_owned(),
+            },
+            super::rest_request("abc", &Pause::new(guild_id, true).into())?,
+        );
+        assert_eq!(
+            RestRequest {
+                body: Some(json!({ "track": { "encoded": null } })),
+                method: Method::PATCH,
+                path: "/v4/sessions/abc/players/1".to_owned(),
+            },
+            super::rest_request("abc", &Stop::new(guild_id).into())?,
+        );
+
+        let mut filters = Filters::new(guild_id);
+        filters.timescale = Some(Timescale::new(1.2, 1.2, 1.0));
+        assert_eq!(
+            RestRequest {
+                body: Some(json!({
+                    "filters": {
+                        "timescale": {
+                            "pitch": 1.2,
+                            "rate": 1.0,
+                            "speed": 1.2,
+                        },
+                    },
+                })),
+                method: Method::PATCH,
+                path: "/v4/sessions/abc/players/1".to_owned(),
+            },
+            super::rest_request("abc", &filters.into())?,
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_closed() {
+        let closed = Arc::new(Closed::default());
+        let waiter = tokio::spawn({
+            let closed = Arc::clone(&closed);
+
+            async move { closed.wait().await }
+        });
+
+        assert!(!closed.is_closed());
+        closed.close();
+        assert!(closed.is_closed());
+        waiter.await.unwrap();
+
+        // Waiting on an already closed node resolves immediately.
+        closed.wait().await;
+    }
+
+    #[test]
+    fn test_resume_key() {
+        let config = NodeConfig::new(
+            UserId::new(1),
+            1,
+            ([127, 0, 0, 1], 2333),
+            "youshallnotpass",
+            Resume::default(),
+        );
+
+        assert_eq!(
+            "127.0.0.1:2333",
+            super::resume_key(&config, &Resume::default())
+        );
+        assert_eq!(
+            "key",
+            super::resume_key(&config, &Resume::new(30).key("key"))
+        );
+    }
 }
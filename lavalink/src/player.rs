@@ -14,12 +14,12 @@ use dashmap::{
     mapref::one::{Ref, RefMut},
     DashMap,
 };
-use futures_channel::mpsc::TrySendError;
+use futures_channel::mpsc::{self, TrySendError, UnboundedReceiver, UnboundedSender};
 use std::{
     fmt::Debug,
     sync::{
-        atomic::{AtomicBool, Ordering},
-        Arc,
+        atomic::{AtomicBool, AtomicU16, Ordering},
+        Arc, Mutex,
     },
 };
 use twilight_model::id::{ChannelId, GuildId};
@@ -65,26 +65,32 @@ impl PlayerManager {
 #[derive(Debug)]
 pub struct Player {
     channel_id: Option<ChannelId>,
+    events: Mutex<Vec<UnboundedSender<IncomingEvent>>>,
+    filters: Mutex<Option<Filters>>,
     guild_id: GuildId,
     node: Node,
     paused: AtomicBool,
-    playing: Option<()>,
     position: i64,
     time: i64,
-    volume: u16,
+    track: Mutex<Option<String>>,
+    voice: Mutex<Option<VoiceUpdate>>,
+    volume: AtomicU16,
 }
 
 impl Player {
     pub(crate) fn new(guild_id: GuildId, node: Node) -> Self {
         Self {
             channel_id: None,
+            events: Mutex::new(Vec::new()),
+            filters: Mutex::new(None),
             guild_id,
             node,
             paused: AtomicBool::new(false),
-            playing: None,
             position: 0,
             time: 0,
-            volume: 0,
+            track: Mutex::new(None),
+            voice: Mutex::new(None),
+            volume: AtomicU16::new(100),
         }
     }
 
@@ -100,7 +106,7 @@ impl Player {
     /// use twilight_lavalink::{model::{Play, Pause}, Lavalink};
     /// # use twilight_model::id::{GuildId, UserId};
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let (guild_id, user_id) = (GuildId(1), UserId(2));
+    /// # let (guild_id, user_id) = (GuildId::new(1), UserId::new(2));
     /// # let track = String::new();
     ///
     /// let lavalink = Lavalink::new(user_id, 10);
@@ -126,13 +132,179 @@ impl Player {
             event
         );
 
-        if let OutgoingEvent::Pause(ref event) = event {
-            self.paused.store(event.pause, Ordering::Release);
+        match event {
+            OutgoingEvent::Filters(ref event) => {
+                self.filters
+                    .lock()
+                    .expect("filters poisoned")
+                    .replace(event.clone());
+            }
+            OutgoingEvent::Pause(ref event) => {
+                self.paused.store(event.pause, Ordering::Release);
+            }
+            OutgoingEvent::Play(ref event) => {
+                self.track
+                    .lock()
+                    .expect("track poisoned")
+                    .replace(event.track.clone());
+            }
+            OutgoingEvent::Destroy(_) | OutgoingEvent::Stop(_) => {
+                self.track.lock().expect("track poisoned").take();
+            }
+            OutgoingEvent::VoiceUpdate(ref event) => {
+                self.voice
+                    .lock()
+                    .expect("voice poisoned")
+                    .replace(event.clone());
+            }
+            OutgoingEvent::Volume(ref event) => {
+                // Lavalink accepts volumes from 0 to 1000.
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let volume = event.volume.max(0).min(1000) as u16;
+
+                self.volume.store(volume, Ordering::Release);
+            }
+            _ => {}
         }
 
         self.node.send(event)
     }
 
+    /// Set the audio filters of the player, replacing any previously set
+    /// filters.
+    ///
+    /// Returns a `futures_channel` `TrySendError` if the node has been removed.
+    ///
+    /// # Examples
+    ///
+    /// Boost the bass of the player's audio:
+    ///
+    /// ```
+    /// use twilight_lavalink::{model::{EqualizerBand, Filters}, Lavalink};
+    /// # use twilight_model::id::{GuildId, UserId};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let (guild_id, user_id) = (GuildId::new(1), UserId::new(2));
+    ///
+    /// let lavalink = Lavalink::new(user_id, 10);
+    /// let players = lavalink.players();
+    ///
+    /// if let Some(player) = players.get(&guild_id) {
+    ///     let mut filters = Filters::new(guild_id);
+    ///     filters.equalizer = Some(vec![
+    ///         EqualizerBand::new(0, 0.25),
+    ///         EqualizerBand::new(1, 0.2),
+    ///         EqualizerBand::new(2, 0.15),
+    ///     ]);
+    ///
+    ///     player.set_filters(filters)?;
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn set_filters(&self, filters: Filters) -> Result<(), TrySendError<OutgoingEvent>> {
+        self._send(OutgoingEvent::Filters(filters))
+    }
+
+    /// Return a copy of the filters last set on the player, if any.
+    pub fn filters(&self) -> Option<Filters> {
+        self.filters.lock().expect("filters poisoned").clone()
+    }
+
+    /// Move the player to another node, replaying its voice connection,
+    /// track, position, filters, and paused state and volume.
+    ///
+    /// This is used when the player's node closes.
+    pub(crate) fn migrate(&mut self, node: Node) -> Result<(), TrySendError<OutgoingEvent>> {
+        tracing::debug!(
+            "migrating player for guild {} from node {} to node {}",
+            self.guild_id,
+            self.node.config().address,
+            node.config().address,
+        );
+
+        self.node = node;
+
+        let voice = self.voice.lock().expect("voice poisoned").clone();
+
+        if let Some(voice) = voice {
+            self.node.send(voice.into())?;
+        }
+
+        let track = self.track.lock().expect("track poisoned").clone();
+
+        if let Some(track) = track {
+            #[allow(clippy::cast_sign_loss)]
+            let position = self.position.max(0) as u64;
+
+            self.node
+                .send(Play::new(self.guild_id, track, position, None::<u64>, false).into())?;
+        }
+
+        if let Some(filters) = self.filters() {
+            self.node.send(filters.into())?;
+        }
+
+        if self.paused() {
+            self.node.send(Pause::new(self.guild_id, true).into())?;
+        }
+
+        let volume = Volume::new(self.guild_id, self.volume_ref().into());
+
+        self.node.send(volume.into())
+    }
+
+    /// Create a stream of the events received from the player's node about
+    /// this player, such as track events and player updates.
+    ///
+    /// This is an alternative to filtering the events of every player
+    /// received from a node by guild ID. The stream ends when the player is
+    /// removed.
+    ///
+    /// # Examples
+    ///
+    /// Print when tracks end in a guild:
+    ///
+    /// ```no_run
+    /// use futures_util::stream::StreamExt;
+    /// use twilight_lavalink::{model::IncomingEvent, Lavalink};
+    /// # use twilight_model::id::{GuildId, UserId};
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let (guild_id, user_id) = (GuildId::new(1), UserId::new(2));
+    ///
+    /// let lavalink = Lavalink::new(user_id, 10);
+    /// let mut events = lavalink.player(guild_id).await?.events();
+    ///
+    /// while let Some(event) = events.next().await {
+    ///     if let IncomingEvent::TrackEnd(end) = event {
+    ///         println!("track ended: {}", end.reason);
+    ///     }
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn events(&self) -> UnboundedReceiver<IncomingEvent> {
+        let (tx, rx) = mpsc::unbounded();
+        self.events.lock().expect("events poisoned").push(tx);
+
+        rx
+    }
+
+    /// Send an event to the player's event streams, removing streams that
+    /// have been dropped.
+    pub(crate) fn dispatch(&self, event: &IncomingEvent) {
+        self.events
+            .lock()
+            .expect("events poisoned")
+            .retain(|tx| tx.unbounded_send(event.clone()).is_ok());
+    }
+
+    /// Clear the player's track if it's the track that ended.
+    pub(crate) fn track_ended(&self, track: &str) {
+        let mut current = self.track.lock().expect("track poisoned");
+
+        if current.as_deref() == Some(track) {
+            current.take();
+        }
+    }
+
     /// Return an immutable reference to the node linked to the player.
     pub fn node(&self) -> &Node {
         &self.node
@@ -173,9 +345,14 @@ impl Player {
         &mut self.time
     }
 
+    /// Return a copy of the encoded track the player is playing, if any.
+    pub fn track(&self) -> Option<String> {
+        self.track.lock().expect("track poisoned").clone()
+    }
+
     /// Return a copy of the player's volume.
     pub fn volume_ref(&self) -> u16 {
-        self.volume
+        self.volume.load(Ordering::Acquire)
     }
 }
 
@@ -0,0 +1,114 @@
+//! Mentions of application commands, which clients display as clickable
+//! references to the command.
+//!
+//! Command mentions are formatted as `</name:id>`, where `name` is the full
+//! name of the command, including the names of its subcommand group and
+//! subcommand, if any.
+//!
+//! # Examples
+//!
+//! Mention the `user` subcommand of a `ban` command:
+//!
+//! ```
+//! use twilight_mention::{command::CommandMention, Mention};
+//! use twilight_model::id::CommandId;
+//!
+//! let command = CommandMention::subcommand(CommandId::new(123), "ban", "user");
+//! assert_eq!("</ban user:123>", command.mention().to_string());
+//! ```
+
+use twilight_model::id::CommandId;
+
+/// Application command, subcommand, or subcommand in a subcommand group to be
+/// mentioned.
+///
+/// Commands can be formatted as [`Mention`]s using [`Mention::mention`].
+///
+/// [`Mention`]: super::fmt::Mention
+/// [`Mention::mention`]: super::fmt::Mention::mention
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CommandMention {
+    /// ID of the top-level command.
+    id: CommandId,
+    /// Full name of the command, with the names of subcommand groups and
+    /// subcommands separated by spaces.
+    name: String,
+}
+
+impl CommandMention {
+    /// Create a mention of a top-level command.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_mention::{command::CommandMention, Mention};
+    /// use twilight_model::id::CommandId;
+    ///
+    /// let command = CommandMention::new(CommandId::new(123), "help");
+    /// assert_eq!("</help:123>", command.mention().to_string());
+    /// ```
+    pub fn new(id: CommandId, name: impl Into<String>) -> Self {
+        Self {
+            id,
+            name: name.into(),
+        }
+    }
+
+    /// Create a mention of a subcommand of a top-level command.
+    pub fn subcommand(id: CommandId, name: &str, subcommand: &str) -> Self {
+        Self::new(id, format!("{} {}", name, subcommand))
+    }
+
+    /// Create a mention of a subcommand within a subcommand group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_mention::{command::CommandMention, Mention};
+    /// use twilight_model::id::CommandId;
+    ///
+    /// let command =
+    ///     CommandMention::subcommand_group(CommandId::new(123), "role", "color", "set");
+    /// assert_eq!("</role color set:123>", command.mention().to_string());
+    /// ```
+    pub fn subcommand_group(id: CommandId, name: &str, group: &str, subcommand: &str) -> Self {
+        Self::new(id, format!("{} {} {}", name, group, subcommand))
+    }
+
+    /// ID of the top-level command.
+    pub const fn id(&self) -> CommandId {
+        self.id
+    }
+
+    /// Full name of the command, with the names of subcommand groups and
+    /// subcommands separated by spaces.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CommandMention;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+    use twilight_model::id::CommandId;
+
+    assert_impl_all!(CommandMention: Clone, Debug, Eq, Hash, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_name() {
+        let id = CommandId::new(1);
+
+        assert_eq!("help", CommandMention::new(id, "help").name());
+        assert_eq!(
+            "ban user",
+            CommandMention::subcommand(id, "ban", "user").name()
+        );
+        assert_eq!(
+            "role color set",
+            CommandMention::subcommand_group(id, "role", "color", "set").name()
+        );
+        assert_eq!(id, CommandMention::new(id, "help").id());
+    }
+}
@@ -1,5 +1,6 @@
 //! Formatters for creating mentions.
 
+use crate::{command::CommandMention, timestamp::Timestamp};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use twilight_model::{
     channel::{
@@ -20,7 +21,7 @@ use twilight_model::{
 /// use twilight_mention::Mention;
 /// use twilight_model::id::UserId;
 ///
-/// assert_eq!("<@123>", UserId(123).mention().to_string());
+/// assert_eq!("<@123>", UserId::new(123).mention().to_string());
 /// ```
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct MentionFormat<T>(T);
@@ -32,6 +33,13 @@ impl Display for MentionFormat<ChannelId> {
     }
 }
 
+/// Mention a command. This will format as `</NAME:ID>`.
+impl Display for MentionFormat<CommandMention> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_fmt(format_args!("</{}:{}>", self.0.name(), self.0.id()))
+    }
+}
+
 /// Mention an emoji. This will format as `<:emoji:ID>`.
 impl Display for MentionFormat<EmojiId> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -46,6 +54,18 @@ impl Display for MentionFormat<RoleId> {
     }
 }
 
+/// Mention a timestamp. This will format as `<t:UNIX>` if a style is not
+/// specified or `<t:UNIX:STYLE>` if a style is specified.
+impl Display for MentionFormat<Timestamp> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if let Some(style) = self.0.style() {
+            f.write_fmt(format_args!("<t:{}:{}>", self.0.unix(), style))
+        } else {
+            f.write_fmt(format_args!("<t:{}>", self.0.unix()))
+        }
+    }
+}
+
 /// Mention a user. This will format as `<@ID>`.
 impl Display for MentionFormat<UserId> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -67,7 +87,7 @@ impl Display for MentionFormat<UserId> {
 /// use twilight_mention::Mention;
 /// use twilight_model::id::ChannelId;
 ///
-/// assert_eq!("<#123>", ChannelId(123).mention().to_string());
+/// assert_eq!("<#123>", ChannelId::new(123).mention().to_string());
 /// ```
 pub trait Mention<T> {
     /// Mention a resource by using its ID.
@@ -116,6 +136,20 @@ impl Mention<ChannelId> for &'_ Channel {
     }
 }
 
+/// Mention a command. This will format as `</NAME:ID>`.
+impl Mention<CommandMention> for CommandMention {
+    fn mention(&self) -> MentionFormat<CommandMention> {
+        MentionFormat(self.clone())
+    }
+}
+
+/// Mention a command. This will format as `</NAME:ID>`.
+impl Mention<CommandMention> for &'_ CommandMention {
+    fn mention(&self) -> MentionFormat<CommandMention> {
+        (*self).mention()
+    }
+}
+
 /// Mention the current user. This will format as `<@ID>`.
 impl Mention<UserId> for CurrentUser {
     fn mention(&self) -> MentionFormat<UserId> {
@@ -256,6 +290,20 @@ impl Mention<ChannelId> for &'_ TextChannel {
     }
 }
 
+/// Mention a timestamp. This will format as `<t:UNIX>` or `<t:UNIX:STYLE>`.
+impl Mention<Timestamp> for Timestamp {
+    fn mention(&self) -> MentionFormat<Timestamp> {
+        MentionFormat(*self)
+    }
+}
+
+/// Mention a timestamp. This will format as `<t:UNIX>` or `<t:UNIX:STYLE>`.
+impl Mention<Timestamp> for &'_ Timestamp {
+    fn mention(&self) -> MentionFormat<Timestamp> {
+        (*self).mention()
+    }
+}
+
 /// Mention a user ID. This will format as `<&ID>`.
 impl Mention<UserId> for UserId {
     fn mention(&self) -> MentionFormat<UserId> {
@@ -301,6 +349,10 @@ impl Mention<ChannelId> for &'_ VoiceChannel {
 #[cfg(test)]
 mod tests {
     use super::{Mention, MentionFormat};
+    use crate::{
+        command::CommandMention,
+        timestamp::{Timestamp, TimestampStyle},
+    };
     use static_assertions::assert_impl_all;
     use std::fmt::{Debug, Display};
     use twilight_model::{
@@ -309,14 +361,16 @@ mod tests {
             VoiceChannel,
         },
         guild::{Emoji, Member, Role},
-        id::{ChannelId, EmojiId, RoleId, UserId},
+        id::{ChannelId, CommandId, EmojiId, RoleId, UserId},
         user::{CurrentUser, User},
     };
 
     assert_impl_all!(MentionFormat<()>: Clone, Copy, Debug, Eq, PartialEq, Send, Sync);
     assert_impl_all!(MentionFormat<ChannelId>: Clone, Copy, Debug, Display, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(MentionFormat<CommandMention>: Clone, Debug, Display, Eq, PartialEq, Send, Sync);
     assert_impl_all!(MentionFormat<EmojiId>: Clone, Copy, Debug, Display, Eq, PartialEq, Send, Sync);
     assert_impl_all!(MentionFormat<RoleId>: Clone, Copy, Debug, Display, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(MentionFormat<Timestamp>: Clone, Copy, Debug, Display, Eq, PartialEq, Send, Sync);
     assert_impl_all!(MentionFormat<UserId>: Clone, Copy, Debug, Display, Eq, PartialEq, Send, Sync);
     assert_impl_all!(ChannelId: Mention<ChannelId>);
     assert_impl_all!(&'static ChannelId: Mention<ChannelId>);
@@ -324,6 +378,8 @@ mod tests {
     assert_impl_all!(&'static CategoryChannel: Mention<ChannelId>);
     assert_impl_all!(Channel: Mention<ChannelId>);
     assert_impl_all!(&'static Channel: Mention<ChannelId>);
+    assert_impl_all!(CommandMention: Mention<CommandMention>);
+    assert_impl_all!(&'static CommandMention: Mention<CommandMention>);
     assert_impl_all!(CurrentUser: Mention<UserId>);
     assert_impl_all!(&'static CurrentUser: Mention<UserId>);
     assert_impl_all!(EmojiId: Mention<EmojiId>);
@@ -344,6 +400,8 @@ mod tests {
     assert_impl_all!(&'static Role: Mention<RoleId>);
     assert_impl_all!(TextChannel: Mention<ChannelId>);
     assert_impl_all!(&'static TextChannel: Mention<ChannelId>);
+    assert_impl_all!(Timestamp: Mention<Timestamp>);
+    assert_impl_all!(&'static Timestamp: Mention<Timestamp>);
     assert_impl_all!(UserId: Mention<UserId>);
     assert_impl_all!(&'static UserId: Mention<UserId>);
     assert_impl_all!(User: Mention<UserId>);
@@ -353,21 +411,57 @@ mod tests {
 
     #[test]
     fn test_mention_format_channel_id() {
-        assert_eq!("<#123>", ChannelId(123).mention().to_string());
+        assert_eq!("<#123>", ChannelId::new(123).mention().to_string());
+    }
+
+    #[test]
+    fn test_mention_format_command() {
+        let id = CommandId::new(123);
+
+        assert_eq!(
+            "</help:123>",
+            CommandMention::new(id, "help").mention().to_string()
+        );
+        assert_eq!(
+            "</ban user:123>",
+            CommandMention::subcommand(id, "ban", "user")
+                .mention()
+                .to_string()
+        );
+        assert_eq!(
+            "</role color set:123>",
+            CommandMention::subcommand_group(id, "role", "color", "set")
+                .mention()
+                .to_string()
+        );
     }
 
     #[test]
     fn test_mention_format_emoji_id() {
-        assert_eq!("<:emoji:123>", EmojiId(123).mention().to_string());
+        assert_eq!("<:emoji:123>", EmojiId::new(123).mention().to_string());
     }
 
     #[test]
     fn test_mention_format_role_id() {
-        assert_eq!("<@&123>", RoleId(123).mention().to_string());
+        assert_eq!("<@&123>", RoleId::new(123).mention().to_string());
+    }
+
+    #[test]
+    fn test_mention_format_timestamp() {
+        assert_eq!(
+            "<t:1624047064>",
+            Timestamp::new(1_624_047_064, None).mention().to_string()
+        );
+        assert_eq!(
+            "<t:1624047064:R>",
+            Timestamp::new(1_624_047_064, Some(TimestampStyle::RelativeTime))
+                .mention()
+                .to_string()
+        );
     }
 
     #[test]
     fn test_mention_format_user_id() {
-        assert_eq!("<@123>", UserId(123).mention().to_string());
+        assert_eq!("<@123>", UserId::new(123).mention().to_string());
     }
 }
@@ -6,7 +6,9 @@
 //! ecosystem to mention its model types and parse those mentions.
 //!
 //! With this library, you can create mentions for various types, such as users,
-//! emojis, roles, members, or channels.
+//! emojis, roles, members, or channels. Timestamps that are displayed in each
+//! client's local timezone can be created via the [`timestamp`] module, and
+//! clickable references to application commands via the [`command`] module.
 //!
 //! ## Examples
 //!
@@ -16,7 +18,7 @@
 //! use twilight_mention::Mention;
 //! use twilight_model::id::UserId;
 //!
-//! let user_id = UserId(123);
+//! let user_id = UserId::new(123);
 //! let message = format!("Hey there, {}!", user_id.mention());
 //! ```
 //!
@@ -43,10 +45,14 @@
 )]
 #![allow(clippy::module_name_repetitions)]
 
+pub mod command;
+
 pub mod fmt;
 
 pub mod parse;
 
+pub mod timestamp;
+
 #[doc(no_inline)]
 pub use fmt::{Mention, MentionFormat};
 
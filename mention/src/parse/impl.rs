@@ -1,6 +1,6 @@
 use super::{MentionIter, MentionType, ParseMentionError};
 use std::str::Chars;
-use twilight_model::id::{ChannelId, EmojiId, RoleId, UserId};
+use twilight_model::id::{ChannelId, CommandId, EmojiId, RoleId, UserId};
 
 /// Parse mentions out of buffers.
 ///
@@ -29,8 +29,8 @@ pub trait ParseMention: private::Sealed {
     /// use twilight_model::id::{ChannelId, UserId};
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// assert_eq!(ChannelId(123), ChannelId::parse("<#123>")?);
-    /// assert_eq!(UserId(456), UserId::parse("<@456>")?);
+    /// assert_eq!(ChannelId::new(123), ChannelId::parse("<#123>")?);
+    /// assert_eq!(UserId::new(456), UserId::parse("<@456>")?);
     /// assert!(ChannelId::parse("not a mention").is_err());
     /// # Ok(()) }
     /// ```
@@ -72,18 +72,37 @@ impl ParseMention for ChannelId {
     where
         Self: Sized,
     {
-        parse_id(buf, Self::SIGILS).map(|(id, _)| ChannelId(id))
+        parse_id(buf, Self::SIGILS).map(|(id, _)| ChannelId::new(id))
+    }
+}
+
+impl ParseMention for CommandId {
+    /// Sigils for command mentions.
+    ///
+    /// The name of the command, including any subcommand group and subcommand
+    /// names, follows the sigil and is itself followed by a `:` and the ID.
+    const SIGILS: &'static [&'static str] = &["/"];
+
+    fn parse(buf: &str) -> Result<Self, ParseMentionError<'_>>
+    where
+        Self: Sized,
+    {
+        parse_id(buf, Self::SIGILS).map(|(id, _)| CommandId::new(id))
     }
 }
 
 impl ParseMention for EmojiId {
-    const SIGILS: &'static [&'static str] = &[":"];
+    /// Sigils for emoji mentions.
+    ///
+    /// Static emojis have the sigil `:` while animated emojis have the sigil
+    /// `a:`.
+    const SIGILS: &'static [&'static str] = &[":", "a:"];
 
     fn parse(buf: &str) -> Result<Self, ParseMentionError<'_>>
     where
         Self: Sized,
     {
-        parse_id(buf, Self::SIGILS).map(|(id, _)| EmojiId(id))
+        parse_id(buf, Self::SIGILS).map(|(id, _)| EmojiId::new(id))
     }
 }
 
@@ -91,7 +110,7 @@ impl ParseMention for MentionType {
     /// Sigils for any type of mention.
     ///
     /// Contains all of the sigils of every other type of mention.
-    const SIGILS: &'static [&'static str] = &["#", ":", "@&", "@!", "@"];
+    const SIGILS: &'static [&'static str] = &["#", "/", ":", "a:", "@&", "@!", "@"];
 
     fn parse(buf: &str) -> Result<Self, ParseMentionError<'_>>
     where
@@ -101,25 +120,31 @@ impl ParseMention for MentionType {
 
         for sigil in ChannelId::SIGILS {
             if *sigil == found {
-                return Ok(MentionType::Channel(ChannelId(id)));
+                return Ok(MentionType::Channel(ChannelId::new(id)));
+            }
+        }
+
+        for sigil in CommandId::SIGILS {
+            if *sigil == found {
+                return Ok(MentionType::Command(CommandId::new(id)));
             }
         }
 
         for sigil in EmojiId::SIGILS {
             if *sigil == found {
-                return Ok(MentionType::Emoji(EmojiId(id)));
+                return Ok(MentionType::Emoji(EmojiId::new(id)));
             }
         }
 
         for sigil in RoleId::SIGILS {
             if *sigil == found {
-                return Ok(MentionType::Role(RoleId(id)));
+                return Ok(MentionType::Role(RoleId::new(id)));
             }
         }
 
         for sigil in UserId::SIGILS {
             if *sigil == found {
-                return Ok(MentionType::User(UserId(id)));
+                return Ok(MentionType::User(UserId::new(id)));
             }
         }
 
@@ -134,7 +159,7 @@ impl ParseMention for RoleId {
     where
         Self: Sized,
     {
-        parse_id(buf, Self::SIGILS).map(|(id, _)| RoleId(id))
+        parse_id(buf, Self::SIGILS).map(|(id, _)| RoleId::new(id))
     }
 }
 
@@ -148,7 +173,7 @@ impl ParseMention for UserId {
     where
         Self: Sized,
     {
-        parse_id(buf, Self::SIGILS).map(|(id, _)| UserId(id))
+        parse_id(buf, Self::SIGILS).map(|(id, _)| UserId::new(id))
     }
 }
 
@@ -195,7 +220,9 @@ fn parse_id<'a>(
         });
     };
 
-    if sigil == ":" && !emoji_sigil_present(&mut chars) {
+    // Command and emoji mentions have a name part before the ID, separated
+    // by a `:`.
+    if matches!(sigil, "/" | ":" | "a:") && !separator_present(&mut chars) {
         return Err(ParseMentionError::PartMissing {
             found: 1,
             expected: 2,
@@ -219,7 +246,7 @@ fn parse_id<'a>(
 
 // Don't use `Iterator::skip_while` so we can mutate `chars` in-place;
 // `skip_while` is consuming.
-fn emoji_sigil_present(chars: &mut Chars<'_>) -> bool {
+fn separator_present(chars: &mut Chars<'_>) -> bool {
     for c in chars {
         if c == ':' {
             return true;
@@ -238,11 +265,12 @@ fn emoji_sigil_present(chars: &mut Chars<'_>) -> bool {
 /// <https://rust-lang.github.io/api-guidelines/future-proofing.html>
 mod private {
     use super::super::MentionType;
-    use twilight_model::id::{ChannelId, EmojiId, RoleId, UserId};
+    use twilight_model::id::{ChannelId, CommandId, EmojiId, RoleId, UserId};
 
     pub trait Sealed {}
 
     impl Sealed for ChannelId {}
+    impl Sealed for CommandId {}
     impl Sealed for EmojiId {}
     impl Sealed for MentionType {}
     impl Sealed for RoleId {}
@@ -257,9 +285,10 @@ mod tests {
         ParseMention,
     };
     use static_assertions::assert_impl_all;
-    use twilight_model::id::{ChannelId, EmojiId, RoleId, UserId};
+    use twilight_model::id::{ChannelId, CommandId, EmojiId, RoleId, UserId};
 
     assert_impl_all!(ChannelId: ParseMention, Sealed);
+    assert_impl_all!(CommandId: ParseMention, Sealed);
     assert_impl_all!(EmojiId: ParseMention, Sealed);
     assert_impl_all!(MentionType: ParseMention, Sealed);
     assert_impl_all!(RoleId: ParseMention, Sealed);
@@ -268,15 +297,16 @@ mod tests {
     #[test]
     fn test_sigils() {
         assert_eq!(&["#"], ChannelId::SIGILS);
-        assert_eq!(&[":"], EmojiId::SIGILS);
-        assert_eq!(&["#", ":", "@&", "@!", "@"], MentionType::SIGILS);
+        assert_eq!(&["/"], CommandId::SIGILS);
+        assert_eq!(&[":", "a:"], EmojiId::SIGILS);
+        assert_eq!(&["#", "/", ":", "a:", "@&", "@!", "@"], MentionType::SIGILS);
         assert_eq!(&["@&"], RoleId::SIGILS);
         assert_eq!(&["@!", "@"], UserId::SIGILS);
     }
 
     #[test]
     fn test_parse_channel_id() {
-        assert_eq!(ChannelId(123), ChannelId::parse("<#123>").unwrap());
+        assert_eq!(ChannelId::new(123), ChannelId::parse("<#123>").unwrap());
         assert_eq!(
             ParseMentionError::Sigil {
                 expected: &["#"],
@@ -286,12 +316,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_command_id() {
+        assert_eq!(
+            CommandId::new(123),
+            CommandId::parse("</name:123>").unwrap()
+        );
+        assert_eq!(
+            CommandId::new(123),
+            CommandId::parse("</name group sub:123>").unwrap()
+        );
+        assert_eq!(
+            ParseMentionError::PartMissing {
+                expected: 2,
+                found: 1,
+            },
+            CommandId::parse("</123>").unwrap_err(),
+        );
+    }
+
     #[test]
     fn test_parse_emoji_id() {
-        assert_eq!(EmojiId(123), EmojiId::parse("<:name:123>").unwrap());
+        assert_eq!(EmojiId::new(123), EmojiId::parse("<:name:123>").unwrap());
+        assert_eq!(EmojiId::new(123), EmojiId::parse("<a:name:123>").unwrap());
         assert_eq!(
             ParseMentionError::Sigil {
-                expected: &[":"],
+                expected: &[":", "a:"],
                 found: Some('@'),
             },
             EmojiId::parse("<@123>").unwrap_err(),
@@ -301,24 +351,32 @@ mod tests {
     #[test]
     fn test_parse_mention_type() {
         assert_eq!(
-            MentionType::Channel(ChannelId(123)),
+            MentionType::Channel(ChannelId::new(123)),
             MentionType::parse("<#123>").unwrap()
         );
         assert_eq!(
-            MentionType::Emoji(EmojiId(123)),
+            MentionType::Command(CommandId::new(123)),
+            MentionType::parse("</name:123>").unwrap()
+        );
+        assert_eq!(
+            MentionType::Emoji(EmojiId::new(123)),
             MentionType::parse("<:name:123>").unwrap()
         );
         assert_eq!(
-            MentionType::Role(RoleId(123)),
+            MentionType::Emoji(EmojiId::new(123)),
+            MentionType::parse("<a:name:123>").unwrap()
+        );
+        assert_eq!(
+            MentionType::Role(RoleId::new(123)),
             MentionType::parse("<@&123>").unwrap()
         );
         assert_eq!(
-            MentionType::User(UserId(123)),
+            MentionType::User(UserId::new(123)),
             MentionType::parse("<@123>").unwrap()
         );
         assert_eq!(
             ParseMentionError::Sigil {
-                expected: &["#", ":", "@&", "@!", "@"],
+                expected: &["#", "/", ":", "a:", "@&", "@!", "@"],
                 found: Some(';'),
             },
             MentionType::parse("<;123>").unwrap_err(),
@@ -327,7 +385,7 @@ mod tests {
 
     #[test]
     fn test_parse_role_id() {
-        assert_eq!(RoleId(123), RoleId::parse("<@&123>").unwrap());
+        assert_eq!(RoleId::new(123), RoleId::parse("<@&123>").unwrap());
         assert_eq!(
             ParseMentionError::Sigil {
                 expected: &["@&"],
@@ -339,7 +397,7 @@ mod tests {
 
     #[test]
     fn test_parse_user_id() {
-        assert_eq!(UserId(123), UserId::parse("<@123>").unwrap());
+        assert_eq!(UserId::new(123), UserId::parse("<@123>").unwrap());
         assert_eq!(
             ParseMentionError::IdNotU64 {
                 found: "&123",
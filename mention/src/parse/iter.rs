@@ -21,9 +21,9 @@ use std::{iter::Iterator, marker::PhantomData, str::CharIndices};
 ///
 /// let buf = "<@123> some <@456> users <@789>!";
 /// let mut iter = UserId::iter(buf);
-/// assert!(matches!(iter.next(), Some((UserId(123), _, _))));
-/// assert!(matches!(iter.next(), Some((UserId(456), _, _))));
-/// assert!(matches!(iter.next(), Some((UserId(789), _, _))));
+/// assert_eq!(Some(UserId::new(123)), iter.next().map(|(id, _, _)| id));
+/// assert_eq!(Some(UserId::new(456)), iter.next().map(|(id, _, _)| id));
+/// assert_eq!(Some(UserId::new(789)), iter.next().map(|(id, _, _)| id));
 /// ```
 #[derive(Clone, Debug)]
 pub struct MentionIter<'a, T> {
@@ -105,15 +105,17 @@ mod tests {
     };
     use static_assertions::{assert_impl_all, assert_obj_safe};
     use std::fmt::Debug;
-    use twilight_model::id::{ChannelId, EmojiId, RoleId, UserId};
+    use twilight_model::id::{ChannelId, CommandId, EmojiId, RoleId, UserId};
 
     assert_impl_all!(MentionIter<'_, ChannelId>: Clone, Debug, Iterator, Send, Sync);
+    assert_impl_all!(MentionIter<'_, CommandId>: Clone, Debug, Iterator, Send, Sync);
     assert_impl_all!(MentionIter<'_, EmojiId>: Clone, Debug, Iterator, Send, Sync);
     assert_impl_all!(MentionIter<'_, MentionType>: Clone, Debug, Iterator, Send, Sync);
     assert_impl_all!(MentionIter<'_, RoleId>: Clone, Debug, Iterator, Send, Sync);
     assert_impl_all!(MentionIter<'_, UserId>: Clone, Debug, Iterator, Send, Sync);
     assert_obj_safe!(
         MentionIter<'_, ChannelId>,
+        MentionIter<'_, CommandId>,
         MentionIter<'_, EmojiId>,
         MentionIter<'_, MentionType>,
         MentionIter<'_, RoleId>,
@@ -123,7 +125,7 @@ mod tests {
     #[test]
     fn test_iter_channel_id() {
         let mut iter = ChannelId::iter("<#123>");
-        assert_eq!(ChannelId(123), iter.next().unwrap().0);
+        assert_eq!(ChannelId::new(123), iter.next().unwrap().0);
         assert!(iter.next().is_none());
     }
 
@@ -131,46 +133,64 @@ mod tests {
     fn test_iter_multiple_ids() {
         let buf = "one <@123>two<#456><@789> ----";
         let mut iter = UserId::iter(buf);
-        assert_eq!(UserId(123), iter.next().unwrap().0);
+        assert_eq!(UserId::new(123), iter.next().unwrap().0);
         let (mention, start, end) = iter.next().unwrap();
-        assert_eq!(UserId(789), mention);
+        assert_eq!(UserId::new(789), mention);
         assert_eq!(19, start);
         assert_eq!(24, end);
         assert!(iter.next().is_none());
     }
 
+    #[test]
+    fn test_iter_command_ids() {
+        let mut iter = CommandId::iter("use </ping:123> or </config set:456>");
+        assert_eq!(CommandId::new(123), iter.next().unwrap().0);
+        let (mention, start, end) = iter.next().unwrap();
+        assert_eq!(CommandId::new(456), mention);
+        assert_eq!(19, start);
+        assert_eq!(35, end);
+        assert!(iter.next().is_none());
+    }
+
     #[test]
     fn test_iter_emoji_ids() {
-        let mut iter = EmojiId::iter("some <:name:123> emojis <:emoji:456>");
-        assert_eq!(EmojiId(123), iter.next().unwrap().0);
-        assert_eq!(EmojiId(456), iter.next().unwrap().0);
+        let mut iter = EmojiId::iter("some <:name:123> emojis <a:emoji:456>");
+        assert_eq!(EmojiId::new(123), iter.next().unwrap().0);
+        assert_eq!(EmojiId::new(456), iter.next().unwrap().0);
         assert!(iter.next().is_none());
     }
 
     #[test]
     fn test_iter_mention_type() {
-        let mut iter = MentionType::iter("<#12><:name:34><@&56><@!78><@90>");
-        assert_eq!(MentionType::Channel(ChannelId(12)), iter.next().unwrap().0);
-        assert_eq!(MentionType::Emoji(EmojiId(34)), iter.next().unwrap().0);
-        assert_eq!(MentionType::Role(RoleId(56)), iter.next().unwrap().0);
-        assert_eq!(MentionType::User(UserId(78)), iter.next().unwrap().0);
-        assert_eq!(MentionType::User(UserId(90)), iter.next().unwrap().0);
+        let mut iter = MentionType::iter("<#12></name:23><:name:34><@&56><@!78><@90>");
+        assert_eq!(
+            MentionType::Channel(ChannelId::new(12)),
+            iter.next().unwrap().0
+        );
+        assert_eq!(
+            MentionType::Command(CommandId::new(23)),
+            iter.next().unwrap().0
+        );
+        assert_eq!(MentionType::Emoji(EmojiId::new(34)), iter.next().unwrap().0);
+        assert_eq!(MentionType::Role(RoleId::new(56)), iter.next().unwrap().0);
+        assert_eq!(MentionType::User(UserId::new(78)), iter.next().unwrap().0);
+        assert_eq!(MentionType::User(UserId::new(90)), iter.next().unwrap().0);
         assert!(iter.next().is_none());
     }
 
     #[test]
     fn test_iter_role_ids() {
         let mut iter = RoleId::iter("some <@&123> roles <@&456>");
-        assert_eq!(RoleId(123), iter.next().unwrap().0);
-        assert_eq!(RoleId(456), iter.next().unwrap().0);
+        assert_eq!(RoleId::new(123), iter.next().unwrap().0);
+        assert_eq!(RoleId::new(456), iter.next().unwrap().0);
         assert!(iter.next().is_none());
     }
 
     #[test]
     fn test_iter_user_ids() {
         let mut iter = UserId::iter("some <@123>users<@456>");
-        assert_eq!(UserId(123), iter.next().unwrap().0);
-        assert_eq!(UserId(456), iter.next().unwrap().0);
+        assert_eq!(UserId::new(123), iter.next().unwrap().0);
+        assert_eq!(UserId::new(456), iter.next().unwrap().0);
         assert!(iter.next().is_none());
     }
 
@@ -19,8 +19,8 @@
 //! use twilight_model::id::{ChannelId, EmojiId, RoleId};
 //!
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
-//! assert_eq!(EmojiId(123), EmojiId::parse("<:name:123>")?);
-//! assert_eq!(RoleId(456), RoleId::parse("<@&456>")?);
+//! assert_eq!(EmojiId::new(123), EmojiId::parse("<:name:123>")?);
+//! assert_eq!(RoleId::new(456), RoleId::parse("<@&456>")?);
 //! assert!(ChannelId::parse("<#notamention>").is_err());
 //! # Ok(()) }
 //! ```
@@ -32,8 +32,8 @@
 //! use twilight_model::id::UserId;
 //!
 //! let mut iter = UserId::iter("these <@123> are <#456> mentions <@789>");
-//! assert!(matches!(iter.next(), Some((UserId(123), _, _))));
-//! assert!(matches!(iter.next(), Some((UserId(789), _, _))));
+//! assert_eq!(Some(UserId::new(123)), iter.next().map(|(id, _, _)| id));
+//! assert_eq!(Some(UserId::new(789)), iter.next().map(|(id, _, _)| id));
 //! assert!(iter.next().is_none());
 //! ```
 
@@ -44,7 +44,7 @@ mod iter;
 pub use self::{error::ParseMentionError, iter::MentionIter, r#impl::ParseMention};
 
 use std::fmt::{Display, Formatter, Result as FmtResult};
-use twilight_model::id::{ChannelId, EmojiId, RoleId, UserId};
+use twilight_model::id::{ChannelId, CommandId, EmojiId, RoleId, UserId};
 
 /// Any type of mention.
 ///
@@ -60,9 +60,9 @@ use twilight_model::id::{ChannelId, EmojiId, RoleId, UserId};
 /// use twilight_model::id::{ChannelId, RoleId, UserId};
 ///
 /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-/// assert_eq!(MentionType::Channel(ChannelId(123)), MentionType::parse("<#123>")?);
-/// assert_eq!(MentionType::Role(RoleId(123)), MentionType::parse("<@&123>")?);
-/// assert_eq!(MentionType::User(UserId(123)), MentionType::parse("<@!123>")?);
+/// assert_eq!(MentionType::Channel(ChannelId::new(123)), MentionType::parse("<#123>")?);
+/// assert_eq!(MentionType::Role(RoleId::new(123)), MentionType::parse("<@&123>")?);
+/// assert_eq!(MentionType::User(UserId::new(123)), MentionType::parse("<@!123>")?);
 /// # Ok(()) }
 /// ```
 ///
@@ -70,15 +70,31 @@ use twilight_model::id::{ChannelId, EmojiId, RoleId, UserId};
 ///
 /// ```
 /// use twilight_mention::parse::{MentionType, ParseMention};
-/// use twilight_model::id::{ChannelId, EmojiId, RoleId, UserId};
+/// use twilight_model::id::{ChannelId, CommandId, EmojiId, RoleId, UserId};
 ///
-/// let buf = "channel <#12> emoji <:name:34> role <@&56> user <@78>";
+/// let buf = "channel <#12> command </ping:23> emoji <a:name:34> role <@&56> user <@78>";
 ///
 /// let mut iter = MentionType::iter(buf);
-/// assert!(matches!(iter.next(), Some((MentionType::Channel(ChannelId(12)), _, _))));
-/// assert!(matches!(iter.next(), Some((MentionType::Emoji(EmojiId(34)), _, _))));
-/// assert!(matches!(iter.next(), Some((MentionType::Role(RoleId(56)), _, _))));
-/// assert!(matches!(iter.next(), Some((MentionType::User(UserId(78)), _, _))));
+/// assert_eq!(
+///     Some(MentionType::Channel(ChannelId::new(12))),
+///     iter.next().map(|(mention, _, _)| mention),
+/// );
+/// assert_eq!(
+///     Some(MentionType::Command(CommandId::new(23))),
+///     iter.next().map(|(mention, _, _)| mention),
+/// );
+/// assert_eq!(
+///     Some(MentionType::Emoji(EmojiId::new(34))),
+///     iter.next().map(|(mention, _, _)| mention),
+/// );
+/// assert_eq!(
+///     Some(MentionType::Role(RoleId::new(56))),
+///     iter.next().map(|(mention, _, _)| mention),
+/// );
+/// assert_eq!(
+///     Some(MentionType::User(UserId::new(78))),
+///     iter.next().map(|(mention, _, _)| mention),
+/// );
 /// assert!(iter.next().is_none());
 /// ```
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -86,6 +102,8 @@ use twilight_model::id::{ChannelId, EmojiId, RoleId, UserId};
 pub enum MentionType {
     /// Channel mention.
     Channel(ChannelId),
+    /// Command mention.
+    Command(CommandId),
     /// Emoji mention.
     Emoji(EmojiId),
     /// Role mention.
@@ -98,6 +116,7 @@ impl Display for MentionType {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
             Self::Channel(id) => Display::fmt(id, f),
+            Self::Command(id) => Display::fmt(id, f),
             Self::Emoji(id) => Display::fmt(id, f),
             Self::Role(id) => Display::fmt(id, f),
             Self::User(id) => Display::fmt(id, f),
@@ -0,0 +1,251 @@
+//! Timestamps with the ability to be formatted in clients based on the client's
+//! local timezone and locale.
+//!
+//! Timestamps are formatted as `<t:unix:style>`, where `unix` is the number of
+//! seconds since the Unix epoch and the optional `style` determines how the
+//! client displays it.
+//!
+//! # Examples
+//!
+//! Format a timestamp as a relative time, such as "2 months ago":
+//!
+//! ```
+//! use twilight_mention::{
+//!     timestamp::{Timestamp, TimestampStyle},
+//!     Mention,
+//! };
+//!
+//! let timestamp = Timestamp::new(1_624_047_064, Some(TimestampStyle::RelativeTime));
+//! assert_eq!("<t:1624047064:R>", timestamp.mention().to_string());
+//! ```
+
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    time::{SystemTime, SystemTimeError, UNIX_EPOCH},
+};
+use twilight_model::id::Id;
+
+/// Timestamp representing a time to be formatted based on a client's current
+/// local timezone and locale.
+///
+/// Timestamps can be formatted as [`Mention`]s using [`Mention::mention`].
+///
+/// [`Mention`]: super::fmt::Mention
+/// [`Mention::mention`]: super::fmt::Mention::mention
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Timestamp {
+    /// Display modifier style.
+    style: Option<TimestampStyle>,
+    /// Unix timestamp in seconds.
+    unix: u64,
+}
+
+impl Timestamp {
+    /// Create a new timestamp from a Unix timestamp in seconds, with an
+    /// optional display style.
+    ///
+    /// If no style is provided then clients will default to
+    /// [`TimestampStyle::ShortDateTime`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_mention::timestamp::{Timestamp, TimestampStyle};
+    ///
+    /// let timestamp = Timestamp::new(1_624_047_064, Some(TimestampStyle::LongDate));
+    /// assert_eq!(1_624_047_064, timestamp.unix());
+    /// ```
+    #[must_use]
+    pub const fn new(unix: u64, style: Option<TimestampStyle>) -> Self {
+        Self { style, unix }
+    }
+
+    /// Create a new timestamp from the time an ID was created.
+    ///
+    /// # Examples
+    ///
+    /// Create a timestamp showing how long ago a message was sent:
+    ///
+    /// ```
+    /// use twilight_mention::timestamp::{Timestamp, TimestampStyle};
+    /// use twilight_model::id::MessageId;
+    ///
+    /// let message_id = MessageId::new(123_456_789_012_345_678);
+    /// let timestamp = Timestamp::from_id(message_id, Some(TimestampStyle::RelativeTime));
+    /// assert_eq!(1_449_504_792, timestamp.unix());
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_sign_loss)]
+    pub const fn from_id<T>(id: Id<T>, style: Option<TimestampStyle>) -> Self {
+        // IDs are always after the Discord epoch, so the timestamp can't be
+        // negative.
+        Self::new(id.timestamp() as u64 / 1000, style)
+    }
+
+    /// Create a new timestamp from a system time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::SystemTime;
+    /// use twilight_mention::timestamp::Timestamp;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let timestamp = Timestamp::from_system_time(SystemTime::now(), None)?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SystemTimeError`] if the time is before the Unix epoch.
+    pub fn from_system_time(
+        time: SystemTime,
+        style: Option<TimestampStyle>,
+    ) -> Result<Self, SystemTimeError> {
+        let duration = time.duration_since(UNIX_EPOCH)?;
+
+        Ok(Self::new(duration.as_secs(), style))
+    }
+
+    /// Style to display the timestamp as, if any.
+    #[must_use]
+    pub const fn style(&self) -> Option<TimestampStyle> {
+        self.style
+    }
+
+    /// Unix timestamp in seconds.
+    #[must_use]
+    pub const fn unix(&self) -> u64 {
+        self.unix
+    }
+}
+
+/// Style modifier denoting how to display a timestamp.
+///
+/// The examples of each style are for the time of June 18th, 2021 at 20:11:04
+/// in an English locale.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum TimestampStyle {
+    /// Style modifier to display a timestamp as a long date and time, such as
+    /// "Friday, June 18, 2021 8:11 PM".
+    ///
+    /// Formats as `F`.
+    LongDateTime,
+    /// Style modifier to display a timestamp as a long date, such as
+    /// "June 18, 2021".
+    ///
+    /// Formats as `D`.
+    LongDate,
+    /// Style modifier to display a timestamp as a long time, such as
+    /// "8:11:04 PM".
+    ///
+    /// Formats as `T`.
+    LongTime,
+    /// Style modifier to display a timestamp relative to the current time,
+    /// such as "2 months ago".
+    ///
+    /// Formats as `R`.
+    RelativeTime,
+    /// Style modifier to display a timestamp as a short date and time, such as
+    /// "June 18, 2021 8:11 PM".
+    ///
+    /// This is the default style when one isn't specified.
+    ///
+    /// Formats as `f`.
+    ShortDateTime,
+    /// Style modifier to display a timestamp as a short date, such as
+    /// "06/18/2021".
+    ///
+    /// Formats as `d`.
+    ShortDate,
+    /// Style modifier to display a timestamp as a short time, such as
+    /// "8:11 PM".
+    ///
+    /// Formats as `t`.
+    ShortTime,
+}
+
+impl TimestampStyle {
+    /// Retrieve the display character of a style.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_mention::timestamp::TimestampStyle;
+    ///
+    /// assert_eq!("R", TimestampStyle::RelativeTime.style());
+    /// ```
+    #[must_use]
+    pub const fn style(self) -> &'static str {
+        match self {
+            Self::LongDateTime => "F",
+            Self::LongDate => "D",
+            Self::LongTime => "T",
+            Self::RelativeTime => "R",
+            Self::ShortDateTime => "f",
+            Self::ShortDate => "d",
+            Self::ShortTime => "t",
+        }
+    }
+}
+
+impl Display for TimestampStyle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.style())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Timestamp, TimestampStyle};
+    use static_assertions::assert_impl_all;
+    use std::{
+        fmt::{Debug, Display},
+        hash::Hash,
+        time::{Duration, UNIX_EPOCH},
+    };
+    use twilight_model::id::UserId;
+
+    assert_impl_all!(Timestamp: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+    assert_impl_all!(
+        TimestampStyle: Clone,
+        Copy,
+        Debug,
+        Display,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
+
+    #[test]
+    fn test_timestamp_style() {
+        assert_eq!("F", TimestampStyle::LongDateTime.style());
+        assert_eq!("D", TimestampStyle::LongDate.style());
+        assert_eq!("T", TimestampStyle::LongTime.style());
+        assert_eq!("R", TimestampStyle::RelativeTime.style());
+        assert_eq!("f", TimestampStyle::ShortDateTime.style());
+        assert_eq!("d", TimestampStyle::ShortDate.style());
+        assert_eq!("t", TimestampStyle::ShortTime.style());
+    }
+
+    #[test]
+    fn test_from_id() {
+        let timestamp = Timestamp::from_id(UserId::new(105_484_726_235_607_040), None);
+        assert_eq!(1_445_219_918, timestamp.unix());
+        assert!(timestamp.style().is_none());
+    }
+
+    #[test]
+    fn test_from_system_time() {
+        let time = UNIX_EPOCH + Duration::from_millis(1_624_047_064_500);
+        let timestamp = Timestamp::from_system_time(time, Some(TimestampStyle::ShortTime)).unwrap();
+        assert_eq!(1_624_047_064, timestamp.unix());
+        assert_eq!(Some(TimestampStyle::ShortTime), timestamp.style());
+
+        let before_epoch = UNIX_EPOCH - Duration::from_secs(1);
+        assert!(Timestamp::from_system_time(before_epoch, None).is_err());
+    }
+}
@@ -1,14 +1,85 @@
 use crate::id::AttachmentId;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Conversion of durations to and from a number of seconds.
+///
+/// Durations are stored as a [`Duration`] rather than a float so that types
+/// containing them can implement [`Eq`].
+mod duration_secs {
+    use serde::{
+        de::{Deserialize, Deserializer, Error as DeError},
+        ser::Serializer,
+    };
+    use std::time::Duration;
+
+    /// Number of seconds at which a [`Duration`] overflows.
+    #[allow(clippy::cast_precision_loss)]
+    const MAX_SECS: f64 = u64::MAX as f64;
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Duration>, D::Error> {
+        let secs = match Option::<f64>::deserialize(deserializer)? {
+            Some(secs) => secs,
+            None => return Ok(None),
+        };
+
+        if !secs.is_finite() || secs < 0.0 || secs >= MAX_SECS {
+            return Err(DeError::custom(format_args!(
+                "{} is not a valid number of seconds",
+                secs
+            )));
+        }
+
+        Ok(Some(Duration::from_secs_f64(secs)))
+    }
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<Duration>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(duration) => serializer.serialize_some(&duration.as_secs_f64()),
+            None => serializer.serialize_none(),
+        }
+    }
+}
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct Attachment {
+    /// Description of the file, used as alt text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Duration of the audio file, present on voice messages.
+    ///
+    /// # serde
+    ///
+    /// The duration is (de)serialized as a number of seconds, such as `3.5`.
+    #[serde(
+        default,
+        rename = "duration_secs",
+        skip_serializing_if = "Option::is_none",
+        with = "duration_secs"
+    )]
+    pub duration: Option<Duration>,
+    /// Whether the attachment is ephemeral.
+    ///
+    /// Ephemeral attachments are removed after a set period of time. They are
+    /// present on messages created in response to interactions, and on
+    /// messages that were edited to have an attachment removed.
+    #[serde(default)]
+    pub ephemeral: bool,
     pub filename: String,
     pub height: Option<u64>,
     pub id: AttachmentId,
     pub proxy_url: String,
     pub size: u64,
     pub url: String,
+    /// Base64 encoded bytearray representing a sampled waveform, present on
+    /// voice messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub waveform: Option<String>,
     pub width: Option<u64>,
 }
 
@@ -17,16 +88,21 @@ mod tests {
     use super::Attachment;
     use crate::id::AttachmentId;
     use serde_test::Token;
+    use std::time::Duration;
 
     #[test]
     fn test_attachment() {
         let value = Attachment {
+            description: None,
+            duration: None,
+            ephemeral: false,
             filename: "a.png".to_owned(),
             height: Some(184),
-            id: AttachmentId(700_000_000_000_000_000),
+            id: AttachmentId::new(700_000_000_000_000_000),
             proxy_url: "https://cdn.example.com/1.png".to_owned(),
             size: 13_593,
             url: "https://example.com/1.png".to_owned(),
+            waveform: None,
             width: Some(184),
         };
 
@@ -35,17 +111,17 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Attachment",
-                    len: 7,
+                    len: 8,
                 },
+                Token::Str("ephemeral"),
+                Token::Bool(false),
                 Token::Str("filename"),
                 Token::Str("a.png"),
                 Token::Str("height"),
                 Token::Some,
                 Token::U64(184),
                 Token::Str("id"),
-                Token::NewtypeStruct {
-                    name: "AttachmentId",
-                },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("700000000000000000"),
                 Token::Str("proxy_url"),
                 Token::Str("https://cdn.example.com/1.png"),
@@ -60,4 +136,75 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_attachment_voice_message() {
+        let value = Attachment {
+            description: Some("a voice message".to_owned()),
+            duration: Some(Duration::from_millis(3500)),
+            ephemeral: true,
+            filename: "voice-message.ogg".to_owned(),
+            height: None,
+            id: AttachmentId::new(700_000_000_000_000_000),
+            proxy_url: "https://cdn.example.com/voice-message.ogg".to_owned(),
+            size: 10_000,
+            url: "https://example.com/voice-message.ogg".to_owned(),
+            waveform: Some("AAAA".to_owned()),
+            width: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Attachment",
+                    len: 11,
+                },
+                Token::Str("description"),
+                Token::Some,
+                Token::Str("a voice message"),
+                Token::Str("duration_secs"),
+                Token::Some,
+                Token::F64(3.5),
+                Token::Str("ephemeral"),
+                Token::Bool(true),
+                Token::Str("filename"),
+                Token::Str("voice-message.ogg"),
+                Token::Str("height"),
+                Token::None,
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("700000000000000000"),
+                Token::Str("proxy_url"),
+                Token::Str("https://cdn.example.com/voice-message.ogg"),
+                Token::Str("size"),
+                Token::U64(10_000),
+                Token::Str("url"),
+                Token::Str("https://example.com/voice-message.ogg"),
+                Token::Str("waveform"),
+                Token::Some,
+                Token::Str("AAAA"),
+                Token::Str("width"),
+                Token::None,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_attachment_duration_invalid() {
+        serde_test::assert_de_tokens_error::<Attachment>(
+            &[
+                Token::Struct {
+                    name: "Attachment",
+                    len: 1,
+                },
+                Token::Str("duration_secs"),
+                Token::Some,
+                Token::F64(-1.0),
+                Token::StructEnd,
+            ],
+            "-1 is not a valid number of seconds",
+        );
+    }
 }
@@ -24,8 +24,8 @@ mod tests {
     #[test]
     fn test_category_channel() {
         let value = CategoryChannel {
-            guild_id: Some(GuildId(1)),
-            id: ChannelId(2),
+            guild_id: Some(GuildId::new(1)),
+            id: ChannelId::new(2),
             kind: ChannelType::GuildCategory,
             name: "category".to_owned(),
             permission_overwrites: Vec::new(),
@@ -41,10 +41,10 @@ mod tests {
                 },
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("type"),
                 Token::U8(4),
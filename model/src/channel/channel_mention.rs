@@ -21,8 +21,8 @@ mod tests {
     #[test]
     fn test_channel_mention() {
         let value = ChannelMention {
-            guild_id: GuildId(1),
-            id: ChannelId(2),
+            guild_id: GuildId::new(1),
+            id: ChannelId::new(2),
             kind: ChannelType::GuildText,
             name: "channel".to_owned(),
         };
@@ -35,10 +35,10 @@ mod tests {
                     len: 4,
                 },
                 Token::Str("guild_id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("type"),
                 Token::U8(0),
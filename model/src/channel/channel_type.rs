@@ -12,6 +12,7 @@ pub enum ChannelType {
     GuildCategory = 4,
     GuildNews = 5,
     GuildStore = 6,
+    GuildForum = 15,
 }
 
 impl ChannelType {
@@ -19,6 +20,7 @@ impl ChannelType {
         match self {
             Self::Group => "Group",
             Self::GuildCategory => "GuildCategory",
+            Self::GuildForum => "GuildForum",
             Self::GuildNews => "GuildNews",
             Self::GuildStore => "GuildStore",
             Self::GuildText => "GuildText",
@@ -42,12 +44,14 @@ mod tests {
         serde_test::assert_tokens(&ChannelType::GuildCategory, &[Token::U8(4)]);
         serde_test::assert_tokens(&ChannelType::GuildNews, &[Token::U8(5)]);
         serde_test::assert_tokens(&ChannelType::GuildStore, &[Token::U8(6)]);
+        serde_test::assert_tokens(&ChannelType::GuildForum, &[Token::U8(15)]);
     }
 
     #[test]
     fn test_names() {
         assert_eq!("Group", ChannelType::Group.name());
         assert_eq!("GuildCategory", ChannelType::GuildCategory.name());
+        assert_eq!("GuildForum", ChannelType::GuildForum.name());
         assert_eq!("GuildNews", ChannelType::GuildNews.name());
         assert_eq!("GuildStore", ChannelType::GuildStore.name());
         assert_eq!("GuildText", ChannelType::GuildText.name());
@@ -21,8 +21,8 @@ mod tests {
     #[test]
     fn test_followed_channel() {
         let value = FollowedChannel {
-            channel_id: ChannelId(1),
-            webhook_id: WebhookId(2),
+            channel_id: ChannelId::new(1),
+            webhook_id: WebhookId::new(2),
         };
 
         serde_test::assert_tokens(
@@ -33,10 +33,10 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("channel_id"),
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("webhook_id"),
-                Token::NewtypeStruct { name: "WebhookId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::StructEnd,
             ],
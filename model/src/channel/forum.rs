@@ -0,0 +1,142 @@
+//! Models specific to forum channels.
+
+use crate::id::{EmojiId, TagId};
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Emoji shown in the add reaction button of posts in a forum channel by
+/// default.
+///
+/// Exactly one of the fields is set.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct DefaultReaction {
+    /// ID of the guild's custom emoji.
+    pub emoji_id: Option<EmojiId>,
+    /// Unicode character of the emoji.
+    pub emoji_name: Option<String>,
+}
+
+/// Layout used to display posts in a forum channel by default.
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum ForumLayout {
+    /// No default has been set for the forum channel.
+    NotSet = 0,
+    /// Display posts as a list.
+    ListView = 1,
+    /// Display posts as a collection of tiles.
+    GalleryView = 2,
+}
+
+/// Order used to sort posts in a forum channel by default.
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum ForumSortOrder {
+    /// Sort posts by activity.
+    LatestActivity = 0,
+    /// Sort posts by creation time, from most recent to oldest.
+    CreationDate = 1,
+}
+
+/// Tag that can be applied to posts in a forum channel.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ForumTag {
+    /// ID of the guild's custom emoji.
+    ///
+    /// At most one of `emoji_id` and `emoji_name` is set.
+    pub emoji_id: Option<EmojiId>,
+    /// Unicode character of the emoji.
+    ///
+    /// At most one of `emoji_id` and `emoji_name` is set.
+    pub emoji_name: Option<String>,
+    /// ID of the tag.
+    pub id: TagId,
+    /// Whether the tag can only be added to or removed from posts by members
+    /// with the permission to manage threads.
+    pub moderated: bool,
+    /// Name of the tag.
+    pub name: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DefaultReaction, ForumLayout, ForumSortOrder, ForumTag};
+    use crate::id::{EmojiId, TagId};
+    use serde_test::Token;
+
+    #[test]
+    fn test_default_reaction() {
+        let value = DefaultReaction {
+            emoji_id: None,
+            emoji_name: Some("🙂".to_owned()),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "DefaultReaction",
+                    len: 2,
+                },
+                Token::Str("emoji_id"),
+                Token::None,
+                Token::Str("emoji_name"),
+                Token::Some,
+                Token::Str("🙂"),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_forum_layout() {
+        serde_test::assert_tokens(&ForumLayout::NotSet, &[Token::U8(0)]);
+        serde_test::assert_tokens(&ForumLayout::ListView, &[Token::U8(1)]);
+        serde_test::assert_tokens(&ForumLayout::GalleryView, &[Token::U8(2)]);
+    }
+
+    #[test]
+    fn test_forum_sort_order() {
+        serde_test::assert_tokens(&ForumSortOrder::LatestActivity, &[Token::U8(0)]);
+        serde_test::assert_tokens(&ForumSortOrder::CreationDate, &[Token::U8(1)]);
+    }
+
+    #[test]
+    fn test_forum_tag() {
+        let value = ForumTag {
+            emoji_id: Some(EmojiId::new(1)),
+            emoji_name: None,
+            id: TagId::new(2),
+            moderated: true,
+            name: "question".to_owned(),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ForumTag",
+                    len: 5,
+                },
+                Token::Str("emoji_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("emoji_name"),
+                Token::None,
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::Str("moderated"),
+                Token::Bool(true),
+                Token::Str("name"),
+                Token::Str("question"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
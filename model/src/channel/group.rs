@@ -2,6 +2,7 @@ use crate::{
     channel::ChannelType,
     id::{ApplicationId, ChannelId, MessageId, UserId},
     user::User,
+    util::ImageHash,
 };
 use serde::{Deserialize, Serialize};
 
@@ -9,7 +10,7 @@ use serde::{Deserialize, Serialize};
 pub struct Group {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_id: Option<ApplicationId>,
-    pub icon: Option<String>,
+    pub icon: Option<ImageHash>,
     pub id: ChannelId,
     #[serde(rename = "type")]
     pub kind: ChannelType,
@@ -25,20 +26,20 @@ pub struct Group {
 
 #[cfg(test)]
 mod tests {
-    use super::{ApplicationId, ChannelId, ChannelType, Group, MessageId, UserId};
+    use super::{ApplicationId, ChannelId, ChannelType, Group, ImageHash, MessageId, UserId};
     use serde_test::Token;
 
     #[test]
     fn test_group() {
         let value = Group {
-            application_id: Some(ApplicationId(1)),
-            icon: Some("icon hash".to_owned()),
-            id: ChannelId(2),
+            application_id: Some(ApplicationId::new(1)),
+            icon: Some(ImageHash::parse("1acefe340fafb4ecefae407f3abdb323").unwrap()),
+            id: ChannelId::new(2),
             kind: ChannelType::Group,
-            last_message_id: Some(MessageId(3)),
+            last_message_id: Some(MessageId::new(3)),
             last_pin_timestamp: None,
             name: Some("a group".to_owned()),
-            owner_id: UserId(4),
+            owner_id: UserId::new(4),
             recipients: Vec::new(),
         };
 
@@ -51,27 +52,25 @@ mod tests {
                 },
                 Token::Str("application_id"),
                 Token::Some,
-                Token::NewtypeStruct {
-                    name: "ApplicationId",
-                },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("icon"),
                 Token::Some,
-                Token::Str("icon hash"),
+                Token::Str("1acefe340fafb4ecefae407f3abdb323"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("type"),
                 Token::U8(3),
                 Token::Str("last_message_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "MessageId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("3"),
                 Token::Str("name"),
                 Token::Some,
                 Token::Str("a group"),
                 Token::Str("owner_id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("4"),
                 Token::Str("recipients"),
                 Token::Seq { len: Some(0) },
@@ -84,14 +83,14 @@ mod tests {
     #[test]
     fn test_group_complete() {
         let value = Group {
-            application_id: Some(ApplicationId(1)),
-            icon: Some("icon hash".to_owned()),
-            id: ChannelId(2),
+            application_id: Some(ApplicationId::new(1)),
+            icon: Some(ImageHash::parse("1acefe340fafb4ecefae407f3abdb323").unwrap()),
+            id: ChannelId::new(2),
             kind: ChannelType::Group,
-            last_message_id: Some(MessageId(3)),
+            last_message_id: Some(MessageId::new(3)),
             last_pin_timestamp: Some("123".to_owned()),
             name: Some("a group".to_owned()),
-            owner_id: UserId(4),
+            owner_id: UserId::new(4),
             recipients: Vec::new(),
         };
 
@@ -104,21 +103,19 @@ mod tests {
                 },
                 Token::Str("application_id"),
                 Token::Some,
-                Token::NewtypeStruct {
-                    name: "ApplicationId",
-                },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("icon"),
                 Token::Some,
-                Token::Str("icon hash"),
+                Token::Str("1acefe340fafb4ecefae407f3abdb323"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("type"),
                 Token::U8(3),
                 Token::Str("last_message_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "MessageId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("3"),
                 Token::Str("last_pin_timestamp"),
                 Token::Some,
@@ -127,7 +124,7 @@ mod tests {
                 Token::Some,
                 Token::Str("a group"),
                 Token::Str("owner_id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("4"),
                 Token::Str("recipients"),
                 Token::Seq { len: Some(0) },
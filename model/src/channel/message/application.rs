@@ -1,28 +1,28 @@
-use crate::id::ApplicationId;
+use crate::{id::ApplicationId, util::ImageHash};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct MessageApplication {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cover_image: Option<String>,
+    pub cover_image: Option<ImageHash>,
     pub description: String,
-    pub icon: Option<String>,
+    pub icon: Option<ImageHash>,
     pub id: ApplicationId,
     pub name: String,
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{ApplicationId, MessageApplication};
+    use super::{ApplicationId, ImageHash, MessageApplication};
     use serde_test::Token;
 
     #[test]
     fn test_message_application() {
         let value = MessageApplication {
-            cover_image: Some("cover".to_owned()),
+            cover_image: Some(ImageHash::parse("5f3f2e7b1e1c43f2a0c5e6d8b8e9a7c1").unwrap()),
             description: "a description".to_owned(),
-            icon: Some("an icon".to_owned()),
-            id: ApplicationId(1),
+            icon: Some(ImageHash::parse("1acefe340fafb4ecefae407f3abdb323").unwrap()),
+            id: ApplicationId::new(1),
             name: "application".to_owned(),
         };
 
@@ -35,16 +35,14 @@ mod tests {
                 },
                 Token::Str("cover_image"),
                 Token::Some,
-                Token::Str("cover"),
+                Token::Str("5f3f2e7b1e1c43f2a0c5e6d8b8e9a7c1"),
                 Token::Str("description"),
                 Token::Str("a description"),
                 Token::Str("icon"),
                 Token::Some,
-                Token::Str("an icon"),
+                Token::Str("1acefe340fafb4ecefae407f3abdb323"),
                 Token::Str("id"),
-                Token::NewtypeStruct {
-                    name: "ApplicationId",
-                },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("name"),
                 Token::Str("application"),
@@ -6,11 +6,32 @@ use serde::{
 
 bitflags! {
     pub struct MessageFlags: u64 {
+        /// Message has been published to subscribed channels via channel
+        /// following.
         const CROSSPOSTED = 1;
+        /// Message originated from a message in another channel via channel
+        /// following.
         const IS_CROSSPOST = 1 << 1;
+        /// Embeds of the message won't be included when serializing it.
         const SUPPRESS_EMBEDS = 1 << 2;
+        /// Source message for this crosspost has been deleted via channel
+        /// following.
         const SOURCE_MESSAGE_DELETED = 1 << 3;
+        /// Message came from the urgent message system.
         const URGENT = 1 << 4;
+        /// Message has an associated thread, with the same ID as the message.
+        const HAS_THREAD = 1 << 5;
+        /// Message is only visible to the user who invoked the interaction.
+        const EPHEMERAL = 1 << 6;
+        /// Message is an interaction response and the bot is "thinking".
+        const LOADING = 1 << 7;
+        /// Message failed to mention some roles and add their members to the
+        /// thread.
+        const FAILED_TO_MENTION_SOME_ROLES_IN_THREAD = 1 << 8;
+        /// Message won't trigger push and desktop notifications.
+        const SUPPRESS_NOTIFICATIONS = 1 << 12;
+        /// Message is a voice message.
+        const IS_VOICE_MESSAGE = 1 << 13;
     }
 }
 
@@ -41,5 +62,17 @@ mod tests {
         serde_test::assert_tokens(&MessageFlags::SUPPRESS_EMBEDS, &[Token::U64(1 << 2)]);
         serde_test::assert_tokens(&MessageFlags::SOURCE_MESSAGE_DELETED, &[Token::U64(1 << 3)]);
         serde_test::assert_tokens(&MessageFlags::URGENT, &[Token::U64(1 << 4)]);
+        serde_test::assert_tokens(&MessageFlags::HAS_THREAD, &[Token::U64(1 << 5)]);
+        serde_test::assert_tokens(&MessageFlags::EPHEMERAL, &[Token::U64(1 << 6)]);
+        serde_test::assert_tokens(&MessageFlags::LOADING, &[Token::U64(1 << 7)]);
+        serde_test::assert_tokens(
+            &MessageFlags::FAILED_TO_MENTION_SOME_ROLES_IN_THREAD,
+            &[Token::U64(1 << 8)],
+        );
+        serde_test::assert_tokens(
+            &MessageFlags::SUPPRESS_NOTIFICATIONS,
+            &[Token::U64(1 << 12)],
+        );
+        serde_test::assert_tokens(&MessageFlags::IS_VOICE_MESSAGE, &[Token::U64(1 << 13)]);
     }
 }
@@ -0,0 +1,89 @@
+use super::MessageInteractionType;
+use crate::{id::InteractionId, user::User};
+use serde::{Deserialize, Serialize};
+
+/// Information about the interaction that a message is a response to.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct MessageInteraction {
+    /// ID of the interaction.
+    pub id: InteractionId,
+    /// Type of the interaction.
+    #[serde(rename = "type")]
+    pub kind: MessageInteractionType,
+    /// Name of the application command that was invoked.
+    ///
+    /// For subcommands this includes the names of the parent commands,
+    /// separated by spaces.
+    pub name: String,
+    /// User who invoked the interaction.
+    pub user: User,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MessageInteraction, MessageInteractionType};
+    use crate::{
+        id::{InteractionId, UserId},
+        user::User,
+    };
+    use serde_test::Token;
+
+    #[test]
+    fn test_message_interaction() {
+        let value = MessageInteraction {
+            id: InteractionId::new(1),
+            kind: MessageInteractionType::ApplicationCommand,
+            name: "ping".to_owned(),
+            user: User {
+                avatar: None,
+                bot: false,
+                discriminator: "0001".to_owned(),
+                email: None,
+                flags: None,
+                id: UserId::new(2),
+                locale: None,
+                mfa_enabled: None,
+                name: "test".to_owned(),
+                premium_type: None,
+                public_flags: None,
+                system: None,
+                verified: None,
+            },
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "MessageInteraction",
+                    len: 4,
+                },
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("type"),
+                Token::U8(2),
+                Token::Str("name"),
+                Token::Str("ping"),
+                Token::Str("user"),
+                Token::Struct {
+                    name: "User",
+                    len: 5,
+                },
+                Token::Str("avatar"),
+                Token::None,
+                Token::Str("bot"),
+                Token::Bool(false),
+                Token::Str("discriminator"),
+                Token::Str("0001"),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::Str("username"),
+                Token::Str("test"),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,39 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Type of interaction that a [`MessageInteraction`] was created from.
+///
+/// [`MessageInteraction`]: super::MessageInteraction
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum MessageInteractionType {
+    /// Initial ping from Discord.
+    Ping = 1,
+    /// Invocation of an application command.
+    ApplicationCommand = 2,
+    /// Interaction with a message component.
+    MessageComponent = 3,
+    /// Autocomplete request for an application command option.
+    ApplicationCommandAutocomplete = 4,
+    /// Submission of a modal.
+    ModalSubmit = 5,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MessageInteractionType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&MessageInteractionType::Ping, &[Token::U8(1)]);
+        serde_test::assert_tokens(&MessageInteractionType::ApplicationCommand, &[Token::U8(2)]);
+        serde_test::assert_tokens(&MessageInteractionType::MessageComponent, &[Token::U8(3)]);
+        serde_test::assert_tokens(
+            &MessageInteractionType::ApplicationCommandAutocomplete,
+            &[Token::U8(4)],
+        );
+        serde_test::assert_tokens(&MessageInteractionType::ModalSubmit, &[Token::U8(5)]);
+    }
+}
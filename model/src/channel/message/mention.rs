@@ -2,6 +2,7 @@ use crate::{
     guild::PartialMember,
     id::UserId,
     user::{self, UserFlags},
+    util::ImageHash,
 };
 use serde::{Deserialize, Serialize};
 
@@ -9,7 +10,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct Mention {
     /// Hash of the user's avatar, if any.
-    pub avatar: Option<String>,
+    pub avatar: Option<ImageHash>,
     /// Whether the user is a bot.
     #[serde(default)]
     pub bot: bool,
@@ -44,7 +45,7 @@ mod tests {
             avatar: None,
             bot: false,
             discriminator: "0001".to_owned(),
-            id: UserId(1),
+            id: UserId::new(1),
             member: None,
             name: "foo".to_owned(),
             public_flags: UserFlags::empty(),
@@ -64,7 +65,7 @@ mod tests {
                 Token::Str("discriminator"),
                 Token::Str("0001"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("member"),
                 Token::None,
@@ -83,7 +84,7 @@ mod tests {
             avatar: None,
             bot: false,
             discriminator: "0001".to_owned(),
-            id: UserId(1),
+            id: UserId::new(1),
             member: Some(PartialMember {
                 deaf: false,
                 joined_at: None,
@@ -110,7 +111,7 @@ mod tests {
                 Token::Str("discriminator"),
                 Token::Str("0001"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("member"),
                 Token::Some,
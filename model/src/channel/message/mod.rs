@@ -4,6 +4,8 @@ mod activity;
 mod activity_type;
 mod application;
 mod flags;
+mod interaction;
+mod interaction_type;
 mod kind;
 mod mention;
 mod reaction;
@@ -11,8 +13,9 @@ mod reference;
 
 pub use self::{
     activity::MessageActivity, activity_type::MessageActivityType, application::MessageApplication,
-    flags::MessageFlags, kind::MessageType, mention::Mention, reaction::MessageReaction,
-    reference::MessageReference, sticker::Sticker,
+    flags::MessageFlags, interaction::MessageInteraction, interaction_type::MessageInteractionType,
+    kind::MessageType, mention::Mention, reaction::MessageReaction, reference::MessageReference,
+    sticker::Sticker,
 };
 
 use crate::{
@@ -40,6 +43,9 @@ pub struct Message {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub guild_id: Option<GuildId>,
     pub id: MessageId,
+    /// Information about the interaction this message is a response to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interaction: Option<MessageInteraction>,
     #[serde(rename = "type")]
     pub kind: MessageType,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -81,6 +87,7 @@ mod tests {
         guild::PartialMember,
         id::{ApplicationId, ChannelId, GuildId, MessageId, UserId},
         user::User,
+        util::ImageHash,
     };
     use serde_test::Token;
 
@@ -92,12 +99,12 @@ mod tests {
             application: None,
             attachments: Vec::new(),
             author: User {
-                avatar: Some("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".to_owned()),
+                avatar: Some(ImageHash::parse("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa").unwrap()),
                 bot: false,
                 discriminator: "0001".to_owned(),
                 email: None,
                 flags: None,
-                id: UserId(3),
+                id: UserId::new(3),
                 locale: None,
                 mfa_enabled: None,
                 name: "test".to_owned(),
@@ -106,13 +113,14 @@ mod tests {
                 system: None,
                 verified: None,
             },
-            channel_id: ChannelId(2),
+            channel_id: ChannelId::new(2),
             content: "ping".to_owned(),
             edited_timestamp: None,
             embeds: Vec::new(),
             flags: Some(MessageFlags::empty()),
-            guild_id: Some(GuildId(1)),
-            id: MessageId(4),
+            guild_id: Some(GuildId::new(1)),
+            id: MessageId::new(4),
+            interaction: None,
             kind: MessageType::Regular,
             member: Some(PartialMember {
                 deaf: false,
@@ -131,13 +139,16 @@ mod tests {
             reference: None,
             stickers: vec![Sticker {
                 asset: "foo1".to_owned(),
+                available: None,
                 description: "foo2".to_owned(),
                 format_type: StickerFormatType::Png,
-                id: StickerId(1),
+                guild_id: None,
+                id: StickerId::new(1),
                 name: "sticker name".to_owned(),
-                pack_id: StickerPackId(2),
+                pack_id: Some(StickerPackId::new(2)),
                 preview_asset: None,
                 tags: Some("foo,bar,baz".to_owned()),
+                user: None,
             }],
             referenced_message: None,
             timestamp: "2020-02-02T02:02:02.020000+00:00".to_owned(),
@@ -168,13 +179,13 @@ mod tests {
                 Token::Str("discriminator"),
                 Token::Str("0001"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("3"),
                 Token::Str("username"),
                 Token::Str("test"),
                 Token::StructEnd,
                 Token::Str("channel_id"),
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("content"),
                 Token::Str("ping"),
@@ -188,10 +199,10 @@ mod tests {
                 Token::U64(0),
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "MessageId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("4"),
                 Token::Str("type"),
                 Token::U8(0),
@@ -238,14 +249,13 @@ mod tests {
                 Token::Str("format_type"),
                 Token::U8(1),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "StickerId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("name"),
                 Token::Str("sticker name"),
                 Token::Str("pack_id"),
-                Token::NewtypeStruct {
-                    name: "StickerPackId",
-                },
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("preview_asset"),
                 Token::None,
@@ -272,20 +282,20 @@ mod tests {
                 party_id: None,
             }),
             application: Some(MessageApplication {
-                cover_image: Some("cover".to_owned()),
+                cover_image: Some(ImageHash::parse("5f3f2e7b1e1c43f2a0c5e6d8b8e9a7c1").unwrap()),
                 description: "a description".to_owned(),
-                icon: Some("an icon".to_owned()),
-                id: ApplicationId(1),
+                icon: Some(ImageHash::parse("1acefe340fafb4ecefae407f3abdb323").unwrap()),
+                id: ApplicationId::new(1),
                 name: "application".to_owned(),
             }),
             attachments: Vec::new(),
             author: User {
-                avatar: Some("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".to_owned()),
+                avatar: Some(ImageHash::parse("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa").unwrap()),
                 bot: false,
                 discriminator: "0001".to_owned(),
                 email: None,
                 flags: None,
-                id: UserId(3),
+                id: UserId::new(3),
                 locale: None,
                 mfa_enabled: None,
                 name: "test".to_owned(),
@@ -294,13 +304,14 @@ mod tests {
                 system: None,
                 verified: None,
             },
-            channel_id: ChannelId(2),
+            channel_id: ChannelId::new(2),
             content: "ping".to_owned(),
             edited_timestamp: Some("123".to_owned()),
             embeds: Vec::new(),
             flags: Some(MessageFlags::empty()),
-            guild_id: Some(GuildId(1)),
-            id: MessageId(4),
+            guild_id: Some(GuildId::new(1)),
+            id: MessageId::new(4),
+            interaction: None,
             kind: MessageType::Regular,
             member: Some(PartialMember {
                 deaf: false,
@@ -311,8 +322,8 @@ mod tests {
                 roles: Vec::new(),
             }),
             mention_channels: vec![ChannelMention {
-                guild_id: GuildId(1),
-                id: ChannelId(2),
+                guild_id: GuildId::new(1),
+                id: ChannelId::new(2),
                 kind: ChannelType::GuildText,
                 name: "channel".to_owned(),
             }],
@@ -328,24 +339,27 @@ mod tests {
                 me: true,
             }],
             reference: Some(MessageReference {
-                channel_id: Some(ChannelId(1)),
+                channel_id: Some(ChannelId::new(1)),
                 guild_id: None,
                 message_id: None,
             }),
             stickers: vec![Sticker {
                 asset: "foo1".to_owned(),
+                available: None,
                 description: "foo2".to_owned(),
                 format_type: StickerFormatType::Png,
-                id: StickerId(1),
+                guild_id: None,
+                id: StickerId::new(1),
                 name: "sticker name".to_owned(),
-                pack_id: StickerPackId(2),
+                pack_id: Some(StickerPackId::new(2)),
                 preview_asset: None,
                 tags: Some("foo,bar,baz".to_owned()),
+                user: None,
             }],
             referenced_message: None,
             timestamp: "2020-02-02T02:02:02.020000+00:00".to_owned(),
             tts: false,
-            webhook_id: Some(WebhookId(1)),
+            webhook_id: Some(WebhookId::new(1)),
         };
 
         serde_test::assert_tokens(
@@ -372,16 +386,14 @@ mod tests {
                 },
                 Token::Str("cover_image"),
                 Token::Some,
-                Token::Str("cover"),
+                Token::Str("5f3f2e7b1e1c43f2a0c5e6d8b8e9a7c1"),
                 Token::Str("description"),
                 Token::Str("a description"),
                 Token::Str("icon"),
                 Token::Some,
-                Token::Str("an icon"),
+                Token::Str("1acefe340fafb4ecefae407f3abdb323"),
                 Token::Str("id"),
-                Token::NewtypeStruct {
-                    name: "ApplicationId",
-                },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("name"),
                 Token::Str("application"),
@@ -402,13 +414,13 @@ mod tests {
                 Token::Str("discriminator"),
                 Token::Str("0001"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("3"),
                 Token::Str("username"),
                 Token::Str("test"),
                 Token::StructEnd,
                 Token::Str("channel_id"),
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("content"),
                 Token::Str("ping"),
@@ -423,10 +435,10 @@ mod tests {
                 Token::U64(0),
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "MessageId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("4"),
                 Token::Str("type"),
                 Token::U8(0),
@@ -457,10 +469,10 @@ mod tests {
                     len: 4,
                 },
                 Token::Str("guild_id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("type"),
                 Token::U8(0),
@@ -506,7 +518,7 @@ mod tests {
                 },
                 Token::Str("channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::StructEnd,
                 Token::Str("stickers"),
@@ -522,14 +534,13 @@ mod tests {
                 Token::Str("format_type"),
                 Token::U8(1),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "StickerId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("name"),
                 Token::Str("sticker name"),
                 Token::Str("pack_id"),
-                Token::NewtypeStruct {
-                    name: "StickerPackId",
-                },
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("preview_asset"),
                 Token::None,
@@ -544,7 +555,7 @@ mod tests {
                 Token::Bool(false),
                 Token::Str("webhook_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "WebhookId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::StructEnd,
             ],
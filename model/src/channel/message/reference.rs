@@ -19,7 +19,7 @@ mod tests {
     #[test]
     fn test_minimal() {
         let value = MessageReference {
-            channel_id: Some(ChannelId(1)),
+            channel_id: Some(ChannelId::new(1)),
             guild_id: None,
             message_id: None,
         };
@@ -33,7 +33,7 @@ mod tests {
                 },
                 Token::Str("channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::StructEnd,
             ],
@@ -43,9 +43,9 @@ mod tests {
     #[test]
     fn test_complete() {
         let value = MessageReference {
-            channel_id: Some(ChannelId(1)),
-            guild_id: Some(GuildId(2)),
-            message_id: Some(MessageId(3)),
+            channel_id: Some(ChannelId::new(1)),
+            guild_id: Some(GuildId::new(2)),
+            message_id: Some(MessageId::new(3)),
         };
 
         serde_test::assert_tokens(
@@ -57,15 +57,15 @@ mod tests {
                 },
                 Token::Str("channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("message_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "MessageId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("3"),
                 Token::StructEnd,
             ],
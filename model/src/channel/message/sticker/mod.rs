@@ -12,28 +12,53 @@ pub use self::{
     kind::{StickerFormatType, StickerFormatTypeConversionError},
 };
 
+use crate::{id::GuildId, user::User};
 use serde::{Deserialize, Serialize};
 
-/// Message sticker.
+/// Message or guild sticker.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct Sticker {
     /// Hash of the asset.
+    ///
+    /// Empty for guild stickers.
+    #[serde(default)]
     pub asset: String,
+    /// Whether the sticker is available.
+    ///
+    /// Only present for guild stickers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available: Option<bool>,
     /// Description of the sticker.
     pub description: String,
     /// Format type.
     pub format_type: StickerFormatType,
+    /// ID of the guild the sticker belongs to.
+    ///
+    /// Only present for guild stickers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
     /// Unique ID of the sticker.
     pub id: StickerId,
     /// Name of the sticker.
     pub name: String,
     /// Unique ID of the pack the sticker is in.
-    pub pack_id: StickerPackId,
+    ///
+    /// Only present for stickers in a pack.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pack_id: Option<StickerPackId>,
     /// Hash of the preview asset, if it has one.
     pub preview_asset: Option<String>,
     /// CSV list of tags the sticker is assigned to, if any.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<String>,
+    /// User who uploaded the sticker.
+    ///
+    /// Only present for guild stickers when the current user has the
+    /// [`MANAGE_EMOJIS`] permission.
+    ///
+    /// [`MANAGE_EMOJIS`]: crate::guild::Permissions::MANAGE_EMOJIS
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
 }
 
 #[cfg(test)]
@@ -45,13 +70,16 @@ mod tests {
     fn test_minimal() {
         let value = Sticker {
             asset: "foo1".to_owned(),
+            available: None,
             description: "foo2".to_owned(),
             format_type: StickerFormatType::Png,
-            id: StickerId(1),
+            guild_id: None,
+            id: StickerId::new(1),
             name: "sticker name".to_owned(),
-            pack_id: StickerPackId(2),
+            pack_id: Some(StickerPackId::new(2)),
             preview_asset: None,
             tags: Some("foo,bar,baz".to_owned()),
+            user: None,
         };
 
         serde_test::assert_tokens(
@@ -68,14 +96,13 @@ mod tests {
                 Token::Str("format_type"),
                 Token::U8(1),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "StickerId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("name"),
                 Token::Str("sticker name"),
                 Token::Str("pack_id"),
-                Token::NewtypeStruct {
-                    name: "StickerPackId",
-                },
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("preview_asset"),
                 Token::None,
@@ -1,4 +1,5 @@
 pub mod embed;
+pub mod forum;
 pub mod message;
 pub mod permission_overwrite;
 
@@ -12,6 +13,7 @@ mod private_channel;
 mod reaction;
 mod reaction_type;
 mod text_channel;
+mod video_quality_mode;
 mod voice_channel;
 mod webhook;
 mod webhook_type;
@@ -20,10 +22,11 @@ pub use self::{
     attachment::Attachment, category_channel::CategoryChannel, channel_mention::ChannelMention,
     channel_type::ChannelType, followed_channel::FollowedChannel, group::Group, message::Message,
     private_channel::PrivateChannel, reaction::Reaction, reaction_type::ReactionType,
-    text_channel::TextChannel, voice_channel::VoiceChannel, webhook::Webhook,
-    webhook_type::WebhookType,
+    text_channel::TextChannel, video_quality_mode::VideoQualityMode, voice_channel::VoiceChannel,
+    webhook::Webhook, webhook_type::WebhookType,
 };
 
+use self::forum::{DefaultReaction, ForumLayout, ForumSortOrder};
 use crate::id::{ChannelId, GuildId, MessageId};
 use serde::{
     de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Visitor},
@@ -118,7 +121,11 @@ impl GuildChannel {
 #[derive(Debug, Deserialize)]
 #[serde(field_identifier, rename_all = "snake_case")]
 enum GuildChannelField {
+    AvailableTags,
     Bitrate,
+    DefaultForumLayout,
+    DefaultReactionEmoji,
+    DefaultSortOrder,
     GuildId,
     Id,
     LastMessageId,
@@ -129,9 +136,11 @@ enum GuildChannelField {
     PermissionOverwrites,
     Position,
     RateLimitPerUser,
+    RtcRegion,
     Topic,
     Type,
     UserLimit,
+    VideoQualityMode,
 }
 
 struct GuildChannelVisitor;
@@ -147,13 +156,18 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
     fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> Result<Self::Value, V::Error> {
         const VARIANTS: &[&str] = &[
             "GuildCategory",
+            "GuildForum",
             "GuildNews",
             "GuildStore",
             "GuildText",
             "GuildVoice",
         ];
 
+        let mut available_tags = None;
         let mut bitrate = None;
+        let mut default_forum_layout: Option<Option<ForumLayout>> = None;
+        let mut default_reaction_emoji: Option<Option<DefaultReaction>> = None;
+        let mut default_sort_order: Option<Option<ForumSortOrder>> = None;
         let mut guild_id = None;
         let mut id = None;
         let mut kind = None;
@@ -165,8 +179,10 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
         let mut permission_overwrites = None;
         let mut position = None;
         let mut rate_limit_per_user = None;
+        let mut rtc_region: Option<Option<String>> = None;
         let mut topic: Option<Option<String>> = None;
         let mut user_limit = None;
+        let mut video_quality_mode = None;
 
         let span = tracing::trace_span!("deserializing guild channel");
         let _span_enter = span.enter();
@@ -193,6 +209,13 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
             };
 
             match key {
+                GuildChannelField::AvailableTags => {
+                    if available_tags.is_some() {
+                        return Err(DeError::duplicate_field("available_tags"));
+                    }
+
+                    available_tags = Some(map.next_value()?);
+                }
                 GuildChannelField::Bitrate => {
                     if bitrate.is_some() {
                         return Err(DeError::duplicate_field("bitrate"));
@@ -200,6 +223,27 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
 
                     bitrate = Some(map.next_value()?);
                 }
+                GuildChannelField::DefaultForumLayout => {
+                    if default_forum_layout.is_some() {
+                        return Err(DeError::duplicate_field("default_forum_layout"));
+                    }
+
+                    default_forum_layout = Some(map.next_value()?);
+                }
+                GuildChannelField::DefaultReactionEmoji => {
+                    if default_reaction_emoji.is_some() {
+                        return Err(DeError::duplicate_field("default_reaction_emoji"));
+                    }
+
+                    default_reaction_emoji = Some(map.next_value()?);
+                }
+                GuildChannelField::DefaultSortOrder => {
+                    if default_sort_order.is_some() {
+                        return Err(DeError::duplicate_field("default_sort_order"));
+                    }
+
+                    default_sort_order = Some(map.next_value()?);
+                }
                 GuildChannelField::GuildId => {
                     if guild_id.is_some() {
                         return Err(DeError::duplicate_field("guild_id"));
@@ -277,6 +321,13 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
 
                     rate_limit_per_user = Some(map.next_value()?);
                 }
+                GuildChannelField::RtcRegion => {
+                    if rtc_region.is_some() {
+                        return Err(DeError::duplicate_field("rtc_region"));
+                    }
+
+                    rtc_region = Some(map.next_value()?);
+                }
                 GuildChannelField::Topic => {
                     if topic.is_some() {
                         return Err(DeError::duplicate_field("topic"));
@@ -291,6 +342,13 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
 
                     user_limit = Some(map.next_value()?);
                 }
+                GuildChannelField::VideoQualityMode => {
+                    if video_quality_mode.is_some() {
+                        return Err(DeError::duplicate_field("video_quality_mode"));
+                    }
+
+                    video_quality_mode = Some(map.next_value()?);
+                }
             }
         }
 
@@ -333,8 +391,15 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
             ChannelType::GuildVoice => {
                 let bitrate = bitrate.ok_or_else(|| DeError::missing_field("bitrate"))?;
                 let user_limit = user_limit.ok_or_else(|| DeError::missing_field("user_limit"))?;
+                let rtc_region = rtc_region.unwrap_or_default();
 
-                tracing::trace!(%bitrate, ?user_limit, "handling voice channel");
+                tracing::trace!(
+                    %bitrate,
+                    ?rtc_region,
+                    ?user_limit,
+                    ?video_quality_mode,
+                    "handling voice channel"
+                );
 
                 GuildChannel::Voice(VoiceChannel {
                     id,
@@ -345,22 +410,39 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
                     permission_overwrites,
                     parent_id,
                     position,
+                    rtc_region,
                     user_limit,
+                    video_quality_mode,
                 })
             }
-            ChannelType::GuildNews | ChannelType::GuildStore | ChannelType::GuildText => {
+            ChannelType::GuildForum
+            | ChannelType::GuildNews
+            | ChannelType::GuildStore
+            | ChannelType::GuildText => {
+                let available_tags = available_tags.unwrap_or_default();
+                let default_forum_layout = default_forum_layout.unwrap_or_default();
+                let default_reaction_emoji = default_reaction_emoji.unwrap_or_default();
+                let default_sort_order = default_sort_order.unwrap_or_default();
                 let last_message_id = last_message_id.unwrap_or_default();
                 let last_pin_timestamp = last_pin_timestamp.unwrap_or_default();
                 let topic = topic.unwrap_or_default();
 
                 tracing::trace!(
+                    ?available_tags,
+                    ?default_forum_layout,
+                    ?default_reaction_emoji,
+                    ?default_sort_order,
                     ?last_message_id,
                     ?last_pin_timestamp,
                     ?topic,
-                    "handling news, store, or text channel"
+                    "handling forum, news, store, or text channel"
                 );
 
                 GuildChannel::Text(TextChannel {
+                    available_tags,
+                    default_forum_layout,
+                    default_reaction_emoji,
+                    default_sort_order,
                     id,
                     guild_id,
                     kind,
@@ -390,31 +472,34 @@ impl<'de> Deserialize<'de> for GuildChannel {
 mod tests {
     use super::{
         CategoryChannel, Channel, ChannelType, Group, GuildChannel, PrivateChannel, TextChannel,
-        VoiceChannel,
+        VideoQualityMode, VoiceChannel,
     };
     use crate::{
-        channel::permission_overwrite::PermissionOverwrite,
-        id::{ChannelId, GuildId, MessageId, UserId},
+        channel::{
+            forum::{ForumLayout, ForumSortOrder, ForumTag},
+            permission_overwrite::PermissionOverwrite,
+        },
+        id::{ChannelId, GuildId, MessageId, TagId, UserId},
     };
 
     fn group() -> Group {
         Group {
             application_id: None,
             icon: None,
-            id: ChannelId(123),
+            id: ChannelId::new(123),
             kind: ChannelType::Group,
             last_message_id: None,
             last_pin_timestamp: None,
             name: Some("a group".to_owned()),
-            owner_id: UserId(456),
+            owner_id: UserId::new(456),
             recipients: Vec::new(),
         }
     }
 
     fn guild_category() -> CategoryChannel {
         CategoryChannel {
-            guild_id: Some(GuildId(321)),
-            id: ChannelId(123),
+            guild_id: Some(GuildId::new(321)),
+            id: ChannelId::new(123),
             kind: ChannelType::GuildCategory,
             name: "category".to_owned(),
             permission_overwrites: Vec::new(),
@@ -424,8 +509,12 @@ mod tests {
 
     fn guild_text() -> TextChannel {
         TextChannel {
-            guild_id: Some(GuildId(321)),
-            id: ChannelId(456),
+            available_tags: Vec::new(),
+            default_forum_layout: None,
+            default_reaction_emoji: None,
+            default_sort_order: None,
+            guild_id: Some(GuildId::new(321)),
+            id: ChannelId::new(456),
             kind: ChannelType::GuildText,
             last_message_id: None,
             last_pin_timestamp: None,
@@ -442,20 +531,22 @@ mod tests {
     fn guild_voice() -> VoiceChannel {
         VoiceChannel {
             bitrate: 1000,
-            guild_id: Some(GuildId(321)),
-            id: ChannelId(789),
+            guild_id: Some(GuildId::new(321)),
+            id: ChannelId::new(789),
             kind: ChannelType::GuildVoice,
             name: "voice".to_owned(),
             permission_overwrites: Vec::new(),
             parent_id: None,
             position: 2,
+            rtc_region: None,
             user_limit: None,
+            video_quality_mode: None,
         }
     }
 
     fn private() -> PrivateChannel {
         PrivateChannel {
-            id: ChannelId(234),
+            id: ChannelId::new(234),
             last_message_id: None,
             last_pin_timestamp: None,
             kind: ChannelType::Private,
@@ -465,20 +556,20 @@ mod tests {
 
     #[test]
     fn test_channel_helpers() {
-        assert_eq!(Channel::Group(group()).id(), ChannelId(123));
+        assert_eq!(Channel::Group(group()).id(), ChannelId::new(123));
         assert_eq!(
             Channel::Guild(GuildChannel::Category(guild_category())).id(),
-            ChannelId(123)
+            ChannelId::new(123)
         );
         assert_eq!(
             Channel::Guild(GuildChannel::Text(guild_text())).id(),
-            ChannelId(456)
+            ChannelId::new(456)
         );
         assert_eq!(
             Channel::Guild(GuildChannel::Voice(guild_voice())).id(),
-            ChannelId(789)
+            ChannelId::new(789)
         );
-        assert_eq!(Channel::Private(private()).id(), ChannelId(234));
+        assert_eq!(Channel::Private(private()).id(), ChannelId::new(234));
     }
 
     #[test]
@@ -506,15 +597,15 @@ mod tests {
     fn test_guild_channel_guild_id() {
         assert_eq!(
             GuildChannel::Category(guild_category()).guild_id(),
-            Some(GuildId(321))
+            Some(GuildId::new(321))
         );
         assert_eq!(
             GuildChannel::Text(guild_text()).guild_id(),
-            Some(GuildId(321))
+            Some(GuildId::new(321))
         );
         assert_eq!(
             GuildChannel::Voice(guild_voice()).guild_id(),
-            Some(GuildId(321))
+            Some(GuildId::new(321))
         );
     }
 
@@ -522,10 +613,10 @@ mod tests {
     fn test_guild_channel_id() {
         assert_eq!(
             GuildChannel::Category(guild_category()).id(),
-            ChannelId(123)
+            ChannelId::new(123)
         );
-        assert_eq!(GuildChannel::Text(guild_text()).id(), ChannelId(456));
-        assert_eq!(GuildChannel::Voice(guild_voice()).id(), ChannelId(789));
+        assert_eq!(GuildChannel::Text(guild_text()).id(), ChannelId::new(456));
+        assert_eq!(GuildChannel::Voice(guild_voice()).id(), ChannelId::new(789));
     }
 
     #[test]
@@ -535,6 +626,30 @@ mod tests {
         assert_eq!(GuildChannel::Voice(guild_voice()).name(), "voice");
     }
 
+    #[test]
+    fn test_guild_channel_voice_deserialization() {
+        let input = serde_json::json!({
+            "bitrate": 1000,
+            "guild_id": "321",
+            "id": "789",
+            "name": "voice",
+            "permission_overwrites": [],
+            "position": 2,
+            "rtc_region": null,
+            "type": 2,
+            "user_limit": null,
+            "video_quality_mode": 2,
+        });
+
+        let mut expected = guild_voice();
+        expected.video_quality_mode.replace(VideoQualityMode::Full);
+
+        assert_eq!(
+            GuildChannel::Voice(expected),
+            serde_json::from_value(input).unwrap()
+        );
+    }
+
     // The deserializer for GuildChannel should skip over fields names that
     // it couldn't deserialize.
     #[test]
@@ -567,10 +682,14 @@ mod tests {
         });
 
         let value = GuildChannel::Text(TextChannel {
-            guild_id: Some(GuildId(1)),
-            id: ChannelId(2),
+            available_tags: Vec::new(),
+            default_forum_layout: None,
+            default_reaction_emoji: None,
+            default_sort_order: None,
+            guild_id: Some(GuildId::new(1)),
+            id: ChannelId::new(2),
             kind: ChannelType::GuildText,
-            last_message_id: Some(MessageId(3)),
+            last_message_id: Some(MessageId::new(3)),
             last_pin_timestamp: None,
             name: "hey".to_owned(),
             nsfw: false,
@@ -587,8 +706,8 @@ mod tests {
     #[test]
     fn test_guild_category_channel_deserialization() {
         let value = GuildChannel::Category(CategoryChannel {
-            id: ChannelId(1),
-            guild_id: Some(GuildId(2)),
+            id: ChannelId::new(1),
+            guild_id: Some(GuildId::new(2)),
             kind: ChannelType::GuildCategory,
             name: "foo".to_owned(),
             permission_overwrites: Vec::new(),
@@ -615,15 +734,19 @@ mod tests {
     #[test]
     fn test_guild_news_channel_deserialization() {
         let value = GuildChannel::Text(TextChannel {
-            id: ChannelId(1),
-            guild_id: Some(GuildId(2)),
+            available_tags: Vec::new(),
+            default_forum_layout: None,
+            default_reaction_emoji: None,
+            default_sort_order: None,
+            id: ChannelId::new(1),
+            guild_id: Some(GuildId::new(2)),
             kind: ChannelType::GuildNews,
-            last_message_id: Some(MessageId(4)),
+            last_message_id: Some(MessageId::new(4)),
             last_pin_timestamp: None,
             name: "news".to_owned(),
             nsfw: true,
             permission_overwrites: Vec::new(),
-            parent_id: Some(ChannelId(5)),
+            parent_id: Some(ChannelId::new(5)),
             position: 3,
             rate_limit_per_user: None,
             topic: Some("a news channel".to_owned()),
@@ -651,8 +774,12 @@ mod tests {
     #[test]
     fn test_guild_store_channel_deserialization() {
         let value = GuildChannel::Text(TextChannel {
-            id: ChannelId(1),
-            guild_id: Some(GuildId(2)),
+            available_tags: Vec::new(),
+            default_forum_layout: None,
+            default_reaction_emoji: None,
+            default_sort_order: None,
+            id: ChannelId::new(1),
+            guild_id: Some(GuildId::new(2)),
             kind: ChannelType::GuildStore,
             last_message_id: None,
             last_pin_timestamp: None,
@@ -681,4 +808,58 @@ mod tests {
             .unwrap()
         );
     }
+
+    #[test]
+    fn test_guild_forum_channel_deserialization() {
+        let value = GuildChannel::Text(TextChannel {
+            available_tags: vec![ForumTag {
+                emoji_id: None,
+                emoji_name: Some("❓".to_owned()),
+                id: TagId::new(3),
+                moderated: false,
+                name: "question".to_owned(),
+            }],
+            default_forum_layout: Some(ForumLayout::ListView),
+            default_reaction_emoji: None,
+            default_sort_order: Some(ForumSortOrder::CreationDate),
+            id: ChannelId::new(1),
+            guild_id: Some(GuildId::new(2)),
+            kind: ChannelType::GuildForum,
+            last_message_id: None,
+            last_pin_timestamp: None,
+            name: "forum".to_owned(),
+            nsfw: false,
+            permission_overwrites: Vec::new(),
+            parent_id: None,
+            position: 2,
+            rate_limit_per_user: None,
+            topic: Some("forum guidelines".to_owned()),
+        });
+        let permission_overwrites: Vec<PermissionOverwrite> = Vec::new();
+
+        assert_eq!(
+            value,
+            serde_json::from_value(serde_json::json!({
+                "available_tags": [{
+                    "emoji_id": null,
+                    "emoji_name": "❓",
+                    "id": "3",
+                    "moderated": false,
+                    "name": "question",
+                }],
+                "default_forum_layout": 1,
+                "default_reaction_emoji": null,
+                "default_sort_order": 1,
+                "id": "1",
+                "guild_id": "2",
+                "name": "forum",
+                "nsfw": false,
+                "permission_overwrites": permission_overwrites,
+                "position": 2,
+                "topic": "forum guidelines",
+                "type": ChannelType::GuildForum,
+            }))
+            .unwrap()
+        );
+    }
 }
@@ -51,14 +51,14 @@ impl<'de> Deserialize<'de> for PermissionOverwrite {
 
         let kind = match data.kind {
             PermissionOverwriteTargetType::Member => {
-                let id = UserId(data.id.parse().map_err(DeError::custom)?);
-                tracing::trace!(id = %id.0, kind = ?data.kind);
+                let id = UserId::new(data.id.parse().map_err(DeError::custom)?);
+                tracing::trace!(id = %id, kind = ?data.kind);
 
                 PermissionOverwriteType::Member(id)
             }
             PermissionOverwriteTargetType::Role => {
-                let id = RoleId(data.id.parse().map_err(DeError::custom)?);
-                tracing::trace!(id = %id.0, kind = ?data.kind);
+                let id = RoleId::new(data.id.parse().map_err(DeError::custom)?);
+                tracing::trace!(id = %id, kind = ?data.kind);
 
                 PermissionOverwriteType::Role(id)
             }
@@ -107,7 +107,7 @@ mod tests {
         let overwrite = PermissionOverwrite {
             allow: Permissions::CREATE_INVITE,
             deny: Permissions::KICK_MEMBERS,
-            kind: PermissionOverwriteType::Member(UserId(12_345_678)),
+            kind: PermissionOverwriteType::Member(UserId::new(12_345_678)),
         };
 
         // We can't use serde_test because it doesn't support 128 bit integers.
@@ -25,8 +25,8 @@ mod tests {
     #[test]
     fn test_category_channel() {
         let value = PrivateChannel {
-            id: ChannelId(1),
-            last_message_id: Some(MessageId(2)),
+            id: ChannelId::new(1),
+            last_message_id: Some(MessageId::new(2)),
             last_pin_timestamp: Some("timestamp".to_owned()),
             kind: ChannelType::Private,
             recipients: Vec::new(),
@@ -40,11 +40,11 @@ mod tests {
                     len: 5,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("last_message_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "MessageId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("last_pin_timestamp"),
                 Token::Some,
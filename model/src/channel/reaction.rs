@@ -98,7 +98,7 @@ impl<'de> Visitor<'de> for ReactionVisitor {
                         return Err(DeError::duplicate_field("member"));
                     }
 
-                    let deserializer = OptionalMemberDeserializer::new(GuildId(0));
+                    let deserializer = OptionalMemberDeserializer::new(GuildId::new(0));
 
                     member = map.next_value_seed(deserializer)?;
                 }
@@ -165,6 +165,7 @@ mod tests {
         guild::Member,
         id::{ChannelId, GuildId, MessageId, RoleId, UserId},
         user::User,
+        util::ImageHash,
     };
     use serde_test::Token;
 
@@ -172,27 +173,27 @@ mod tests {
     #[test]
     fn test_reaction_with_member() {
         let value = Reaction {
-            channel_id: ChannelId(2),
+            channel_id: ChannelId::new(2),
             emoji: ReactionType::Unicode {
                 name: "a".to_owned(),
             },
-            guild_id: Some(GuildId(1)),
+            guild_id: Some(GuildId::new(1)),
             member: Some(Member {
                 deaf: false,
-                guild_id: GuildId(1),
-                hoisted_role: Some(RoleId(5)),
+                guild_id: GuildId::new(1),
+                hoisted_role: Some(RoleId::new(5)),
                 joined_at: Some("2020-01-01T00:00:00.000000+00:00".to_owned()),
                 mute: false,
                 nick: Some("typing".to_owned()),
                 premium_since: None,
-                roles: vec![RoleId(5)],
+                roles: vec![RoleId::new(5)],
                 user: User {
-                    avatar: Some("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".to_owned()),
+                    avatar: Some(ImageHash::parse("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa").unwrap()),
                     bot: false,
                     discriminator: "0001".to_owned(),
                     email: None,
                     flags: None,
-                    id: UserId(4),
+                    id: UserId::new(4),
                     locale: None,
                     mfa_enabled: None,
                     name: "test".to_owned(),
@@ -202,8 +203,8 @@ mod tests {
                     verified: None,
                 },
             }),
-            message_id: MessageId(3),
-            user_id: UserId(4),
+            message_id: MessageId::new(3),
+            user_id: UserId::new(4),
         };
 
         serde_test::assert_tokens(
@@ -214,7 +215,7 @@ mod tests {
                     len: 6,
                 },
                 Token::Str("channel_id"),
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("emoji"),
                 Token::Struct {
@@ -226,7 +227,7 @@ mod tests {
                 Token::StructEnd,
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("member"),
                 Token::Some,
@@ -237,11 +238,11 @@ mod tests {
                 Token::Str("deaf"),
                 Token::Bool(false),
                 Token::Str("guild_id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("hoisted_role"),
                 Token::Some,
-                Token::NewtypeStruct { name: "RoleId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("5"),
                 Token::Str("joined_at"),
                 Token::Some,
@@ -253,7 +254,7 @@ mod tests {
                 Token::Str("typing"),
                 Token::Str("roles"),
                 Token::Seq { len: Some(1) },
-                Token::NewtypeStruct { name: "RoleId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("5"),
                 Token::SeqEnd,
                 Token::Str("user"),
@@ -269,17 +270,17 @@ mod tests {
                 Token::Str("discriminator"),
                 Token::Str("0001"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("4"),
                 Token::Str("username"),
                 Token::Str("test"),
                 Token::StructEnd,
                 Token::StructEnd,
                 Token::Str("message_id"),
-                Token::NewtypeStruct { name: "MessageId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("3"),
                 Token::Str("user_id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("4"),
                 Token::StructEnd,
             ],
@@ -289,14 +290,14 @@ mod tests {
     #[test]
     fn test_reaction_without_member() {
         let value = Reaction {
-            channel_id: ChannelId(2),
+            channel_id: ChannelId::new(2),
             emoji: ReactionType::Unicode {
                 name: "a".to_owned(),
             },
             guild_id: None,
             member: None,
-            message_id: MessageId(3),
-            user_id: UserId(4),
+            message_id: MessageId::new(3),
+            user_id: UserId::new(4),
         };
 
         serde_test::assert_tokens(
@@ -307,7 +308,7 @@ mod tests {
                     len: 6,
                 },
                 Token::Str("channel_id"),
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("emoji"),
                 Token::Struct {
@@ -322,10 +323,10 @@ mod tests {
                 Token::Str("member"),
                 Token::None,
                 Token::Str("message_id"),
-                Token::NewtypeStruct { name: "MessageId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("3"),
                 Token::Str("user_id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("4"),
                 Token::StructEnd,
             ],
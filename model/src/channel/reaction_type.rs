@@ -31,7 +31,7 @@ mod tests {
     fn test_custom() {
         let value = ReactionType::Custom {
             animated: false,
-            id: EmojiId(1337),
+            id: EmojiId::new(1337),
             name: Some("foo".to_owned()),
         };
 
@@ -45,7 +45,7 @@ mod tests {
                 Token::Str("animated"),
                 Token::Bool(false),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "EmojiId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1337"),
                 Token::Str("name"),
                 Token::Some,
@@ -64,7 +64,7 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "EmojiId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1337"),
                 Token::Str("name"),
                 Token::Some,
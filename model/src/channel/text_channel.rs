@@ -1,11 +1,32 @@
 use crate::{
-    channel::{permission_overwrite::PermissionOverwrite, ChannelType},
+    channel::{
+        forum::{DefaultReaction, ForumLayout, ForumSortOrder, ForumTag},
+        permission_overwrite::PermissionOverwrite,
+        ChannelType,
+    },
     id::{ChannelId, GuildId, MessageId},
 };
 use serde::{Deserialize, Serialize};
 
+/// Text-based guild channel.
+///
+/// This represents news, store, text, and forum channels. Fields specific to
+/// forum channels are empty for other types of channels.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct TextChannel {
+    /// Tags that can be applied to posts in a forum channel.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub available_tags: Vec<ForumTag>,
+    /// Default layout used to display posts in a forum channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_forum_layout: Option<ForumLayout>,
+    /// Default emoji shown in the add reaction button of posts in a forum
+    /// channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_reaction_emoji: Option<DefaultReaction>,
+    /// Default order used to sort posts in a forum channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_sort_order: Option<ForumSortOrder>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub guild_id: Option<GuildId>,
     pub id: ChannelId,
@@ -36,8 +57,12 @@ mod tests {
     #[test]
     fn test_text_channel() {
         let value = TextChannel {
-            id: ChannelId(1),
-            guild_id: Some(GuildId(2)),
+            available_tags: Vec::new(),
+            default_forum_layout: None,
+            default_reaction_emoji: None,
+            default_sort_order: None,
+            id: ChannelId::new(1),
+            guild_id: Some(GuildId::new(2)),
             kind: ChannelType::GuildText,
             last_message_id: None,
             last_pin_timestamp: None,
@@ -59,10 +84,10 @@ mod tests {
                 },
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("type"),
                 Token::U8(0),
@@ -89,15 +114,19 @@ mod tests {
     #[test]
     fn test_text_channel_complete() {
         let value = TextChannel {
-            id: ChannelId(1),
-            guild_id: Some(GuildId(2)),
+            available_tags: Vec::new(),
+            default_forum_layout: None,
+            default_reaction_emoji: None,
+            default_sort_order: None,
+            id: ChannelId::new(1),
+            guild_id: Some(GuildId::new(2)),
             kind: ChannelType::GuildText,
-            last_message_id: Some(MessageId(3)),
+            last_message_id: Some(MessageId::new(3)),
             last_pin_timestamp: Some("123".to_owned()),
             name: "foo".to_owned(),
             nsfw: true,
             permission_overwrites: Vec::new(),
-            parent_id: Some(ChannelId(4)),
+            parent_id: Some(ChannelId::new(4)),
             position: 3,
             rate_limit_per_user: Some(10),
             topic: Some("a topic".to_owned()),
@@ -112,16 +141,16 @@ mod tests {
                 },
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("type"),
                 Token::U8(0),
                 Token::Str("last_message_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "MessageId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("3"),
                 Token::Str("last_pin_timestamp"),
                 Token::Some,
@@ -135,7 +164,7 @@ mod tests {
                 Token::SeqEnd,
                 Token::Str("parent_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("4"),
                 Token::Str("position"),
                 Token::I64(3),
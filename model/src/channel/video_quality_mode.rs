@@ -0,0 +1,36 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Camera video quality mode of a voice channel.
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum VideoQualityMode {
+    /// Discord chooses the quality for optimal performance.
+    Auto = 1,
+    /// 720p.
+    Full = 2,
+}
+
+impl Default for VideoQualityMode {
+    fn default() -> Self {
+        Self::Auto
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VideoQualityMode;
+    use serde_test::Token;
+
+    #[test]
+    fn test_default() {
+        assert_eq!(VideoQualityMode::Auto, VideoQualityMode::default());
+    }
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&VideoQualityMode::Auto, &[Token::U8(1)]);
+        serde_test::assert_tokens(&VideoQualityMode::Full, &[Token::U8(2)]);
+    }
+}
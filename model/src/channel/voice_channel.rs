@@ -1,5 +1,5 @@
 use crate::{
-    channel::{permission_overwrite::PermissionOverwrite, ChannelType},
+    channel::{permission_overwrite::PermissionOverwrite, ChannelType, VideoQualityMode},
     id::{ChannelId, GuildId},
 };
 use serde::{Deserialize, Serialize};
@@ -17,27 +17,39 @@ pub struct VoiceChannel {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parent_id: Option<ChannelId>,
     pub position: i64,
+    /// ID of the voice region of the channel.
+    ///
+    /// `None` if the region is chosen automatically.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rtc_region: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_limit: Option<u64>,
+    /// Camera video quality mode of the channel.
+    ///
+    /// `None` is equivalent to [`VideoQualityMode::Auto`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_quality_mode: Option<VideoQualityMode>,
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{ChannelId, ChannelType, GuildId, VoiceChannel};
+    use super::{ChannelId, ChannelType, GuildId, VideoQualityMode, VoiceChannel};
     use serde_test::Token;
 
     #[test]
     fn test_voice_channel() {
         let value = VoiceChannel {
-            id: ChannelId(1),
+            id: ChannelId::new(1),
             bitrate: 124_000,
-            guild_id: Some(GuildId(2)),
+            guild_id: Some(GuildId::new(2)),
             kind: ChannelType::GuildVoice,
             name: "foo".to_owned(),
             permission_overwrites: Vec::new(),
             parent_id: None,
             position: 3,
+            rtc_region: None,
             user_limit: Some(7),
+            video_quality_mode: None,
         };
 
         serde_test::assert_tokens(
@@ -51,10 +63,10 @@ mod tests {
                 Token::U64(124_000),
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("type"),
                 Token::U8(2),
@@ -76,15 +88,17 @@ mod tests {
     #[test]
     fn test_voice_channel_complete() {
         let value = VoiceChannel {
-            id: ChannelId(1),
+            id: ChannelId::new(1),
             bitrate: 124_000,
-            guild_id: Some(GuildId(2)),
+            guild_id: Some(GuildId::new(2)),
             kind: ChannelType::GuildVoice,
             name: "foo".to_owned(),
             permission_overwrites: Vec::new(),
-            parent_id: Some(ChannelId(3)),
+            parent_id: Some(ChannelId::new(3)),
             position: 3,
+            rtc_region: Some("us-east".to_owned()),
             user_limit: Some(7),
+            video_quality_mode: Some(VideoQualityMode::Full),
         };
 
         serde_test::assert_tokens(
@@ -92,16 +106,16 @@ mod tests {
             &[
                 Token::Struct {
                     name: "VoiceChannel",
-                    len: 9,
+                    len: 11,
                 },
                 Token::Str("bitrate"),
                 Token::U64(124_000),
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("type"),
                 Token::U8(2),
@@ -112,13 +126,19 @@ mod tests {
                 Token::SeqEnd,
                 Token::Str("parent_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("3"),
                 Token::Str("position"),
                 Token::I64(3),
+                Token::Str("rtc_region"),
+                Token::Some,
+                Token::Str("us-east"),
                 Token::Str("user_limit"),
                 Token::Some,
                 Token::U64(7),
+                Token::Str("video_quality_mode"),
+                Token::Some,
+                Token::U8(2),
                 Token::StructEnd,
             ],
         );
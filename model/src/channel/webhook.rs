@@ -2,12 +2,13 @@ use crate::{
     channel::WebhookType,
     id::{ChannelId, GuildId, WebhookId},
     user::User,
+    util::ImageHash,
 };
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct Webhook {
-    pub avatar: Option<String>,
+    pub avatar: Option<ImageHash>,
     pub channel_id: ChannelId,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub guild_id: Option<GuildId>,
@@ -23,17 +24,17 @@ pub struct Webhook {
 
 #[cfg(test)]
 mod tests {
-    use super::{ChannelId, GuildId, User, Webhook, WebhookId, WebhookType};
+    use super::{ChannelId, GuildId, ImageHash, User, Webhook, WebhookId, WebhookType};
     use crate::id::UserId;
     use serde_test::Token;
 
     #[test]
     fn test_webhook() {
         let value = Webhook {
-            avatar: Some("avatar".to_owned()),
-            channel_id: ChannelId(1),
-            guild_id: Some(GuildId(2)),
-            id: WebhookId(3),
+            avatar: Some(ImageHash::parse("5bd5c7c3f2a4ab7d2e3a1b9c9a4d3e2f").unwrap()),
+            channel_id: ChannelId::new(1),
+            guild_id: Some(GuildId::new(2)),
+            id: WebhookId::new(3),
             kind: WebhookType::Incoming,
             name: Some("a webhook".to_owned()),
             token: Some("a token".to_owned()),
@@ -49,16 +50,16 @@ mod tests {
                 },
                 Token::Str("avatar"),
                 Token::Some,
-                Token::Str("avatar"),
+                Token::Str("5bd5c7c3f2a4ab7d2e3a1b9c9a4d3e2f"),
                 Token::Str("channel_id"),
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "WebhookId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("3"),
                 Token::Str("type"),
                 Token::U8(1),
@@ -76,10 +77,10 @@ mod tests {
     #[test]
     fn test_webhook_complete() {
         let value = Webhook {
-            avatar: Some("avatar".to_owned()),
-            channel_id: ChannelId(1),
-            guild_id: Some(GuildId(2)),
-            id: WebhookId(3),
+            avatar: Some(ImageHash::parse("5bd5c7c3f2a4ab7d2e3a1b9c9a4d3e2f").unwrap()),
+            channel_id: ChannelId::new(1),
+            guild_id: Some(GuildId::new(2)),
+            id: WebhookId::new(3),
             kind: WebhookType::Incoming,
             name: Some("a webhook".to_owned()),
             token: Some("a token".to_owned()),
@@ -89,7 +90,7 @@ mod tests {
                 discriminator: "0001".to_owned(),
                 email: None,
                 flags: None,
-                id: UserId(2),
+                id: UserId::new(2),
                 locale: None,
                 mfa_enabled: None,
                 name: "test".to_owned(),
@@ -109,16 +110,16 @@ mod tests {
                 },
                 Token::Str("avatar"),
                 Token::Some,
-                Token::Str("avatar"),
+                Token::Str("5bd5c7c3f2a4ab7d2e3a1b9c9a4d3e2f"),
                 Token::Str("channel_id"),
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "WebhookId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("3"),
                 Token::Str("type"),
                 Token::U8(1),
@@ -141,7 +142,7 @@ mod tests {
                 Token::Str("discriminator"),
                 Token::Str("0001"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("username"),
                 Token::Str("test"),
@@ -42,6 +42,38 @@ pub enum CloseCode {
     DisallowedIntents = 4014,
 }
 
+impl CloseCode {
+    /// Whether the shard can reconnect after being closed with this code.
+    ///
+    /// Codes such as [`AuthenticationFailed`] and [`ShardingRequired`] are
+    /// caused by the shard's configuration and will happen again if the shard
+    /// reconnects, while other codes are transient.
+    ///
+    /// [`AuthenticationFailed`]: Self::AuthenticationFailed
+    /// [`ShardingRequired`]: Self::ShardingRequired
+    pub const fn can_reconnect(self) -> bool {
+        !self.is_fatal()
+    }
+
+    /// Whether the close code is caused by the shard's configuration and
+    /// requires user intervention to fix.
+    ///
+    /// This is the inverse of [`can_reconnect`].
+    ///
+    /// [`can_reconnect`]: Self::can_reconnect
+    pub const fn is_fatal(self) -> bool {
+        matches!(
+            self,
+            Self::AuthenticationFailed
+                | Self::InvalidShard
+                | Self::ShardingRequired
+                | Self::InvalidApiVersion
+                | Self::InvalidIntents
+                | Self::DisallowedIntents
+        )
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct CloseCodeConversionError {
     code: u16,
@@ -115,6 +147,21 @@ mod tests {
         serde_test::assert_tokens(&CloseCode::DisallowedIntents, &[Token::U16(4014)]);
     }
 
+    #[test]
+    fn test_can_reconnect() {
+        assert!(CloseCode::UnknownError.can_reconnect());
+        assert!(CloseCode::InvalidSequence.can_reconnect());
+        assert!(CloseCode::SessionTimedOut.can_reconnect());
+        assert!(!CloseCode::AuthenticationFailed.can_reconnect());
+        assert!(!CloseCode::InvalidShard.can_reconnect());
+        assert!(!CloseCode::ShardingRequired.can_reconnect());
+        assert!(!CloseCode::InvalidApiVersion.can_reconnect());
+        assert!(!CloseCode::InvalidIntents.can_reconnect());
+        assert!(!CloseCode::DisallowedIntents.can_reconnect());
+        assert!(CloseCode::DisallowedIntents.is_fatal());
+        assert!(!CloseCode::RateLimited.is_fatal());
+    }
+
     #[test]
     fn test_conversion() {
         assert_eq!(CloseCode::try_from(4000).unwrap(), CloseCode::UnknownError);
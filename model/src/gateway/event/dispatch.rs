@@ -15,6 +15,10 @@ use std::convert::TryFrom;
 #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum DispatchEvent {
+    AutoModerationActionExecution(AutoModerationActionExecution),
+    AutoModerationRuleCreate(AutoModerationRuleCreate),
+    AutoModerationRuleDelete(AutoModerationRuleDelete),
+    AutoModerationRuleUpdate(AutoModerationRuleUpdate),
     BanAdd(BanAdd),
     BanRemove(BanRemove),
     ChannelCreate(ChannelCreate),
@@ -26,6 +30,12 @@ pub enum DispatchEvent {
     GuildDelete(Box<GuildDelete>),
     GuildEmojisUpdate(GuildEmojisUpdate),
     GuildIntegrationsUpdate(GuildIntegrationsUpdate),
+    GuildScheduledEventCreate(Box<GuildScheduledEventCreate>),
+    GuildScheduledEventDelete(Box<GuildScheduledEventDelete>),
+    GuildScheduledEventUpdate(Box<GuildScheduledEventUpdate>),
+    GuildScheduledEventUserAdd(GuildScheduledEventUserAdd),
+    GuildScheduledEventUserRemove(GuildScheduledEventUserRemove),
+    GuildStickersUpdate(GuildStickersUpdate),
     GuildUpdate(Box<GuildUpdate>),
     InviteCreate(Box<InviteCreate>),
     InviteDelete(InviteDelete),
@@ -60,6 +70,10 @@ impl DispatchEvent {
     /// Returns the type of event that this event is.
     pub fn kind(&self) -> EventType {
         match self {
+            Self::AutoModerationActionExecution(_) => EventType::AutoModerationActionExecution,
+            Self::AutoModerationRuleCreate(_) => EventType::AutoModerationRuleCreate,
+            Self::AutoModerationRuleDelete(_) => EventType::AutoModerationRuleDelete,
+            Self::AutoModerationRuleUpdate(_) => EventType::AutoModerationRuleUpdate,
             Self::BanAdd(_) => EventType::BanAdd,
             Self::BanRemove(_) => EventType::BanRemove,
             Self::ChannelCreate(_) => EventType::ChannelCreate,
@@ -71,6 +85,12 @@ impl DispatchEvent {
             Self::GuildDelete(_) => EventType::GuildDelete,
             Self::GuildEmojisUpdate(_) => EventType::GuildEmojisUpdate,
             Self::GuildIntegrationsUpdate(_) => EventType::GuildIntegrationsUpdate,
+            Self::GuildScheduledEventCreate(_) => EventType::GuildScheduledEventCreate,
+            Self::GuildScheduledEventDelete(_) => EventType::GuildScheduledEventDelete,
+            Self::GuildScheduledEventUpdate(_) => EventType::GuildScheduledEventUpdate,
+            Self::GuildScheduledEventUserAdd(_) => EventType::GuildScheduledEventUserAdd,
+            Self::GuildScheduledEventUserRemove(_) => EventType::GuildScheduledEventUserRemove,
+            Self::GuildStickersUpdate(_) => EventType::GuildStickersUpdate,
             Self::GuildUpdate(_) => EventType::GuildUpdate,
             Self::InviteCreate(_) => EventType::InviteCreate,
             Self::InviteDelete(_) => EventType::InviteDelete,
@@ -108,6 +128,10 @@ impl TryFrom<Event> for DispatchEvent {
 
     fn try_from(event: Event) -> Result<Self, Self::Error> {
         Ok(match event {
+            Event::AutoModerationActionExecution(v) => Self::AutoModerationActionExecution(v),
+            Event::AutoModerationRuleCreate(v) => Self::AutoModerationRuleCreate(v),
+            Event::AutoModerationRuleDelete(v) => Self::AutoModerationRuleDelete(v),
+            Event::AutoModerationRuleUpdate(v) => Self::AutoModerationRuleUpdate(v),
             Event::BanAdd(v) => Self::BanAdd(v),
             Event::BanRemove(v) => Self::BanRemove(v),
             Event::ChannelCreate(v) => Self::ChannelCreate(v),
@@ -119,6 +143,12 @@ impl TryFrom<Event> for DispatchEvent {
             Event::GuildDelete(v) => Self::GuildDelete(v),
             Event::GuildEmojisUpdate(v) => Self::GuildEmojisUpdate(v),
             Event::GuildIntegrationsUpdate(v) => Self::GuildIntegrationsUpdate(v),
+            Event::GuildScheduledEventCreate(v) => Self::GuildScheduledEventCreate(v),
+            Event::GuildScheduledEventDelete(v) => Self::GuildScheduledEventDelete(v),
+            Event::GuildScheduledEventUpdate(v) => Self::GuildScheduledEventUpdate(v),
+            Event::GuildScheduledEventUserAdd(v) => Self::GuildScheduledEventUserAdd(v),
+            Event::GuildScheduledEventUserRemove(v) => Self::GuildScheduledEventUserRemove(v),
+            Event::GuildStickersUpdate(v) => Self::GuildStickersUpdate(v),
             Event::GuildUpdate(v) => Self::GuildUpdate(v),
             Event::InviteCreate(v) => Self::InviteCreate(v),
             Event::InviteDelete(v) => Self::InviteDelete(v),
@@ -172,6 +202,18 @@ impl<'de, 'a> DeserializeSeed<'de> for DispatchEventWithTypeDeserializer<'a> {
     #[allow(clippy::too_many_lines)]
     fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
         Ok(match self.0 {
+            "AUTO_MODERATION_ACTION_EXECUTION" => DispatchEvent::AutoModerationActionExecution(
+                AutoModerationActionExecution::deserialize(deserializer)?,
+            ),
+            "AUTO_MODERATION_RULE_CREATE" => DispatchEvent::AutoModerationRuleCreate(
+                AutoModerationRuleCreate::deserialize(deserializer)?,
+            ),
+            "AUTO_MODERATION_RULE_DELETE" => DispatchEvent::AutoModerationRuleDelete(
+                AutoModerationRuleDelete::deserialize(deserializer)?,
+            ),
+            "AUTO_MODERATION_RULE_UPDATE" => DispatchEvent::AutoModerationRuleUpdate(
+                AutoModerationRuleUpdate::deserialize(deserializer)?,
+            ),
             "CHANNEL_CREATE" => {
                 DispatchEvent::ChannelCreate(ChannelCreate::deserialize(deserializer)?)
             }
@@ -224,6 +266,24 @@ impl<'de, 'a> DeserializeSeed<'de> for DispatchEventWithTypeDeserializer<'a> {
             "GUILD_ROLE_UPDATE" => {
                 DispatchEvent::RoleUpdate(RoleUpdate::deserialize(deserializer)?)
             }
+            "GUILD_SCHEDULED_EVENT_CREATE" => DispatchEvent::GuildScheduledEventCreate(Box::new(
+                GuildScheduledEventCreate::deserialize(deserializer)?,
+            )),
+            "GUILD_SCHEDULED_EVENT_DELETE" => DispatchEvent::GuildScheduledEventDelete(Box::new(
+                GuildScheduledEventDelete::deserialize(deserializer)?,
+            )),
+            "GUILD_SCHEDULED_EVENT_UPDATE" => DispatchEvent::GuildScheduledEventUpdate(Box::new(
+                GuildScheduledEventUpdate::deserialize(deserializer)?,
+            )),
+            "GUILD_SCHEDULED_EVENT_USER_ADD" => DispatchEvent::GuildScheduledEventUserAdd(
+                GuildScheduledEventUserAdd::deserialize(deserializer)?,
+            ),
+            "GUILD_SCHEDULED_EVENT_USER_REMOVE" => DispatchEvent::GuildScheduledEventUserRemove(
+                GuildScheduledEventUserRemove::deserialize(deserializer)?,
+            ),
+            "GUILD_STICKERS_UPDATE" => {
+                DispatchEvent::GuildStickersUpdate(GuildStickersUpdate::deserialize(deserializer)?)
+            }
             "GUILD_UPDATE" => {
                 DispatchEvent::GuildUpdate(Box::new(GuildUpdate::deserialize(deserializer)?))
             }
@@ -572,7 +572,7 @@ mod tests {
       "ANIMATED_ICON"
     ],
     "guild_id": "1339",
-    "icon": "foobar",
+    "icon": "1acefe340fafb4ecefae407f3abdb323",
     "id": "13310",
     "max_members": 250000,
     "max_presences": null,
@@ -596,7 +596,7 @@ mod tests {
       }
     ],
     "rules_channel_id": null,
-    "splash": "barbaz",
+    "splash": "0f1e2d3c4b5a69788796a5b4c3d2e1f0",
     "system_channel_flags": 0,
     "system_channel_id": "13313",
     "vanity_url_code": null,
@@ -641,7 +641,7 @@ mod tests {
     "explicit_content_filter": 2,
     "features": [],
     "guild_id": "43",
-    "icon": "44",
+    "icon": "1acefe340fafb4ecefae407f3abdb323",
     "id": "45",
     "max_members": 250000,
     "max_presences": null,
@@ -827,8 +827,8 @@ mod tests {
     #[test]
     fn test_serialize_dispatch() {
         let role_delete = RoleDelete {
-            guild_id: GuildId(1),
-            role_id: RoleId(2),
+            guild_id: GuildId::new(1),
+            role_id: RoleId::new(2),
         };
         let dispatch = Box::new(DispatchEvent::RoleDelete(role_delete));
         let value = GatewayEvent::Dispatch(2_048, dispatch);
@@ -855,10 +855,10 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("guild_id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("role_id"),
-                Token::NewtypeStruct { name: "RoleId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::StructEnd,
                 Token::StructEnd,
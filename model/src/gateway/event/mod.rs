@@ -23,6 +23,14 @@ use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 /// [`GatewayEvent`]s, and [`ShardEvent`]s.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Event {
+    /// An auto moderation rule was triggered and an action was executed.
+    AutoModerationActionExecution(AutoModerationActionExecution),
+    /// An auto moderation rule was created.
+    AutoModerationRuleCreate(AutoModerationRuleCreate),
+    /// An auto moderation rule was deleted.
+    AutoModerationRuleDelete(AutoModerationRuleDelete),
+    /// An auto moderation rule was updated.
+    AutoModerationRuleUpdate(AutoModerationRuleUpdate),
     /// A user was banned from a guild.
     BanAdd(BanAdd),
     /// A user's ban from a guild was removed.
@@ -57,6 +65,18 @@ pub enum Event {
     GuildEmojisUpdate(GuildEmojisUpdate),
     /// A guild's integrations were updated.
     GuildIntegrationsUpdate(GuildIntegrationsUpdate),
+    /// A guild scheduled event was created.
+    GuildScheduledEventCreate(Box<GuildScheduledEventCreate>),
+    /// A guild scheduled event was deleted.
+    GuildScheduledEventDelete(Box<GuildScheduledEventDelete>),
+    /// A guild scheduled event was updated.
+    GuildScheduledEventUpdate(Box<GuildScheduledEventUpdate>),
+    /// A user subscribed to a guild scheduled event.
+    GuildScheduledEventUserAdd(GuildScheduledEventUserAdd),
+    /// A user unsubscribed from a guild scheduled event.
+    GuildScheduledEventUserRemove(GuildScheduledEventUserRemove),
+    /// A guild's stickers were updated.
+    GuildStickersUpdate(GuildStickersUpdate),
     /// A guild was updated.
     GuildUpdate(Box<GuildUpdate>),
     /// A invite was made.
@@ -138,6 +158,10 @@ pub enum Event {
 impl Event {
     pub fn kind(&self) -> EventType {
         match self {
+            Self::AutoModerationActionExecution(_) => EventType::AutoModerationActionExecution,
+            Self::AutoModerationRuleCreate(_) => EventType::AutoModerationRuleCreate,
+            Self::AutoModerationRuleDelete(_) => EventType::AutoModerationRuleDelete,
+            Self::AutoModerationRuleUpdate(_) => EventType::AutoModerationRuleUpdate,
             Self::BanAdd(_) => EventType::BanAdd,
             Self::BanRemove(_) => EventType::BanRemove,
             Self::ChannelCreate(_) => EventType::ChannelCreate,
@@ -154,6 +178,12 @@ impl Event {
             Self::GuildDelete(_) => EventType::GuildDelete,
             Self::GuildEmojisUpdate(_) => EventType::GuildEmojisUpdate,
             Self::GuildIntegrationsUpdate(_) => EventType::GuildIntegrationsUpdate,
+            Self::GuildScheduledEventCreate(_) => EventType::GuildScheduledEventCreate,
+            Self::GuildScheduledEventDelete(_) => EventType::GuildScheduledEventDelete,
+            Self::GuildScheduledEventUpdate(_) => EventType::GuildScheduledEventUpdate,
+            Self::GuildScheduledEventUserAdd(_) => EventType::GuildScheduledEventUserAdd,
+            Self::GuildScheduledEventUserRemove(_) => EventType::GuildScheduledEventUserRemove,
+            Self::GuildStickersUpdate(_) => EventType::GuildStickersUpdate,
             Self::GuildUpdate(_) => EventType::GuildUpdate,
             Self::InviteCreate(_) => EventType::InviteCreate,
             Self::InviteDelete(_) => EventType::InviteDelete,
@@ -196,6 +226,12 @@ impl Event {
 impl From<Box<DispatchEvent>> for Event {
     fn from(event: Box<DispatchEvent>) -> Self {
         match *event {
+            DispatchEvent::AutoModerationActionExecution(v) => {
+                Self::AutoModerationActionExecution(v)
+            }
+            DispatchEvent::AutoModerationRuleCreate(v) => Self::AutoModerationRuleCreate(v),
+            DispatchEvent::AutoModerationRuleDelete(v) => Self::AutoModerationRuleDelete(v),
+            DispatchEvent::AutoModerationRuleUpdate(v) => Self::AutoModerationRuleUpdate(v),
             DispatchEvent::BanAdd(v) => Self::BanAdd(v),
             DispatchEvent::BanRemove(v) => Self::BanRemove(v),
             DispatchEvent::ChannelCreate(v) => Self::ChannelCreate(v),
@@ -207,6 +243,14 @@ impl From<Box<DispatchEvent>> for Event {
             DispatchEvent::GuildDelete(v) => Self::GuildDelete(v),
             DispatchEvent::GuildEmojisUpdate(v) => Self::GuildEmojisUpdate(v),
             DispatchEvent::GuildIntegrationsUpdate(v) => Self::GuildIntegrationsUpdate(v),
+            DispatchEvent::GuildScheduledEventCreate(v) => Self::GuildScheduledEventCreate(v),
+            DispatchEvent::GuildScheduledEventDelete(v) => Self::GuildScheduledEventDelete(v),
+            DispatchEvent::GuildScheduledEventUpdate(v) => Self::GuildScheduledEventUpdate(v),
+            DispatchEvent::GuildScheduledEventUserAdd(v) => Self::GuildScheduledEventUserAdd(v),
+            DispatchEvent::GuildScheduledEventUserRemove(v) => {
+                Self::GuildScheduledEventUserRemove(v)
+            }
+            DispatchEvent::GuildStickersUpdate(v) => Self::GuildStickersUpdate(v),
             DispatchEvent::InviteCreate(v) => Self::InviteCreate(v),
             DispatchEvent::InviteDelete(v) => Self::InviteDelete(v),
             DispatchEvent::MemberAdd(v) => Self::MemberAdd(v),
@@ -0,0 +1,114 @@
+use crate::{
+    guild::auto_moderation::{AutoModerationAction, AutoModerationTriggerType},
+    id::{AutoModerationRuleId, ChannelId, GuildId, MessageId, UserId},
+};
+use serde::{Deserialize, Serialize};
+
+/// An [`AutoModerationAction`] was executed because a rule was triggered.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct AutoModerationActionExecution {
+    /// Action which was executed.
+    pub action: AutoModerationAction,
+    /// ID of any system auto moderation message posted as a result of the
+    /// action.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alert_system_message_id: Option<MessageId>,
+    /// ID of the channel in which the content was posted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<ChannelId>,
+    /// Content of the message which triggered the rule.
+    ///
+    /// Empty unless the current application has the message content intent.
+    pub content: String,
+    /// ID of the guild in which the action was executed.
+    pub guild_id: GuildId,
+    /// Substring in the content which triggered the rule.
+    pub matched_content: Option<String>,
+    /// Word or phrase configured in the rule which triggered the rule.
+    pub matched_keyword: Option<String>,
+    /// ID of the message which triggered the rule.
+    ///
+    /// Not present if the message was blocked by the action.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<MessageId>,
+    /// ID of the rule which was triggered.
+    pub rule_id: AutoModerationRuleId,
+    /// Type of content which triggered the rule.
+    pub rule_trigger_type: AutoModerationTriggerType,
+    /// ID of the user which generated the content.
+    pub user_id: UserId,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AutoModerationActionExecution;
+    use crate::{
+        guild::auto_moderation::{
+            AutoModerationAction, AutoModerationActionType, AutoModerationTriggerType,
+        },
+        id::{AutoModerationRuleId, ChannelId, GuildId, UserId},
+    };
+    use serde_test::Token;
+
+    #[test]
+    fn test_action_execution() {
+        let value = AutoModerationActionExecution {
+            action: AutoModerationAction {
+                metadata: None,
+                kind: AutoModerationActionType::BlockMessage,
+            },
+            alert_system_message_id: None,
+            channel_id: Some(ChannelId::new(1)),
+            content: "bad".to_owned(),
+            guild_id: GuildId::new(2),
+            matched_content: Some("bad".to_owned()),
+            matched_keyword: Some("bad".to_owned()),
+            message_id: None,
+            rule_id: AutoModerationRuleId::new(3),
+            rule_trigger_type: AutoModerationTriggerType::Keyword,
+            user_id: UserId::new(4),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AutoModerationActionExecution",
+                    len: 9,
+                },
+                Token::Str("action"),
+                Token::Struct {
+                    name: "AutoModerationAction",
+                    len: 1,
+                },
+                Token::Str("type"),
+                Token::U8(1),
+                Token::StructEnd,
+                Token::Str("channel_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("content"),
+                Token::Str("bad"),
+                Token::Str("guild_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::Str("matched_content"),
+                Token::Some,
+                Token::Str("bad"),
+                Token::Str("matched_keyword"),
+                Token::Some,
+                Token::Str("bad"),
+                Token::Str("rule_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("3"),
+                Token::Str("rule_trigger_type"),
+                Token::U8(1),
+                Token::Str("user_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("4"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -22,7 +22,7 @@ mod tests {
     #[test]
     fn test_guild_delete_available() {
         let expected = GuildDelete {
-            id: GuildId(123),
+            id: GuildId::new(123),
             unavailable: true,
         };
 
@@ -34,7 +34,7 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("123"),
                 Token::Str("unavailable"),
                 Token::Bool(true),
@@ -49,7 +49,7 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("123"),
                 Token::Str("unavailable"),
                 Token::Bool(true),
@@ -61,7 +61,7 @@ mod tests {
     #[test]
     fn test_guild_delete_unavailable() {
         let expected = GuildDelete {
-            id: GuildId(123),
+            id: GuildId::new(123),
             unavailable: false,
         };
 
@@ -73,7 +73,7 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("123"),
                 Token::Str("unavailable"),
                 Token::Bool(false),
@@ -88,7 +88,7 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("123"),
                 Token::Str("unavailable"),
                 Token::Bool(false),
@@ -100,7 +100,7 @@ mod tests {
     #[test]
     fn test_guild_delete_unavailable_null_default() {
         let expected = GuildDelete {
-            id: GuildId(123),
+            id: GuildId::new(123),
             unavailable: false,
         };
 
@@ -112,7 +112,7 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("123"),
                 Token::Str("unavailable"),
                 Token::None,
@@ -0,0 +1,8 @@
+use crate::{channel::message::sticker::Sticker, id::GuildId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct GuildStickersUpdate {
+    pub guild_id: GuildId,
+    pub stickers: Vec<Sticker>,
+}
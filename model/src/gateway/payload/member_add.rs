@@ -32,7 +32,7 @@ mod tests {
     fn test_member_add() {
         let value = MemberAdd(Member {
             deaf: false,
-            guild_id: GuildId(1),
+            guild_id: GuildId::new(1),
             hoisted_role: None,
             joined_at: None,
             mute: false,
@@ -40,7 +40,7 @@ mod tests {
             premium_since: None,
             roles: vec![],
             user: User {
-                id: UserId(2),
+                id: UserId::new(2),
                 avatar: None,
                 bot: false,
                 discriminator: "0987".to_string(),
@@ -67,7 +67,7 @@ mod tests {
                 Token::Str("deaf"),
                 Token::Bool(false),
                 Token::Str("guild_id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("hoisted_role"),
                 Token::None,
@@ -92,7 +92,7 @@ mod tests {
                 Token::Str("discriminator"),
                 Token::Str("0987"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("username"),
                 Token::Str("ab"),
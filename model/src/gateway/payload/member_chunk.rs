@@ -107,7 +107,7 @@ impl<'de> Visitor<'de> for MemberChunkVisitor {
                     // Since the guild ID may not be deserialised yet we'll use
                     // a temporary placeholder value and update it with the real
                     // guild ID after all the fields have been deserialised.
-                    let deserializer = MemberListDeserializer::new(GuildId(0));
+                    let deserializer = MemberListDeserializer::new(GuildId::new(0));
 
                     members = Some(map.next_value_seed(deserializer)?);
                 }
@@ -130,7 +130,7 @@ impl<'de> Visitor<'de> for MemberChunkVisitor {
                         return Err(DeError::duplicate_field("presences"));
                     }
 
-                    let deserializer = PresenceListDeserializer::new(GuildId(0));
+                    let deserializer = PresenceListDeserializer::new(GuildId::new(0));
 
                     presences = Some(map.next_value_seed(deserializer)?);
                 }
@@ -197,6 +197,7 @@ mod tests {
         guild::Member,
         id::{GuildId, RoleId, UserId},
         user::{User, UserFlags},
+        util::ImageHash,
     };
 
     #[allow(clippy::too_many_lines)]
@@ -301,21 +302,21 @@ mod tests {
         let expected = MemberChunk {
             chunk_count: 1,
             chunk_index: 0,
-            guild_id: GuildId(1),
+            guild_id: GuildId::new(1),
             members: {
                 let mut members = Vec::new();
                 members.push(Member {
                     deaf: false,
-                    guild_id: GuildId(1),
-                    hoisted_role: Some(RoleId(6)),
+                    guild_id: GuildId::new(1),
+                    hoisted_role: Some(RoleId::new(6)),
                     joined_at: Some("2020-04-04T04:04:04.000000+00:00".to_owned()),
                     mute: false,
                     nick: Some("chunk".to_owned()),
                     premium_since: None,
-                    roles: vec![RoleId(6), RoleId(7)],
+                    roles: vec![RoleId::new(6), RoleId::new(7)],
                     user: User {
-                        id: UserId(2),
-                        avatar: Some("dddddddddddddddddddddddddddddddd".to_owned()),
+                        id: UserId::new(2),
+                        avatar: Some(ImageHash::parse("dddddddddddddddddddddddddddddddd").unwrap()),
                         bot: true,
                         discriminator: "0001".to_owned(),
                         name: "test".to_owned(),
@@ -331,16 +332,16 @@ mod tests {
                 });
                 members.push(Member {
                     deaf: false,
-                    guild_id: GuildId(1),
-                    hoisted_role: Some(RoleId(6)),
+                    guild_id: GuildId::new(1),
+                    hoisted_role: Some(RoleId::new(6)),
                     joined_at: Some("2020-04-04T04:04:04.000000+00:00".to_owned()),
                     mute: false,
                     nick: Some("chunk".to_owned()),
                     premium_since: None,
-                    roles: vec![RoleId(6)],
+                    roles: vec![RoleId::new(6)],
                     user: User {
-                        id: UserId(3),
-                        avatar: Some("cccccccccccccccccccccccccccccccc".to_owned()),
+                        id: UserId::new(3),
+                        avatar: Some(ImageHash::parse("cccccccccccccccccccccccccccccccc").unwrap()),
                         bot: true,
                         discriminator: "0001".to_owned(),
                         name: "test".to_owned(),
@@ -356,16 +357,16 @@ mod tests {
                 });
                 members.push(Member {
                     deaf: false,
-                    guild_id: GuildId(1),
-                    hoisted_role: Some(RoleId(6)),
+                    guild_id: GuildId::new(1),
+                    hoisted_role: Some(RoleId::new(6)),
                     joined_at: Some("2020-04-04T04:04:04.000000+00:00".to_owned()),
                     mute: false,
                     nick: Some("chunk".to_owned()),
                     premium_since: None,
-                    roles: vec![RoleId(6)],
+                    roles: vec![RoleId::new(6)],
                     user: User {
-                        id: UserId(5),
-                        avatar: Some("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".to_owned()),
+                        id: UserId::new(5),
+                        avatar: Some(ImageHash::parse("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa").unwrap()),
                         bot: false,
                         discriminator: "0001".to_owned(),
                         name: "test".to_owned(),
@@ -381,16 +382,16 @@ mod tests {
                 });
                 members.push(Member {
                     deaf: false,
-                    guild_id: GuildId(1),
-                    hoisted_role: Some(RoleId(6)),
+                    guild_id: GuildId::new(1),
+                    hoisted_role: Some(RoleId::new(6)),
                     joined_at: Some("2020-04-04T04:04:04.000000+00:00".to_owned()),
                     mute: false,
                     nick: Some("chunk".to_owned()),
                     premium_since: None,
-                    roles: vec![RoleId(6)],
+                    roles: vec![RoleId::new(6)],
                     user: User {
-                        id: UserId(6),
-                        avatar: Some("bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb".to_owned()),
+                        id: UserId::new(6),
+                        avatar: Some(ImageHash::parse("bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb").unwrap()),
                         bot: false,
                         discriminator: "0001".to_owned(),
                         name: "test".to_owned(),
@@ -418,9 +419,9 @@ mod tests {
                         mobile: None,
                         web: Some(Status::Online),
                     },
-                    guild_id: GuildId(1),
+                    guild_id: GuildId::new(1),
                     status: Status::Online,
-                    user: UserOrId::UserId { id: UserId(2) },
+                    user: UserOrId::UserId { id: UserId::new(2) },
                 });
                 presences.push(Presence {
                     activities: Vec::new(),
@@ -429,9 +430,9 @@ mod tests {
                         mobile: None,
                         web: Some(Status::Online),
                     },
-                    guild_id: GuildId(1),
+                    guild_id: GuildId::new(1),
                     status: Status::Online,
-                    user: UserOrId::UserId { id: UserId(3) },
+                    user: UserOrId::UserId { id: UserId::new(3) },
                 });
                 presences.push(Presence {
                     activities: Vec::new(),
@@ -440,9 +441,9 @@ mod tests {
                         mobile: None,
                         web: None,
                     },
-                    guild_id: GuildId(1),
+                    guild_id: GuildId::new(1),
                     status: Status::DoNotDisturb,
-                    user: UserOrId::UserId { id: UserId(5) },
+                    user: UserOrId::UserId { id: UserId::new(5) },
                 });
 
                 presences
@@ -17,7 +17,7 @@ pub struct MemberUpdate {
 #[cfg(test)]
 mod tests {
     use super::MemberUpdate;
-    use crate::user::User;
+    use crate::{user::User, util::ImageHash};
     use serde_test::Token;
 
     #[test]
@@ -28,7 +28,7 @@ mod tests {
                 public_flags: None,
                 id: 424_242.into(),
                 discriminator: 1_234.to_string(),
-                avatar: Some("cool image".to_string()),
+                avatar: Some(ImageHash::parse("a_b2a6536641da91a0b59bd66557c56c36").unwrap()),
                 bot: false,
                 email: None,
                 flags: None,
@@ -53,7 +53,7 @@ mod tests {
                     len: 6,
                 },
                 Token::Str("guild_id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1234"),
                 Token::Str("joined_at"),
                 Token::Str("2017-02-27T22:21:50.121000+00:00"),
@@ -72,13 +72,13 @@ mod tests {
                 },
                 Token::Str("avatar"),
                 Token::Some,
-                Token::Str("cool image"),
+                Token::Str("a_b2a6536641da91a0b59bd66557c56c36"),
                 Token::Str("bot"),
                 Token::Bool(false),
                 Token::Str("discriminator"),
                 Token::Str("1234"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("424242"),
                 Token::Str("username"),
                 Token::Str("Twilight Sparkle"),
@@ -4,6 +4,10 @@ pub mod request_guild_members;
 pub mod resume;
 pub mod update_status;
 
+mod auto_moderation_action_execution;
+mod auto_moderation_rule_create;
+mod auto_moderation_rule_delete;
+mod auto_moderation_rule_update;
 mod ban_add;
 mod ban_remove;
 mod channel_create;
@@ -14,6 +18,12 @@ mod guild_create;
 mod guild_delete;
 mod guild_emojis_update;
 mod guild_integrations_update;
+mod guild_scheduled_event_create;
+mod guild_scheduled_event_delete;
+mod guild_scheduled_event_update;
+mod guild_scheduled_event_user_add;
+mod guild_scheduled_event_user_remove;
+mod guild_stickers_update;
 mod guild_update;
 mod heartbeat;
 mod invite_create;
@@ -43,20 +53,30 @@ mod voice_state_update;
 mod webhooks_update;
 
 pub use self::{
-    ban_add::BanAdd, ban_remove::BanRemove, channel_create::ChannelCreate,
-    channel_delete::ChannelDelete, channel_pins_update::ChannelPinsUpdate,
-    channel_update::ChannelUpdate, guild_create::GuildCreate, guild_delete::GuildDelete,
-    guild_emojis_update::GuildEmojisUpdate, guild_integrations_update::GuildIntegrationsUpdate,
-    guild_update::GuildUpdate, heartbeat::Heartbeat, invite_create::InviteCreate,
-    invite_delete::InviteDelete, member_add::MemberAdd, member_chunk::MemberChunk,
-    member_remove::MemberRemove, member_update::MemberUpdate, message_create::MessageCreate,
-    message_delete::MessageDelete, message_delete_bulk::MessageDeleteBulk,
-    message_update::MessageUpdate, presence_update::PresenceUpdate, reaction_add::ReactionAdd,
-    reaction_remove::ReactionRemove, reaction_remove_all::ReactionRemoveAll,
-    reaction_remove_emoji::ReactionRemoveEmoji, ready::Ready,
-    request_guild_members::RequestGuildMembers, role_create::RoleCreate, role_delete::RoleDelete,
-    role_update::RoleUpdate, typing_start::TypingStart, unavailable_guild::UnavailableGuild,
-    update_status::UpdateStatus, update_voice_state::UpdateVoiceState, user_update::UserUpdate,
+    auto_moderation_action_execution::AutoModerationActionExecution,
+    auto_moderation_rule_create::AutoModerationRuleCreate,
+    auto_moderation_rule_delete::AutoModerationRuleDelete,
+    auto_moderation_rule_update::AutoModerationRuleUpdate, ban_add::BanAdd, ban_remove::BanRemove,
+    channel_create::ChannelCreate, channel_delete::ChannelDelete,
+    channel_pins_update::ChannelPinsUpdate, channel_update::ChannelUpdate,
+    guild_create::GuildCreate, guild_delete::GuildDelete, guild_emojis_update::GuildEmojisUpdate,
+    guild_integrations_update::GuildIntegrationsUpdate,
+    guild_scheduled_event_create::GuildScheduledEventCreate,
+    guild_scheduled_event_delete::GuildScheduledEventDelete,
+    guild_scheduled_event_update::GuildScheduledEventUpdate,
+    guild_scheduled_event_user_add::GuildScheduledEventUserAdd,
+    guild_scheduled_event_user_remove::GuildScheduledEventUserRemove,
+    guild_stickers_update::GuildStickersUpdate, guild_update::GuildUpdate, heartbeat::Heartbeat,
+    invite_create::InviteCreate, invite_delete::InviteDelete, member_add::MemberAdd,
+    member_chunk::MemberChunk, member_remove::MemberRemove, member_update::MemberUpdate,
+    message_create::MessageCreate, message_delete::MessageDelete,
+    message_delete_bulk::MessageDeleteBulk, message_update::MessageUpdate,
+    presence_update::PresenceUpdate, reaction_add::ReactionAdd, reaction_remove::ReactionRemove,
+    reaction_remove_all::ReactionRemoveAll, reaction_remove_emoji::ReactionRemoveEmoji,
+    ready::Ready, request_guild_members::RequestGuildMembers, role_create::RoleCreate,
+    role_delete::RoleDelete, role_update::RoleUpdate, typing_start::TypingStart,
+    unavailable_guild::UnavailableGuild, update_status::UpdateStatus,
+    update_voice_state::UpdateVoiceState, user_update::UserUpdate,
     voice_server_update::VoiceServerUpdate, voice_state_update::VoiceStateUpdate,
     webhooks_update::WebhooksUpdate,
 };
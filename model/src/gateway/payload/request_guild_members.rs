@@ -107,11 +107,11 @@ impl RequestGuildMembersBuilder {
     /// ```
     /// use twilight_model::{gateway::payload::RequestGuildMembers, id::GuildId};
     ///
-    /// let request = RequestGuildMembers::builder(GuildId(1))
+    /// let request = RequestGuildMembers::builder(GuildId::new(1))
     ///     .presences(true)
     ///     .query("a", None);
     ///
-    /// assert_eq!(GuildId(1), request.d.guild_id);
+    /// assert_eq!(GuildId::new(1), request.d.guild_id);
     /// assert_eq!(Some(0), request.d.limit);
     /// assert_eq!(Some("a"), request.d.query.as_deref());
     /// assert_eq!(Some(true), request.d.presences);
@@ -147,11 +147,11 @@ impl RequestGuildMembersBuilder {
     ///     id::{GuildId, UserId},
     /// };
     ///
-    /// let request = RequestGuildMembers::builder(GuildId(1))
+    /// let request = RequestGuildMembers::builder(GuildId::new(1))
     ///     .nonce("test")
-    ///     .user_id(UserId(2));
+    ///     .user_id(UserId::new(2));
     ///
-    /// assert_eq!(Some(RequestGuildMemberId::One(UserId(2))), request.d.user_ids);
+    /// assert_eq!(Some(RequestGuildMemberId::One(UserId::new(2))), request.d.user_ids);
     /// ```
     pub fn user_id(self, user_id: UserId) -> RequestGuildMembers {
         RequestGuildMembers {
@@ -183,9 +183,9 @@ impl RequestGuildMembersBuilder {
     /// };
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let request = RequestGuildMembers::builder(GuildId(1))
+    /// let request = RequestGuildMembers::builder(GuildId::new(1))
     ///     .nonce("test")
-    ///     .user_ids(vec![UserId(2), UserId(3)])?;
+    ///     .user_ids(vec![UserId::new(2), UserId::new(3)])?;
     ///
     /// assert!(matches!(request.d.user_ids, Some(RequestGuildMemberId::Multiple(ids)) if ids.len() == 2));
     /// # Ok(()) }
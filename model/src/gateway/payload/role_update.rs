@@ -16,11 +16,11 @@ mod tests {
     #[test]
     fn test_role_update() {
         let value = RoleUpdate {
-            guild_id: GuildId(1),
+            guild_id: GuildId::new(1),
             role: Role {
                 color: 0,
                 hoist: true,
-                id: RoleId(1),
+                id: RoleId::new(1),
                 managed: false,
                 mentionable: false,
                 name: "a role".to_owned(),
@@ -38,7 +38,7 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("guild_id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("role"),
                 Token::Struct {
@@ -50,7 +50,7 @@ mod tests {
                 Token::Str("hoist"),
                 Token::Bool(true),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "RoleId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("managed"),
                 Token::Bool(false),
@@ -89,7 +89,7 @@ impl<'de> Visitor<'de> for TypingStartVisitor {
                         return Err(DeError::duplicate_field("member"));
                     }
 
-                    let deserializer = OptionalMemberDeserializer::new(GuildId(0));
+                    let deserializer = OptionalMemberDeserializer::new(GuildId::new(0));
 
                     member = map.next_value_seed(deserializer)?;
                 }
@@ -153,6 +153,7 @@ mod tests {
         guild::Member,
         id::{ChannelId, GuildId, RoleId, UserId},
         user::User,
+        util::ImageHash,
     };
     use serde_test::Token;
 
@@ -160,20 +161,20 @@ mod tests {
     #[test]
     fn test_typing_start_with_member() {
         let value = TypingStart {
-            channel_id: ChannelId(2),
-            guild_id: Some(GuildId(1)),
+            channel_id: ChannelId::new(2),
+            guild_id: Some(GuildId::new(1)),
             member: Some(Member {
                 deaf: false,
-                guild_id: GuildId(1),
-                hoisted_role: Some(RoleId(4)),
+                guild_id: GuildId::new(1),
+                hoisted_role: Some(RoleId::new(4)),
                 joined_at: Some("2020-01-01T00:00:00.000000+00:00".to_owned()),
                 mute: false,
                 nick: Some("typing".to_owned()),
                 premium_since: None,
-                roles: vec![RoleId(4)],
+                roles: vec![RoleId::new(4)],
                 user: User {
-                    id: UserId(3),
-                    avatar: Some("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".to_owned()),
+                    id: UserId::new(3),
+                    avatar: Some(ImageHash::parse("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa").unwrap()),
                     bot: false,
                     discriminator: "0001".to_owned(),
                     name: "test".to_owned(),
@@ -188,7 +189,7 @@ mod tests {
                 },
             }),
             timestamp: 1_500_000_000,
-            user_id: UserId(3),
+            user_id: UserId::new(3),
         };
 
         serde_test::assert_tokens(
@@ -199,11 +200,11 @@ mod tests {
                     len: 5,
                 },
                 Token::Str("channel_id"),
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("member"),
                 Token::Some,
@@ -214,11 +215,11 @@ mod tests {
                 Token::Str("deaf"),
                 Token::Bool(false),
                 Token::Str("guild_id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("hoisted_role"),
                 Token::Some,
-                Token::NewtypeStruct { name: "RoleId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("4"),
                 Token::Str("joined_at"),
                 Token::Some,
@@ -230,7 +231,7 @@ mod tests {
                 Token::Str("typing"),
                 Token::Str("roles"),
                 Token::Seq { len: Some(1) },
-                Token::NewtypeStruct { name: "RoleId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("4"),
                 Token::SeqEnd,
                 Token::Str("user"),
@@ -246,7 +247,7 @@ mod tests {
                 Token::Str("discriminator"),
                 Token::Str("0001"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("3"),
                 Token::Str("username"),
                 Token::Str("test"),
@@ -255,7 +256,7 @@ mod tests {
                 Token::Str("timestamp"),
                 Token::U64(1_500_000_000),
                 Token::Str("user_id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("3"),
                 Token::StructEnd,
             ],
@@ -265,11 +266,11 @@ mod tests {
     #[test]
     fn test_typing_start_without_member() {
         let value = TypingStart {
-            channel_id: ChannelId(2),
+            channel_id: ChannelId::new(2),
             guild_id: None,
             member: None,
             timestamp: 1_500_000_000,
-            user_id: UserId(3),
+            user_id: UserId::new(3),
         };
 
         serde_test::assert_tokens(
@@ -280,12 +281,12 @@ mod tests {
                     len: 3,
                 },
                 Token::Str("channel_id"),
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("timestamp"),
                 Token::U64(1_500_000_000),
                 Token::Str("user_id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("3"),
                 Token::StructEnd,
             ],
@@ -13,7 +13,9 @@ mod tests {
 
     #[test]
     fn test_unavailable_guild() {
-        let value = UnavailableGuild { id: GuildId(1) };
+        let value = UnavailableGuild {
+            id: GuildId::new(1),
+        };
 
         serde_test::assert_tokens(
             &value,
@@ -23,7 +25,7 @@ mod tests {
                     len: 1,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::StructEnd,
             ],
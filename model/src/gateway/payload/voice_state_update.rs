@@ -11,6 +11,7 @@ mod tests {
         guild::Member,
         id::{GuildId, RoleId, UserId},
         user::User,
+        util::ImageHash,
     };
     use serde_test::Token;
 
@@ -20,18 +21,18 @@ mod tests {
         let value = VoiceStateUpdate(VoiceState {
             channel_id: None,
             deaf: false,
-            guild_id: Some(GuildId(1)),
+            guild_id: Some(GuildId::new(1)),
             member: Some(Member {
                 deaf: false,
-                guild_id: GuildId(1),
-                hoisted_role: Some(RoleId(4)),
+                guild_id: GuildId::new(1),
+                hoisted_role: Some(RoleId::new(4)),
                 joined_at: None,
                 mute: false,
                 nick: None,
                 premium_since: None,
-                roles: vec![RoleId(4)],
+                roles: vec![RoleId::new(4)],
                 user: User {
-                    id: UserId(1),
+                    id: UserId::new(1),
                     avatar: None,
                     bot: false,
                     discriminator: "0909".to_string(),
@@ -53,7 +54,7 @@ mod tests {
             session_id: "a".to_owned(),
             suppress: false,
             token: None,
-            user_id: UserId(1),
+            user_id: UserId::new(1),
         });
 
         serde_test::assert_tokens(
@@ -72,7 +73,7 @@ mod tests {
                 Token::Bool(false),
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("member"),
                 Token::Some,
@@ -83,11 +84,11 @@ mod tests {
                 Token::Str("deaf"),
                 Token::Bool(false),
                 Token::Str("guild_id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("hoisted_role"),
                 Token::Some,
-                Token::NewtypeStruct { name: "RoleId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("4"),
                 Token::Str("joined_at"),
                 Token::None,
@@ -97,7 +98,7 @@ mod tests {
                 Token::None,
                 Token::Str("roles"),
                 Token::Seq { len: Some(1) },
-                Token::NewtypeStruct { name: "RoleId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("4"),
                 Token::SeqEnd,
                 Token::Str("user"),
@@ -112,7 +113,7 @@ mod tests {
                 Token::Str("discriminator"),
                 Token::Str("0909"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("username"),
                 Token::Str("foo"),
@@ -131,7 +132,7 @@ mod tests {
                 Token::Str("suppress"),
                 Token::Bool(false),
                 Token::Str("user_id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::StructEnd,
             ],
@@ -144,19 +145,19 @@ mod tests {
         let value = VoiceStateUpdate(VoiceState {
             channel_id: None,
             deaf: false,
-            guild_id: Some(GuildId(999_999)),
+            guild_id: Some(GuildId::new(999_999)),
             member: Some(Member {
                 deaf: false,
-                guild_id: GuildId(999_999),
-                hoisted_role: Some(RoleId(123)),
+                guild_id: GuildId::new(999_999),
+                hoisted_role: Some(RoleId::new(123)),
                 joined_at: Some("2016-12-08T18:41:21.954000+00:00".to_string()),
                 mute: false,
                 nick: Some("Twilight".to_string()),
                 premium_since: None,
-                roles: vec![RoleId(123), RoleId(124)],
+                roles: vec![RoleId::new(123), RoleId::new(124)],
                 user: User {
-                    id: UserId(1_234_123_123_123),
-                    avatar: Some("a21312321231236060dfe562c".to_string()),
+                    id: UserId::new(1_234_123_123_123),
+                    avatar: Some(ImageHash::parse("a21312321231236060dfe562c0b1a2b3").unwrap()),
                     bot: false,
                     discriminator: "4242".to_string(),
                     name: "Twilight Sparkle".to_string(),
@@ -177,7 +178,7 @@ mod tests {
             session_id: "asdasdas1da98da2b3ab3a".to_owned(),
             suppress: false,
             token: None,
-            user_id: UserId(123_213),
+            user_id: UserId::new(123_213),
         });
 
         // Token stream here's `Member` has no `guild_id`, which deserialiser
@@ -200,7 +201,7 @@ mod tests {
                 Token::Bool(false),
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("999999"),
                 Token::Str("member"),
                 Token::Some,
@@ -212,7 +213,7 @@ mod tests {
                 Token::Bool(false),
                 Token::Str("hoisted_role"),
                 Token::Some,
-                Token::NewtypeStruct { name: "RoleId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("123"),
                 Token::Str("joined_at"),
                 Token::Some,
@@ -224,9 +225,9 @@ mod tests {
                 Token::Str("Twilight"),
                 Token::Str("roles"),
                 Token::Seq { len: Some(2) },
-                Token::NewtypeStruct { name: "RoleId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("123"),
-                Token::NewtypeStruct { name: "RoleId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("124"),
                 Token::SeqEnd,
                 Token::Str("user"),
@@ -236,13 +237,13 @@ mod tests {
                 },
                 Token::Str("avatar"),
                 Token::Some,
-                Token::Str("a21312321231236060dfe562c"),
+                Token::Str("a21312321231236060dfe562c0b1a2b3"),
                 Token::Str("bot"),
                 Token::Bool(false),
                 Token::Str("discriminator"),
                 Token::Str("4242"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1234123123123"),
                 Token::Str("username"),
                 Token::Str("Twilight Sparkle"),
@@ -261,7 +262,7 @@ mod tests {
                 Token::Str("suppress"),
                 Token::Bool(false),
                 Token::Str("user_id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("123213"),
                 Token::StructEnd,
             ],
@@ -15,8 +15,8 @@ mod tests {
     #[test]
     fn test_webhooks_update() {
         let value = WebhooksUpdate {
-            channel_id: ChannelId(1),
-            guild_id: GuildId(2),
+            channel_id: ChannelId::new(1),
+            guild_id: GuildId::new(2),
         };
 
         serde_test::assert_tokens(
@@ -27,10 +27,10 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("channel_id"),
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("guild_id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::StructEnd,
             ],
@@ -179,9 +179,9 @@ mod tests {
                 mobile: None,
                 web: None,
             },
-            guild_id: GuildId(2),
+            guild_id: GuildId::new(2),
             status: Status::Online,
-            user: UserOrId::UserId { id: UserId(1) },
+            user: UserOrId::UserId { id: UserId::new(1) },
         };
 
         serde_test::assert_de_tokens(
@@ -200,7 +200,7 @@ mod tests {
                 Token::Str("1"),
                 Token::StructEnd,
                 Token::Str("guild_id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("status"),
                 Token::Enum { name: "Status" },
@@ -282,13 +282,13 @@ mod tests {
                 mobile: None,
                 web: None,
             },
-            guild_id: GuildId(2),
+            guild_id: GuildId::new(2),
             status: Status::Online,
-            user: UserOrId::UserId { id: UserId(1) },
+            user: UserOrId::UserId { id: UserId::new(1) },
         });
 
         let mut json_deserializer = Deserializer::from_str(input);
-        let deserializer = PresenceListDeserializer::new(GuildId(2));
+        let deserializer = PresenceListDeserializer::new(GuildId::new(2));
         let actual = deserializer.deserialize(&mut json_deserializer).unwrap();
 
         assert_eq!(actual, expected);
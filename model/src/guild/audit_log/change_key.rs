@@ -1,29 +1,58 @@
+use crate::id::GenericId;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
+/// Key of a field changed by an audit log entry.
+///
+/// Keys that aren't yet known by the library are deserialized into
+/// [`AuditLogChangeKey::Unknown`].
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 #[non_exhaustive]
-#[serde(rename_all = "snake_case")]
+#[serde(from = "String", into = "Cow<'static, str>")]
 pub enum AuditLogChangeKey {
+    Actions,
     AfkChannelId,
     AfkTimeout,
     Allow,
     ApplicationId,
+    Archived,
+    Asset,
+    AutoArchiveDuration,
+    Available,
     AvatarHash,
+    BannerHash,
     Bitrate,
     ChannelId,
     Code,
     Color,
+    CommunicationDisabledUntil,
     Deaf,
+    DefaultAutoArchiveDuration,
     DefaultMessageNotifications,
     Deny,
+    Description,
+    DiscoverySplashHash,
+    EmojiId,
     EnableEmoticons,
+    Enabled,
+    EntityType,
+    EventType,
+    ExemptChannels,
+    ExemptRoles,
     ExpireBehavior,
     ExpireGracePeriod,
     ExplicitContentFilter,
+    Flags,
+    FormatType,
+    GuildId,
     Hoist,
     IconHash,
     Id,
+    ImageHash,
+    Invitable,
     InviterId,
+    Location,
+    Locked,
     MaxAge,
     MaxUses,
     Mentionable,
@@ -36,21 +65,262 @@ pub enum AuditLogChangeKey {
     PermissionOverwrites,
     Permissions,
     Position,
+    PremiumProgressBarEnabled,
+    PrivacyLevel,
     PruneDeleteDays,
     RateLimitPerUser,
-    #[serde(rename = "$add")]
     RoleAdded,
-    #[serde(rename = "$remove")]
     RoleRemoved,
     Region,
+    RtcRegion,
     SplashHash,
+    Status,
     SystemChannelId,
+    Tags,
     Temporary,
     Topic,
+    TriggerMetadata,
+    TriggerType,
     Type,
+    UnicodeEmoji,
+    UserLimit,
     Uses,
     VanityUrlCode,
     VerificationLevel,
+    VideoQualityMode,
     WidgetChannelId,
     WidgetEnabled,
+    /// Permission of an application command, keyed by the ID of the command,
+    /// role, user, or channel that it applies to.
+    ///
+    /// Used by [`AuditLogEvent::ApplicationCommandPermissionUpdate`] entries.
+    ///
+    /// [`AuditLogEvent::ApplicationCommandPermissionUpdate`]: super::AuditLogEvent::ApplicationCommandPermissionUpdate
+    ApplicationCommandPermission(GenericId),
+    /// Variant value is unknown to the library.
+    Unknown(String),
+}
+
+impl From<AuditLogChangeKey> for Cow<'static, str> {
+    fn from(value: AuditLogChangeKey) -> Self {
+        match value {
+            AuditLogChangeKey::Actions => "actions".into(),
+            AuditLogChangeKey::AfkChannelId => "afk_channel_id".into(),
+            AuditLogChangeKey::AfkTimeout => "afk_timeout".into(),
+            AuditLogChangeKey::Allow => "allow".into(),
+            AuditLogChangeKey::ApplicationId => "application_id".into(),
+            AuditLogChangeKey::Archived => "archived".into(),
+            AuditLogChangeKey::Asset => "asset".into(),
+            AuditLogChangeKey::AutoArchiveDuration => "auto_archive_duration".into(),
+            AuditLogChangeKey::Available => "available".into(),
+            AuditLogChangeKey::AvatarHash => "avatar_hash".into(),
+            AuditLogChangeKey::BannerHash => "banner_hash".into(),
+            AuditLogChangeKey::Bitrate => "bitrate".into(),
+            AuditLogChangeKey::ChannelId => "channel_id".into(),
+            AuditLogChangeKey::Code => "code".into(),
+            AuditLogChangeKey::Color => "color".into(),
+            AuditLogChangeKey::CommunicationDisabledUntil => "communication_disabled_until".into(),
+            AuditLogChangeKey::Deaf => "deaf".into(),
+            AuditLogChangeKey::DefaultAutoArchiveDuration => "default_auto_archive_duration".into(),
+            AuditLogChangeKey::DefaultMessageNotifications => {
+                "default_message_notifications".into()
+            }
+            AuditLogChangeKey::Deny => "deny".into(),
+            AuditLogChangeKey::Description => "description".into(),
+            AuditLogChangeKey::DiscoverySplashHash => "discovery_splash_hash".into(),
+            AuditLogChangeKey::EmojiId => "emoji_id".into(),
+            AuditLogChangeKey::EnableEmoticons => "enable_emoticons".into(),
+            AuditLogChangeKey::Enabled => "enabled".into(),
+            AuditLogChangeKey::EntityType => "entity_type".into(),
+            AuditLogChangeKey::EventType => "event_type".into(),
+            AuditLogChangeKey::ExemptChannels => "exempt_channels".into(),
+            AuditLogChangeKey::ExemptRoles => "exempt_roles".into(),
+            AuditLogChangeKey::ExpireBehavior => "expire_behavior".into(),
+            AuditLogChangeKey::ExpireGracePeriod => "expire_grace_period".into(),
+            AuditLogChangeKey::ExplicitContentFilter => "explicit_content_filter".into(),
+            AuditLogChangeKey::Flags => "flags".into(),
+            AuditLogChangeKey::FormatType => "format_type".into(),
+            AuditLogChangeKey::GuildId => "guild_id".into(),
+            AuditLogChangeKey::Hoist => "hoist".into(),
+            AuditLogChangeKey::IconHash => "icon_hash".into(),
+            AuditLogChangeKey::Id => "id".into(),
+            AuditLogChangeKey::ImageHash => "image_hash".into(),
+            AuditLogChangeKey::Invitable => "invitable".into(),
+            AuditLogChangeKey::InviterId => "inviter_id".into(),
+            AuditLogChangeKey::Location => "location".into(),
+            AuditLogChangeKey::Locked => "locked".into(),
+            AuditLogChangeKey::MaxAge => "max_age".into(),
+            AuditLogChangeKey::MaxUses => "max_uses".into(),
+            AuditLogChangeKey::Mentionable => "mentionable".into(),
+            AuditLogChangeKey::MfaLevel => "mfa_level".into(),
+            AuditLogChangeKey::Mute => "mute".into(),
+            AuditLogChangeKey::Name => "name".into(),
+            AuditLogChangeKey::Nick => "nick".into(),
+            AuditLogChangeKey::Nsfw => "nsfw".into(),
+            AuditLogChangeKey::OwnerId => "owner_id".into(),
+            AuditLogChangeKey::PermissionOverwrites => "permission_overwrites".into(),
+            AuditLogChangeKey::Permissions => "permissions".into(),
+            AuditLogChangeKey::Position => "position".into(),
+            AuditLogChangeKey::PremiumProgressBarEnabled => "premium_progress_bar_enabled".into(),
+            AuditLogChangeKey::PrivacyLevel => "privacy_level".into(),
+            AuditLogChangeKey::PruneDeleteDays => "prune_delete_days".into(),
+            AuditLogChangeKey::RateLimitPerUser => "rate_limit_per_user".into(),
+            AuditLogChangeKey::RoleAdded => "$add".into(),
+            AuditLogChangeKey::RoleRemoved => "$remove".into(),
+            AuditLogChangeKey::Region => "region".into(),
+            AuditLogChangeKey::RtcRegion => "rtc_region".into(),
+            AuditLogChangeKey::SplashHash => "splash_hash".into(),
+            AuditLogChangeKey::Status => "status".into(),
+            AuditLogChangeKey::SystemChannelId => "system_channel_id".into(),
+            AuditLogChangeKey::Tags => "tags".into(),
+            AuditLogChangeKey::Temporary => "temporary".into(),
+            AuditLogChangeKey::Topic => "topic".into(),
+            AuditLogChangeKey::TriggerMetadata => "trigger_metadata".into(),
+            AuditLogChangeKey::TriggerType => "trigger_type".into(),
+            AuditLogChangeKey::Type => "type".into(),
+            AuditLogChangeKey::UnicodeEmoji => "unicode_emoji".into(),
+            AuditLogChangeKey::UserLimit => "user_limit".into(),
+            AuditLogChangeKey::Uses => "uses".into(),
+            AuditLogChangeKey::VanityUrlCode => "vanity_url_code".into(),
+            AuditLogChangeKey::VerificationLevel => "verification_level".into(),
+            AuditLogChangeKey::VideoQualityMode => "video_quality_mode".into(),
+            AuditLogChangeKey::WidgetChannelId => "widget_channel_id".into(),
+            AuditLogChangeKey::WidgetEnabled => "widget_enabled".into(),
+            AuditLogChangeKey::ApplicationCommandPermission(id) => id.to_string().into(),
+            AuditLogChangeKey::Unknown(unknown) => unknown.into(),
+        }
+    }
+}
+
+impl From<String> for AuditLogChangeKey {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "actions" => Self::Actions,
+            "afk_channel_id" => Self::AfkChannelId,
+            "afk_timeout" => Self::AfkTimeout,
+            "allow" => Self::Allow,
+            "application_id" => Self::ApplicationId,
+            "archived" => Self::Archived,
+            "asset" => Self::Asset,
+            "auto_archive_duration" => Self::AutoArchiveDuration,
+            "available" => Self::Available,
+            "avatar_hash" => Self::AvatarHash,
+            "banner_hash" => Self::BannerHash,
+            "bitrate" => Self::Bitrate,
+            "channel_id" => Self::ChannelId,
+            "code" => Self::Code,
+            "color" => Self::Color,
+            "communication_disabled_until" => Self::CommunicationDisabledUntil,
+            "deaf" => Self::Deaf,
+            "default_auto_archive_duration" => Self::DefaultAutoArchiveDuration,
+            "default_message_notifications" => Self::DefaultMessageNotifications,
+            "deny" => Self::Deny,
+            "description" => Self::Description,
+            "discovery_splash_hash" => Self::DiscoverySplashHash,
+            "emoji_id" => Self::EmojiId,
+            "enable_emoticons" => Self::EnableEmoticons,
+            "enabled" => Self::Enabled,
+            "entity_type" => Self::EntityType,
+            "event_type" => Self::EventType,
+            "exempt_channels" => Self::ExemptChannels,
+            "exempt_roles" => Self::ExemptRoles,
+            "expire_behavior" => Self::ExpireBehavior,
+            "expire_grace_period" => Self::ExpireGracePeriod,
+            "explicit_content_filter" => Self::ExplicitContentFilter,
+            "flags" => Self::Flags,
+            "format_type" => Self::FormatType,
+            "guild_id" => Self::GuildId,
+            "hoist" => Self::Hoist,
+            "icon_hash" => Self::IconHash,
+            "id" => Self::Id,
+            "image_hash" => Self::ImageHash,
+            "invitable" => Self::Invitable,
+            "inviter_id" => Self::InviterId,
+            "location" => Self::Location,
+            "locked" => Self::Locked,
+            "max_age" => Self::MaxAge,
+            "max_uses" => Self::MaxUses,
+            "mentionable" => Self::Mentionable,
+            "mfa_level" => Self::MfaLevel,
+            "mute" => Self::Mute,
+            "name" => Self::Name,
+            "nick" => Self::Nick,
+            "nsfw" => Self::Nsfw,
+            "owner_id" => Self::OwnerId,
+            "permission_overwrites" => Self::PermissionOverwrites,
+            "permissions" => Self::Permissions,
+            "position" => Self::Position,
+            "premium_progress_bar_enabled" => Self::PremiumProgressBarEnabled,
+            "privacy_level" => Self::PrivacyLevel,
+            "prune_delete_days" => Self::PruneDeleteDays,
+            "rate_limit_per_user" => Self::RateLimitPerUser,
+            "$add" => Self::RoleAdded,
+            "$remove" => Self::RoleRemoved,
+            "region" => Self::Region,
+            "rtc_region" => Self::RtcRegion,
+            "splash_hash" => Self::SplashHash,
+            "status" => Self::Status,
+            "system_channel_id" => Self::SystemChannelId,
+            "tags" => Self::Tags,
+            "temporary" => Self::Temporary,
+            "topic" => Self::Topic,
+            "trigger_metadata" => Self::TriggerMetadata,
+            "trigger_type" => Self::TriggerType,
+            "type" => Self::Type,
+            "unicode_emoji" => Self::UnicodeEmoji,
+            "user_limit" => Self::UserLimit,
+            "uses" => Self::Uses,
+            "vanity_url_code" => Self::VanityUrlCode,
+            "verification_level" => Self::VerificationLevel,
+            "video_quality_mode" => Self::VideoQualityMode,
+            "widget_channel_id" => Self::WidgetChannelId,
+            "widget_enabled" => Self::WidgetEnabled,
+            _ => match value.parse() {
+                Ok(id) => Self::ApplicationCommandPermission(GenericId::new(id)),
+                Err(_) => Self::Unknown(value),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AuditLogChangeKey;
+    use crate::id::GenericId;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&AuditLogChangeKey::AvatarHash, &[Token::Str("avatar_hash")]);
+        serde_test::assert_tokens(
+            &AuditLogChangeKey::CommunicationDisabledUntil,
+            &[Token::Str("communication_disabled_until")],
+        );
+        serde_test::assert_tokens(
+            &AuditLogChangeKey::PremiumProgressBarEnabled,
+            &[Token::Str("premium_progress_bar_enabled")],
+        );
+        serde_test::assert_tokens(&AuditLogChangeKey::RoleAdded, &[Token::Str("$add")]);
+        serde_test::assert_tokens(&AuditLogChangeKey::RoleRemoved, &[Token::Str("$remove")]);
+        serde_test::assert_tokens(
+            &AuditLogChangeKey::UnicodeEmoji,
+            &[Token::Str("unicode_emoji")],
+        );
+    }
+
+    #[test]
+    fn test_application_command_permission() {
+        serde_test::assert_tokens(
+            &AuditLogChangeKey::ApplicationCommandPermission(GenericId::new(123)),
+            &[Token::Str("123")],
+        );
+    }
+
+    #[test]
+    fn test_unknown() {
+        serde_test::assert_tokens(
+            &AuditLogChangeKey::Unknown("some_new_key".to_owned()),
+            &[Token::Str("some_new_key")],
+        );
+    }
 }
@@ -40,4 +40,99 @@ pub enum AuditLogEvent {
     IntegrationCreate = 80,
     IntegrationUpdate = 81,
     IntegrationDelete = 82,
+    StageInstanceCreate = 83,
+    StageInstanceUpdate = 84,
+    StageInstanceDelete = 85,
+    StickerCreate = 90,
+    StickerUpdate = 91,
+    StickerDelete = 92,
+    GuildScheduledEventCreate = 100,
+    GuildScheduledEventUpdate = 101,
+    GuildScheduledEventDelete = 102,
+    ThreadCreate = 110,
+    ThreadUpdate = 111,
+    ThreadDelete = 112,
+    ApplicationCommandPermissionUpdate = 121,
+    AutoModerationRuleCreate = 140,
+    AutoModerationRuleUpdate = 141,
+    AutoModerationRuleDelete = 142,
+    AutoModerationBlockMessage = 143,
+    AutoModerationFlagToChannel = 144,
+    AutoModerationUserCommunicationDisabled = 145,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AuditLogEvent;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&AuditLogEvent::GuildCreate, &[Token::U8(1)]);
+        serde_test::assert_tokens(&AuditLogEvent::ChannelCreate, &[Token::U8(10)]);
+        serde_test::assert_tokens(&AuditLogEvent::ChannelUpdate, &[Token::U8(11)]);
+        serde_test::assert_tokens(&AuditLogEvent::ChannelDelete, &[Token::U8(12)]);
+        serde_test::assert_tokens(&AuditLogEvent::ChannelOverwriteCreate, &[Token::U8(13)]);
+        serde_test::assert_tokens(&AuditLogEvent::ChannelOverwriteUpdate, &[Token::U8(14)]);
+        serde_test::assert_tokens(&AuditLogEvent::ChannelOverwriteDelete, &[Token::U8(15)]);
+        serde_test::assert_tokens(&AuditLogEvent::MemberKick, &[Token::U8(20)]);
+        serde_test::assert_tokens(&AuditLogEvent::MemberPrune, &[Token::U8(21)]);
+        serde_test::assert_tokens(&AuditLogEvent::MemberBanAdd, &[Token::U8(22)]);
+        serde_test::assert_tokens(&AuditLogEvent::MemberBanRemove, &[Token::U8(23)]);
+        serde_test::assert_tokens(&AuditLogEvent::MemberUpdate, &[Token::U8(24)]);
+        serde_test::assert_tokens(&AuditLogEvent::MemberRoleUpdate, &[Token::U8(25)]);
+        serde_test::assert_tokens(&AuditLogEvent::MemberMove, &[Token::U8(26)]);
+        serde_test::assert_tokens(&AuditLogEvent::MemberDisconnect, &[Token::U8(27)]);
+        serde_test::assert_tokens(&AuditLogEvent::BotAdd, &[Token::U8(28)]);
+        serde_test::assert_tokens(&AuditLogEvent::RoleCreate, &[Token::U8(30)]);
+        serde_test::assert_tokens(&AuditLogEvent::RoleUpdate, &[Token::U8(31)]);
+        serde_test::assert_tokens(&AuditLogEvent::RoleDelete, &[Token::U8(32)]);
+        serde_test::assert_tokens(&AuditLogEvent::InviteCreate, &[Token::U8(40)]);
+        serde_test::assert_tokens(&AuditLogEvent::InviteUpdate, &[Token::U8(41)]);
+        serde_test::assert_tokens(&AuditLogEvent::InviteDelete, &[Token::U8(42)]);
+        serde_test::assert_tokens(&AuditLogEvent::WebhookCreate, &[Token::U8(50)]);
+        serde_test::assert_tokens(&AuditLogEvent::WebhookUpdate, &[Token::U8(51)]);
+        serde_test::assert_tokens(&AuditLogEvent::WebhookDelete, &[Token::U8(52)]);
+        serde_test::assert_tokens(&AuditLogEvent::EmojiCreate, &[Token::U8(60)]);
+        serde_test::assert_tokens(&AuditLogEvent::EmojiUpdate, &[Token::U8(61)]);
+        serde_test::assert_tokens(&AuditLogEvent::EmojiDelete, &[Token::U8(62)]);
+        serde_test::assert_tokens(&AuditLogEvent::MessageDelete, &[Token::U8(72)]);
+        serde_test::assert_tokens(&AuditLogEvent::MessageBulkDelete, &[Token::U8(73)]);
+        serde_test::assert_tokens(&AuditLogEvent::MessagePin, &[Token::U8(74)]);
+        serde_test::assert_tokens(&AuditLogEvent::MessageUnpin, &[Token::U8(75)]);
+        serde_test::assert_tokens(&AuditLogEvent::IntegrationCreate, &[Token::U8(80)]);
+        serde_test::assert_tokens(&AuditLogEvent::IntegrationUpdate, &[Token::U8(81)]);
+        serde_test::assert_tokens(&AuditLogEvent::IntegrationDelete, &[Token::U8(82)]);
+        serde_test::assert_tokens(&AuditLogEvent::StageInstanceCreate, &[Token::U8(83)]);
+        serde_test::assert_tokens(&AuditLogEvent::StageInstanceUpdate, &[Token::U8(84)]);
+        serde_test::assert_tokens(&AuditLogEvent::StageInstanceDelete, &[Token::U8(85)]);
+        serde_test::assert_tokens(&AuditLogEvent::StickerCreate, &[Token::U8(90)]);
+        serde_test::assert_tokens(&AuditLogEvent::StickerUpdate, &[Token::U8(91)]);
+        serde_test::assert_tokens(&AuditLogEvent::StickerDelete, &[Token::U8(92)]);
+        serde_test::assert_tokens(&AuditLogEvent::GuildScheduledEventCreate, &[Token::U8(100)]);
+        serde_test::assert_tokens(&AuditLogEvent::GuildScheduledEventUpdate, &[Token::U8(101)]);
+        serde_test::assert_tokens(&AuditLogEvent::GuildScheduledEventDelete, &[Token::U8(102)]);
+        serde_test::assert_tokens(&AuditLogEvent::ThreadCreate, &[Token::U8(110)]);
+        serde_test::assert_tokens(&AuditLogEvent::ThreadUpdate, &[Token::U8(111)]);
+        serde_test::assert_tokens(&AuditLogEvent::ThreadDelete, &[Token::U8(112)]);
+        serde_test::assert_tokens(
+            &AuditLogEvent::ApplicationCommandPermissionUpdate,
+            &[Token::U8(121)],
+        );
+        serde_test::assert_tokens(&AuditLogEvent::AutoModerationRuleCreate, &[Token::U8(140)]);
+        serde_test::assert_tokens(&AuditLogEvent::AutoModerationRuleUpdate, &[Token::U8(141)]);
+        serde_test::assert_tokens(&AuditLogEvent::AutoModerationRuleDelete, &[Token::U8(142)]);
+        serde_test::assert_tokens(
+            &AuditLogEvent::AutoModerationBlockMessage,
+            &[Token::U8(143)],
+        );
+        serde_test::assert_tokens(
+            &AuditLogEvent::AutoModerationFlagToChannel,
+            &[Token::U8(144)],
+        );
+        serde_test::assert_tokens(
+            &AuditLogEvent::AutoModerationUserCommunicationDisabled,
+            &[Token::U8(145)],
+        );
+    }
 }
@@ -1,8 +1,17 @@
-use crate::id::{ChannelId, GenericId, MessageId, UserId};
+use crate::id::{ApplicationId, ChannelId, GenericId, MessageId, UserId};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct AuditLogOptionalEntryInfo {
+    /// ID of the application whose command permissions were updated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<ApplicationId>,
+    /// Name of the auto moderation rule that was triggered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_moderation_rule_name: Option<String>,
+    /// Trigger type of the auto moderation rule that was triggered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_moderation_rule_trigger_type: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub channel_id: Option<ChannelId>,
     #[serde(skip_serializing_if = "Option::is_none")]
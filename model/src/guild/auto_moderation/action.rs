@@ -0,0 +1,96 @@
+use crate::id::ChannelId;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Action which will execute whenever an [`AutoModerationRule`] is triggered.
+///
+/// [`AutoModerationRule`]: super::AutoModerationRule
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct AutoModerationAction {
+    /// Additional metadata needed during execution for this specific action
+    /// type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<AutoModerationActionMetadata>,
+    /// Type of action.
+    #[serde(rename = "type")]
+    pub kind: AutoModerationActionType,
+}
+
+/// Additional metadata needed during execution for a specific
+/// [`AutoModerationActionType`].
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct AutoModerationActionMetadata {
+    /// Channel to which user content should be logged.
+    ///
+    /// Only present for [`AutoModerationActionType::SendAlertMessage`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<ChannelId>,
+    /// Timeout duration in seconds, up to 2419200 seconds (four weeks).
+    ///
+    /// Only present for [`AutoModerationActionType::Timeout`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_seconds: Option<u32>,
+}
+
+/// Type of [`AutoModerationAction`].
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum AutoModerationActionType {
+    /// Blocks the content of a message according to the rule.
+    BlockMessage = 1,
+    /// Logs user content to a specified channel.
+    SendAlertMessage = 2,
+    /// Timeout user for a specified duration.
+    Timeout = 3,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AutoModerationAction, AutoModerationActionMetadata, AutoModerationActionType};
+    use crate::id::ChannelId;
+    use serde_test::Token;
+
+    #[test]
+    fn test_action() {
+        let value = AutoModerationAction {
+            metadata: Some(AutoModerationActionMetadata {
+                channel_id: Some(ChannelId::new(1)),
+                duration_seconds: None,
+            }),
+            kind: AutoModerationActionType::SendAlertMessage,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AutoModerationAction",
+                    len: 2,
+                },
+                Token::Str("metadata"),
+                Token::Some,
+                Token::Struct {
+                    name: "AutoModerationActionMetadata",
+                    len: 1,
+                },
+                Token::Str("channel_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::StructEnd,
+                Token::Str("type"),
+                Token::U8(2),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_action_type_variants() {
+        serde_test::assert_tokens(&AutoModerationActionType::BlockMessage, &[Token::U8(1)]);
+        serde_test::assert_tokens(&AutoModerationActionType::SendAlertMessage, &[Token::U8(2)]);
+        serde_test::assert_tokens(&AutoModerationActionType::Timeout, &[Token::U8(3)]);
+    }
+}
@@ -0,0 +1,24 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Context in which an [`AutoModerationRule`] is checked.
+///
+/// [`AutoModerationRule`]: super::AutoModerationRule
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum AutoModerationEventType {
+    /// When a member sends or edits a message in a guild.
+    MessageSend = 1,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AutoModerationEventType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&AutoModerationEventType::MessageSend, &[Token::U8(1)]);
+    }
+}
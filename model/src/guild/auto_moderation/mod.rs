@@ -0,0 +1,141 @@
+//! Auto moderation rules, which are checked against content in a guild and
+//! execute actions when triggered.
+
+mod action;
+mod event_type;
+mod preset_type;
+mod trigger_metadata;
+mod trigger_type;
+
+pub use self::{
+    action::{AutoModerationAction, AutoModerationActionMetadata, AutoModerationActionType},
+    event_type::AutoModerationEventType,
+    preset_type::AutoModerationKeywordPresetType,
+    trigger_metadata::AutoModerationTriggerMetadata,
+    trigger_type::AutoModerationTriggerType,
+};
+
+use crate::id::{AutoModerationRuleId, ChannelId, GuildId, RoleId, UserId};
+use serde::{Deserialize, Serialize};
+
+/// Rule that automatically moderates content in a guild.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct AutoModerationRule {
+    /// Actions which will execute when the rule is triggered.
+    pub actions: Vec<AutoModerationAction>,
+    /// ID of the user which created the rule.
+    pub creator_id: UserId,
+    /// Whether the rule is enabled.
+    pub enabled: bool,
+    /// Context in which the rule is checked.
+    pub event_type: AutoModerationEventType,
+    /// Channels that are not affected by the rule.
+    pub exempt_channels: Vec<ChannelId>,
+    /// Roles that are not affected by the rule.
+    pub exempt_roles: Vec<RoleId>,
+    /// ID of the guild the rule belongs to.
+    pub guild_id: GuildId,
+    /// ID of the rule.
+    pub id: AutoModerationRuleId,
+    /// Name of the rule.
+    pub name: String,
+    /// Additional data used to determine whether the rule should be
+    /// triggered.
+    pub trigger_metadata: AutoModerationTriggerMetadata,
+    /// Type of content which can trigger the rule.
+    pub trigger_type: AutoModerationTriggerType,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AutoModerationAction, AutoModerationActionType, AutoModerationEventType,
+        AutoModerationRule, AutoModerationTriggerMetadata, AutoModerationTriggerType,
+    };
+    use crate::id::{AutoModerationRuleId, ChannelId, GuildId, RoleId, UserId};
+    use serde_test::Token;
+
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn test_rule() {
+        let value = AutoModerationRule {
+            actions: vec![AutoModerationAction {
+                metadata: None,
+                kind: AutoModerationActionType::BlockMessage,
+            }],
+            creator_id: UserId::new(1),
+            enabled: true,
+            event_type: AutoModerationEventType::MessageSend,
+            exempt_channels: vec![ChannelId::new(2)],
+            exempt_roles: vec![RoleId::new(3)],
+            guild_id: GuildId::new(4),
+            id: AutoModerationRuleId::new(5),
+            name: "no bad words".to_owned(),
+            trigger_metadata: AutoModerationTriggerMetadata {
+                allow_list: Vec::new(),
+                keyword_filter: vec!["bad".to_owned()],
+                mention_total_limit: None,
+                presets: Vec::new(),
+            },
+            trigger_type: AutoModerationTriggerType::Keyword,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AutoModerationRule",
+                    len: 11,
+                },
+                Token::Str("actions"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "AutoModerationAction",
+                    len: 1,
+                },
+                Token::Str("type"),
+                Token::U8(1),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("creator_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("enabled"),
+                Token::Bool(true),
+                Token::Str("event_type"),
+                Token::U8(1),
+                Token::Str("exempt_channels"),
+                Token::Seq { len: Some(1) },
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::SeqEnd,
+                Token::Str("exempt_roles"),
+                Token::Seq { len: Some(1) },
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("3"),
+                Token::SeqEnd,
+                Token::Str("guild_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("4"),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("5"),
+                Token::Str("name"),
+                Token::Str("no bad words"),
+                Token::Str("trigger_metadata"),
+                Token::Struct {
+                    name: "AutoModerationTriggerMetadata",
+                    len: 1,
+                },
+                Token::Str("keyword_filter"),
+                Token::Seq { len: Some(1) },
+                Token::Str("bad"),
+                Token::SeqEnd,
+                Token::StructEnd,
+                Token::Str("trigger_type"),
+                Token::U8(1),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
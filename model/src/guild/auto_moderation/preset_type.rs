@@ -0,0 +1,31 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Internally pre-defined wordset which will be searched for in content.
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum AutoModerationKeywordPresetType {
+    /// Words that may be considered forms of swearing or cursing.
+    Profanity = 1,
+    /// Words that refer to sexually explicit behavior or activity.
+    SexualContent = 2,
+    /// Personal insults or words that may be considered hate speech.
+    Slurs = 3,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AutoModerationKeywordPresetType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&AutoModerationKeywordPresetType::Profanity, &[Token::U8(1)]);
+        serde_test::assert_tokens(
+            &AutoModerationKeywordPresetType::SexualContent,
+            &[Token::U8(2)],
+        );
+        serde_test::assert_tokens(&AutoModerationKeywordPresetType::Slurs, &[Token::U8(3)]);
+    }
+}
@@ -0,0 +1,60 @@
+use super::AutoModerationKeywordPresetType;
+use serde::{Deserialize, Serialize};
+
+/// Additional data used to determine whether an [`AutoModerationRule`] should
+/// be triggered.
+///
+/// Which fields are relevant depends on the rule's [`AutoModerationTriggerType`].
+///
+/// [`AutoModerationRule`]: super::AutoModerationRule
+/// [`AutoModerationTriggerType`]: super::AutoModerationTriggerType
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct AutoModerationTriggerMetadata {
+    /// Substrings which will be exempt from triggering the preset type.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allow_list: Vec<String>,
+    /// Substrings which will be searched for in content.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keyword_filter: Vec<String>,
+    /// Total number of unique role and user mentions allowed per message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mention_total_limit: Option<u8>,
+    /// Internally pre-defined wordsets which will be searched for in content.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub presets: Vec<AutoModerationKeywordPresetType>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AutoModerationKeywordPresetType, AutoModerationTriggerMetadata};
+    use serde_test::Token;
+
+    #[test]
+    fn test_trigger_metadata() {
+        let value = AutoModerationTriggerMetadata {
+            allow_list: vec!["heck".to_owned()],
+            keyword_filter: Vec::new(),
+            mention_total_limit: None,
+            presets: vec![AutoModerationKeywordPresetType::Profanity],
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AutoModerationTriggerMetadata",
+                    len: 2,
+                },
+                Token::Str("allow_list"),
+                Token::Seq { len: Some(1) },
+                Token::Str("heck"),
+                Token::SeqEnd,
+                Token::Str("presets"),
+                Token::Seq { len: Some(1) },
+                Token::U8(1),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
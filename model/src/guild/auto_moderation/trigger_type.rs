@@ -0,0 +1,33 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Type of content which can trigger an [`AutoModerationRule`].
+///
+/// [`AutoModerationRule`]: super::AutoModerationRule
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum AutoModerationTriggerType {
+    /// Check if content contains words from a list of keywords.
+    Keyword = 1,
+    /// Check if content represents generic spam.
+    Spam = 3,
+    /// Check if content contains words from internal pre-defined wordsets.
+    KeywordPreset = 4,
+    /// Check if content contains more unique mentions than allowed.
+    MentionSpam = 5,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AutoModerationTriggerType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&AutoModerationTriggerType::Keyword, &[Token::U8(1)]);
+        serde_test::assert_tokens(&AutoModerationTriggerType::Spam, &[Token::U8(3)]);
+        serde_test::assert_tokens(&AutoModerationTriggerType::KeywordPreset, &[Token::U8(4)]);
+        serde_test::assert_tokens(&AutoModerationTriggerType::MentionSpam, &[Token::U8(5)]);
+    }
+}
@@ -10,7 +10,7 @@ pub struct Ban {
 #[cfg(test)]
 mod tests {
     use super::{Ban, User};
-    use crate::id::UserId;
+    use crate::{id::UserId, util::ImageHash};
     use serde_test::Token;
 
     #[test]
@@ -18,12 +18,12 @@ mod tests {
         let ban = Ban {
             reason: Some("foo".to_owned()),
             user: User {
-                avatar: Some("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".to_owned()),
+                avatar: Some(ImageHash::parse("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa").unwrap()),
                 bot: false,
                 discriminator: "0001".to_owned(),
                 email: None,
                 flags: None,
-                id: UserId(100_000_000_000_000_000),
+                id: UserId::new(100_000_000_000_000_000),
                 locale: None,
                 mfa_enabled: None,
                 name: "test".to_owned(),
@@ -55,7 +55,7 @@ mod tests {
                 Token::Str("discriminator"),
                 Token::Str("0001"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("100000000000000000"),
                 Token::Str("public_flags"),
                 Token::None,
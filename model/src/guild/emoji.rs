@@ -37,7 +37,7 @@ mod tests {
         let emoji = Emoji {
             animated: false,
             available: true,
-            id: EmojiId(100_000_000_000_000_000),
+            id: EmojiId::new(100_000_000_000_000_000),
             managed: false,
             name: "test".to_owned(),
             require_colons: true,
@@ -48,7 +48,7 @@ mod tests {
                 discriminator: "0001".to_owned(),
                 email: None,
                 flags: None,
-                id: UserId(1),
+                id: UserId::new(1),
                 locale: None,
                 mfa_enabled: None,
                 name: "test".to_owned(),
@@ -71,7 +71,7 @@ mod tests {
                 Token::Str("available"),
                 Token::Bool(true),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "EmojiId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("100000000000000000"),
                 Token::Str("managed"),
                 Token::Bool(false),
@@ -92,7 +92,7 @@ mod tests {
                 Token::Str("discriminator"),
                 Token::Str("0001"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("username"),
                 Token::Str("test"),
@@ -107,18 +107,18 @@ mod tests {
         let emoji = Emoji {
             animated: false,
             available: true,
-            id: EmojiId(100_000_000_000_000_000),
+            id: EmojiId::new(100_000_000_000_000_000),
             managed: false,
             name: "test".to_owned(),
             require_colons: true,
-            roles: vec![RoleId(1)],
+            roles: vec![RoleId::new(1)],
             user: Some(User {
                 avatar: None,
                 bot: false,
                 discriminator: "0001".to_owned(),
                 email: None,
                 flags: None,
-                id: UserId(1),
+                id: UserId::new(1),
                 locale: None,
                 mfa_enabled: None,
                 name: "test".to_owned(),
@@ -141,7 +141,7 @@ mod tests {
                 Token::Str("available"),
                 Token::Bool(true),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "EmojiId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("100000000000000000"),
                 Token::Str("managed"),
                 Token::Bool(false),
@@ -151,7 +151,7 @@ mod tests {
                 Token::Bool(true),
                 Token::Str("roles"),
                 Token::Seq { len: Some(1) },
-                Token::NewtypeStruct { name: "RoleId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::SeqEnd,
                 Token::Str("user"),
@@ -167,7 +167,7 @@ mod tests {
                 Token::Str("discriminator"),
                 Token::Str("0001"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("username"),
                 Token::Str("test"),
@@ -1,9 +1,9 @@
-use crate::{guild::Permissions, id::GuildId};
+use crate::{guild::Permissions, id::GuildId, util::ImageHash};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct GuildInfo {
-    pub icon: Option<String>,
+    pub icon: Option<ImageHash>,
     pub id: GuildId,
     pub name: String,
     pub owner: bool,
@@ -12,14 +12,14 @@ pub struct GuildInfo {
 
 #[cfg(test)]
 mod tests {
-    use super::{GuildId, GuildInfo, Permissions};
+    use super::{GuildId, GuildInfo, ImageHash, Permissions};
     use serde_test::Token;
 
     #[test]
     fn test_guild_info() {
         let value = GuildInfo {
-            icon: Some("icon hash".to_owned()),
-            id: GuildId(1),
+            icon: Some(ImageHash::parse("1acefe340fafb4ecefae407f3abdb323").unwrap()),
+            id: GuildId::new(1),
             name: "guild name".to_owned(),
             owner: false,
             permissions: Permissions::MANAGE_CHANNELS,
@@ -34,9 +34,9 @@ mod tests {
                 },
                 Token::Str("icon"),
                 Token::Some,
-                Token::Str("icon hash"),
+                Token::Str("1acefe340fafb4ecefae407f3abdb323"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("name"),
                 Token::Str("guild name"),
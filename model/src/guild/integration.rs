@@ -42,6 +42,7 @@ mod tests {
         IntegrationId, User,
     };
     use crate::id::{ApplicationId, RoleId, UserId};
+    use crate::util::ImageHash;
     use serde_test::Token;
 
     #[allow(clippy::too_many_lines)]
@@ -57,21 +58,21 @@ mod tests {
             enabled: true,
             expire_behavior: Some(IntegrationExpireBehavior::Kick),
             expire_grace_period: Some(3_600),
-            id: IntegrationId(2),
+            id: IntegrationId::new(2),
             kind: "a".to_owned(),
             name: "integration name".to_owned(),
             revoked: Some(false),
-            role_id: Some(RoleId(3)),
+            role_id: Some(RoleId::new(3)),
             subscriber_count: Some(1337),
             synced_at: Some("timestamp".to_owned()),
             syncing: Some(false),
             user: Some(User {
-                avatar: Some("hash".to_owned()),
+                avatar: Some(ImageHash::parse("58e31b0e4e3e3b2b5b6d8e8d5e5d5f5c").unwrap()),
                 bot: true,
                 discriminator: "1000".to_owned(),
                 email: None,
                 flags: None,
-                id: UserId(4),
+                id: UserId::new(4),
                 locale: None,
                 mfa_enabled: None,
                 name: "user".to_owned(),
@@ -111,9 +112,7 @@ mod tests {
                 Token::Some,
                 Token::U64(3_600),
                 Token::Str("id"),
-                Token::NewtypeStruct {
-                    name: "IntegrationId",
-                },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("type"),
                 Token::Str("a"),
@@ -124,7 +123,7 @@ mod tests {
                 Token::Bool(false),
                 Token::Str("role_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "RoleId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("3"),
                 Token::Str("subscriber_count"),
                 Token::Some,
@@ -143,13 +142,13 @@ mod tests {
                 },
                 Token::Str("avatar"),
                 Token::Some,
-                Token::Str("hash"),
+                Token::Str("58e31b0e4e3e3b2b5b6d8e8d5e5d5f5c"),
                 Token::Str("bot"),
                 Token::Bool(true),
                 Token::Str("discriminator"),
                 Token::Str("1000"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("4"),
                 Token::Str("username"),
                 Token::Str("user"),
@@ -171,7 +170,7 @@ mod tests {
                 bot: None,
                 description: "Friendship is Magic".to_string(),
                 icon: None,
-                id: ApplicationId(123),
+                id: ApplicationId::new(123),
                 name: "Twilight".to_string(),
                 summary: "A cool pony".to_string(),
             }),
@@ -179,21 +178,21 @@ mod tests {
             enabled: true,
             expire_behavior: Some(IntegrationExpireBehavior::Kick),
             expire_grace_period: Some(3_600),
-            id: IntegrationId(2),
+            id: IntegrationId::new(2),
             kind: "a".to_owned(),
             name: "integration name".to_owned(),
             revoked: Some(false),
-            role_id: Some(RoleId(3)),
+            role_id: Some(RoleId::new(3)),
             subscriber_count: Some(1337),
             synced_at: Some("timestamp".to_owned()),
             syncing: Some(false),
             user: Some(User {
-                avatar: Some("hash".to_owned()),
+                avatar: Some(ImageHash::parse("58e31b0e4e3e3b2b5b6d8e8d5e5d5f5c").unwrap()),
                 bot: true,
                 discriminator: "1000".to_owned(),
                 email: None,
                 flags: None,
-                id: UserId(4),
+                id: UserId::new(4),
                 locale: None,
                 mfa_enabled: None,
                 name: "user".to_owned(),
@@ -232,9 +231,7 @@ mod tests {
                 Token::Str("icon"),
                 Token::None,
                 Token::Str("id"),
-                Token::NewtypeStruct {
-                    name: "ApplicationId",
-                },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("123"),
                 Token::Str("name"),
                 Token::Str("Twilight"),
@@ -253,9 +250,7 @@ mod tests {
                 Token::Some,
                 Token::U64(3_600),
                 Token::Str("id"),
-                Token::NewtypeStruct {
-                    name: "IntegrationId",
-                },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("type"),
                 Token::Str("a"),
@@ -266,7 +261,7 @@ mod tests {
                 Token::Bool(false),
                 Token::Str("role_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "RoleId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("3"),
                 Token::Str("subscriber_count"),
                 Token::Some,
@@ -285,13 +280,13 @@ mod tests {
                 },
                 Token::Str("avatar"),
                 Token::Some,
-                Token::Str("hash"),
+                Token::Str("58e31b0e4e3e3b2b5b6d8e8d5e5d5f5c"),
                 Token::Str("bot"),
                 Token::Bool(true),
                 Token::Str("discriminator"),
                 Token::Str("1000"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("4"),
                 Token::Str("username"),
                 Token::Str("user"),
@@ -1,4 +1,4 @@
-use crate::{id::ApplicationId, user::User};
+use crate::{id::ApplicationId, user::User, util::ImageHash};
 
 use serde::{Deserialize, Serialize};
 
@@ -7,7 +7,7 @@ pub struct IntegrationApplication {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bot: Option<User>,
     pub description: String,
-    pub icon: Option<String>,
+    pub icon: Option<ImageHash>,
     pub id: ApplicationId,
     pub name: String,
     pub summary: String,
@@ -25,7 +25,7 @@ mod tests {
             bot: None,
             description: "Friendship is Magic".to_string(),
             icon: None,
-            id: ApplicationId(123),
+            id: ApplicationId::new(123),
             name: "Twilight".to_string(),
             summary: "A cool pony".to_string(),
         };
@@ -42,9 +42,7 @@ mod tests {
                 Token::Str("icon"),
                 Token::None,
                 Token::Str("id"),
-                Token::NewtypeStruct {
-                    name: "ApplicationId",
-                },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("123"),
                 Token::Str("name"),
                 Token::Str("Twilight"),
@@ -64,7 +62,7 @@ mod tests {
                 discriminator: "0001".to_owned(),
                 email: None,
                 flags: None,
-                id: UserId(2),
+                id: UserId::new(2),
                 locale: None,
                 mfa_enabled: None,
                 name: "test".to_owned(),
@@ -75,7 +73,7 @@ mod tests {
             }),
             description: "Friendship is Magic".to_string(),
             icon: None,
-            id: ApplicationId(123),
+            id: ApplicationId::new(123),
             name: "Twilight".to_string(),
             summary: "A cool pony".to_string(),
         };
@@ -100,7 +98,7 @@ mod tests {
                 Token::Str("discriminator"),
                 Token::Str("0001"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("username"),
                 Token::Str("test"),
@@ -110,9 +108,7 @@ mod tests {
                 Token::Str("icon"),
                 Token::None,
                 Token::Str("id"),
-                Token::NewtypeStruct {
-                    name: "ApplicationId",
-                },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("123"),
                 Token::Str("name"),
                 Token::Str("Twilight"),
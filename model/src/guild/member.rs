@@ -179,8 +179,8 @@ mod tests {
     fn test_member_deserializer() {
         let value = Member {
             deaf: false,
-            guild_id: GuildId(1),
-            hoisted_role: Some(RoleId(2)),
+            guild_id: GuildId::new(1),
+            hoisted_role: Some(RoleId::new(2)),
             joined_at: Some("timestamp".to_owned()),
             mute: true,
             nick: Some("twilight".to_owned()),
@@ -192,7 +192,7 @@ mod tests {
                 discriminator: "0001".to_owned(),
                 email: None,
                 flags: None,
-                id: UserId(3),
+                id: UserId::new(3),
                 locale: None,
                 mfa_enabled: None,
                 name: "twilight".to_owned(),
@@ -213,11 +213,11 @@ mod tests {
                 Token::Str("deaf"),
                 Token::Bool(false),
                 Token::Str("guild_id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("hoisted_role"),
                 Token::Some,
-                Token::NewtypeStruct { name: "RoleId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("joined_at"),
                 Token::Some,
@@ -245,7 +245,7 @@ mod tests {
                 Token::Str("discriminator"),
                 Token::Str("0001"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("3"),
                 Token::Str("username"),
                 Token::Str("twilight"),
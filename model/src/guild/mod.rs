@@ -1,15 +1,18 @@
 pub mod audit_log;
+pub mod auto_moderation;
 pub mod member;
 
 mod ban;
 mod default_message_notification_level;
 mod emoji;
 mod explicit_content_filter;
+mod feature;
 mod info;
 mod integration;
 mod integration_account;
 mod integration_application;
 mod integration_expire_behavior;
+mod mfa;
 mod mfa_level;
 mod partial_guild;
 mod partial_member;
@@ -27,12 +30,13 @@ mod widget;
 
 pub use self::{
     ban::Ban, default_message_notification_level::DefaultMessageNotificationLevel, emoji::Emoji,
-    explicit_content_filter::ExplicitContentFilter, info::GuildInfo, integration::GuildIntegration,
-    integration_account::IntegrationAccount, integration_application::IntegrationApplication,
-    integration_expire_behavior::IntegrationExpireBehavior, member::Member, mfa_level::MfaLevel,
-    partial_guild::PartialGuild, partial_member::PartialMember, permissions::Permissions,
-    premium_tier::PremiumTier, preview::GuildPreview, prune::GuildPrune, role::Role,
-    role_tags::RoleTags, status::GuildStatus, system_channel_flags::SystemChannelFlags,
+    explicit_content_filter::ExplicitContentFilter, feature::GuildFeature, info::GuildInfo,
+    integration::GuildIntegration, integration_account::IntegrationAccount,
+    integration_application::IntegrationApplication,
+    integration_expire_behavior::IntegrationExpireBehavior, member::Member, mfa::GuildMfa,
+    mfa_level::MfaLevel, partial_guild::PartialGuild, partial_member::PartialMember,
+    permissions::Permissions, premium_tier::PremiumTier, preview::GuildPreview, prune::GuildPrune,
+    role::Role, role_tags::RoleTags, status::GuildStatus, system_channel_flags::SystemChannelFlags,
     unavailable_guild::UnavailableGuild, verification_level::VerificationLevel,
     widget::GuildWidget,
 };
@@ -40,9 +44,10 @@ pub use self::{
 use self::member::MemberListDeserializer;
 use super::gateway::presence::PresenceListDeserializer;
 use crate::{
-    channel::GuildChannel,
+    channel::{message::sticker::Sticker, GuildChannel},
     gateway::presence::Presence,
     id::{ApplicationId, ChannelId, GuildId, UserId},
+    util::{ImageHash, Locale},
     voice::voice_state::VoiceState,
 };
 use serde::{
@@ -60,16 +65,16 @@ pub struct Guild {
     pub approximate_member_count: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub approximate_presence_count: Option<u64>,
-    pub banner: Option<String>,
+    pub banner: Option<ImageHash>,
     #[serde(default)]
     pub channels: Vec<GuildChannel>,
     pub default_message_notifications: DefaultMessageNotificationLevel,
     pub description: Option<String>,
-    pub discovery_splash: Option<String>,
+    pub discovery_splash: Option<ImageHash>,
     pub emojis: Vec<Emoji>,
     pub explicit_content_filter: ExplicitContentFilter,
-    pub features: Vec<String>,
-    pub icon: Option<String>,
+    pub features: Vec<GuildFeature>,
+    pub icon: Option<ImageHash>,
     pub id: GuildId,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub joined_at: Option<String>,
@@ -94,7 +99,7 @@ pub struct Guild {
     pub owner: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub permissions: Option<Permissions>,
-    pub preferred_locale: String,
+    pub preferred_locale: Locale,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub premium_subscription_count: Option<u64>,
     #[serde(default)]
@@ -104,7 +109,9 @@ pub struct Guild {
     pub region: String,
     pub roles: Vec<Role>,
     pub rules_channel_id: Option<ChannelId>,
-    pub splash: Option<String>,
+    pub splash: Option<ImageHash>,
+    #[serde(default)]
+    pub stickers: Vec<Sticker>,
     pub system_channel_flags: SystemChannelFlags,
     pub system_channel_id: Option<ChannelId>,
     #[serde(default)]
@@ -160,6 +167,7 @@ impl<'de> Deserialize<'de> for Guild {
             Region,
             Roles,
             Splash,
+            Stickers,
             SystemChannelFlags,
             SystemChannelId,
             RulesChannelId,
@@ -217,6 +225,7 @@ impl<'de> Deserialize<'de> for Guild {
                 let mut region = None;
                 let mut roles = None;
                 let mut splash = None::<Option<_>>;
+                let mut stickers = None;
                 let mut system_channel_id = None::<Option<_>>;
                 let mut system_channel_flags = None;
                 let mut rules_channel_id = None::<Option<_>>;
@@ -413,7 +422,7 @@ impl<'de> Deserialize<'de> for Guild {
                                 return Err(DeError::duplicate_field("members"));
                             }
 
-                            let deserializer = MemberListDeserializer::new(GuildId(0));
+                            let deserializer = MemberListDeserializer::new(GuildId::new(0));
 
                             members = Some(map.next_value_seed(deserializer)?);
                         }
@@ -478,7 +487,7 @@ impl<'de> Deserialize<'de> for Guild {
                                 return Err(DeError::duplicate_field("presences"));
                             }
 
-                            let deserializer = PresenceListDeserializer::new(GuildId(0));
+                            let deserializer = PresenceListDeserializer::new(GuildId::new(0));
 
                             presences = Some(map.next_value_seed(deserializer)?);
                         }
@@ -503,6 +512,13 @@ impl<'de> Deserialize<'de> for Guild {
 
                             splash = Some(map.next_value()?);
                         }
+                        Field::Stickers => {
+                            if stickers.is_some() {
+                                return Err(DeError::duplicate_field("stickers"));
+                            }
+
+                            stickers = Some(map.next_value()?);
+                        }
                         Field::SystemChannelId => {
                             if system_channel_id.is_some() {
                                 return Err(DeError::duplicate_field("system_channel_id"));
@@ -614,6 +630,7 @@ impl<'de> Deserialize<'de> for Guild {
                 let mut presences = presences.unwrap_or_default();
                 let rules_channel_id = rules_channel_id.unwrap_or_default();
                 let splash = splash.unwrap_or_default();
+                let stickers = stickers.unwrap_or_default();
                 let system_channel_id = system_channel_id.unwrap_or_default();
                 let unavailable = unavailable.unwrap_or_default();
                 let vanity_url_code = vanity_url_code.unwrap_or_default();
@@ -662,6 +679,7 @@ impl<'de> Deserialize<'de> for Guild {
                     ?rules_channel_id,
                     ?roles,
                     ?splash,
+                    ?stickers,
                     ?system_channel_flags,
                     ?system_channel_id,
                     ?unavailable,
@@ -734,6 +752,7 @@ impl<'de> Deserialize<'de> for Guild {
                     region,
                     roles,
                     splash,
+                    stickers,
                     system_channel_id,
                     system_channel_flags,
                     rules_channel_id,
@@ -783,6 +802,7 @@ impl<'de> Deserialize<'de> for Guild {
             "region",
             "roles",
             "splash",
+            "stickers",
             "system_channel_id",
             "system_channel_flags",
             "rules_channel_id",
@@ -802,7 +822,8 @@ impl<'de> Deserialize<'de> for Guild {
 mod tests {
     use super::{
         ApplicationId, ChannelId, DefaultMessageNotificationLevel, ExplicitContentFilter, Guild,
-        GuildId, MfaLevel, Permissions, PremiumTier, SystemChannelFlags, UserId, VerificationLevel,
+        GuildFeature, GuildId, ImageHash, Locale, MfaLevel, Permissions, PremiumTier,
+        SystemChannelFlags, UserId, VerificationLevel,
     };
     use serde_test::Token;
 
@@ -810,21 +831,21 @@ mod tests {
     #[test]
     fn test_guild() {
         let value = Guild {
-            afk_channel_id: Some(ChannelId(2)),
+            afk_channel_id: Some(ChannelId::new(2)),
             afk_timeout: 900,
-            application_id: Some(ApplicationId(3)),
+            application_id: Some(ApplicationId::new(3)),
             approximate_member_count: Some(1_200),
             approximate_presence_count: Some(900),
-            banner: Some("banner hash".to_owned()),
+            banner: Some(ImageHash::parse("9e2a9b5f4c1d3b6a8e7f0c2d4b6a8e0f").unwrap()),
             channels: Vec::new(),
             default_message_notifications: DefaultMessageNotificationLevel::Mentions,
             description: Some("a description".to_owned()),
-            discovery_splash: Some("discovery splash hash".to_owned()),
+            discovery_splash: Some(ImageHash::parse("3f2e1d0c9b8a7f6e5d4c3b2a1f0e9d8c").unwrap()),
             emojis: Vec::new(),
             explicit_content_filter: ExplicitContentFilter::MembersWithoutRole,
-            features: vec!["a feature".to_owned()],
-            icon: Some("icon hash".to_owned()),
-            id: GuildId(1),
+            features: vec![GuildFeature::AnimatedIcon],
+            icon: Some(ImageHash::parse("1acefe340fafb4ecefae407f3abdb323").unwrap()),
+            id: GuildId::new(1),
             joined_at: Some("timestamp".to_owned()),
             large: true,
             lazy: Some(true),
@@ -835,24 +856,25 @@ mod tests {
             members: Vec::new(),
             mfa_level: MfaLevel::Elevated,
             name: "the name".to_owned(),
-            owner_id: UserId(5),
+            owner_id: UserId::new(5),
             owner: Some(false),
             permissions: Some(Permissions::SEND_MESSAGES),
-            preferred_locale: "en-us".to_owned(),
+            preferred_locale: Locale::EnglishUs,
             premium_subscription_count: Some(3),
             premium_tier: PremiumTier::Tier1,
             presences: Vec::new(),
             region: "us-west".to_owned(),
             roles: Vec::new(),
-            rules_channel_id: Some(ChannelId(6)),
-            splash: Some("splash hash".to_owned()),
+            rules_channel_id: Some(ChannelId::new(6)),
+            splash: Some(ImageHash::parse("0f1e2d3c4b5a69788796a5b4c3d2e1f0").unwrap()),
+            stickers: Vec::new(),
             system_channel_flags: SystemChannelFlags::SUPPRESS_PREMIUM_SUBSCRIPTIONS,
-            system_channel_id: Some(ChannelId(7)),
+            system_channel_id: Some(ChannelId::new(7)),
             unavailable: false,
             vanity_url_code: Some("twilight".to_owned()),
             verification_level: VerificationLevel::Medium,
             voice_states: Vec::new(),
-            widget_channel_id: Some(ChannelId(8)),
+            widget_channel_id: Some(ChannelId::new(8)),
             widget_enabled: Some(true),
         };
 
@@ -861,19 +883,17 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Guild",
-                    len: 44,
+                    len: 45,
                 },
                 Token::Str("afk_channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("afk_timeout"),
                 Token::U64(900),
                 Token::Str("application_id"),
                 Token::Some,
-                Token::NewtypeStruct {
-                    name: "ApplicationId",
-                },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("3"),
                 Token::Str("approximate_member_count"),
                 Token::Some,
@@ -883,7 +903,7 @@ mod tests {
                 Token::U64(900),
                 Token::Str("banner"),
                 Token::Some,
-                Token::Str("banner hash"),
+                Token::Str("9e2a9b5f4c1d3b6a8e7f0c2d4b6a8e0f"),
                 Token::Str("channels"),
                 Token::Seq { len: Some(0) },
                 Token::SeqEnd,
@@ -894,7 +914,7 @@ mod tests {
                 Token::Str("a description"),
                 Token::Str("discovery_splash"),
                 Token::Some,
-                Token::Str("discovery splash hash"),
+                Token::Str("3f2e1d0c9b8a7f6e5d4c3b2a1f0e9d8c"),
                 Token::Str("emojis"),
                 Token::Seq { len: Some(0) },
                 Token::SeqEnd,
@@ -902,13 +922,13 @@ mod tests {
                 Token::U8(1),
                 Token::Str("features"),
                 Token::Seq { len: Some(1) },
-                Token::Str("a feature"),
+                Token::Str("ANIMATED_ICON"),
                 Token::SeqEnd,
                 Token::Str("icon"),
                 Token::Some,
-                Token::Str("icon hash"),
+                Token::Str("1acefe340fafb4ecefae407f3abdb323"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("joined_at"),
                 Token::Some,
@@ -938,7 +958,7 @@ mod tests {
                 Token::Str("name"),
                 Token::Str("the name"),
                 Token::Str("owner_id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("5"),
                 Token::Str("owner"),
                 Token::Some,
@@ -947,7 +967,7 @@ mod tests {
                 Token::Some,
                 Token::Str("2048"),
                 Token::Str("preferred_locale"),
-                Token::Str("en-us"),
+                Token::Str("en-US"),
                 Token::Str("premium_subscription_count"),
                 Token::Some,
                 Token::U64(3),
@@ -963,16 +983,19 @@ mod tests {
                 Token::SeqEnd,
                 Token::Str("rules_channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("6"),
                 Token::Str("splash"),
                 Token::Some,
-                Token::Str("splash hash"),
+                Token::Str("0f1e2d3c4b5a69788796a5b4c3d2e1f0"),
+                Token::Str("stickers"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
                 Token::Str("system_channel_flags"),
                 Token::U64(2),
                 Token::Str("system_channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("7"),
                 Token::Str("unavailable"),
                 Token::Bool(false),
@@ -986,7 +1009,7 @@ mod tests {
                 Token::SeqEnd,
                 Token::Str("widget_channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("8"),
                 Token::Str("widget_enabled"),
                 Token::Some,
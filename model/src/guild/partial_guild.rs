@@ -1,9 +1,10 @@
 use crate::{
     guild::{
-        DefaultMessageNotificationLevel, Emoji, ExplicitContentFilter, MfaLevel, Permissions,
-        PremiumTier, Role, SystemChannelFlags, VerificationLevel,
+        DefaultMessageNotificationLevel, Emoji, ExplicitContentFilter, GuildFeature, MfaLevel,
+        Permissions, PremiumTier, Role, SystemChannelFlags, VerificationLevel,
     },
     id::{ApplicationId, ChannelId, GuildId, UserId},
+    util::{ImageHash, Locale},
 };
 use serde::{Deserialize, Serialize};
 
@@ -13,14 +14,14 @@ pub struct PartialGuild {
     pub afk_channel_id: Option<ChannelId>,
     pub afk_timeout: u64,
     pub application_id: Option<ApplicationId>,
-    pub banner: Option<String>,
+    pub banner: Option<ImageHash>,
     pub default_message_notifications: DefaultMessageNotificationLevel,
     pub description: Option<String>,
-    pub discovery_splash: Option<String>,
+    pub discovery_splash: Option<ImageHash>,
     pub emojis: Vec<Emoji>,
     pub explicit_content_filter: ExplicitContentFilter,
-    pub features: Vec<String>,
-    pub icon: Option<String>,
+    pub features: Vec<GuildFeature>,
+    pub icon: Option<ImageHash>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_members: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -34,14 +35,14 @@ pub struct PartialGuild {
     pub owner: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub permissions: Option<Permissions>,
-    pub preferred_locale: String,
+    pub preferred_locale: Locale,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub premium_subscription_count: Option<u64>,
     pub premium_tier: PremiumTier,
     pub region: String,
     pub roles: Vec<Role>,
     pub rules_channel_id: Option<ChannelId>,
-    pub splash: Option<String>,
+    pub splash: Option<ImageHash>,
     pub system_channel_flags: SystemChannelFlags,
     pub system_channel_id: Option<ChannelId>,
     pub verification_level: VerificationLevel,
@@ -55,9 +56,9 @@ pub struct PartialGuild {
 #[cfg(test)]
 mod tests {
     use super::{
-        ApplicationId, ChannelId, DefaultMessageNotificationLevel, ExplicitContentFilter, GuildId,
-        MfaLevel, PartialGuild, Permissions, PremiumTier, SystemChannelFlags, UserId,
-        VerificationLevel,
+        ApplicationId, ChannelId, DefaultMessageNotificationLevel, ExplicitContentFilter,
+        GuildFeature, GuildId, ImageHash, Locale, MfaLevel, PartialGuild, Permissions, PremiumTier,
+        SystemChannelFlags, UserId, VerificationLevel,
     };
     use serde_test::Token;
 
@@ -65,38 +66,38 @@ mod tests {
     #[test]
     fn test_partial_guild() {
         let value = PartialGuild {
-            id: GuildId(1),
-            afk_channel_id: Some(ChannelId(2)),
+            id: GuildId::new(1),
+            afk_channel_id: Some(ChannelId::new(2)),
             afk_timeout: 900,
-            application_id: Some(ApplicationId(3)),
-            banner: Some("banner hash".to_owned()),
+            application_id: Some(ApplicationId::new(3)),
+            banner: Some(ImageHash::parse("9e2a9b5f4c1d3b6a8e7f0c2d4b6a8e0f").unwrap()),
             default_message_notifications: DefaultMessageNotificationLevel::Mentions,
             description: Some("a description".to_owned()),
-            discovery_splash: Some("discovery splash hash".to_owned()),
+            discovery_splash: Some(ImageHash::parse("3f2e1d0c9b8a7f6e5d4c3b2a1f0e9d8c").unwrap()),
             emojis: Vec::new(),
             explicit_content_filter: ExplicitContentFilter::MembersWithoutRole,
-            features: vec!["a feature".to_owned()],
-            icon: Some("icon hash".to_owned()),
+            features: vec![GuildFeature::AnimatedIcon],
+            icon: Some(ImageHash::parse("1acefe340fafb4ecefae407f3abdb323").unwrap()),
             max_members: Some(25_000),
             max_presences: Some(10_000),
             member_count: Some(12_000),
             mfa_level: MfaLevel::Elevated,
             name: "the name".to_owned(),
-            owner_id: UserId(5),
+            owner_id: UserId::new(5),
             owner: Some(false),
             permissions: Some(Permissions::SEND_MESSAGES),
-            preferred_locale: "en-us".to_owned(),
+            preferred_locale: Locale::EnglishUs,
             premium_subscription_count: Some(3),
             premium_tier: PremiumTier::Tier1,
             region: "us-west".to_owned(),
             roles: Vec::new(),
-            rules_channel_id: Some(ChannelId(6)),
-            splash: Some("splash hash".to_owned()),
+            rules_channel_id: Some(ChannelId::new(6)),
+            splash: Some(ImageHash::parse("0f1e2d3c4b5a69788796a5b4c3d2e1f0").unwrap()),
             system_channel_flags: SystemChannelFlags::SUPPRESS_PREMIUM_SUBSCRIPTIONS,
-            system_channel_id: Some(ChannelId(7)),
+            system_channel_id: Some(ChannelId::new(7)),
             verification_level: VerificationLevel::Medium,
             vanity_url_code: Some("twilight".to_owned()),
-            widget_channel_id: Some(ChannelId(8)),
+            widget_channel_id: Some(ChannelId::new(8)),
             widget_enabled: Some(true),
         };
 
@@ -108,23 +109,21 @@ mod tests {
                     len: 33,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("afk_channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("afk_timeout"),
                 Token::U64(900),
                 Token::Str("application_id"),
                 Token::Some,
-                Token::NewtypeStruct {
-                    name: "ApplicationId",
-                },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("3"),
                 Token::Str("banner"),
                 Token::Some,
-                Token::Str("banner hash"),
+                Token::Str("9e2a9b5f4c1d3b6a8e7f0c2d4b6a8e0f"),
                 Token::Str("default_message_notifications"),
                 Token::U8(1),
                 Token::Str("description"),
@@ -132,7 +131,7 @@ mod tests {
                 Token::Str("a description"),
                 Token::Str("discovery_splash"),
                 Token::Some,
-                Token::Str("discovery splash hash"),
+                Token::Str("3f2e1d0c9b8a7f6e5d4c3b2a1f0e9d8c"),
                 Token::Str("emojis"),
                 Token::Seq { len: Some(0) },
                 Token::SeqEnd,
@@ -140,11 +139,11 @@ mod tests {
                 Token::U8(1),
                 Token::Str("features"),
                 Token::Seq { len: Some(1) },
-                Token::Str("a feature"),
+                Token::Str("ANIMATED_ICON"),
                 Token::SeqEnd,
                 Token::Str("icon"),
                 Token::Some,
-                Token::Str("icon hash"),
+                Token::Str("1acefe340fafb4ecefae407f3abdb323"),
                 Token::Str("max_members"),
                 Token::Some,
                 Token::U64(25_000),
@@ -159,7 +158,7 @@ mod tests {
                 Token::Str("name"),
                 Token::Str("the name"),
                 Token::Str("owner_id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("5"),
                 Token::Str("owner"),
                 Token::Some,
@@ -168,7 +167,7 @@ mod tests {
                 Token::Some,
                 Token::Str("2048"),
                 Token::Str("preferred_locale"),
-                Token::Str("en-us"),
+                Token::Str("en-US"),
                 Token::Str("premium_subscription_count"),
                 Token::Some,
                 Token::U64(3),
@@ -181,16 +180,16 @@ mod tests {
                 Token::SeqEnd,
                 Token::Str("rules_channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("6"),
                 Token::Str("splash"),
                 Token::Some,
-                Token::Str("splash hash"),
+                Token::Str("0f1e2d3c4b5a69788796a5b4c3d2e1f0"),
                 Token::Str("system_channel_flags"),
                 Token::U64(2),
                 Token::Str("system_channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("7"),
                 Token::Str("verification_level"),
                 Token::U8(2),
@@ -199,7 +198,7 @@ mod tests {
                 Token::Str("twilight"),
                 Token::Str("widget_channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("8"),
                 Token::Str("widget_enabled"),
                 Token::Some,
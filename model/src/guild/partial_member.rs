@@ -25,7 +25,7 @@ mod tests {
             mute: true,
             nick: Some("a nickname".to_owned()),
             premium_since: None,
-            roles: vec![RoleId(1)],
+            roles: vec![RoleId::new(1)],
         };
 
         serde_test::assert_tokens(
@@ -47,7 +47,7 @@ mod tests {
                 Token::Str("a nickname"),
                 Token::Str("roles"),
                 Token::Seq { len: Some(1) },
-                Token::NewtypeStruct { name: "RoleId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::SeqEnd,
                 Token::StructEnd,
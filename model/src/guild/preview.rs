@@ -1,4 +1,8 @@
-use crate::{guild::Emoji, id::GuildId};
+use crate::{
+    guild::{Emoji, GuildFeature},
+    id::GuildId,
+    util::ImageHash,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
@@ -6,18 +10,18 @@ pub struct GuildPreview {
     pub approximate_member_count: u64,
     pub approximate_presence_count: u64,
     pub description: Option<String>,
-    pub discovery_splash: Option<String>,
+    pub discovery_splash: Option<ImageHash>,
     pub emojis: Vec<Emoji>,
-    pub features: Vec<String>,
+    pub features: Vec<GuildFeature>,
     pub id: GuildId,
     pub name: String,
-    pub icon: Option<String>,
-    pub splash: Option<String>,
+    pub icon: Option<ImageHash>,
+    pub splash: Option<ImageHash>,
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Emoji, GuildId, GuildPreview};
+    use super::{Emoji, GuildFeature, GuildId, GuildPreview, ImageHash};
     use crate::id::EmojiId;
     use serde_test::Token;
 
@@ -27,22 +31,22 @@ mod tests {
             approximate_member_count: 1_000,
             approximate_presence_count: 500,
             description: Some("guild description".to_owned()),
-            discovery_splash: Some("discovery splash hash".to_owned()),
+            discovery_splash: Some(ImageHash::parse("3f2e1d0c9b8a7f6e5d4c3b2a1f0e9d8c").unwrap()),
             emojis: vec![Emoji {
                 animated: false,
                 available: true,
-                id: EmojiId(2),
+                id: EmojiId::new(2),
                 managed: false,
                 name: "test".to_owned(),
                 require_colons: true,
                 roles: Vec::new(),
                 user: None,
             }],
-            features: vec!["a feature".to_owned()],
-            id: GuildId(1),
+            features: vec![GuildFeature::AnimatedIcon],
+            id: GuildId::new(1),
             name: "guild name".to_owned(),
-            icon: Some("icon hash".to_owned()),
-            splash: Some("splash hash".to_owned()),
+            icon: Some(ImageHash::parse("1acefe340fafb4ecefae407f3abdb323").unwrap()),
+            splash: Some(ImageHash::parse("0f1e2d3c4b5a69788796a5b4c3d2e1f0").unwrap()),
         };
 
         serde_test::assert_tokens(
@@ -61,7 +65,7 @@ mod tests {
                 Token::Str("guild description"),
                 Token::Str("discovery_splash"),
                 Token::Some,
-                Token::Str("discovery splash hash"),
+                Token::Str("3f2e1d0c9b8a7f6e5d4c3b2a1f0e9d8c"),
                 Token::Str("emojis"),
                 Token::Seq { len: Some(1) },
                 Token::Struct {
@@ -73,7 +77,7 @@ mod tests {
                 Token::Str("available"),
                 Token::Bool(true),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "EmojiId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("managed"),
                 Token::Bool(false),
@@ -85,19 +89,19 @@ mod tests {
                 Token::SeqEnd,
                 Token::Str("features"),
                 Token::Seq { len: Some(1) },
-                Token::Str("a feature"),
+                Token::Str("ANIMATED_ICON"),
                 Token::SeqEnd,
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("name"),
                 Token::Str("guild name"),
                 Token::Str("icon"),
                 Token::Some,
-                Token::Str("icon hash"),
+                Token::Str("1acefe340fafb4ecefae407f3abdb323"),
                 Token::Str("splash"),
                 Token::Some,
-                Token::Str("splash hash"),
+                Token::Str("0f1e2d3c4b5a69788796a5b4c3d2e1f0"),
                 Token::StructEnd,
             ],
         );
@@ -27,7 +27,7 @@ mod tests {
         let role = Role {
             color: 0,
             hoist: true,
-            id: RoleId(123),
+            id: RoleId::new(123),
             managed: false,
             mentionable: true,
             name: "test".to_owned(),
@@ -48,7 +48,7 @@ mod tests {
                 Token::Str("hoist"),
                 Token::Bool(true),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "RoleId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("123"),
                 Token::Str("managed"),
                 Token::Bool(false),
@@ -77,8 +77,8 @@ mod tests {
     #[test]
     fn test_role_tags_all() {
         let tags = RoleTags {
-            bot_id: Some(UserId(1)),
-            integration_id: Some(IntegrationId(2)),
+            bot_id: Some(UserId::new(1)),
+            integration_id: Some(IntegrationId::new(2)),
             premium_subscriber: true,
         };
 
@@ -91,13 +91,11 @@ mod tests {
                 },
                 Token::Str("bot_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("integration_id"),
                 Token::Some,
-                Token::NewtypeStruct {
-                    name: "IntegrationId",
-                },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("premium_subscriber"),
                 Token::None,
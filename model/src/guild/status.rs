@@ -18,7 +18,7 @@ mod tests {
     #[test]
     fn test_guild_status() {
         let value = GuildStatus::Offline(UnavailableGuild {
-            id: GuildId(1),
+            id: GuildId::new(1),
             unavailable: true,
         });
 
@@ -30,7 +30,7 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("unavailable"),
                 Token::Bool(true),
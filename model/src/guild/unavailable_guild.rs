@@ -15,7 +15,7 @@ mod tests {
     #[test]
     fn test_unavailable_guild() {
         let value = UnavailableGuild {
-            id: GuildId(1),
+            id: GuildId::new(1),
             unavailable: true,
         };
 
@@ -27,7 +27,7 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("unavailable"),
                 Token::Bool(true),
@@ -15,7 +15,7 @@ mod tests {
     #[test]
     fn test_guild_widget() {
         let value = GuildWidget {
-            channel_id: ChannelId(111_111_111_111_111_111),
+            channel_id: ChannelId::new(111_111_111_111_111_111),
             enabled: true,
         };
 
@@ -27,7 +27,7 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("channel_id"),
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("111111111111111111"),
                 Token::Str("enabled"),
                 Token::Bool(true),
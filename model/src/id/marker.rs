@@ -0,0 +1,190 @@
+//! Markers for various resource types, such as channels or users.
+//!
+//! Markers themselves perform no logical action, and are only used to
+//! ensure that IDs of incorrect types aren't used. If IDs were only 64-bit
+//! integers then a role's ID may be erroneously used in the place of where
+//! a user's ID is required; by using markers it can be ensured that only an
+//! ID with a [`RoleMarker`] can be used where a role's ID is required.
+
+/// Marker for application IDs.
+///
+/// Types such as [`MessageApplication`] use this ID marker.
+///
+/// [`MessageApplication`]: crate::channel::message::MessageApplication
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct ApplicationMarker;
+
+/// Marker for attachment IDs.
+///
+/// Types such as [`Attachment`] use this ID marker.
+///
+/// [`Attachment`]: crate::channel::Attachment
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct AttachmentMarker;
+
+/// Marker for audit log entry IDs.
+///
+/// Types such as [`AuditLogEntry`] use this ID marker.
+///
+/// [`AuditLogEntry`]: crate::guild::audit_log::AuditLogEntry
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct AuditLogEntryMarker;
+
+/// Marker for auto moderation rule IDs.
+///
+/// Types such as [`AutoModerationRule`] use this ID marker.
+///
+/// [`AutoModerationRule`]: crate::guild::auto_moderation::AutoModerationRule
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct AutoModerationRuleMarker;
+
+/// Marker for channel IDs.
+///
+/// Types such as [`GuildChannel`] use this ID marker.
+///
+/// [`GuildChannel`]: crate::channel::GuildChannel
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct ChannelMarker;
+
+/// Marker for application command IDs.
+///
+/// Used for IDs of application commands, such as when parsing command mentions
+/// (`</name:id>`) out of message content.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct CommandMarker;
+
+/// Marker for emoji IDs.
+///
+/// Types such as [`Emoji`] use this ID marker.
+///
+/// [`Emoji`]: crate::guild::Emoji
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct EmojiMarker;
+
+/// Marker for generic IDs.
+///
+/// Used for IDs that can refer to more than one type of resource, such as the
+/// target of an audit log entry.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct GenericMarker;
+
+/// Marker for guild IDs.
+///
+/// Types such as [`Guild`] use this ID marker.
+///
+/// [`Guild`]: crate::guild::Guild
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct GuildMarker;
+
+/// Marker for integration IDs.
+///
+/// Types such as [`GuildIntegration`] use this ID marker.
+///
+/// [`GuildIntegration`]: crate::guild::GuildIntegration
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct IntegrationMarker;
+
+/// Marker for interaction IDs.
+///
+/// Types such as [`MessageInteraction`] use this ID marker.
+///
+/// [`MessageInteraction`]: crate::channel::message::MessageInteraction
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct InteractionMarker;
+
+/// Marker for message IDs.
+///
+/// Types such as [`Message`] use this ID marker.
+///
+/// [`Message`]: crate::channel::Message
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct MessageMarker;
+
+/// Marker for role IDs.
+///
+/// Types such as [`Role`] use this ID marker.
+///
+/// [`Role`]: crate::guild::Role
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct RoleMarker;
+
+/// Marker for scheduled event IDs.
+///
+/// Types such as [`GuildScheduledEvent`] use this ID marker.
+///
+/// [`GuildScheduledEvent`]: crate::scheduled_event::GuildScheduledEvent
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct ScheduledEventMarker;
+
+/// Marker for SKU IDs.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct SkuMarker;
+
+/// Marker for sticker IDs.
+///
+/// Types such as [`Sticker`] use this ID marker.
+///
+/// [`Sticker`]: crate::channel::message::Sticker
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct StickerMarker;
+
+/// Marker for sticker pack IDs.
+///
+/// Types such as [`Sticker`] use this ID marker.
+///
+/// [`Sticker`]: crate::channel::message::Sticker
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct StickerPackMarker;
+
+/// Marker for forum tag IDs.
+///
+/// Types such as [`ForumTag`] use this ID marker.
+///
+/// [`ForumTag`]: crate::channel::forum::ForumTag
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct TagMarker;
+
+/// Marker for team IDs.
+///
+/// Types such as [`Team`] use this ID marker.
+///
+/// [`Team`]: crate::oauth::team::Team
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct TeamMarker;
+
+/// Marker for user IDs.
+///
+/// Types such as [`User`] use this ID marker.
+///
+/// [`User`]: crate::user::User
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct UserMarker;
+
+/// Marker for webhook IDs.
+///
+/// Types such as [`Webhook`] use this ID marker.
+///
+/// [`Webhook`]: crate::channel::Webhook
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct WebhookMarker;
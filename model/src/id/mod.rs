@@ -0,0 +1,406 @@
+//! Type-safe IDs for each resource to avoid mixing the IDs of resources like
+//! channels and guilds.
+//!
+//! IDs are a single generic [`Id`] type, with a [marker] denoting the type of
+//! resource the ID belongs to. Type aliases such as [`ChannelId`] are provided
+//! for each marker.
+//!
+//! # Examples
+//!
+//! Create an ID for a channel and read its creation time:
+//!
+//! ```
+//! use twilight_model::id::ChannelId;
+//!
+//! let channel_id = ChannelId::new(123_456_789_012_345_678);
+//! assert_eq!(123_456_789_012_345_678, channel_id.get());
+//! assert_eq!(1_449_504_792_216, channel_id.timestamp());
+//! ```
+//!
+//! # serde
+//!
+//! These IDs support deserializing from both integers and strings and serialize
+//! into strings.
+
+pub mod marker;
+
+use self::marker::{
+    ApplicationMarker, AttachmentMarker, AuditLogEntryMarker, AutoModerationRuleMarker,
+    ChannelMarker, CommandMarker, EmojiMarker, GenericMarker, GuildMarker, IntegrationMarker,
+    InteractionMarker, MessageMarker, RoleMarker, ScheduledEventMarker, TagMarker, UserMarker,
+    WebhookMarker,
+};
+use serde::{
+    de::{Deserializer, Error as DeError, Visitor},
+    ser::Serializer,
+    Deserialize, Serialize,
+};
+use std::{
+    any,
+    cmp::Ordering,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+/// Discord's custom epoch, the unix time in milliseconds for the first second
+/// of 2015.
+const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
+/// ID of a resource, such as the ID of a [channel] or [user].
+///
+/// The marker type parameter denotes the type of resource that the ID
+/// belongs to, so that, for example, a guild's ID can't be used where a
+/// channel's ID is expected.
+///
+/// [channel]: marker::ChannelMarker
+/// [user]: marker::UserMarker
+pub struct Id<T> {
+    phantom: PhantomData<fn(T) -> T>,
+    value: u64,
+}
+
+impl<T> Id<T> {
+    /// Create a new ID from a 64-bit integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::id::{marker::GuildMarker, Id};
+    ///
+    /// let id: Id<GuildMarker> = Id::new(123);
+    /// assert_eq!(123, id.get());
+    /// ```
+    pub const fn new(value: u64) -> Self {
+        Self {
+            phantom: PhantomData,
+            value,
+        }
+    }
+
+    /// Return the inner primitive value.
+    pub const fn get(self) -> u64 {
+        self.value
+    }
+
+    /// Cast an ID from one type to another.
+    ///
+    /// This is useful where one ID is known to also be a valid ID of another
+    /// resource, such as a guild's `@everyone` role having the same ID as the
+    /// guild itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::id::{GuildId, RoleId};
+    ///
+    /// let guild_id = GuildId::new(123);
+    /// let everyone_role_id: RoleId = guild_id.cast();
+    /// assert_eq!(123, everyone_role_id.get());
+    /// ```
+    pub const fn cast<New>(self) -> Id<New> {
+        Id::new(self.value)
+    }
+
+    /// The Unix epoch of the ID in milliseconds, indicating when it was
+    /// generated.
+    ///
+    /// Derived from bits 22..63 of the ID.
+    #[allow(clippy::cast_possible_wrap)]
+    pub const fn timestamp(self) -> i64 {
+        ((self.value >> 22) + DISCORD_EPOCH) as i64
+    }
+
+    /// The ID of the internal worker that generated the ID.
+    ///
+    /// Derived from bits 17..21 of the ID.
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn worker_id(self) -> u8 {
+        ((self.value & 0x003E_0000) >> 17) as u8
+    }
+
+    /// The ID of the internal process that generated the ID.
+    ///
+    /// Derived from bits 12..16 of the ID.
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn process_id(self) -> u8 {
+        ((self.value & 0x1F000) >> 12) as u8
+    }
+
+    /// The increment of the ID. For every ID that is generated on a process,
+    /// this number is incremented.
+    ///
+    /// Derived from bits 0..11 of the ID.
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn increment(self) -> u16 {
+        (self.value & 0xFFF) as u16
+    }
+}
+
+impl<T> Clone for Id<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Id<T> {}
+
+impl<T> Debug for Id<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let type_name = any::type_name::<T>();
+
+        // Only print the name of the marker rather than its full path.
+        let marker = type_name.rsplit("::").next().unwrap_or(type_name);
+
+        write!(f, "Id<{}>({})", marker, self.value)
+    }
+}
+
+impl<T> Default for Id<T> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<T> Display for Id<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.value, f)
+    }
+}
+
+impl<T> Eq for Id<T> {}
+
+impl<T> From<u64> for Id<T> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> From<Id<T>> for u64 {
+    fn from(id: Id<T>) -> Self {
+        id.get()
+    }
+}
+
+impl<T> Hash for Id<T> {
+    fn hash<U: Hasher>(&self, state: &mut U) {
+        state.write_u64(self.value);
+    }
+}
+
+impl<T> Ord for Id<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<T> PartialEq for Id<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T> PartialOrd for Id<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+struct IdVisitor<T>(PhantomData<fn(T) -> T>);
+
+impl<'de, T> Visitor<'de> for IdVisitor<T> {
+    type Value = Id<T>;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("string or integer snowflake")
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_u64<E: DeError>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(Id::new(value))
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+        value.parse().map(Id::new).map_err(DeError::custom)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Id<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_newtype_struct("Id", IdVisitor(PhantomData))
+    }
+}
+
+impl<T> Serialize for Id<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        struct Value(u64);
+
+        impl Serialize for Value {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(&self.0)
+            }
+        }
+
+        serializer.serialize_newtype_struct("Id", &Value(self.value))
+    }
+}
+
+/// ID of an application.
+pub type ApplicationId = Id<ApplicationMarker>;
+
+/// ID of an attachment.
+pub type AttachmentId = Id<AttachmentMarker>;
+
+/// ID of an audit log entry.
+pub type AuditLogEntryId = Id<AuditLogEntryMarker>;
+
+/// ID of an auto moderation rule.
+pub type AutoModerationRuleId = Id<AutoModerationRuleMarker>;
+
+/// ID of a channel.
+pub type ChannelId = Id<ChannelMarker>;
+
+/// ID of an application command.
+pub type CommandId = Id<CommandMarker>;
+
+/// ID of an emoji.
+pub type EmojiId = Id<EmojiMarker>;
+
+/// ID which may refer to more than one type of resource.
+pub type GenericId = Id<GenericMarker>;
+
+/// ID of a guild.
+pub type GuildId = Id<GuildMarker>;
+
+/// ID of an integration.
+pub type IntegrationId = Id<IntegrationMarker>;
+
+/// ID of an interaction.
+pub type InteractionId = Id<InteractionMarker>;
+
+/// ID of a message.
+pub type MessageId = Id<MessageMarker>;
+
+/// ID of a role.
+pub type RoleId = Id<RoleMarker>;
+
+/// ID of a scheduled event.
+pub type ScheduledEventId = Id<ScheduledEventMarker>;
+
+/// ID of a forum tag.
+pub type TagId = Id<TagMarker>;
+
+/// ID of a user.
+pub type UserId = Id<UserMarker>;
+
+/// ID of a webhook.
+pub type WebhookId = Id<WebhookMarker>;
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        marker::{GuildMarker, RoleMarker, UserMarker},
+        ChannelId, GuildId, Id, RoleId, UserId,
+    };
+    use serde_test::Token;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn test_id_deser() {
+        serde_test::assert_tokens(
+            &ChannelId::new(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("114941315417899012"),
+            ],
+        );
+        serde_test::assert_de_tokens(
+            &ChannelId::new(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct { name: "Id" },
+                Token::U64(114_941_315_417_899_012),
+            ],
+        );
+        serde_test::assert_de_tokens(
+            &UserId::new(114_941_315_417_899_012),
+            &[Token::Str("114941315417899012")],
+        );
+        serde_test::assert_de_tokens(
+            &UserId::new(114_941_315_417_899_012),
+            &[Token::U64(114_941_315_417_899_012)],
+        );
+    }
+
+    #[test]
+    fn test_id_from_json() {
+        let id: GuildId = serde_json::from_str(r#""114941315417899012""#).unwrap();
+        assert_eq!(GuildId::new(114_941_315_417_899_012), id);
+
+        let id: GuildId = serde_json::from_str("114941315417899012").unwrap();
+        assert_eq!(GuildId::new(114_941_315_417_899_012), id);
+    }
+
+    #[test]
+    fn test_cast() {
+        let guild_id: Id<GuildMarker> = Id::new(123);
+        let role_id: Id<RoleMarker> = guild_id.cast();
+
+        assert_eq!(RoleId::new(123), role_id);
+    }
+
+    #[test]
+    fn test_debug() {
+        assert_eq!(
+            "Id<UserMarker>(123)",
+            format!("{:?}", Id::<UserMarker>::new(123))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("123", ChannelId::new(123).to_string());
+    }
+
+    #[test]
+    fn test_hash() {
+        let hash = |value: &dyn Fn(&mut DefaultHasher)| {
+            let mut hasher = DefaultHasher::new();
+            value(&mut hasher);
+            hasher.finish()
+        };
+
+        assert_eq!(
+            hash(&|hasher| 123_u64.hash(hasher)),
+            hash(&|hasher| ChannelId::new(123).hash(hasher)),
+        );
+    }
+
+    #[test]
+    fn test_ord() {
+        assert!(ChannelId::new(1) < ChannelId::new(2));
+        assert_eq!(vec![UserId::new(1), UserId::new(2), UserId::new(3)], {
+            let mut ids = vec![UserId::new(3), UserId::new(1), UserId::new(2)];
+            ids.sort();
+            ids
+        },);
+    }
+
+    #[test]
+    fn test_snowflake() {
+        let id = UserId::new(105_484_726_235_607_040);
+
+        assert_eq!(1_445_219_918_546, id.timestamp());
+        assert_eq!(0, id.worker_id());
+        assert_eq!(11, id.process_id());
+        assert_eq!(0, id.increment());
+    }
+}
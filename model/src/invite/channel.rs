@@ -21,7 +21,7 @@ mod tests {
     #[test]
     fn test_invite_channel() {
         let value = InviteChannel {
-            id: ChannelId(1),
+            id: ChannelId::new(1),
             name: Some("channel name".to_owned()),
             kind: ChannelType::GuildText,
         };
@@ -34,7 +34,7 @@ mod tests {
                     len: 3,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("name"),
                 Token::Some,
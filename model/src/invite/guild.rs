@@ -1,22 +1,26 @@
-use crate::{guild::VerificationLevel, id::GuildId};
+use crate::{
+    guild::{GuildFeature, VerificationLevel},
+    id::GuildId,
+    util::ImageHash,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct InviteGuild {
     /// Hash of the banner image.
-    pub banner: Option<String>,
+    pub banner: Option<ImageHash>,
     /// Description used for guild discovery.
     pub description: Option<String>,
     /// List of features that the guild has had enabled.
-    pub features: Vec<String>,
+    pub features: Vec<GuildFeature>,
     /// Hash of the icon image.
-    pub icon: Option<String>,
+    pub icon: Option<ImageHash>,
     /// ID of the guild.
     pub id: GuildId,
     /// Name of the guild.
     pub name: String,
     /// Hash of the splash image.
-    pub splash: Option<String>,
+    pub splash: Option<ImageHash>,
     /// Vanity code unique to the guild for invites.
     pub vanity_url_code: Option<String>,
     /// Account verification level required to participate.
@@ -25,19 +29,19 @@ pub struct InviteGuild {
 
 #[cfg(test)]
 mod tests {
-    use super::{GuildId, InviteGuild, VerificationLevel};
+    use super::{GuildFeature, GuildId, ImageHash, InviteGuild, VerificationLevel};
     use serde_test::Token;
 
     #[test]
     fn test_invite_guild() {
         let value = InviteGuild {
-            banner: Some("banner hash".to_owned()),
+            banner: Some(ImageHash::parse("9e2a9b5f4c1d3b6a8e7f0c2d4b6a8e0f").unwrap()),
             description: Some("a description".to_owned()),
-            features: vec!["a feature".to_owned()],
-            icon: Some("icon hash".to_owned()),
-            id: GuildId(1),
+            features: vec![GuildFeature::AnimatedIcon],
+            icon: Some(ImageHash::parse("1acefe340fafb4ecefae407f3abdb323").unwrap()),
+            id: GuildId::new(1),
             name: "guild name".to_owned(),
-            splash: Some("splash hash".to_owned()),
+            splash: Some(ImageHash::parse("0f1e2d3c4b5a69788796a5b4c3d2e1f0").unwrap()),
             vanity_url_code: Some("twilight".to_owned()),
             verification_level: VerificationLevel::Medium,
         };
@@ -51,25 +55,25 @@ mod tests {
                 },
                 Token::Str("banner"),
                 Token::Some,
-                Token::Str("banner hash"),
+                Token::Str("9e2a9b5f4c1d3b6a8e7f0c2d4b6a8e0f"),
                 Token::Str("description"),
                 Token::Some,
                 Token::Str("a description"),
                 Token::Str("features"),
                 Token::Seq { len: Some(1) },
-                Token::Str("a feature"),
+                Token::Str("ANIMATED_ICON"),
                 Token::SeqEnd,
                 Token::Str("icon"),
                 Token::Some,
-                Token::Str("icon hash"),
+                Token::Str("1acefe340fafb4ecefae407f3abdb323"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("name"),
                 Token::Str("guild name"),
                 Token::Str("splash"),
                 Token::Some,
-                Token::Str("splash hash"),
+                Token::Str("0f1e2d3c4b5a69788796a5b4c3d2e1f0"),
                 Token::Str("vanity_url_code"),
                 Token::Some,
                 Token::Str("twilight"),
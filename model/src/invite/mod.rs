@@ -34,8 +34,9 @@ mod tests {
     use super::{Invite, InviteChannel, InviteGuild, TargetUserType, User};
     use crate::{
         channel::ChannelType,
-        guild::VerificationLevel,
+        guild::{GuildFeature, VerificationLevel},
         id::{ChannelId, GuildId, UserId},
+        util::ImageHash,
     };
     use serde_test::Token;
 
@@ -45,7 +46,7 @@ mod tests {
             approximate_member_count: Some(31),
             approximate_presence_count: Some(7),
             channel: InviteChannel {
-                id: ChannelId(2),
+                id: ChannelId::new(2),
                 kind: ChannelType::Group,
                 name: None,
             },
@@ -75,7 +76,7 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("type"),
                 Token::U8(3),
@@ -97,19 +98,19 @@ mod tests {
             approximate_member_count: Some(31),
             approximate_presence_count: Some(7),
             channel: InviteChannel {
-                id: ChannelId(2),
+                id: ChannelId::new(2),
                 kind: ChannelType::Group,
                 name: None,
             },
             code: "uniquecode".to_owned(),
             guild: Some(InviteGuild {
-                banner: Some("banner hash".to_owned()),
+                banner: Some(ImageHash::parse("9e2a9b5f4c1d3b6a8e7f0c2d4b6a8e0f").unwrap()),
                 description: Some("a description".to_owned()),
-                features: vec!["a feature".to_owned()],
-                icon: Some("icon hash".to_owned()),
-                id: GuildId(1),
+                features: vec![GuildFeature::AnimatedIcon],
+                icon: Some(ImageHash::parse("1acefe340fafb4ecefae407f3abdb323").unwrap()),
+                id: GuildId::new(1),
                 name: "guild name".to_owned(),
-                splash: Some("splash hash".to_owned()),
+                splash: Some(ImageHash::parse("0f1e2d3c4b5a69788796a5b4c3d2e1f0").unwrap()),
                 vanity_url_code: Some("twilight".to_owned()),
                 verification_level: VerificationLevel::Medium,
             }),
@@ -119,7 +120,7 @@ mod tests {
                 discriminator: "0001".to_owned(),
                 email: None,
                 flags: None,
-                id: UserId(2),
+                id: UserId::new(2),
                 locale: None,
                 mfa_enabled: None,
                 name: "test".to_owned(),
@@ -135,7 +136,7 @@ mod tests {
                 discriminator: "0001".to_owned(),
                 email: None,
                 flags: None,
-                id: UserId(2),
+                id: UserId::new(2),
                 locale: None,
                 mfa_enabled: None,
                 name: "test".to_owned(),
@@ -165,7 +166,7 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("type"),
                 Token::U8(3),
@@ -180,25 +181,25 @@ mod tests {
                 },
                 Token::Str("banner"),
                 Token::Some,
-                Token::Str("banner hash"),
+                Token::Str("9e2a9b5f4c1d3b6a8e7f0c2d4b6a8e0f"),
                 Token::Str("description"),
                 Token::Some,
                 Token::Str("a description"),
                 Token::Str("features"),
                 Token::Seq { len: Some(1) },
-                Token::Str("a feature"),
+                Token::Str("ANIMATED_ICON"),
                 Token::SeqEnd,
                 Token::Str("icon"),
                 Token::Some,
-                Token::Str("icon hash"),
+                Token::Str("1acefe340fafb4ecefae407f3abdb323"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("name"),
                 Token::Str("guild name"),
                 Token::Str("splash"),
                 Token::Some,
-                Token::Str("splash hash"),
+                Token::Str("0f1e2d3c4b5a69788796a5b4c3d2e1f0"),
                 Token::Str("vanity_url_code"),
                 Token::Some,
                 Token::Str("twilight"),
@@ -218,7 +219,7 @@ mod tests {
                 Token::Str("discriminator"),
                 Token::Str("0001"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("username"),
                 Token::Str("test"),
@@ -239,7 +240,7 @@ mod tests {
                 Token::Str("discriminator"),
                 Token::Str("0001"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("username"),
                 Token::Str("test"),
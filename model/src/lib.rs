@@ -53,5 +53,7 @@ pub mod guild;
 pub mod id;
 pub mod invite;
 pub mod oauth;
+pub mod scheduled_event;
 pub mod user;
+pub mod util;
 pub mod voice;
@@ -2,6 +2,7 @@ use crate::{
     id::{ApplicationId, GuildId},
     oauth::{id::SkuId, team::Team},
     user::User,
+    util::ImageHash,
 };
 use serde::{Deserialize, Serialize};
 
@@ -9,10 +10,10 @@ use serde::{Deserialize, Serialize};
 pub struct CurrentApplicationInfo {
     pub bot_public: bool,
     pub bot_require_code_grant: bool,
-    pub cover_image: Option<String>,
+    pub cover_image: Option<ImageHash>,
     pub description: String,
     pub guild_id: Option<GuildId>,
-    pub icon: Option<String>,
+    pub icon: Option<ImageHash>,
     pub id: ApplicationId,
     pub name: String,
     pub owner: User,
@@ -27,7 +28,7 @@ pub struct CurrentApplicationInfo {
 
 #[cfg(test)]
 mod tests {
-    use super::{CurrentApplicationInfo, GuildId, SkuId, Team, User};
+    use super::{CurrentApplicationInfo, GuildId, ImageHash, SkuId, Team, User};
     use crate::{id::ApplicationId, id::UserId, oauth::id::TeamId};
     use serde_test::Token;
 
@@ -37,11 +38,11 @@ mod tests {
         let value = CurrentApplicationInfo {
             bot_public: true,
             bot_require_code_grant: false,
-            cover_image: Some("cover image hash".to_owned()),
+            cover_image: Some(ImageHash::parse("5f3f2e7b1e1c43f2a0c5e6d8b8e9a7c1").unwrap()),
             description: "a pretty cool application".to_owned(),
-            guild_id: Some(GuildId(1)),
-            icon: Some("icon hash".to_owned()),
-            id: ApplicationId(2),
+            guild_id: Some(GuildId::new(1)),
+            icon: Some(ImageHash::parse("1acefe340fafb4ecefae407f3abdb323").unwrap()),
+            id: ApplicationId::new(2),
             name: "cool application".to_owned(),
             owner: User {
                 avatar: None,
@@ -49,7 +50,7 @@ mod tests {
                 discriminator: "0001".to_owned(),
                 email: None,
                 flags: None,
-                id: UserId(3),
+                id: UserId::new(3),
                 locale: None,
                 mfa_enabled: None,
                 name: "app dev".to_owned(),
@@ -58,15 +59,15 @@ mod tests {
                 system: None,
                 verified: None,
             },
-            primary_sku_id: Some(SkuId(4)),
+            primary_sku_id: Some(SkuId::new(4)),
             rpc_origins: vec!["one".to_owned()],
             slug: Some("app slug".to_owned()),
             summary: "a summary".to_owned(),
             team: Some(Team {
                 icon: None,
-                id: TeamId(5),
+                id: TeamId::new(5),
                 members: Vec::new(),
-                owner_user_id: UserId(6),
+                owner_user_id: UserId::new(6),
             }),
             verify_key: "key".to_owned(),
         };
@@ -84,20 +85,18 @@ mod tests {
                 Token::Bool(false),
                 Token::Str("cover_image"),
                 Token::Some,
-                Token::Str("cover image hash"),
+                Token::Str("5f3f2e7b1e1c43f2a0c5e6d8b8e9a7c1"),
                 Token::Str("description"),
                 Token::Str("a pretty cool application"),
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("icon"),
                 Token::Some,
-                Token::Str("icon hash"),
+                Token::Str("1acefe340fafb4ecefae407f3abdb323"),
                 Token::Str("id"),
-                Token::NewtypeStruct {
-                    name: "ApplicationId",
-                },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("name"),
                 Token::Str("cool application"),
@@ -113,14 +112,14 @@ mod tests {
                 Token::Str("discriminator"),
                 Token::Str("0001"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("3"),
                 Token::Str("username"),
                 Token::Str("app dev"),
                 Token::StructEnd,
                 Token::Str("primary_sku_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "SkuId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("4"),
                 Token::Str("rpc_origins"),
                 Token::Seq { len: Some(1) },
@@ -140,13 +139,13 @@ mod tests {
                 Token::Str("icon"),
                 Token::None,
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "TeamId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("5"),
                 Token::Str("members"),
                 Token::Seq { len: Some(0) },
                 Token::SeqEnd,
                 Token::Str("owner_user_id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("6"),
                 Token::StructEnd,
                 Token::Str("verify_key"),
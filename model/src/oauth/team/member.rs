@@ -23,14 +23,14 @@ mod tests {
         let value = TeamMember {
             membership_state: TeamMembershipState::Accepted,
             permissions: vec!["*".to_owned()],
-            team_id: TeamId(1),
+            team_id: TeamId::new(1),
             user: User {
                 avatar: None,
                 bot: false,
                 discriminator: "0001".to_owned(),
                 email: None,
                 flags: None,
-                id: UserId(2),
+                id: UserId::new(2),
                 locale: None,
                 mfa_enabled: None,
                 name: "test".to_owned(),
@@ -55,7 +55,7 @@ mod tests {
                 Token::Str("*"),
                 Token::SeqEnd,
                 Token::Str("team_id"),
-                Token::NewtypeStruct { name: "TeamId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("user"),
                 Token::Struct {
@@ -69,7 +69,7 @@ mod tests {
                 Token::Str("discriminator"),
                 Token::Str("0001"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("username"),
                 Token::Str("test"),
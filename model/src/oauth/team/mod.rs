@@ -3,12 +3,12 @@ mod membership_state;
 
 pub use self::{member::TeamMember, membership_state::TeamMembershipState};
 
-use crate::{id::UserId, oauth::id::TeamId};
+use crate::{id::UserId, oauth::id::TeamId, util::ImageHash};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct Team {
-    pub icon: Option<String>,
+    pub icon: Option<ImageHash>,
     pub id: TeamId,
     pub members: Vec<TeamMember>,
     pub owner_user_id: UserId,
@@ -16,16 +16,16 @@ pub struct Team {
 
 #[cfg(test)]
 mod tests {
-    use super::{Team, TeamId, UserId};
+    use super::{ImageHash, Team, TeamId, UserId};
     use serde_test::Token;
 
     #[test]
     fn test_team() {
         let value = Team {
-            icon: Some("hash".to_owned()),
-            id: TeamId(1),
+            icon: Some(ImageHash::parse("58e31b0e4e3e3b2b5b6d8e8d5e5d5f5c").unwrap()),
+            id: TeamId::new(1),
             members: Vec::new(),
-            owner_user_id: UserId(2),
+            owner_user_id: UserId::new(2),
         };
 
         serde_test::assert_tokens(
@@ -37,15 +37,15 @@ mod tests {
                 },
                 Token::Str("icon"),
                 Token::Some,
-                Token::Str("hash"),
+                Token::Str("58e31b0e4e3e3b2b5b6d8e8d5e5d5f5c"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "TeamId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("members"),
                 Token::Seq { len: Some(0) },
                 Token::SeqEnd,
                 Token::Str("owner_user_id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::StructEnd,
             ],
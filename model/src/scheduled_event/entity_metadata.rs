@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+/// Additional metadata for a [`GuildScheduledEvent`].
+///
+/// [`GuildScheduledEvent`]: super::GuildScheduledEvent
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct EntityMetadata {
+    /// Physical location of an event with the [`External`] entity type.
+    ///
+    /// [`External`]: super::EntityType::External
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EntityMetadata;
+    use serde_test::Token;
+
+    #[test]
+    fn test_entity_metadata() {
+        let value = EntityMetadata {
+            location: Some("park".to_owned()),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "EntityMetadata",
+                    len: 1,
+                },
+                Token::Str("location"),
+                Token::Some,
+                Token::Str("park"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
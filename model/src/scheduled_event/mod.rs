@@ -0,0 +1,161 @@
+//! Scheduled events, which are planned activities in a guild that members can
+//! subscribe to.
+
+mod entity_metadata;
+mod entity_type;
+mod privacy_level;
+mod status;
+
+pub use self::{
+    entity_metadata::EntityMetadata, entity_type::EntityType, privacy_level::PrivacyLevel,
+    status::Status,
+};
+
+use crate::{
+    id::{ChannelId, GenericId, GuildId, ScheduledEventId, UserId},
+    user::User,
+    util::ImageHash,
+};
+use serde::{Deserialize, Serialize};
+
+/// Planned activity in a guild.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct GuildScheduledEvent {
+    /// ID of the stage or voice channel if there is one.
+    ///
+    /// Present on events of [`EntityType::StageInstance`] and
+    /// [`EntityType::Voice`].
+    pub channel_id: Option<ChannelId>,
+    /// User object of the event's creator.
+    ///
+    /// Only present on events created after October 25th, 2021.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator: Option<User>,
+    /// ID of the event's creator.
+    ///
+    /// Only present on events created after October 25th, 2021.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator_id: Option<UserId>,
+    /// Description of the event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// ID of the event's entity.
+    ///
+    /// For example, the ID of the stage instance hosting the event.
+    pub entity_id: Option<GenericId>,
+    /// Metadata of an entity, if it exists.
+    ///
+    /// Currently, only present on events of [`EntityType::External`].
+    pub entity_metadata: Option<EntityMetadata>,
+    /// Type of entity associated with the event.
+    pub entity_type: EntityType,
+    /// ID of the guild the event takes place in.
+    pub guild_id: GuildId,
+    /// ID of the event.
+    pub id: ScheduledEventId,
+    /// Hash of the event's cover image, if it has one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<ImageHash>,
+    /// Name of the event.
+    pub name: String,
+    /// Privacy level of the event.
+    pub privacy_level: PrivacyLevel,
+    /// Scheduled end time of the event.
+    ///
+    /// Required on events of type [`EntityType::External`]. It also may be
+    /// present in other event types.
+    pub scheduled_end_time: Option<String>,
+    /// Scheduled start time of the event.
+    pub scheduled_start_time: String,
+    /// Status of the event.
+    pub status: Status,
+    /// Number of users subscribed to the event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_count: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EntityMetadata, EntityType, GuildScheduledEvent, PrivacyLevel, Status};
+    use crate::id::{GuildId, ScheduledEventId, UserId};
+    use serde_test::Token;
+
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn test_scheduled_event() {
+        let value = GuildScheduledEvent {
+            channel_id: None,
+            creator: None,
+            creator_id: Some(UserId::new(1)),
+            description: Some("this is a dance party for garfield lovers".to_owned()),
+            entity_id: None,
+            entity_metadata: Some(EntityMetadata {
+                location: Some("garfield's house".to_owned()),
+            }),
+            entity_type: EntityType::External,
+            guild_id: GuildId::new(2),
+            id: ScheduledEventId::new(3),
+            image: None,
+            name: "garfield dance party".to_owned(),
+            privacy_level: PrivacyLevel::GuildOnly,
+            scheduled_end_time: Some("2022-01-01T01:00:00.000000+00:00".to_owned()),
+            scheduled_start_time: "2022-01-01T00:00:00.000000+00:00".to_owned(),
+            status: Status::Completed,
+            user_count: Some(1),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "GuildScheduledEvent",
+                    len: 14,
+                },
+                Token::Str("channel_id"),
+                Token::None,
+                Token::Str("creator_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("description"),
+                Token::Some,
+                Token::Str("this is a dance party for garfield lovers"),
+                Token::Str("entity_id"),
+                Token::None,
+                Token::Str("entity_metadata"),
+                Token::Some,
+                Token::Struct {
+                    name: "EntityMetadata",
+                    len: 1,
+                },
+                Token::Str("location"),
+                Token::Some,
+                Token::Str("garfield's house"),
+                Token::StructEnd,
+                Token::Str("entity_type"),
+                Token::U8(3),
+                Token::Str("guild_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("3"),
+                Token::Str("name"),
+                Token::Str("garfield dance party"),
+                Token::Str("privacy_level"),
+                Token::U8(2),
+                Token::Str("scheduled_end_time"),
+                Token::Some,
+                Token::Str("2022-01-01T01:00:00.000000+00:00"),
+                Token::Str("scheduled_start_time"),
+                Token::Str("2022-01-01T00:00:00.000000+00:00"),
+                Token::Str("status"),
+                Token::U8(3),
+                Token::Str("user_count"),
+                Token::Some,
+                Token::U64(1),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
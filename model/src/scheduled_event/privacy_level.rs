@@ -0,0 +1,24 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Privacy level of a [`GuildScheduledEvent`].
+///
+/// [`GuildScheduledEvent`]: super::GuildScheduledEvent
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum PrivacyLevel {
+    /// Event is only accessible to guild members.
+    GuildOnly = 2,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PrivacyLevel;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&PrivacyLevel::GuildOnly, &[Token::U8(2)]);
+    }
+}
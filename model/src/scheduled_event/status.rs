@@ -0,0 +1,42 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Current status of a [`GuildScheduledEvent`].
+///
+/// [`GuildScheduledEvent`]: super::GuildScheduledEvent
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum Status {
+    /// Event is scheduled.
+    ///
+    /// May be updated to [`Active`] or [`Cancelled`].
+    ///
+    /// [`Active`]: Self::Active
+    /// [`Cancelled`]: Self::Cancelled
+    Scheduled = 1,
+    /// Event is active.
+    ///
+    /// May be updated to [`Completed`].
+    ///
+    /// [`Completed`]: Self::Completed
+    Active = 2,
+    /// Event has completed.
+    Completed = 3,
+    /// Event was cancelled before it started.
+    Cancelled = 4,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Status;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&Status::Scheduled, &[Token::U8(1)]);
+        serde_test::assert_tokens(&Status::Active, &[Token::U8(2)]);
+        serde_test::assert_tokens(&Status::Completed, &[Token::U8(3)]);
+        serde_test::assert_tokens(&Status::Cancelled, &[Token::U8(4)]);
+    }
+}
@@ -1,5 +1,8 @@
 use super::{PremiumType, UserFlags};
-use crate::id::UserId;
+use crate::{
+    id::UserId,
+    util::{ImageHash, Locale},
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
@@ -10,7 +13,7 @@ pub struct CurrentUser {
     /// Image formatting.
     ///
     /// [Discord's documentation]: https://discord.com/developers/docs/reference#image-formatting
-    pub avatar: Option<String>,
+    pub avatar: Option<ImageHash>,
     /// Whether the user belongs to an OAuth2 application.
     #[serde(default)]
     pub bot: bool,
@@ -38,7 +41,7 @@ pub struct CurrentUser {
     pub id: UserId,
     /// User's chosen language option.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
     /// Whether the user has two factor enabled on their account.
     pub mfa_enabled: bool,
     /// User's username, not unique across the platform.
@@ -62,7 +65,7 @@ pub struct CurrentUser {
 
 #[cfg(test)]
 mod tests {
-    use super::{CurrentUser, PremiumType, UserFlags, UserId};
+    use super::{CurrentUser, ImageHash, Locale, PremiumType, UserFlags, UserId};
     use serde_test::Token;
 
     fn user_tokens(discriminator_token: Token) -> Vec<Token> {
@@ -73,17 +76,17 @@ mod tests {
             },
             Token::Str("avatar"),
             Token::Some,
-            Token::Str("avatar hash"),
+            Token::Str("5bd5c7c3f2a4ab7d2e3a1b9c9a4d3e2f"),
             Token::Str("bot"),
             Token::Bool(true),
             Token::Str("discriminator"),
             discriminator_token,
             Token::Str("id"),
-            Token::NewtypeStruct { name: "UserId" },
+            Token::NewtypeStruct { name: "Id" },
             Token::Str("1"),
             Token::Str("locale"),
             Token::Some,
-            Token::Str("test locale"),
+            Token::Str("en-US"),
             Token::Str("mfa_enabled"),
             Token::Bool(true),
             Token::Str("username"),
@@ -109,7 +112,7 @@ mod tests {
             },
             Token::Str("avatar"),
             Token::Some,
-            Token::Str("avatar hash"),
+            Token::Str("5bd5c7c3f2a4ab7d2e3a1b9c9a4d3e2f"),
             Token::Str("bot"),
             Token::Bool(true),
             Token::Str("discriminator"),
@@ -121,11 +124,11 @@ mod tests {
             Token::Some,
             Token::U64(1),
             Token::Str("id"),
-            Token::NewtypeStruct { name: "UserId" },
+            Token::NewtypeStruct { name: "Id" },
             Token::Str("1"),
             Token::Str("locale"),
             Token::Some,
-            Token::Str("test locale"),
+            Token::Str("en-US"),
             Token::Str("mfa_enabled"),
             Token::Bool(true),
             Token::Str("username"),
@@ -146,18 +149,18 @@ mod tests {
     #[test]
     fn test_current_user() {
         let value = CurrentUser {
-            avatar: Some("avatar hash".to_owned()),
+            avatar: Some(ImageHash::parse("5bd5c7c3f2a4ab7d2e3a1b9c9a4d3e2f").unwrap()),
             bot: true,
             discriminator: "9999".to_owned(),
             email: None,
-            id: UserId(1),
+            id: UserId::new(1),
             mfa_enabled: true,
             name: "test name".to_owned(),
             verified: Some(true),
             premium_type: Some(PremiumType::NitroClassic),
             public_flags: Some(UserFlags::DISCORD_EMPLOYEE),
             flags: None,
-            locale: Some("test locale".to_owned()),
+            locale: Some(Locale::EnglishUs),
         };
 
         // Deserializing a current user with a string discriminator (which
@@ -173,18 +176,18 @@ mod tests {
     #[test]
     fn test_current_user_complete() {
         let value = CurrentUser {
-            avatar: Some("avatar hash".to_owned()),
+            avatar: Some(ImageHash::parse("5bd5c7c3f2a4ab7d2e3a1b9c9a4d3e2f").unwrap()),
             bot: true,
             discriminator: "9999".to_owned(),
             email: Some("test@example.com".to_owned()),
-            id: UserId(1),
+            id: UserId::new(1),
             mfa_enabled: true,
             name: "test name".to_owned(),
             verified: Some(true),
             premium_type: Some(PremiumType::NitroClassic),
             public_flags: Some(UserFlags::DISCORD_EMPLOYEE),
             flags: Some(UserFlags::DISCORD_EMPLOYEE),
-            locale: Some("test locale".to_owned()),
+            locale: Some(Locale::EnglishUs),
         };
 
         // Deserializing a current user with a string discriminator (which
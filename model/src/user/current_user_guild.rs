@@ -1,5 +1,8 @@
-use crate::guild::Permissions;
-use crate::id::GuildId;
+use crate::{
+    guild::{GuildFeature, Permissions},
+    id::GuildId,
+    util::ImageHash,
+};
 use serde::{Deserialize, Serialize};
 
 /// Information about a guild the current user is in.
@@ -22,31 +25,31 @@ pub struct CurrentUserGuild {
     /// Refer to the [Discord documentation] for more information.
     ///
     /// [Discord documentation]: https://discord.com/developers/docs/reference#image-formatting
-    pub icon: Option<String>,
+    pub icon: Option<ImageHash>,
     /// Whether the current user is the owner.
     pub owner: bool,
     /// Permissions of the current user in the guild. This excludes channels'
     /// permission overwrites.
     pub permissions: Permissions,
     /// List of enabled guild features.
-    pub features: Vec<String>,
+    pub features: Vec<GuildFeature>,
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{CurrentUserGuild, GuildId, Permissions};
+    use super::{CurrentUserGuild, GuildFeature, GuildId, ImageHash, Permissions};
     use serde_test::Token;
 
     #[test]
     fn test_current_user_guild() {
         // The example partial guild from the discord docs
         let value = CurrentUserGuild {
-            id: GuildId(80_351_110_224_678_912),
+            id: GuildId::new(80_351_110_224_678_912),
             name: "abcd".to_owned(),
-            icon: Some("8342729096ea3675442027381ff50dfe".to_owned()),
+            icon: Some(ImageHash::parse("8342729096ea3675442027381ff50dfe").unwrap()),
             owner: true,
             permissions: Permissions::from_bits_truncate(36_953_089),
-            features: vec!["a feature".to_owned()],
+            features: vec![GuildFeature::AnimatedIcon],
         };
 
         serde_test::assert_tokens(
@@ -57,7 +60,7 @@ mod tests {
                     len: 6,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("80351110224678912"),
                 Token::Str("name"),
                 Token::Str("abcd"),
@@ -70,7 +73,7 @@ mod tests {
                 Token::Str("36953089"),
                 Token::Str("features"),
                 Token::Seq { len: Some(1) },
-                Token::Str("a feature"),
+                Token::Str("ANIMATED_ICON"),
                 Token::SeqEnd,
                 Token::StructEnd,
             ],
@@ -12,7 +12,10 @@ pub use self::{
     profile::UserProfile,
 };
 
-use crate::id::UserId;
+use crate::{
+    id::UserId,
+    util::{ImageHash, Locale},
+};
 use serde::{Deserialize, Serialize};
 
 pub(crate) mod discriminator {
@@ -51,7 +54,7 @@ pub(crate) mod discriminator {
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct User {
-    pub avatar: Option<String>,
+    pub avatar: Option<ImageHash>,
     #[serde(default)]
     pub bot: bool,
     /// Discriminator used to differentiate people with the same username.
@@ -69,7 +72,7 @@ pub struct User {
     pub flags: Option<UserFlags>,
     pub id: UserId,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mfa_enabled: Option<bool>,
     #[serde(rename = "username")]
@@ -86,7 +89,7 @@ pub struct User {
 
 #[cfg(test)]
 mod tests {
-    use super::{PremiumType, User, UserFlags, UserId};
+    use super::{ImageHash, Locale, PremiumType, User, UserFlags, UserId};
     use serde_test::Token;
 
     fn user_tokens(discriminator_token: Token) -> Vec<Token> {
@@ -109,11 +112,11 @@ mod tests {
             Token::Some,
             Token::U64(131_584),
             Token::Str("id"),
-            Token::NewtypeStruct { name: "UserId" },
+            Token::NewtypeStruct { name: "Id" },
             Token::Str("1"),
             Token::Str("locale"),
             Token::Some,
-            Token::Str("en-us"),
+            Token::Str("en-US"),
             Token::Str("mfa_enabled"),
             Token::Some,
             Token::Bool(true),
@@ -152,11 +155,11 @@ mod tests {
             Token::Some,
             Token::U64(131_584),
             Token::Str("id"),
-            Token::NewtypeStruct { name: "UserId" },
+            Token::NewtypeStruct { name: "Id" },
             Token::Str("1"),
             Token::Str("locale"),
             Token::Some,
-            Token::Str("en-us"),
+            Token::Str("en-US"),
             Token::Str("mfa_enabled"),
             Token::Some,
             Token::Bool(true),
@@ -181,13 +184,13 @@ mod tests {
     #[test]
     fn test_user() {
         let value = User {
-            avatar: Some("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".to_owned()),
+            avatar: Some(ImageHash::parse("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa").unwrap()),
             bot: false,
             discriminator: "0001".to_owned(),
             email: Some("address@example.com".to_owned()),
             flags: Some(UserFlags::EARLY_SUPPORTER | UserFlags::VERIFIED_BOT_DEVELOPER),
-            id: UserId(1),
-            locale: Some("en-us".to_owned()),
+            id: UserId::new(1),
+            locale: Some(Locale::EnglishUs),
             mfa_enabled: Some(true),
             name: "test".to_owned(),
             premium_type: Some(PremiumType::Nitro),
@@ -209,13 +212,13 @@ mod tests {
     #[test]
     fn test_user_complete() {
         let value = User {
-            avatar: Some("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".to_owned()),
+            avatar: Some(ImageHash::parse("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa").unwrap()),
             bot: false,
             discriminator: "0001".to_owned(),
             email: Some("address@example.com".to_owned()),
             flags: Some(UserFlags::EARLY_SUPPORTER | UserFlags::VERIFIED_BOT_DEVELOPER),
-            id: UserId(1),
-            locale: Some("en-us".to_owned()),
+            id: UserId::new(1),
+            locale: Some(Locale::EnglishUs),
             mfa_enabled: Some(true),
             name: "test".to_owned(),
             premium_type: Some(PremiumType::Nitro),
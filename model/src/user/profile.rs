@@ -1,12 +1,13 @@
 use crate::{
     id::UserId,
     user::{PremiumType, UserFlags},
+    util::{ImageHash, Locale},
 };
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct UserProfile {
-    pub avatar: Option<String>,
+    pub avatar: Option<ImageHash>,
     #[serde(default)]
     pub bot: bool,
     /// Discriminator used to differentiate people with the same username.
@@ -24,7 +25,7 @@ pub struct UserProfile {
     pub flags: Option<UserFlags>,
     pub id: UserId,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mfa_enabled: Option<bool>,
     #[serde(rename = "username")]
@@ -37,7 +38,7 @@ pub struct UserProfile {
 
 #[cfg(test)]
 mod tests {
-    use super::{PremiumType, UserFlags, UserId, UserProfile};
+    use super::{ImageHash, Locale, PremiumType, UserFlags, UserId, UserProfile};
     use serde_test::Token;
 
     fn user_tokens(discriminator_token: Token) -> Vec<Token> {
@@ -48,7 +49,7 @@ mod tests {
             },
             Token::Str("avatar"),
             Token::Some,
-            Token::Str("hash"),
+            Token::Str("58e31b0e4e3e3b2b5b6d8e8d5e5d5f5c"),
             Token::Str("bot"),
             Token::Bool(false),
             Token::Str("discriminator"),
@@ -60,11 +61,11 @@ mod tests {
             Token::Some,
             Token::U64(131_072),
             Token::Str("id"),
-            Token::NewtypeStruct { name: "UserId" },
+            Token::NewtypeStruct { name: "Id" },
             Token::Str("1"),
             Token::Str("locale"),
             Token::Some,
-            Token::Str("en-us"),
+            Token::Str("en-US"),
             Token::Str("mfa_enabled"),
             Token::Some,
             Token::Bool(true),
@@ -83,13 +84,13 @@ mod tests {
     #[test]
     fn test_user_profile() {
         let value = UserProfile {
-            avatar: Some("hash".to_owned()),
+            avatar: Some(ImageHash::parse("58e31b0e4e3e3b2b5b6d8e8d5e5d5f5c").unwrap()),
             bot: false,
             discriminator: "0004".to_owned(),
             email: Some("email@example.com".to_owned()),
             flags: Some(UserFlags::VERIFIED_BOT_DEVELOPER),
-            id: UserId(1),
-            locale: Some("en-us".to_owned()),
+            id: UserId::new(1),
+            locale: Some(Locale::EnglishUs),
             mfa_enabled: Some(true),
             name: "user name".to_owned(),
             premium_type: Some(PremiumType::Nitro),
@@ -0,0 +1,307 @@
+//! Efficient parsing and storage of Discord image hashes.
+//!
+//! Image hashes, such as those of user avatars or guild icons, are 32
+//! hexadecimal characters optionally prefixed with `a_` if the image is
+//! animated. Storing them as strings takes at least 56 bytes on 64-bit
+//! platforms, whereas an [`ImageHash`] is only 24 bytes.
+//!
+//! A few hashes, such as the avatars of some system users, aren't
+//! hexadecimal. These are kept in their string form instead.
+
+use serde::{
+    de::{Deserializer, Error as DeError, Visitor},
+    ser::Serializer,
+    Deserialize, Serialize,
+};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+/// Prefix of hashes of animated images.
+const ANIMATED_PREFIX: &str = "a_";
+
+/// Number of bytes of a hash once parsed.
+const HASH_LEN: usize = 16;
+
+/// Parsing an image hash failed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ImageHashParseError {
+    /// Hash isn't 32 characters long, excluding the animation prefix.
+    Length {
+        /// Number of characters in the hash, excluding the animation prefix.
+        len: usize,
+    },
+    /// Hash contains a character that isn't hexadecimal.
+    Character {
+        /// Character that isn't hexadecimal.
+        character: char,
+        /// Index of the character in the hash, excluding the animation prefix.
+        index: usize,
+    },
+}
+
+impl Display for ImageHashParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Length { len } => f.write_fmt(format_args!(
+                "image hash is {} characters long but must be 32",
+                len,
+            )),
+            Self::Character { character, index } => f.write_fmt(format_args!(
+                "image hash contains the non-hexadecimal character '{}' at index {}",
+                character, index,
+            )),
+        }
+    }
+}
+
+impl Error for ImageHashParseError {}
+
+/// Hash of an image, such as a user's avatar or a guild's icon.
+///
+/// Hashes are parsed from their hexadecimal form into bytes, which is much
+/// more compact than storing them as strings. Hashes that aren't hexadecimal
+/// are deserialized as-is rather than failing, and can be created via
+/// [`ImageHash::raw`].
+///
+/// # Examples
+///
+/// Parse the hash of an animated avatar and format it back into a string:
+///
+/// ```
+/// use twilight_model::util::ImageHash;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let hash = ImageHash::parse("a_b2a6536641da91a0b59bd66557c56c36")?;
+/// assert!(hash.is_animated());
+/// assert_eq!("a_b2a6536641da91a0b59bd66557c56c36", hash.to_string());
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ImageHash(Repr);
+
+/// Representation of an [`ImageHash`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum Repr {
+    /// Hexadecimal hash parsed into its bytes.
+    Parsed {
+        animated: bool,
+        bytes: [u8; HASH_LEN],
+    },
+    /// Hash that isn't hexadecimal, in its string form.
+    Raw(Box<str>),
+}
+
+impl ImageHash {
+    /// Create a new image hash from its bytes and whether the image is
+    /// animated.
+    pub const fn new(bytes: [u8; HASH_LEN], animated: bool) -> Self {
+        Self(Repr::Parsed { animated, bytes })
+    }
+
+    /// Create an image hash from a string without parsing it.
+    ///
+    /// This is used for hashes that aren't hexadecimal, such as `clyde`. Use
+    /// [`parse`] for hexadecimal hashes to store them compactly.
+    ///
+    /// [`parse`]: Self::parse
+    pub fn raw(value: impl Into<String>) -> Self {
+        Self(Repr::Raw(value.into().into_boxed_str()))
+    }
+
+    /// Parse an image hash from its string form.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ImageHashParseError::Length`] if the hash isn't 32
+    /// characters long, excluding the `a_` prefix of animated hashes.
+    ///
+    /// Returns [`ImageHashParseError::Character`] if the hash contains a
+    /// character that isn't hexadecimal.
+    pub fn parse(value: &str) -> Result<Self, ImageHashParseError> {
+        let (animated, hex) = if let Some(hex) = value.strip_prefix(ANIMATED_PREFIX) {
+            (true, hex)
+        } else {
+            (false, value)
+        };
+
+        let len = hex.chars().count();
+
+        if len != HASH_LEN * 2 {
+            return Err(ImageHashParseError::Length { len });
+        }
+
+        let mut bytes = [0; HASH_LEN];
+
+        for (index, character) in hex.chars().enumerate() {
+            let nibble = character
+                .to_digit(16)
+                .ok_or(ImageHashParseError::Character { character, index })?;
+
+            // Digits are at most 15, so this can't truncate.
+            #[allow(clippy::cast_possible_truncation)]
+            let nibble = nibble as u8;
+
+            bytes[index / 2] |= if index % 2 == 0 { nibble << 4 } else { nibble };
+        }
+
+        Ok(Self::new(bytes, animated))
+    }
+
+    /// Bytes of the hash, if it's hexadecimal.
+    pub const fn bytes(&self) -> Option<[u8; HASH_LEN]> {
+        match &self.0 {
+            Repr::Parsed { bytes, .. } => Some(*bytes),
+            Repr::Raw(_) => None,
+        }
+    }
+
+    /// Whether the image is animated.
+    ///
+    /// Animated images are GIFs and their hashes are prefixed with `a_`.
+    pub fn is_animated(&self) -> bool {
+        match &self.0 {
+            Repr::Parsed { animated, .. } => *animated,
+            Repr::Raw(raw) => raw.starts_with(ANIMATED_PREFIX),
+        }
+    }
+}
+
+impl Display for ImageHash {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let (animated, bytes) = match &self.0 {
+            Repr::Parsed { animated, bytes } => (*animated, bytes),
+            Repr::Raw(raw) => return f.write_str(raw),
+        };
+
+        if animated {
+            f.write_str(ANIMATED_PREFIX)?;
+        }
+
+        for byte in bytes {
+            f.write_fmt(format_args!("{:02x}", byte))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for ImageHash {
+    type Err = ImageHashParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+struct ImageHashVisitor;
+
+impl<'de> Visitor<'de> for ImageHashVisitor {
+    type Value = ImageHash;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("image hash")
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+        // Keep hashes that aren't hexadecimal rather than failing to
+        // deserialize the resource that they're a part of.
+        Ok(ImageHash::parse(value).unwrap_or_else(|_| ImageHash::raw(value)))
+    }
+}
+
+impl<'de> Deserialize<'de> for ImageHash {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(ImageHashVisitor)
+    }
+}
+
+impl Serialize for ImageHash {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ImageHash, ImageHashParseError};
+    use serde_test::Token;
+    use std::mem;
+
+    const HASH: &str = "b2a6536641da91a0b59bd66557c56c36";
+
+    #[test]
+    fn test_parse() {
+        let hash = ImageHash::parse(HASH).unwrap();
+        assert!(!hash.is_animated());
+        assert_eq!(
+            [
+                0xb2, 0xa6, 0x53, 0x66, 0x41, 0xda, 0x91, 0xa0, 0xb5, 0x9b, 0xd6, 0x65, 0x57, 0xc5,
+                0x6c, 0x36
+            ],
+            hash.bytes().unwrap()
+        );
+        assert_eq!(HASH, hash.to_string());
+    }
+
+    #[test]
+    fn test_parse_animated() {
+        let hash = ImageHash::parse("a_b2a6536641da91a0b59bd66557c56c36").unwrap();
+        assert!(hash.is_animated());
+        assert_eq!("a_b2a6536641da91a0b59bd66557c56c36", hash.to_string());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            ImageHashParseError::Length { len: 4 },
+            ImageHash::parse("hash").unwrap_err()
+        );
+        assert_eq!(
+            ImageHashParseError::Length { len: 0 },
+            ImageHash::parse("a_").unwrap_err()
+        );
+        assert_eq!(
+            ImageHashParseError::Character {
+                character: 'z',
+                index: 1,
+            },
+            ImageHash::parse("bz a6536641da91a0b59bd66557c56c3").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_raw() {
+        let hash = ImageHash::raw("clyde");
+        assert!(!hash.is_animated());
+        assert!(hash.bytes().is_none());
+        assert_eq!("clyde", hash.to_string());
+        assert!(ImageHash::raw("a_clyde").is_animated());
+    }
+
+    #[test]
+    fn test_size() {
+        assert_eq!(24, mem::size_of::<ImageHash>());
+    }
+
+    #[test]
+    fn test_serde() {
+        serde_test::assert_tokens(&ImageHash::parse(HASH).unwrap(), &[Token::Str(HASH)]);
+        serde_test::assert_tokens(
+            &ImageHash::parse("a_b2a6536641da91a0b59bd66557c56c36").unwrap(),
+            &[Token::Str("a_b2a6536641da91a0b59bd66557c56c36")],
+        );
+    }
+
+    #[test]
+    fn test_serde_non_hexadecimal() {
+        serde_test::assert_tokens(&ImageHash::raw("clyde"), &[Token::Str("clyde")]);
+
+        let hash: ImageHash = serde_json::from_str(r#""clyde""#).unwrap();
+        assert_eq!(ImageHash::raw("clyde"), hash);
+        assert_eq!(r#""clyde""#, serde_json::to_string(&hash).unwrap());
+    }
+}
@@ -0,0 +1,303 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    borrow::Cow,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Language and region that a user or guild uses Discord in.
+///
+/// Locales that aren't yet known by the library are deserialized into
+/// [`Locale::Unknown`].
+///
+/// See [Discord Docs/Locales].
+///
+/// [Discord Docs/Locales]: https://discord.com/developers/docs/reference#locales
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(from = "String", into = "Cow<'static, str>")]
+pub enum Locale {
+    /// Bulgarian.
+    ///
+    /// Formats as `bg`.
+    Bulgarian,
+    /// Chinese, as used in China.
+    ///
+    /// Formats as `zh-CN`.
+    ChineseChina,
+    /// Chinese, as used in Taiwan.
+    ///
+    /// Formats as `zh-TW`.
+    ChineseTaiwan,
+    /// Croatian.
+    ///
+    /// Formats as `hr`.
+    Croatian,
+    /// Czech.
+    ///
+    /// Formats as `cs`.
+    Czech,
+    /// Danish.
+    ///
+    /// Formats as `da`.
+    Danish,
+    /// Dutch.
+    ///
+    /// Formats as `nl`.
+    Dutch,
+    /// English, as used in the United Kingdom.
+    ///
+    /// Formats as `en-GB`.
+    EnglishUk,
+    /// English, as used in the United States.
+    ///
+    /// Formats as `en-US`.
+    EnglishUs,
+    /// Finnish.
+    ///
+    /// Formats as `fi`.
+    Finnish,
+    /// French.
+    ///
+    /// Formats as `fr`.
+    French,
+    /// German.
+    ///
+    /// Formats as `de`.
+    German,
+    /// Greek.
+    ///
+    /// Formats as `el`.
+    Greek,
+    /// Hindi.
+    ///
+    /// Formats as `hi`.
+    Hindi,
+    /// Hungarian.
+    ///
+    /// Formats as `hu`.
+    Hungarian,
+    /// Indonesian.
+    ///
+    /// Formats as `id`.
+    Indonesian,
+    /// Italian.
+    ///
+    /// Formats as `it`.
+    Italian,
+    /// Japanese.
+    ///
+    /// Formats as `ja`.
+    Japanese,
+    /// Korean.
+    ///
+    /// Formats as `ko`.
+    Korean,
+    /// Lithuanian.
+    ///
+    /// Formats as `lt`.
+    Lithuanian,
+    /// Norwegian.
+    ///
+    /// Formats as `no`.
+    Norwegian,
+    /// Polish.
+    ///
+    /// Formats as `pl`.
+    Polish,
+    /// Portuguese, as used in Brazil.
+    ///
+    /// Formats as `pt-BR`.
+    PortugueseBrazilian,
+    /// Romanian.
+    ///
+    /// Formats as `ro`.
+    Romanian,
+    /// Russian.
+    ///
+    /// Formats as `ru`.
+    Russian,
+    /// Spanish, as used in Latin America.
+    ///
+    /// Formats as `es-419`.
+    SpanishLatinAmerica,
+    /// Spanish, as used in Spain.
+    ///
+    /// Formats as `es-ES`.
+    SpanishSpain,
+    /// Swedish.
+    ///
+    /// Formats as `sv-SE`.
+    Swedish,
+    /// Thai.
+    ///
+    /// Formats as `th`.
+    Thai,
+    /// Turkish.
+    ///
+    /// Formats as `tr`.
+    Turkish,
+    /// Ukrainian.
+    ///
+    /// Formats as `uk`.
+    Ukrainian,
+    /// Vietnamese.
+    ///
+    /// Formats as `vi`.
+    Vietnamese,
+    /// Variant value is unknown to the library.
+    Unknown(String),
+}
+
+impl Locale {
+    /// Code of the locale, such as `en-US`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::util::Locale;
+    ///
+    /// assert_eq!("pt-BR", Locale::PortugueseBrazilian.code());
+    /// assert_eq!("xx", Locale::Unknown("xx".to_owned()).code());
+    /// ```
+    pub fn code(&self) -> &str {
+        match self {
+            Self::Bulgarian => "bg",
+            Self::ChineseChina => "zh-CN",
+            Self::ChineseTaiwan => "zh-TW",
+            Self::Croatian => "hr",
+            Self::Czech => "cs",
+            Self::Danish => "da",
+            Self::Dutch => "nl",
+            Self::EnglishUk => "en-GB",
+            Self::EnglishUs => "en-US",
+            Self::Finnish => "fi",
+            Self::French => "fr",
+            Self::German => "de",
+            Self::Greek => "el",
+            Self::Hindi => "hi",
+            Self::Hungarian => "hu",
+            Self::Indonesian => "id",
+            Self::Italian => "it",
+            Self::Japanese => "ja",
+            Self::Korean => "ko",
+            Self::Lithuanian => "lt",
+            Self::Norwegian => "no",
+            Self::Polish => "pl",
+            Self::PortugueseBrazilian => "pt-BR",
+            Self::Romanian => "ro",
+            Self::Russian => "ru",
+            Self::SpanishLatinAmerica => "es-419",
+            Self::SpanishSpain => "es-ES",
+            Self::Swedish => "sv-SE",
+            Self::Thai => "th",
+            Self::Turkish => "tr",
+            Self::Ukrainian => "uk",
+            Self::Vietnamese => "vi",
+            Self::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+impl Display for Locale {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.code())
+    }
+}
+
+impl From<Locale> for Cow<'static, str> {
+    fn from(value: Locale) -> Self {
+        match value {
+            Locale::Unknown(unknown) => unknown.into(),
+            known => known.code().to_owned().into(),
+        }
+    }
+}
+
+impl From<String> for Locale {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "bg" => Self::Bulgarian,
+            "zh-CN" => Self::ChineseChina,
+            "zh-TW" => Self::ChineseTaiwan,
+            "hr" => Self::Croatian,
+            "cs" => Self::Czech,
+            "da" => Self::Danish,
+            "nl" => Self::Dutch,
+            "en-GB" => Self::EnglishUk,
+            "en-US" => Self::EnglishUs,
+            "fi" => Self::Finnish,
+            "fr" => Self::French,
+            "de" => Self::German,
+            "el" => Self::Greek,
+            "hi" => Self::Hindi,
+            "hu" => Self::Hungarian,
+            "id" => Self::Indonesian,
+            "it" => Self::Italian,
+            "ja" => Self::Japanese,
+            "ko" => Self::Korean,
+            "lt" => Self::Lithuanian,
+            "no" => Self::Norwegian,
+            "pl" => Self::Polish,
+            "pt-BR" => Self::PortugueseBrazilian,
+            "ro" => Self::Romanian,
+            "ru" => Self::Russian,
+            "es-419" => Self::SpanishLatinAmerica,
+            "es-ES" => Self::SpanishSpain,
+            "sv-SE" => Self::Swedish,
+            "th" => Self::Thai,
+            "tr" => Self::Turkish,
+            "uk" => Self::Ukrainian,
+            "vi" => Self::Vietnamese,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Locale;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&Locale::Bulgarian, &[Token::Str("bg")]);
+        serde_test::assert_tokens(&Locale::ChineseChina, &[Token::Str("zh-CN")]);
+        serde_test::assert_tokens(&Locale::ChineseTaiwan, &[Token::Str("zh-TW")]);
+        serde_test::assert_tokens(&Locale::Croatian, &[Token::Str("hr")]);
+        serde_test::assert_tokens(&Locale::Czech, &[Token::Str("cs")]);
+        serde_test::assert_tokens(&Locale::Danish, &[Token::Str("da")]);
+        serde_test::assert_tokens(&Locale::Dutch, &[Token::Str("nl")]);
+        serde_test::assert_tokens(&Locale::EnglishUk, &[Token::Str("en-GB")]);
+        serde_test::assert_tokens(&Locale::EnglishUs, &[Token::Str("en-US")]);
+        serde_test::assert_tokens(&Locale::Finnish, &[Token::Str("fi")]);
+        serde_test::assert_tokens(&Locale::French, &[Token::Str("fr")]);
+        serde_test::assert_tokens(&Locale::German, &[Token::Str("de")]);
+        serde_test::assert_tokens(&Locale::Greek, &[Token::Str("el")]);
+        serde_test::assert_tokens(&Locale::Hindi, &[Token::Str("hi")]);
+        serde_test::assert_tokens(&Locale::Hungarian, &[Token::Str("hu")]);
+        serde_test::assert_tokens(&Locale::Indonesian, &[Token::Str("id")]);
+        serde_test::assert_tokens(&Locale::Italian, &[Token::Str("it")]);
+        serde_test::assert_tokens(&Locale::Japanese, &[Token::Str("ja")]);
+        serde_test::assert_tokens(&Locale::Korean, &[Token::Str("ko")]);
+        serde_test::assert_tokens(&Locale::Lithuanian, &[Token::Str("lt")]);
+        serde_test::assert_tokens(&Locale::Norwegian, &[Token::Str("no")]);
+        serde_test::assert_tokens(&Locale::Polish, &[Token::Str("pl")]);
+        serde_test::assert_tokens(&Locale::PortugueseBrazilian, &[Token::Str("pt-BR")]);
+        serde_test::assert_tokens(&Locale::Romanian, &[Token::Str("ro")]);
+        serde_test::assert_tokens(&Locale::Russian, &[Token::Str("ru")]);
+        serde_test::assert_tokens(&Locale::SpanishLatinAmerica, &[Token::Str("es-419")]);
+        serde_test::assert_tokens(&Locale::SpanishSpain, &[Token::Str("es-ES")]);
+        serde_test::assert_tokens(&Locale::Swedish, &[Token::Str("sv-SE")]);
+        serde_test::assert_tokens(&Locale::Thai, &[Token::Str("th")]);
+        serde_test::assert_tokens(&Locale::Turkish, &[Token::Str("tr")]);
+        serde_test::assert_tokens(&Locale::Ukrainian, &[Token::Str("uk")]);
+        serde_test::assert_tokens(&Locale::Vietnamese, &[Token::Str("vi")]);
+        serde_test::assert_tokens(&Locale::Unknown("xx".to_owned()), &[Token::Str("xx")]);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("en-US", Locale::EnglishUs.to_string());
+        assert_eq!("es-419", Locale::SpanishLatinAmerica.to_string());
+    }
+}
@@ -0,0 +1,7 @@
+//! Utilities for efficiently parsing and representing data from Discord's API.
+
+pub mod image_hash;
+
+mod locale;
+
+pub use self::{image_hash::ImageHash, locale::Locale};
@@ -38,6 +38,37 @@ pub enum CloseCode {
     UnknownEncryptionMode = 4016,
 }
 
+impl CloseCode {
+    /// Whether the voice connection can reconnect after being closed with this
+    /// code.
+    ///
+    /// Codes such as [`Disconnected`] mean that the client was removed from
+    /// the voice channel and must not reconnect, while other codes are
+    /// transient.
+    ///
+    /// [`Disconnected`]: Self::Disconnected
+    pub const fn can_reconnect(self) -> bool {
+        !self.is_fatal()
+    }
+
+    /// Whether the close code means that the voice connection must not be
+    /// reconnected.
+    ///
+    /// This is the inverse of [`can_reconnect`].
+    ///
+    /// [`can_reconnect`]: Self::can_reconnect
+    pub const fn is_fatal(self) -> bool {
+        matches!(
+            self,
+            Self::AuthenticationFailed
+                | Self::ServerNotFound
+                | Self::UnknownProtocol
+                | Self::Disconnected
+                | Self::UnknownEncryptionMode
+        )
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct CloseCodeConversionError {
     code: u16,
@@ -106,6 +137,19 @@ mod tests {
         serde_test::assert_tokens(&CloseCode::UnknownEncryptionMode, &[Token::U16(4016)]);
     }
 
+    #[test]
+    fn test_can_reconnect() {
+        assert!(CloseCode::SessionNoLongerValid.can_reconnect());
+        assert!(CloseCode::SessionTimedOut.can_reconnect());
+        assert!(CloseCode::VoiceServerCrashed.can_reconnect());
+        assert!(!CloseCode::AuthenticationFailed.can_reconnect());
+        assert!(!CloseCode::ServerNotFound.can_reconnect());
+        assert!(!CloseCode::UnknownProtocol.can_reconnect());
+        assert!(!CloseCode::Disconnected.can_reconnect());
+        assert!(!CloseCode::UnknownEncryptionMode.can_reconnect());
+        assert!(CloseCode::Disconnected.is_fatal());
+    }
+
     #[test]
     fn test_conversion() {
         assert_eq!(CloseCode::try_from(4001).unwrap(), CloseCode::UnknownOpcode);
@@ -122,7 +122,7 @@ impl<'de> Visitor<'de> for VoiceStateVisitor {
                         return Err(DeError::duplicate_field("member"));
                     }
 
-                    let deserializer = OptionalMemberDeserializer::new(GuildId(0));
+                    let deserializer = OptionalMemberDeserializer::new(GuildId::new(0));
 
                     member = map.next_value_seed(deserializer)?;
                 }
@@ -259,9 +259,9 @@ mod tests {
     #[test]
     fn test_voice_state() {
         let value = VoiceState {
-            channel_id: Some(ChannelId(1)),
+            channel_id: Some(ChannelId::new(1)),
             deaf: false,
-            guild_id: Some(GuildId(2)),
+            guild_id: Some(GuildId::new(2)),
             member: None,
             mute: true,
             self_deaf: false,
@@ -270,7 +270,7 @@ mod tests {
             session_id: "a".to_owned(),
             suppress: true,
             token: None,
-            user_id: UserId(3),
+            user_id: UserId::new(3),
         };
 
         serde_test::assert_tokens(
@@ -282,13 +282,13 @@ mod tests {
                 },
                 Token::Str("channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("deaf"),
                 Token::Bool(false),
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("mute"),
                 Token::Bool(true),
@@ -303,7 +303,7 @@ mod tests {
                 Token::Str("suppress"),
                 Token::Bool(true),
                 Token::Str("user_id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("3"),
                 Token::StructEnd,
             ],
@@ -314,13 +314,13 @@ mod tests {
     #[test]
     fn test_voice_state_complete() {
         let value = VoiceState {
-            channel_id: Some(ChannelId(1)),
+            channel_id: Some(ChannelId::new(1)),
             deaf: false,
-            guild_id: Some(GuildId(2)),
+            guild_id: Some(GuildId::new(2)),
             member: Some(Member {
                 deaf: false,
-                guild_id: GuildId(2),
-                hoisted_role: Some(RoleId(2)),
+                guild_id: GuildId::new(2),
+                hoisted_role: Some(RoleId::new(2)),
                 joined_at: Some("timestamp".to_owned()),
                 mute: true,
                 nick: Some("twilight".to_owned()),
@@ -332,7 +332,7 @@ mod tests {
                     discriminator: "0001".to_owned(),
                     email: None,
                     flags: None,
-                    id: UserId(3),
+                    id: UserId::new(3),
                     locale: None,
                     mfa_enabled: None,
                     name: "twilight".to_owned(),
@@ -349,7 +349,7 @@ mod tests {
             session_id: "a".to_owned(),
             suppress: true,
             token: Some("abc".to_owned()),
-            user_id: UserId(3),
+            user_id: UserId::new(3),
         };
 
         serde_test::assert_tokens(
@@ -361,13 +361,13 @@ mod tests {
                 },
                 Token::Str("channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "ChannelId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("deaf"),
                 Token::Bool(false),
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("member"),
                 Token::Some,
@@ -378,11 +378,11 @@ mod tests {
                 Token::Str("deaf"),
                 Token::Bool(false),
                 Token::Str("guild_id"),
-                Token::NewtypeStruct { name: "GuildId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("hoisted_role"),
                 Token::Some,
-                Token::NewtypeStruct { name: "RoleId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
                 Token::Str("joined_at"),
                 Token::Some,
@@ -410,7 +410,7 @@ mod tests {
                 Token::Str("discriminator"),
                 Token::Str("0001"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("3"),
                 Token::Str("username"),
                 Token::Str("twilight"),
@@ -432,7 +432,7 @@ mod tests {
                 Token::Some,
                 Token::Str("abc"),
                 Token::Str("user_id"),
-                Token::NewtypeStruct { name: "UserId" },
+                Token::NewtypeStruct { name: "Id" },
                 Token::Str("3"),
                 Token::StructEnd,
             ],
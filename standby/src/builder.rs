@@ -0,0 +1,47 @@
+use super::{config::Config, Standby};
+
+/// Builder to configure and construct a [`Standby`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct StandbyBuilder(Config);
+
+impl StandbyBuilder {
+    /// Creates a builder to configure and construct a [`Standby`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Consume the builder, returning a configured standby.
+    pub fn build(self) -> Standby {
+        Standby::new_with_config(self.0)
+    }
+
+    /// Sets the maximum number of futures and streams that can wait in a
+    /// single guild, channel, message, or shard.
+    ///
+    /// This bounds memory usage when waiters are created based on user input,
+    /// such as a command that waits for a reaction. Once the limit is reached,
+    /// new futures resolve with a `Canceled` error and new streams end
+    /// immediately until existing waiters are fulfilled or dropped.
+    ///
+    /// Defaults to no limit.
+    pub fn waiter_limit(mut self, waiter_limit: usize) -> Self {
+        self.0.waiter_limit.replace(waiter_limit);
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StandbyBuilder;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(StandbyBuilder: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_waiter_limit() {
+        let standby = StandbyBuilder::new().waiter_limit(5).build();
+        assert_eq!(Some(5), standby.config().waiter_limit());
+    }
+}
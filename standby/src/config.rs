@@ -0,0 +1,31 @@
+/// Configuration for a [`Standby`].
+///
+/// [`Standby`]: crate::Standby
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Config {
+    pub(super) waiter_limit: Option<usize>,
+}
+
+impl Config {
+    /// Returns the maximum number of futures and streams that can wait in a
+    /// single guild, channel, message, or shard.
+    ///
+    /// `None` means there is no limit.
+    pub fn waiter_limit(&self) -> Option<usize> {
+        self.waiter_limit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Config;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(Config: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_defaults() {
+        assert_eq!(None, Config::default().waiter_limit());
+    }
+}
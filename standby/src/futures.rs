@@ -1,3 +1,4 @@
+use crate::Registration;
 use futures_channel::{
     mpsc::UnboundedReceiver as MpscReceiver,
     oneshot::{Canceled, Receiver},
@@ -7,22 +8,128 @@ use futures_util::{
     stream::{Stream, StreamExt},
 };
 use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
     future::Future,
     pin::Pin,
     task::{Context, Poll},
+    time::Duration,
 };
+use tokio::time::{self, Sleep};
 use twilight_model::gateway::{
     event::Event,
     payload::{MessageCreate, ReactionAdd},
 };
 
+/// Waiting for an event with a timeout failed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum TimeoutError {
+    /// The [`Standby`] was dropped before an event matched.
+    ///
+    /// [`Standby`]: crate::Standby
+    Canceled,
+    /// No event matched before the timeout elapsed.
+    ///
+    /// The waiter has been unregistered from the [`Standby`].
+    ///
+    /// [`Standby`]: crate::Standby
+    TimedOut {
+        /// Duration that was waited for.
+        timeout: Duration,
+    },
+}
+
+impl Display for TimeoutError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Canceled => f.write_str("standby was dropped before an event matched"),
+            Self::TimedOut { timeout } => f.write_fmt(format_args!(
+                "no event matched within {}ms",
+                timeout.as_millis()
+            )),
+        }
+    }
+}
+
+impl Error for TimeoutError {}
+
+/// The future returned from the `timeout` method of futures such as
+/// [`WaitForMessageFuture::timeout`].
+///
+/// Resolves with [`TimeoutError::TimedOut`] if no event matched before the
+/// timeout elapsed, in which case the waiter is unregistered from the
+/// [`Standby`].
+///
+/// [`Standby`]: crate::Standby
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Timeout<T> {
+    // Declared before the registration so that it's closed before the
+    // registration is dropped.
+    rx: Receiver<T>,
+    registration: Registration,
+    sleep: Pin<Box<Sleep>>,
+    timeout: Duration,
+}
+
+impl<T> Timeout<T> {
+    fn new(rx: Receiver<T>, registration: Registration, timeout: Duration) -> Self {
+        Self {
+            rx,
+            registration,
+            sleep: Box::pin(time::sleep(timeout)),
+            timeout,
+        }
+    }
+}
+
+impl<T> Future for Timeout<T> {
+    type Output = Result<T, TimeoutError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Poll::Ready(result) = self.rx.poll_unpin(cx) {
+            return Poll::Ready(result.map_err(|_| TimeoutError::Canceled));
+        }
+
+        if self.sleep.as_mut().poll(cx).is_pending() {
+            return Poll::Pending;
+        }
+
+        // Close the receiver now rather than when the future is dropped so
+        // that the bystander is unregistered immediately.
+        self.rx.close();
+        self.registration.unregister();
+
+        Poll::Ready(Err(TimeoutError::TimedOut {
+            timeout: self.timeout,
+        }))
+    }
+}
+
 /// The future returned from [`Standby::wait_for_event`].
 ///
 /// [`Standby::wait_for_event`]: crate::Standby::wait_for_event
 #[derive(Debug)]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct WaitForEventFuture {
+    // Declared before the registration so that it's closed before the
+    // registration is dropped.
     pub(crate) rx: Receiver<Event>,
+    pub(crate) registration: Registration,
+}
+
+impl WaitForEventFuture {
+    /// Resolve with [`TimeoutError::TimedOut`] if no event matches within the
+    /// given duration.
+    ///
+    /// The waiter is unregistered from the [`Standby`] when the timeout
+    /// elapses.
+    ///
+    /// [`Standby`]: crate::Standby
+    pub fn timeout(self, timeout: Duration) -> Timeout<Event> {
+        Timeout::new(self.rx, self.registration, timeout)
+    }
 }
 
 impl Future for WaitForEventFuture {
@@ -39,7 +146,10 @@ impl Future for WaitForEventFuture {
 #[derive(Debug)]
 #[must_use = "streams do nothing unless you poll them"]
 pub struct WaitForEventStream {
+    // Declared before the registration so that it's closed before the
+    // registration is dropped.
     pub(crate) rx: MpscReceiver<Event>,
+    pub(crate) registration: Registration,
 }
 
 impl Stream for WaitForEventStream {
@@ -56,7 +166,23 @@ impl Stream for WaitForEventStream {
 #[derive(Debug)]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct WaitForGuildEventFuture {
+    // Declared before the registration so that it's closed before the
+    // registration is dropped.
     pub(crate) rx: Receiver<Event>,
+    pub(crate) registration: Registration,
+}
+
+impl WaitForGuildEventFuture {
+    /// Resolve with [`TimeoutError::TimedOut`] if no event matches within the
+    /// given duration.
+    ///
+    /// The waiter is unregistered from the [`Standby`] when the timeout
+    /// elapses.
+    ///
+    /// [`Standby`]: crate::Standby
+    pub fn timeout(self, timeout: Duration) -> Timeout<Event> {
+        Timeout::new(self.rx, self.registration, timeout)
+    }
 }
 
 impl Future for WaitForGuildEventFuture {
@@ -73,7 +199,10 @@ impl Future for WaitForGuildEventFuture {
 #[derive(Debug)]
 #[must_use = "streams do nothing unless you poll them"]
 pub struct WaitForGuildEventStream {
+    // Declared before the registration so that it's closed before the
+    // registration is dropped.
     pub(crate) rx: MpscReceiver<Event>,
+    pub(crate) registration: Registration,
 }
 
 impl Stream for WaitForGuildEventStream {
@@ -90,7 +219,23 @@ impl Stream for WaitForGuildEventStream {
 #[derive(Debug)]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct WaitForMessageFuture {
+    // Declared before the registration so that it's closed before the
+    // registration is dropped.
     pub(crate) rx: Receiver<MessageCreate>,
+    pub(crate) registration: Registration,
+}
+
+impl WaitForMessageFuture {
+    /// Resolve with [`TimeoutError::TimedOut`] if no event matches within the
+    /// given duration.
+    ///
+    /// The waiter is unregistered from the [`Standby`] when the timeout
+    /// elapses.
+    ///
+    /// [`Standby`]: crate::Standby
+    pub fn timeout(self, timeout: Duration) -> Timeout<MessageCreate> {
+        Timeout::new(self.rx, self.registration, timeout)
+    }
 }
 
 impl Future for WaitForMessageFuture {
@@ -107,7 +252,10 @@ impl Future for WaitForMessageFuture {
 #[derive(Debug)]
 #[must_use = "streams do nothing unless you poll them"]
 pub struct WaitForMessageStream {
+    // Declared before the registration so that it's closed before the
+    // registration is dropped.
     pub(crate) rx: MpscReceiver<MessageCreate>,
+    pub(crate) registration: Registration,
 }
 
 impl Stream for WaitForMessageStream {
@@ -124,7 +272,23 @@ impl Stream for WaitForMessageStream {
 #[derive(Debug)]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct WaitForReactionFuture {
+    // Declared before the registration so that it's closed before the
+    // registration is dropped.
     pub(crate) rx: Receiver<ReactionAdd>,
+    pub(crate) registration: Registration,
+}
+
+impl WaitForReactionFuture {
+    /// Resolve with [`TimeoutError::TimedOut`] if no event matches within the
+    /// given duration.
+    ///
+    /// The waiter is unregistered from the [`Standby`] when the timeout
+    /// elapses.
+    ///
+    /// [`Standby`]: crate::Standby
+    pub fn timeout(self, timeout: Duration) -> Timeout<ReactionAdd> {
+        Timeout::new(self.rx, self.registration, timeout)
+    }
 }
 
 impl Future for WaitForReactionFuture {
@@ -141,7 +305,10 @@ impl Future for WaitForReactionFuture {
 #[derive(Debug)]
 #[must_use = "streams do nothing unless you poll them"]
 pub struct WaitForReactionStream {
+    // Declared before the registration so that it's closed before the
+    // registration is dropped.
     pub(crate) rx: MpscReceiver<ReactionAdd>,
+    pub(crate) registration: Registration,
 }
 
 impl Stream for WaitForReactionStream {
@@ -155,12 +322,17 @@ impl Stream for WaitForReactionStream {
 #[cfg(test)]
 mod tests {
     use super::{
-        WaitForEventFuture, WaitForEventStream, WaitForGuildEventFuture, WaitForGuildEventStream,
-        WaitForMessageFuture, WaitForMessageStream, WaitForReactionFuture, WaitForReactionStream,
+        Timeout, TimeoutError, WaitForEventFuture, WaitForEventStream, WaitForGuildEventFuture,
+        WaitForGuildEventStream, WaitForMessageFuture, WaitForMessageStream, WaitForReactionFuture,
+        WaitForReactionStream,
     };
     use futures_util::stream::Stream;
     use static_assertions::assert_impl_all;
-    use std::{fmt::Debug, future::Future};
+    use std::{error::Error, fmt::Debug, future::Future};
+    use twilight_model::gateway::payload::MessageCreate;
+
+    assert_impl_all!(Timeout<MessageCreate>: Debug, Future, Send, Sync);
+    assert_impl_all!(TimeoutError: Clone, Copy, Debug, Error, Eq, PartialEq, Send, Sync);
 
     assert_impl_all!(WaitForEventFuture: Debug, Future, Send, Sync);
     assert_impl_all!(WaitForGuildEventFuture: Debug, Future, Send, Sync);
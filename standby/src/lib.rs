@@ -19,6 +19,15 @@
 //! To use Standby, you must process events with it in your main event loop.
 //! Check out the [`Standby::process`] method.
 //!
+//! ## Scoping waiters to shards
+//!
+//! Futures and streams waiting for any event, such as those returned by
+//! [`Standby::wait_for_event`], are all checked against every processed event.
+//! Bots running many shards can instead wait for events from a single shard
+//! with [`Standby::wait_for_shard_event`] and process events with
+//! [`Standby::process_with_shard`], so that processing an event only checks
+//! the waiters of the shard that received it.
+//!
 //! ## When to use futures and streams
 //!
 //! `Standby` has two variants of each method: a future variant and a stream
@@ -47,8 +56,8 @@
 //!
 //! let standby = Standby::new();
 //!
-//! let message = standby.wait_for_message(ChannelId(123), |event: &MessageCreate| {
-//!     event.author.id == UserId(456) && event.content == "test"
+//! let message = standby.wait_for_message(ChannelId::new(123), |event: &MessageCreate| {
+//!     event.author.id == UserId::new(456) && event.content == "test"
 //! }).await?;
 //! # Ok(()) }
 //! ```
@@ -122,13 +131,23 @@
 
 #![deny(rust_2018_idioms, broken_intra_doc_links, unused, warnings)]
 
+mod builder;
+mod config;
 mod futures;
+mod stats;
 
+pub use self::{
+    builder::StandbyBuilder,
+    config::Config,
+    stats::{ProcessResults, StandbyStats},
+};
 pub use futures::{
-    WaitForEventFuture, WaitForEventStream, WaitForGuildEventFuture, WaitForGuildEventStream,
-    WaitForMessageFuture, WaitForMessageStream, WaitForReactionFuture, WaitForReactionStream,
+    Timeout, TimeoutError, WaitForEventFuture, WaitForEventStream, WaitForGuildEventFuture,
+    WaitForGuildEventStream, WaitForMessageFuture, WaitForMessageStream, WaitForReactionFuture,
+    WaitForReactionStream,
 };
 
+use self::stats::ProcessStatus;
 use dashmap::DashMap;
 use futures_channel::{
     mpsc::{self, UnboundedSender as MpscSender},
@@ -136,9 +155,10 @@ use futures_channel::{
 };
 use std::{
     fmt::{Debug, Formatter, Result as FmtResult},
+    hash::Hash,
     sync::{
         atomic::{AtomicU64, Ordering},
-        Arc,
+        Arc, Weak,
     },
 };
 use twilight_model::{
@@ -180,11 +200,83 @@ impl<E> Debug for Bystander<E> {
 
 #[derive(Debug, Default)]
 struct StandbyRef {
+    config: Config,
     events: DashMap<u64, Bystander<Event>>,
     event_counter: AtomicU64,
     guilds: DashMap<GuildId, Vec<Bystander<Event>>>,
     messages: DashMap<ChannelId, Vec<Bystander<MessageCreate>>>,
+    channel_reactions: DashMap<ChannelId, Vec<Bystander<ReactionAdd>>>,
+    guild_reactions: DashMap<GuildId, Vec<Bystander<ReactionAdd>>>,
     reactions: DashMap<MessageId, Vec<Bystander<ReactionAdd>>>,
+    shards: DashMap<u64, Vec<Bystander<Event>>>,
+}
+
+impl StandbyRef {
+    /// Remove the bystanders of a registration whose receivers have been
+    /// closed.
+    fn unregister(&self, kind: &RegistrationKind) {
+        match kind {
+            RegistrationKind::Event(id) => {
+                self.events.remove(id);
+            }
+            RegistrationKind::Guild(guild_id) => remove_closed(&self.guilds, guild_id),
+            RegistrationKind::Guilds(guild_ids) => {
+                for guild_id in guild_ids {
+                    remove_closed(&self.guilds, guild_id);
+                }
+            }
+            RegistrationKind::Message(channel_id) => remove_closed(&self.messages, channel_id),
+            RegistrationKind::ChannelReactions(channel_ids) => {
+                for channel_id in channel_ids {
+                    remove_closed(&self.channel_reactions, channel_id);
+                }
+            }
+            RegistrationKind::GuildReaction(guild_id) => {
+                remove_closed(&self.guild_reactions, guild_id)
+            }
+            RegistrationKind::Reaction(message_id) => remove_closed(&self.reactions, message_id),
+            RegistrationKind::Shard(shard_id) => remove_closed(&self.shards, shard_id),
+        }
+    }
+}
+
+/// Key that a bystander was registered under.
+#[derive(Debug)]
+enum RegistrationKind {
+    ChannelReactions(Vec<ChannelId>),
+    Event(u64),
+    Guild(GuildId),
+    Guilds(Vec<GuildId>),
+    GuildReaction(GuildId),
+    Message(ChannelId),
+    Reaction(MessageId),
+    Shard(u64),
+}
+
+/// Registration of a bystander, used by futures to unregister themselves.
+#[derive(Debug)]
+pub(crate) struct Registration {
+    kind: RegistrationKind,
+    standby: Weak<StandbyRef>,
+}
+
+impl Registration {
+    /// Unregister the bystander if the standby still exists.
+    ///
+    /// The receiver of the bystander must be closed first.
+    pub(crate) fn unregister(&self) {
+        if let Some(standby) = self.standby.upgrade() {
+            tracing::trace!(kind = ?self.kind, "unregistering bystander");
+
+            standby.unregister(&self.kind);
+        }
+    }
+}
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        self.unregister();
+    }
 }
 
 /// The `Standby` struct, used by the main event loop to process events and by
@@ -201,10 +293,29 @@ pub struct Standby(Arc<StandbyRef>);
 
 impl Standby {
     /// Create a new instance of `Standby`.
+    ///
+    /// Use [`Standby::builder`] to configure it instead.
     pub fn new() -> Self {
         Self::default()
     }
 
+    fn new_with_config(config: Config) -> Self {
+        Self(Arc::new(StandbyRef {
+            config,
+            ..StandbyRef::default()
+        }))
+    }
+
+    /// Create a new builder to configure and construct a standby.
+    pub fn builder() -> StandbyBuilder {
+        StandbyBuilder::new()
+    }
+
+    /// Return a copy of the standby's configuration.
+    pub fn config(&self) -> Config {
+        self.0.config.clone()
+    }
+
     /// Process an event, calling any bystanders that might be waiting on it.
     ///
     /// When a bystander checks to see if an event is what it's waiting for, it
@@ -212,25 +323,122 @@ impl Standby {
     ///
     /// This function must be called when events are received in order for
     /// futures returned by methods to fulfill.
-    pub fn process(&self, event: &Event) {
+    ///
+    /// Returns the results of processing the event, such as how many
+    /// bystanders matched it.
+    pub fn process(&self, event: &Event) -> ProcessResults {
         tracing::trace!(event_type = ?event.kind(), ?event, "processing event");
 
+        let mut results = ProcessResults::default();
+
         match event {
-            Event::MessageCreate(e) => self.process_message(e.0.channel_id, &e),
-            Event::ReactionAdd(e) => self.process_reaction(e.0.message_id, &e),
+            Event::MessageCreate(e) => self.process_message(e.0.channel_id, &e, &mut results),
+            Event::ReactionAdd(e) => {
+                self.process_reaction(e.0.message_id, &e, &mut results);
+                self.process_keyed(
+                    &self.0.channel_reactions,
+                    e.0.channel_id,
+                    &**e,
+                    &mut results,
+                );
+
+                if let Some(guild_id) = e.0.guild_id {
+                    self.process_keyed(&self.0.guild_reactions, guild_id, &**e, &mut results);
+                }
+            }
             _ => {}
         }
 
         if let Some(guild_id) = event_guild_id(event) {
-            self.process_guild(guild_id, event);
+            self.process_guild(guild_id, event, &mut results);
         }
 
-        self.process_event(event);
+        self.process_event(event, &mut results);
+
+        results
+    }
+
+    /// Process an event received by a shard, calling any bystanders that might
+    /// be waiting on it.
+    ///
+    /// This is the same as [`process`], but also calls the bystanders waiting
+    /// for events from the shard, such as those registered via
+    /// [`wait_for_shard_event`]. Bystanders waiting for events from other
+    /// shards aren't checked, so the shards of a bot can process their events
+    /// without contending over a single map of bystanders. Bystanders waiting
+    /// for any event, such as those registered via [`wait_for_event`], are
+    /// only scanned if there are any.
+    ///
+    /// # Examples
+    ///
+    /// Process the events of every shard in a cluster:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures_util::stream::StreamExt;
+    /// use std::env;
+    /// use twilight_gateway::{Cluster, Intents};
+    /// use twilight_standby::Standby;
+    ///
+    /// let token = env::var("DISCORD_TOKEN")?;
+    /// let cluster = Cluster::new(token, Intents::GUILD_MESSAGES).await?;
+    /// let mut events = cluster.events();
+    /// cluster.up().await;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// while let Some((shard_id, event)) = events.next().await {
+    ///     standby.process_with_shard(&event, shard_id);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`process`]: Self::process
+    /// [`wait_for_event`]: Self::wait_for_event
+    /// [`wait_for_shard_event`]: Self::wait_for_shard_event
+    pub fn process_with_shard(&self, event: &Event, shard_id: u64) -> ProcessResults {
+        let mut results = self.process(event);
+
+        self.process_keyed(&self.0.shards, shard_id, event, &mut results);
+
+        results
+    }
+
+    /// Number of bystanders currently registered, by the kind of event they're
+    /// waiting for.
+    ///
+    /// Bystanders whose futures or streams have been dropped are counted until
+    /// an event they would have received is processed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::{gateway::payload::MessageCreate, id::ChannelId};
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    /// let _future = standby.wait_for_message(ChannelId::new(123), |_: &MessageCreate| true);
+    ///
+    /// let stats = standby.stats();
+    /// assert_eq!(1, stats.messages());
+    /// assert_eq!(1, stats.total());
+    /// ```
+    pub fn stats(&self) -> StandbyStats {
+        StandbyStats {
+            events: self.0.events.len(),
+            guilds: count(&self.0.guilds),
+            messages: count(&self.0.messages),
+            reactions: count(&self.0.reactions)
+                + count(&self.0.guild_reactions)
+                + count(&self.0.channel_reactions),
+            shards: count(&self.0.shards),
+        }
     }
 
     /// Wait for an event in a certain guild.
     ///
-    /// Returns a Canceled error if the Standby struct was dropped.
+    /// Returns a Canceled error if the Standby struct was dropped or the
+    /// configured [waiter limit] was reached.
     ///
     /// If you need to wait for multiple guild events matching the given
     /// predicate, use [`wait_for_stream`].
@@ -250,13 +458,14 @@ impl Standby {
     ///
     /// let standby = Standby::new();
     ///
-    /// let reaction = standby.wait_for(GuildId(123), |event: &Event| {
+    /// let reaction = standby.wait_for(GuildId::new(123), |event: &Event| {
     ///     event.kind() == EventType::BanAdd
     /// }).await?;
     /// # Ok(()) }
     /// ```
     ///
     /// [`wait_for_stream`]: Self::wait_for_stream
+    /// [waiter limit]: StandbyBuilder::waiter_limit
     pub fn wait_for<F: Fn(&Event) -> bool + Send + Sync + 'static>(
         &self,
         guild_id: GuildId,
@@ -265,20 +474,25 @@ impl Standby {
         tracing::trace!(%guild_id, "waiting for event in guild");
         let (tx, rx) = oneshot::channel();
 
-        {
-            let mut guild = self.0.guilds.entry(guild_id).or_default();
-            guild.push(Bystander {
+        self.register(
+            &self.0.guilds,
+            guild_id,
+            Bystander {
                 func: check.into(),
                 sender: Some(Sender::Oneshot(tx)),
-            });
-        }
+            },
+        );
 
-        WaitForGuildEventFuture { rx }
+        WaitForGuildEventFuture {
+            rx,
+            registration: self.registration(RegistrationKind::Guild(guild_id)),
+        }
     }
 
     /// Wait for a stream of events in a certain guild.
     ///
-    /// Returns a Canceled error if the Standby struct was dropped.
+    /// Returns a Canceled error if the Standby struct was dropped or the
+    /// configured [waiter limit] was reached.
     ///
     /// If you need to wait for only one guild event matching the given
     /// predicate, use [`wait_for`].
@@ -298,7 +512,7 @@ impl Standby {
     ///
     /// let standby = Standby::new();
     ///
-    /// let mut stream = standby.wait_for_stream(GuildId(123), |event: &Event| {
+    /// let mut stream = standby.wait_for_stream(GuildId::new(123), |event: &Event| {
     ///     event.kind() == EventType::BanAdd
     /// });
     ///
@@ -311,6 +525,7 @@ impl Standby {
     /// ```
     ///
     /// [`wait_for`]: Self::wait_for
+    /// [waiter limit]: StandbyBuilder::waiter_limit
     pub fn wait_for_stream<F: Fn(&Event) -> bool + Send + Sync + 'static>(
         &self,
         guild_id: GuildId,
@@ -319,15 +534,86 @@ impl Standby {
         tracing::trace!(%guild_id, "waiting for event in guild");
         let (tx, rx) = mpsc::unbounded();
 
-        {
-            let mut guild = self.0.guilds.entry(guild_id).or_default();
-            guild.push(Bystander {
+        self.register(
+            &self.0.guilds,
+            guild_id,
+            Bystander {
                 func: check.into(),
                 sender: Some(Sender::Mpsc(tx)),
-            });
+            },
+        );
+
+        WaitForGuildEventStream {
+            rx,
+            registration: self.registration(RegistrationKind::Guild(guild_id)),
         }
+    }
+
+    /// Wait for a stream of events in a set of guilds.
+    ///
+    /// Duplicate guild IDs are ignored.
+    ///
+    /// The stream ends if the `Standby` struct was dropped or the configured
+    /// [waiter limit] was reached in every guild.
+    ///
+    /// # Examples
+    ///
+    /// Wait for `BanAdd` events in guilds 123 and 456:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures_util::stream::StreamExt;
+    /// use twilight_model::{
+    ///     gateway::event::{EventType, Event},
+    ///     id::GuildId,
+    /// };
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let guild_ids = vec![GuildId::new(123), GuildId::new(456)];
+    /// let mut events = standby.wait_for_guilds_stream(guild_ids, |event: &Event| {
+    ///     event.kind() == EventType::BanAdd
+    /// });
+    ///
+    /// while let Some(event) = events.next().await {
+    ///     println!("got event with type {:?}", event.kind());
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [waiter limit]: StandbyBuilder::waiter_limit
+    pub fn wait_for_guilds_stream<F: Fn(&Event) -> bool + Send + Sync + 'static>(
+        &self,
+        guild_ids: impl IntoIterator<Item = GuildId>,
+        check: impl Into<Box<F>>,
+    ) -> WaitForGuildEventStream {
+        let mut guild_ids = guild_ids.into_iter().collect::<Vec<_>>();
+        guild_ids.sort_unstable();
+        guild_ids.dedup();
+        tracing::trace!(?guild_ids, "waiting for event in guilds");
+        let (tx, rx) = mpsc::unbounded();
+
+        // Each guild gets its own bystander, so share the check between them.
+        let check: Arc<F> = Arc::from(check.into());
+
+        for guild_id in &guild_ids {
+            let check = Arc::clone(&check);
 
-        WaitForGuildEventStream { rx }
+            self.register(
+                &self.0.guilds,
+                *guild_id,
+                Bystander {
+                    func: Box::new(move |event: &Event| check(event)),
+                    sender: Some(Sender::Mpsc(tx.clone())),
+                },
+            );
+        }
+
+        WaitForGuildEventStream {
+            rx,
+            registration: self.registration(RegistrationKind::Guilds(guild_ids)),
+        }
     }
 
     /// Wait for an event not in a certain guild. This must be filtered by an
@@ -367,10 +653,11 @@ impl Standby {
     ) -> WaitForEventFuture {
         tracing::trace!("waiting for event");
         let (tx, rx) = oneshot::channel();
+        let id = self.next_event_id();
 
         {
             self.0.events.insert(
-                self.next_event_id(),
+                id,
                 Bystander {
                     func: check.into(),
                     sender: Some(Sender::Oneshot(tx)),
@@ -378,7 +665,10 @@ impl Standby {
             );
         }
 
-        WaitForEventFuture { rx }
+        WaitForEventFuture {
+            rx,
+            registration: self.registration(RegistrationKind::Event(id)),
+        }
     }
 
     /// Wait for a stream of events not in a certain guild. This must be
@@ -422,10 +712,11 @@ impl Standby {
     ) -> WaitForEventStream {
         tracing::trace!("waiting for event");
         let (tx, rx) = mpsc::unbounded();
+        let id = self.next_event_id();
 
         {
             self.0.events.insert(
-                self.next_event_id(),
+                id,
                 Bystander {
                     func: check.into(),
                     sender: Some(Sender::Mpsc(tx)),
@@ -433,15 +724,137 @@ impl Standby {
             );
         }
 
-        WaitForEventStream { rx }
+        WaitForEventStream {
+            rx,
+            registration: self.registration(RegistrationKind::Event(id)),
+        }
+    }
+
+    /// Wait for an event received by a certain shard.
+    ///
+    /// Unlike [`wait_for_event`], the future is only checked against events
+    /// processed with [`process_with_shard`] for the shard, not against every
+    /// processed event.
+    ///
+    /// Returns a `Canceled` error if the `Standby` struct was dropped or the
+    /// configured [waiter limit] was reached.
+    ///
+    /// If you need to wait for multiple events matching the given predicate,
+    /// use [`wait_for_shard_event_stream`].
+    ///
+    /// # Examples
+    ///
+    /// Wait for shard 5 to resume its session:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_model::gateway::event::{EventType, Event};
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let resumed = standby.wait_for_shard_event(5, |event: &Event| {
+    ///     event.kind() == EventType::Resumed
+    /// }).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`process_with_shard`]: Self::process_with_shard
+    /// [`wait_for_event`]: Self::wait_for_event
+    /// [`wait_for_shard_event_stream`]: Self::wait_for_shard_event_stream
+    /// [waiter limit]: StandbyBuilder::waiter_limit
+    pub fn wait_for_shard_event<F: Fn(&Event) -> bool + Send + Sync + 'static>(
+        &self,
+        shard_id: u64,
+        check: impl Into<Box<F>>,
+    ) -> WaitForEventFuture {
+        tracing::trace!(%shard_id, "waiting for event in shard");
+        let (tx, rx) = oneshot::channel();
+
+        self.register(
+            &self.0.shards,
+            shard_id,
+            Bystander {
+                func: check.into(),
+                sender: Some(Sender::Oneshot(tx)),
+            },
+        );
+
+        WaitForEventFuture {
+            rx,
+            registration: self.registration(RegistrationKind::Shard(shard_id)),
+        }
+    }
+
+    /// Wait for a stream of events received by a certain shard.
+    ///
+    /// Unlike [`wait_for_event_stream`], the stream is only checked against
+    /// events processed with [`process_with_shard`] for the shard, not against
+    /// every processed event.
+    ///
+    /// The stream ends if the `Standby` struct was dropped or the configured
+    /// [waiter limit] was reached.
+    ///
+    /// If you need to wait for only one event matching the given predicate, use
+    /// [`wait_for_shard_event`].
+    ///
+    /// # Examples
+    ///
+    /// Wait for every session resume of shard 5:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures_util::stream::StreamExt;
+    /// use twilight_model::gateway::event::{EventType, Event};
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let mut events = standby.wait_for_shard_event_stream(5, |event: &Event| {
+    ///     event.kind() == EventType::Resumed
+    /// });
+    ///
+    /// while events.next().await.is_some() {
+    ///     println!("shard 5 resumed");
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`process_with_shard`]: Self::process_with_shard
+    /// [`wait_for_event_stream`]: Self::wait_for_event_stream
+    /// [`wait_for_shard_event`]: Self::wait_for_shard_event
+    /// [waiter limit]: StandbyBuilder::waiter_limit
+    pub fn wait_for_shard_event_stream<F: Fn(&Event) -> bool + Send + Sync + 'static>(
+        &self,
+        shard_id: u64,
+        check: impl Into<Box<F>>,
+    ) -> WaitForEventStream {
+        tracing::trace!(%shard_id, "waiting for event in shard");
+        let (tx, rx) = mpsc::unbounded();
+
+        self.register(
+            &self.0.shards,
+            shard_id,
+            Bystander {
+                func: check.into(),
+                sender: Some(Sender::Mpsc(tx)),
+            },
+        );
+
+        WaitForEventStream {
+            rx,
+            registration: self.registration(RegistrationKind::Shard(shard_id)),
+        }
     }
 
     /// Wait for a message in a certain channel.
     ///
-    /// Returns a `Canceled` error if the `Standby` struct was dropped.
+    /// Returns a `Canceled` error if the `Standby` struct was dropped or the
+    /// configured [waiter limit] was reached.
     ///
     /// If you need to wait for multiple messages matching the given predicate,
-    /// use [`wait_for_message_stream`].
+    /// use [`wait_for_message_stream`]. To stop waiting after some time, use
+    /// [`WaitForMessageFuture::timeout`].
     ///
     /// # Examples
     ///
@@ -455,13 +868,36 @@ impl Standby {
     ///
     /// let standby = Standby::new();
     ///
-    /// let message = standby.wait_for_message(ChannelId(123), |event: &MessageCreate| {
-    ///     event.author.id == UserId(456) && event.content == "test"
+    /// let message = standby.wait_for_message(ChannelId::new(123), |event: &MessageCreate| {
+    ///     event.author.id == UserId::new(456) && event.content == "test"
     /// }).await?;
     /// # Ok(()) }
     /// ```
     ///
+    ///
+    /// Wait up to a minute for a message in channel 123:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::time::Duration;
+    /// use twilight_model::{gateway::payload::MessageCreate, id::ChannelId};
+    /// use twilight_standby::{Standby, TimeoutError};
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let result = standby
+    ///     .wait_for_message(ChannelId::new(123), |_: &MessageCreate| true)
+    ///     .timeout(Duration::from_secs(60))
+    ///     .await;
+    ///
+    /// if let Err(TimeoutError::TimedOut { .. }) = result {
+    ///     println!("nobody said anything");
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
     /// [`wait_for_message_stream`]: Self::wait_for_message_stream
+    /// [waiter limit]: StandbyBuilder::waiter_limit
     pub fn wait_for_message<F: Fn(&MessageCreate) -> bool + Send + Sync + 'static>(
         &self,
         channel_id: ChannelId,
@@ -470,20 +906,25 @@ impl Standby {
         tracing::trace!(%channel_id, "waiting for message in channel");
         let (tx, rx) = oneshot::channel();
 
-        {
-            let mut guild = self.0.messages.entry(channel_id).or_default();
-            guild.push(Bystander {
+        self.register(
+            &self.0.messages,
+            channel_id,
+            Bystander {
                 func: check.into(),
                 sender: Some(Sender::Oneshot(tx)),
-            });
-        }
+            },
+        );
 
-        WaitForMessageFuture { rx }
+        WaitForMessageFuture {
+            rx,
+            registration: self.registration(RegistrationKind::Message(channel_id)),
+        }
     }
 
     /// Wait for a stream of message in a certain channel.
     ///
-    /// Returns a `Canceled` error if the `Standby` struct was dropped.
+    /// Returns a `Canceled` error if the `Standby` struct was dropped or the
+    /// configured [waiter limit] was reached.
     ///
     /// If you need to wait for only one message matching the given predicate,
     /// use [`wait_for_message`].
@@ -501,8 +942,8 @@ impl Standby {
     ///
     /// let standby = Standby::new();
     ///
-    /// let mut messages = standby.wait_for_message_stream(ChannelId(123), |event: &MessageCreate| {
-    ///     event.author.id == UserId(456) && event.content == "test"
+    /// let mut messages = standby.wait_for_message_stream(ChannelId::new(123), |event: &MessageCreate| {
+    ///     event.author.id == UserId::new(456) && event.content == "test"
     /// });
     ///
     /// while let Some(message) = messages.next().await {
@@ -512,6 +953,7 @@ impl Standby {
     /// ```
     ///
     /// [`wait_for_message`]: Self::wait_for_message
+    /// [waiter limit]: StandbyBuilder::waiter_limit
     pub fn wait_for_message_stream<F: Fn(&MessageCreate) -> bool + Send + Sync + 'static>(
         &self,
         channel_id: ChannelId,
@@ -520,20 +962,25 @@ impl Standby {
         tracing::trace!(%channel_id, "waiting for message in channel");
         let (tx, rx) = mpsc::unbounded();
 
-        {
-            let mut guild = self.0.messages.entry(channel_id).or_default();
-            guild.push(Bystander {
+        self.register(
+            &self.0.messages,
+            channel_id,
+            Bystander {
                 func: check.into(),
                 sender: Some(Sender::Mpsc(tx)),
-            });
-        }
+            },
+        );
 
-        WaitForMessageStream { rx }
+        WaitForMessageStream {
+            rx,
+            registration: self.registration(RegistrationKind::Message(channel_id)),
+        }
     }
 
     /// Wait for a reaction on a certain message.
     ///
-    /// Returns a `Canceled` error if the `Standby` struct was dropped.
+    /// Returns a `Canceled` error if the `Standby` struct was dropped or the
+    /// configured [waiter limit] was reached.
     ///
     /// If you need to wait for multiple reactions matching the given predicate,
     /// use [`wait_for_reaction_stream`].
@@ -550,13 +997,14 @@ impl Standby {
     ///
     /// let standby = Standby::new();
     ///
-    /// let reaction = standby.wait_for_reaction(MessageId(123), |event: &ReactionAdd| {
-    ///     event.user_id == UserId(456)
+    /// let reaction = standby.wait_for_reaction(MessageId::new(123), |event: &ReactionAdd| {
+    ///     event.user_id == UserId::new(456)
     /// }).await?;
     /// # Ok(()) }
     /// ```
     ///
     /// [`wait_for_reaction_stream`]: Self::wait_for_reaction_stream
+    /// [waiter limit]: StandbyBuilder::waiter_limit
     pub fn wait_for_reaction<F: Fn(&ReactionAdd) -> bool + Send + Sync + 'static>(
         &self,
         message_id: MessageId,
@@ -565,20 +1013,25 @@ impl Standby {
         tracing::trace!(%message_id, "waiting for reaction on message");
         let (tx, rx) = oneshot::channel();
 
-        {
-            let mut guild = self.0.reactions.entry(message_id).or_default();
-            guild.push(Bystander {
+        self.register(
+            &self.0.reactions,
+            message_id,
+            Bystander {
                 func: check.into(),
                 sender: Some(Sender::Oneshot(tx)),
-            });
-        }
+            },
+        );
 
-        WaitForReactionFuture { rx }
+        WaitForReactionFuture {
+            rx,
+            registration: self.registration(RegistrationKind::Reaction(message_id)),
+        }
     }
 
     /// Wait for a stream of reactions on a certain message.
     ///
-    /// Returns a `Canceled` error if the `Standby` struct was dropped.
+    /// Returns a `Canceled` error if the `Standby` struct was dropped or the
+    /// configured [waiter limit] was reached.
     ///
     /// If you need to wait for only one reaction matching the given predicate,
     /// use [`wait_for_reaction`].
@@ -599,7 +1052,7 @@ impl Standby {
     ///
     /// let standby = Standby::new();
     ///
-    /// let mut reactions = standby.wait_for_reaction_stream(MessageId(123), |event: &ReactionAdd| {
+    /// let mut reactions = standby.wait_for_reaction_stream(MessageId::new(123), |event: &ReactionAdd| {
     ///     matches!(&event.emoji, ReactionType::Unicode { name } if name == "🤠")
     /// });
     ///
@@ -610,6 +1063,7 @@ impl Standby {
     /// ```
     ///
     /// [`wait_for_reaction`]: Self::wait_for_reaction
+    /// [waiter limit]: StandbyBuilder::waiter_limit
     pub fn wait_for_reaction_stream<F: Fn(&ReactionAdd) -> bool + Send + Sync + 'static>(
         &self,
         message_id: MessageId,
@@ -618,29 +1072,228 @@ impl Standby {
         tracing::trace!(%message_id, "waiting for reaction on message");
         let (tx, rx) = mpsc::unbounded();
 
-        {
-            let mut guild = self.0.reactions.entry(message_id).or_default();
-            guild.push(Bystander {
+        self.register(
+            &self.0.reactions,
+            message_id,
+            Bystander {
                 func: check.into(),
                 sender: Some(Sender::Mpsc(tx)),
-            });
+            },
+        );
+
+        WaitForReactionStream {
+            rx,
+            registration: self.registration(RegistrationKind::Reaction(message_id)),
         }
+    }
 
-        WaitForReactionStream { rx }
+    /// Wait for a stream of reactions on any message in a certain guild.
+    ///
+    /// This is useful for features spanning many messages, such as reaction
+    /// roles, where registering a stream per message isn't practical.
+    ///
+    /// The stream ends if the `Standby` struct was dropped or the configured
+    /// [waiter limit] was reached.
+    ///
+    /// # Examples
+    ///
+    /// Wait for reactions by anyone other than user 456 in guild 123:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures_util::stream::StreamExt;
+    /// use twilight_model::{
+    ///     gateway::payload::ReactionAdd,
+    ///     id::{GuildId, UserId},
+    /// };
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let mut reactions = standby.wait_for_guild_reaction_stream(GuildId::new(123), |event: &ReactionAdd| {
+    ///     event.user_id != UserId::new(456)
+    /// });
+    ///
+    /// while let Some(reaction) = reactions.next().await {
+    ///     println!("{} reacted to message {}", reaction.user_id, reaction.message_id);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// The check only receives the reaction, but can capture a cache to
+    /// consult the reacted message. Wait for reactions in guild 123 to
+    /// messages authored by user 456, if the message is cached:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures_util::stream::StreamExt;
+    /// use twilight_cache_inmemory::InMemoryCache;
+    /// use twilight_model::{
+    ///     gateway::payload::ReactionAdd,
+    ///     id::{GuildId, UserId},
+    /// };
+    /// use twilight_standby::Standby;
+    ///
+    /// let cache = InMemoryCache::new();
+    /// let standby = Standby::new();
+    ///
+    /// let check_cache = cache.clone();
+    /// let mut reactions = standby.wait_for_guild_reaction_stream(GuildId::new(123), move |event: &ReactionAdd| {
+    ///     check_cache
+    ///         .message(event.channel_id, event.message_id)
+    ///         .map_or(false, |message| message.author == UserId::new(456))
+    /// });
+    ///
+    /// while let Some(reaction) = reactions.next().await {
+    ///     println!("{} reacted to message {}", reaction.user_id, reaction.message_id);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [waiter limit]: StandbyBuilder::waiter_limit
+    pub fn wait_for_guild_reaction_stream<F: Fn(&ReactionAdd) -> bool + Send + Sync + 'static>(
+        &self,
+        guild_id: GuildId,
+        check: impl Into<Box<F>>,
+    ) -> WaitForReactionStream {
+        tracing::trace!(%guild_id, "waiting for reaction in guild");
+        let (tx, rx) = mpsc::unbounded();
+
+        self.register(
+            &self.0.guild_reactions,
+            guild_id,
+            Bystander {
+                func: check.into(),
+                sender: Some(Sender::Mpsc(tx)),
+            },
+        );
+
+        WaitForReactionStream {
+            rx,
+            registration: self.registration(RegistrationKind::GuildReaction(guild_id)),
+        }
+    }
+
+    /// Wait for a stream of reactions on any message in a set of channels.
+    ///
+    /// Duplicate channel IDs are ignored. Like
+    /// [`wait_for_guild_reaction_stream`], the check can capture a cache to
+    /// consult the reacted message.
+    ///
+    /// The stream ends if the `Standby` struct was dropped or the configured
+    /// [waiter limit] was reached in every channel.
+    ///
+    /// # Examples
+    ///
+    /// Wait for "🤠" reactions in channels 123 and 456:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures_util::stream::StreamExt;
+    /// use twilight_model::{channel::ReactionType, gateway::payload::ReactionAdd, id::ChannelId};
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let channel_ids = vec![ChannelId::new(123), ChannelId::new(456)];
+    /// let mut reactions = standby.wait_for_channel_reaction_stream(channel_ids, |event: &ReactionAdd| {
+    ///     matches!(&event.emoji, ReactionType::Unicode { name } if name == "🤠")
+    /// });
+    ///
+    /// while let Some(reaction) = reactions.next().await {
+    ///     println!("got a reaction in channel {}", reaction.channel_id);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`wait_for_guild_reaction_stream`]: Self::wait_for_guild_reaction_stream
+    /// [waiter limit]: StandbyBuilder::waiter_limit
+    pub fn wait_for_channel_reaction_stream<F: Fn(&ReactionAdd) -> bool + Send + Sync + 'static>(
+        &self,
+        channel_ids: impl IntoIterator<Item = ChannelId>,
+        check: impl Into<Box<F>>,
+    ) -> WaitForReactionStream {
+        let mut channel_ids = channel_ids.into_iter().collect::<Vec<_>>();
+        channel_ids.sort_unstable();
+        channel_ids.dedup();
+        tracing::trace!(?channel_ids, "waiting for reaction in channels");
+        let (tx, rx) = mpsc::unbounded();
+
+        // Each channel gets its own bystander, so share the check between
+        // them.
+        let check: Arc<F> = Arc::from(check.into());
+
+        for channel_id in &channel_ids {
+            let check = Arc::clone(&check);
+
+            self.register(
+                &self.0.channel_reactions,
+                *channel_id,
+                Bystander {
+                    func: Box::new(move |event: &ReactionAdd| check(event)),
+                    sender: Some(Sender::Mpsc(tx.clone())),
+                },
+            );
+        }
+
+        WaitForReactionStream {
+            rx,
+            registration: self.registration(RegistrationKind::ChannelReactions(channel_ids)),
+        }
+    }
+
+    /// Register a bystander under a key, unless the number of bystanders
+    /// under the key is at the configured limit.
+    ///
+    /// If the bystander isn't registered then its sender is dropped, causing
+    /// the future to be canceled or the stream to end.
+    fn register<K: Debug + Eq + Hash, E>(
+        &self,
+        map: &DashMap<K, Vec<Bystander<E>>>,
+        key: K,
+        bystander: Bystander<E>,
+    ) {
+        let mut bystanders = map.entry(key).or_default();
+
+        if let Some(limit) = self.0.config.waiter_limit {
+            if bystanders.len() >= limit {
+                tracing::debug!(key = ?bystanders.key(), %limit, "waiter limit reached");
+
+                return;
+            }
+        }
+
+        bystanders.push(bystander);
+    }
+
+    fn registration(&self, kind: RegistrationKind) -> Registration {
+        Registration {
+            kind,
+            standby: Arc::downgrade(&self.0),
+        }
     }
 
     fn next_event_id(&self) -> u64 {
         self.0.event_counter.fetch_add(1, Ordering::SeqCst)
     }
 
-    #[tracing::instrument(level = "trace")]
-    fn process_event(&self, event: &Event) {
+    #[tracing::instrument(level = "trace", skip(results))]
+    fn process_event(&self, event: &Event, results: &mut ProcessResults) {
         tracing::trace!(?event, event_type = ?event.kind(), "processing event");
 
+        // Retaining locks every shard of the map for writing, so avoid it when
+        // only bystanders scoped to a key, such as a shard, are registered.
+        if self.0.events.is_empty() {
+            return;
+        }
+
         self.0.events.retain(|id, bystander| {
-            // `bystander_process` returns whether it is fulfilled, so invert it
-            // here. If it's fulfilled, then we don't want to retain it.
-            let retaining = !self.bystander_process(bystander, event);
+            let status = self.bystander_process(bystander, event);
+            results.add(status);
+
+            // If the bystander should be removed then we don't want to retain
+            // it.
+            let retaining = !status.remove();
 
             tracing::trace!(bystander_id = id, %retaining, "event bystander processed");
 
@@ -648,11 +1301,11 @@ impl Standby {
         });
     }
 
-    #[tracing::instrument(level = "trace")]
-    fn process_guild(&self, guild_id: GuildId, event: &Event) {
+    #[tracing::instrument(level = "trace", skip(results))]
+    fn process_guild(&self, guild_id: GuildId, event: &Event, results: &mut ProcessResults) {
         let remove = match self.0.guilds.get_mut(&guild_id) {
             Some(mut bystanders) => {
-                self.bystander_iter(&mut bystanders, event);
+                self.bystander_iter(&mut bystanders, event, results);
 
                 bystanders.is_empty()
             }
@@ -670,13 +1323,18 @@ impl Standby {
         }
     }
 
-    #[tracing::instrument(level = "trace")]
-    fn process_message(&self, channel_id: ChannelId, event: &MessageCreate) {
+    #[tracing::instrument(level = "trace", skip(results))]
+    fn process_message(
+        &self,
+        channel_id: ChannelId,
+        event: &MessageCreate,
+        results: &mut ProcessResults,
+    ) {
         tracing::trace!(%channel_id, "processing message bystanders in channel");
 
         let remove = match self.0.messages.get_mut(&channel_id) {
             Some(mut bystanders) => {
-                self.bystander_iter(&mut bystanders, event);
+                self.bystander_iter(&mut bystanders, event, results);
 
                 bystanders.is_empty()
             }
@@ -696,10 +1354,15 @@ impl Standby {
         }
     }
 
-    fn process_reaction(&self, message_id: MessageId, event: &ReactionAdd) {
+    fn process_reaction(
+        &self,
+        message_id: MessageId,
+        event: &ReactionAdd,
+        results: &mut ProcessResults,
+    ) {
         let remove = match self.0.reactions.get_mut(&message_id) {
             Some(mut bystanders) => {
-                self.bystander_iter(&mut bystanders, event);
+                self.bystander_iter(&mut bystanders, event, results);
 
                 bystanders.is_empty()
             }
@@ -716,9 +1379,31 @@ impl Standby {
         }
     }
 
+    /// Process the bystanders under a key, removing the key if no bystanders
+    /// remain.
+    #[tracing::instrument(level = "trace", skip(map, results))]
+    fn process_keyed<K: Debug + Eq + Hash, E: Clone + Debug>(
+        &self,
+        map: &DashMap<K, Vec<Bystander<E>>>,
+        key: K,
+        event: &E,
+        results: &mut ProcessResults,
+    ) {
+        if let Some(mut bystanders) = map.get_mut(&key) {
+            self.bystander_iter(&mut bystanders, event, results);
+        }
+
+        map.remove_if(&key, |_, bystanders| bystanders.is_empty());
+    }
+
     /// Iterate over bystanders and remove the ones that match the predicate.
-    #[tracing::instrument(level = "trace")]
-    fn bystander_iter<E: Clone + Debug>(&self, bystanders: &mut Vec<Bystander<E>>, event: &E) {
+    #[tracing::instrument(level = "trace", skip(results))]
+    fn bystander_iter<E: Clone + Debug>(
+        &self,
+        bystanders: &mut Vec<Bystander<E>>,
+        event: &E,
+        results: &mut ProcessResults,
+    ) {
         tracing::trace!(?bystanders, "iterating over bystanders");
 
         let mut idx = 0;
@@ -727,7 +1412,10 @@ impl Standby {
             tracing::trace!(%idx, "checking bystander");
             let bystander = &mut bystanders[idx];
 
-            if self.bystander_process(bystander, event) {
+            let status = self.bystander_process(bystander, event);
+            results.add(status);
+
+            if status.remove() {
                 tracing::trace!(%idx, "removing bystander in list");
 
                 bystanders.remove(idx);
@@ -740,32 +1428,36 @@ impl Standby {
     }
 
     /// Process a bystander, sending the event if the sender is active and the
-    /// predicate matches. Returns whether the bystander has fulfilled.
+    /// predicate matches.
     ///
-    /// Returns `true` if the bystander is fulfilled, meaning that the channel
-    /// is now closed or the predicate matched and the event closed.
+    /// Returns whether the bystander was dropped, fulfilled, sent the event,
+    /// or skipped. Dropped and fulfilled bystanders need to be removed.
     #[tracing::instrument(level = "trace")]
-    fn bystander_process<E: Clone + Debug>(&self, bystander: &mut Bystander<E>, event: &E) -> bool {
+    fn bystander_process<E: Clone + Debug>(
+        &self,
+        bystander: &mut Bystander<E>,
+        event: &E,
+    ) -> ProcessStatus {
         let sender = match bystander.sender.take() {
             Some(sender) => sender,
             None => {
                 tracing::trace!("bystander has no sender, indicating for removal");
 
-                return true;
+                return ProcessStatus::Dropped;
             }
         };
 
         if sender.is_closed() {
             tracing::trace!("bystander's rx dropped, indicating for removal");
 
-            return true;
+            return ProcessStatus::Dropped;
         }
 
         if !(bystander.func)(event) {
             tracing::trace!("bystander check doesn't match, not removing");
             bystander.sender.replace(sender);
 
-            return false;
+            return ProcessStatus::Skipped;
         }
 
         match sender {
@@ -773,7 +1465,7 @@ impl Standby {
                 let _ = tx.send(event.clone());
                 tracing::trace!("bystander matched event, indicating for removal");
 
-                true
+                ProcessStatus::Fulfilled
             }
             Sender::Mpsc(tx) => {
                 if tx.unbounded_send(event.clone()).is_ok() {
@@ -781,17 +1473,41 @@ impl Standby {
 
                     bystander.sender.replace(Sender::Mpsc(tx));
 
-                    false
+                    ProcessStatus::Sent
                 } else {
-                    true
+                    ProcessStatus::Dropped
                 }
             }
         }
     }
 }
 
+/// Count the bystanders in a map of bystanders by key.
+fn count<K: Eq + Hash, E>(map: &DashMap<K, Vec<Bystander<E>>>) -> usize {
+    map.iter().map(|bystanders| bystanders.len()).sum()
+}
+
+/// Remove the bystanders under a key whose receivers have been closed,
+/// removing the key if no bystanders remain.
+fn remove_closed<K: Eq + Hash, E>(map: &DashMap<K, Vec<Bystander<E>>>, key: &K) {
+    if let Some(mut bystanders) = map.get_mut(key) {
+        bystanders.retain(|bystander| {
+            bystander
+                .sender
+                .as_ref()
+                .map_or(false, |sender| !sender.is_closed())
+        });
+    }
+
+    map.remove_if(key, |_, bystanders| bystanders.is_empty());
+}
+
 fn event_guild_id(event: &Event) -> Option<GuildId> {
     match event {
+        Event::AutoModerationActionExecution(e) => Some(e.guild_id),
+        Event::AutoModerationRuleCreate(e) => Some(e.guild_id),
+        Event::AutoModerationRuleDelete(e) => Some(e.guild_id),
+        Event::AutoModerationRuleUpdate(e) => Some(e.guild_id),
         Event::BanAdd(e) => Some(e.guild_id),
         Event::BanRemove(e) => Some(e.guild_id),
         Event::ChannelCreate(e) => channel_guild_id(e),
@@ -808,6 +1524,12 @@ fn event_guild_id(event: &Event) -> Option<GuildId> {
         Event::GuildDelete(e) => Some(e.id),
         Event::GuildEmojisUpdate(e) => Some(e.guild_id),
         Event::GuildIntegrationsUpdate(e) => Some(e.guild_id),
+        Event::GuildScheduledEventCreate(e) => Some(e.guild_id),
+        Event::GuildScheduledEventDelete(e) => Some(e.guild_id),
+        Event::GuildScheduledEventUpdate(e) => Some(e.guild_id),
+        Event::GuildScheduledEventUserAdd(e) => Some(e.guild_id),
+        Event::GuildScheduledEventUserRemove(e) => Some(e.guild_id),
+        Event::GuildStickersUpdate(e) => Some(e.guild_id),
         Event::GuildUpdate(e) => Some(e.id),
         Event::InviteCreate(e) => Some(e.guild_id),
         Event::InviteDelete(e) => Some(e.guild_id),
@@ -855,10 +1577,10 @@ fn channel_guild_id(channel: &Channel) -> Option<GuildId> {
 
 #[cfg(test)]
 mod tests {
-    use super::Standby;
+    use super::{Standby, TimeoutError};
     use futures_util::StreamExt;
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{fmt::Debug, time::Duration};
     use twilight_model::{
         channel::{
             message::{Message, MessageType},
@@ -876,7 +1598,7 @@ mod tests {
 
     fn message() -> Message {
         Message {
-            id: MessageId(3),
+            id: MessageId::new(3),
             activity: None,
             application: None,
             attachments: Vec::new(),
@@ -886,7 +1608,7 @@ mod tests {
                 discriminator: "0001".to_owned(),
                 email: None,
                 flags: None,
-                id: UserId(2),
+                id: UserId::new(2),
                 locale: None,
                 mfa_enabled: None,
                 name: "twilight".to_owned(),
@@ -895,12 +1617,13 @@ mod tests {
                 system: None,
                 verified: None,
             },
-            channel_id: ChannelId(1),
+            channel_id: ChannelId::new(1),
             content: "test".to_owned(),
             edited_timestamp: None,
             embeds: Vec::new(),
             flags: None,
-            guild_id: Some(GuildId(4)),
+            guild_id: Some(GuildId::new(4)),
+            interaction: None,
             kind: MessageType::Regular,
             member: None,
             mention_channels: Vec::new(),
@@ -920,36 +1643,36 @@ mod tests {
 
     fn reaction() -> Reaction {
         Reaction {
-            channel_id: ChannelId(2),
+            channel_id: ChannelId::new(2),
             emoji: ReactionType::Unicode {
                 name: "🍎".to_owned(),
             },
-            guild_id: Some(GuildId(1)),
+            guild_id: Some(GuildId::new(1)),
             member: None,
-            message_id: MessageId(4),
-            user_id: UserId(3),
+            message_id: MessageId::new(4),
+            user_id: UserId::new(3),
         }
     }
 
     #[tokio::test]
     async fn test_wait_for() {
         let standby = Standby::new();
-        let wait = standby.wait_for(GuildId(1), |event: &Event| match event {
-            Event::RoleDelete(e) => e.guild_id == GuildId(1),
+        let wait = standby.wait_for(GuildId::new(1), |event: &Event| match event {
+            Event::RoleDelete(e) => e.guild_id == GuildId::new(1),
             _ => false,
         });
         standby.process(&Event::RoleDelete(RoleDelete {
-            guild_id: GuildId(1),
-            role_id: RoleId(2),
+            guild_id: GuildId::new(1),
+            role_id: RoleId::new(2),
         }));
 
-        assert!(matches!(
-            wait.await,
+        assert_eq!(
             Ok(Event::RoleDelete(RoleDelete {
-                guild_id: GuildId(1),
-                role_id: RoleId(2),
-            }))
-        ));
+                guild_id: GuildId::new(1),
+                role_id: RoleId::new(2),
+            })),
+            wait.await,
+        );
         assert!(standby.0.guilds.is_empty());
     }
 
@@ -957,37 +1680,37 @@ mod tests {
     async fn test_wait_for_stream() {
         let standby = Standby::new();
         let mut stream = standby.wait_for_stream(
-            GuildId(1),
-            |event: &Event| matches!(event, Event::RoleDelete(e) if e.guild_id.0 == 1),
+            GuildId::new(1),
+            |event: &Event| matches!(event, Event::RoleDelete(e) if e.guild_id.get() == 1),
         );
         standby.process(&Event::RoleDelete(RoleDelete {
-            guild_id: GuildId(1),
-            role_id: RoleId(2),
+            guild_id: GuildId::new(1),
+            role_id: RoleId::new(2),
         }));
         standby.process(&Event::RoleDelete(RoleDelete {
-            guild_id: GuildId(1),
-            role_id: RoleId(3),
+            guild_id: GuildId::new(1),
+            role_id: RoleId::new(3),
         }));
 
-        assert!(matches!(
-            stream.next().await,
+        assert_eq!(
             Some(Event::RoleDelete(RoleDelete {
-                guild_id: GuildId(1),
-                role_id: RoleId(2),
-            }))
-        ));
-        assert!(matches!(
+                guild_id: GuildId::new(1),
+                role_id: RoleId::new(2),
+            })),
             stream.next().await,
+        );
+        assert_eq!(
             Some(Event::RoleDelete(RoleDelete {
-                guild_id: GuildId(1),
-                role_id: RoleId(3),
-            }))
-        ));
+                guild_id: GuildId::new(1),
+                role_id: RoleId::new(3),
+            })),
+            stream.next().await,
+        );
         assert!(!standby.0.guilds.is_empty());
         drop(stream);
         standby.process(&Event::RoleDelete(RoleDelete {
-            guild_id: GuildId(1),
-            role_id: RoleId(4),
+            guild_id: GuildId::new(1),
+            role_id: RoleId::new(4),
         }));
         assert!(standby.0.guilds.is_empty());
     }
@@ -1003,7 +1726,7 @@ mod tests {
                 bot: false,
                 discriminator: "0001".to_owned(),
                 email: None,
-                id: UserId(1),
+                id: UserId::new(1),
                 mfa_enabled: true,
                 name: "twilight".to_owned(),
                 verified: Some(false),
@@ -1047,27 +1770,26 @@ mod tests {
         let event = Event::MessageCreate(Box::new(MessageCreate(message)));
 
         let standby = Standby::new();
-        let wait = standby.wait_for_message(ChannelId(1), |message: &MessageCreate| {
-            message.author.id == UserId(2)
+        let wait = standby.wait_for_message(ChannelId::new(1), |message: &MessageCreate| {
+            message.author.id == UserId::new(2)
         });
         standby.process(&event);
 
-        assert_eq!(Ok(MessageId(3)), wait.await.map(|msg| msg.id));
+        assert_eq!(Ok(MessageId::new(3)), wait.await.map(|msg| msg.id));
         assert!(standby.0.messages.is_empty());
     }
 
     #[tokio::test]
     async fn test_wait_for_message_stream() {
         let standby = Standby::new();
-        let mut stream = standby.wait_for_message_stream(ChannelId(1), |_: &MessageCreate| true);
+        let mut stream =
+            standby.wait_for_message_stream(ChannelId::new(1), |_: &MessageCreate| true);
         standby.process(&Event::MessageCreate(Box::new(MessageCreate(message()))));
         standby.process(&Event::MessageCreate(Box::new(MessageCreate(message()))));
 
         assert!(stream.next().await.is_some());
         assert!(stream.next().await.is_some());
         drop(stream);
-        assert_eq!(1, standby.0.messages.len());
-        standby.process(&Event::MessageCreate(Box::new(MessageCreate(message()))));
         assert!(standby.0.messages.is_empty());
     }
 
@@ -1076,31 +1798,274 @@ mod tests {
         let event = Event::ReactionAdd(Box::new(ReactionAdd(reaction())));
 
         let standby = Standby::new();
-        let wait = standby.wait_for_reaction(MessageId(4), |reaction: &ReactionAdd| {
-            reaction.user_id == UserId(3)
+        let wait = standby.wait_for_reaction(MessageId::new(4), |reaction: &ReactionAdd| {
+            reaction.user_id == UserId::new(3)
         });
 
         standby.process(&event);
 
-        assert_eq!(Ok(UserId(3)), wait.await.map(|reaction| reaction.user_id));
+        assert_eq!(
+            Ok(UserId::new(3)),
+            wait.await.map(|reaction| reaction.user_id)
+        );
         assert!(standby.0.reactions.is_empty());
     }
 
     #[tokio::test]
     async fn test_wait_for_reaction_stream() {
         let standby = Standby::new();
-        let mut stream = standby.wait_for_reaction_stream(MessageId(4), |_: &ReactionAdd| true);
+        let mut stream =
+            standby.wait_for_reaction_stream(MessageId::new(4), |_: &ReactionAdd| true);
         standby.process(&Event::ReactionAdd(Box::new(ReactionAdd(reaction()))));
         standby.process(&Event::ReactionAdd(Box::new(ReactionAdd(reaction()))));
 
         assert!(stream.next().await.is_some());
         assert!(stream.next().await.is_some());
         drop(stream);
-        assert_eq!(1, standby.0.reactions.len());
+        assert!(standby.0.reactions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_process_results() {
+        let standby = Standby::new();
+        let wait = standby.wait_for_message(ChannelId::new(1), |_: &MessageCreate| true);
+        let _stream = standby.wait_for_stream(GuildId::new(4), |_: &Event| true);
+        let _skipped = standby.wait_for_event(|_: &Event| false);
+
+        let stats = standby.stats();
+        assert_eq!(1, stats.events());
+        assert_eq!(1, stats.guilds());
+        assert_eq!(1, stats.messages());
+        assert_eq!(0, stats.reactions());
+        assert_eq!(3, stats.total());
+
+        let results = standby.process(&Event::MessageCreate(Box::new(MessageCreate(message()))));
+        assert_eq!(0, results.dropped());
+        assert_eq!(1, results.fulfilled());
+        assert_eq!(2, results.matched());
+        assert_eq!(1, results.sent());
+        assert_eq!(1, results.skipped());
+        assert!(wait.await.is_ok());
+
+        let stats = standby.stats();
+        assert_eq!(1, stats.events());
+        assert_eq!(1, stats.guilds());
+        assert_eq!(0, stats.messages());
+        assert_eq!(2, stats.total());
+    }
+
+    #[test]
+    fn test_drop_unregisters() {
+        let standby = Standby::new();
+        let first = standby.wait_for_reaction(MessageId::new(4), |_: &ReactionAdd| true);
+        let second = standby.wait_for_reaction(MessageId::new(4), |_: &ReactionAdd| true);
+        let event = standby.wait_for_event(|_: &Event| true);
+        assert_eq!(3, standby.stats().total());
+
+        drop(first);
+        assert_eq!(1, standby.stats().reactions());
+        drop(second);
+        assert!(standby.0.reactions.is_empty());
+        drop(event);
+        assert!(standby.0.events.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_waiter_limit() {
+        let standby = Standby::builder().waiter_limit(1).build();
+        let first = standby.wait_for_message(ChannelId::new(1), |_: &MessageCreate| true);
+        let second = standby.wait_for_message(ChannelId::new(1), |_: &MessageCreate| true);
+        let mut stream =
+            standby.wait_for_message_stream(ChannelId::new(1), |_: &MessageCreate| true);
+        assert_eq!(1, standby.stats().messages());
+
+        assert!(second.await.is_err());
+        assert!(stream.next().await.is_none());
+
+        standby.process(&Event::MessageCreate(Box::new(MessageCreate(message()))));
+        assert!(first.await.is_ok());
+
+        let third = standby.wait_for_message(ChannelId::new(1), |_: &MessageCreate| true);
+        assert_eq!(1, standby.stats().messages());
+        drop(third);
+        assert!(standby.0.messages.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_timeout() {
+        let standby = Standby::new();
+        let wait = standby
+            .wait_for_message(ChannelId::new(1), |_: &MessageCreate| false)
+            .timeout(Duration::from_millis(10));
+        let _other = standby.wait_for_message(ChannelId::new(1), |_: &MessageCreate| false);
+        assert_eq!(2, standby.0.messages.get(&ChannelId::new(1)).unwrap().len());
+
+        assert_eq!(
+            Err(TimeoutError::TimedOut {
+                timeout: Duration::from_millis(10)
+            }),
+            wait.await.map(|message| message.id)
+        );
+        assert_eq!(1, standby.0.messages.get(&ChannelId::new(1)).unwrap().len());
+
+        let wait = standby
+            .wait_for_event(|_: &Event| false)
+            .timeout(Duration::from_millis(10));
+        assert!(wait.await.is_err());
+        assert!(standby.0.events.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_timeout_matched() {
+        let standby = Standby::new();
+        let wait = standby
+            .wait_for_reaction(MessageId::new(4), |_: &ReactionAdd| true)
+            .timeout(Duration::from_secs(60));
         standby.process(&Event::ReactionAdd(Box::new(ReactionAdd(reaction()))));
+
+        assert_eq!(
+            Ok(UserId::new(3)),
+            wait.await.map(|reaction| reaction.user_id)
+        );
         assert!(standby.0.reactions.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_wait_for_guild_reaction_stream() {
+        let standby = Standby::new();
+        let mut stream =
+            standby.wait_for_guild_reaction_stream(GuildId::new(1), |_: &ReactionAdd| true);
+        let _other =
+            standby.wait_for_guild_reaction_stream(GuildId::new(2), |_: &ReactionAdd| true);
+        let results = standby.process(&Event::ReactionAdd(Box::new(ReactionAdd(reaction()))));
+        assert_eq!(1, results.sent());
+
+        assert_eq!(
+            Some(MessageId::new(4)),
+            stream.next().await.map(|reaction| reaction.message_id)
+        );
+        drop(stream);
+        assert_eq!(1, standby.0.guild_reactions.len());
+        assert!(standby.0.guild_reactions.contains_key(&GuildId::new(2)));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_channel_reaction_stream() {
+        let standby = Standby::new();
+        let mut stream = standby.wait_for_channel_reaction_stream(
+            vec![ChannelId::new(2), ChannelId::new(3)],
+            |reaction: &ReactionAdd| reaction.user_id == UserId::new(3),
+        );
+        assert_eq!(2, standby.stats().reactions());
+
+        let mut other_channel = reaction();
+        other_channel.channel_id = ChannelId::new(3);
+        standby.process(&Event::ReactionAdd(Box::new(ReactionAdd(reaction()))));
+        standby.process(&Event::ReactionAdd(Box::new(ReactionAdd(other_channel))));
+
+        assert_eq!(
+            Some(ChannelId::new(2)),
+            stream.next().await.map(|reaction| reaction.channel_id)
+        );
+        assert_eq!(
+            Some(ChannelId::new(3)),
+            stream.next().await.map(|reaction| reaction.channel_id)
+        );
+        drop(stream);
+        assert!(standby.0.channel_reactions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_channel_reaction_stream_duplicates() {
+        let standby = Standby::new();
+        let mut stream = standby.wait_for_channel_reaction_stream(
+            vec![ChannelId::new(2), ChannelId::new(2)],
+            |_: &ReactionAdd| true,
+        );
+        assert_eq!(1, standby.stats().reactions());
+
+        assert_eq!(
+            1,
+            standby
+                .process(&Event::ReactionAdd(Box::new(ReactionAdd(reaction()))))
+                .sent()
+        );
+        assert_eq!(
+            Some(ChannelId::new(2)),
+            stream.next().await.map(|reaction| reaction.channel_id)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_guilds_stream() {
+        let standby = Standby::new();
+        let mut stream = standby
+            .wait_for_guilds_stream(vec![GuildId::new(1), GuildId::new(2)], |event: &Event| {
+                event.kind() == EventType::RoleDelete
+            });
+        assert_eq!(2, standby.stats().guilds());
+
+        for guild_id in 1..=3 {
+            standby.process(&Event::RoleDelete(RoleDelete {
+                guild_id: GuildId::new(guild_id),
+                role_id: RoleId::new(4),
+            }));
+        }
+
+        assert!(matches!(
+            stream.next().await,
+            Some(Event::RoleDelete(event)) if event.guild_id == GuildId::new(1)
+        ));
+        assert!(matches!(
+            stream.next().await,
+            Some(Event::RoleDelete(event)) if event.guild_id == GuildId::new(2)
+        ));
+        drop(stream);
+        assert!(standby.0.guilds.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_guilds_stream_duplicates() {
+        let standby = Standby::new();
+        let mut stream = standby
+            .wait_for_guilds_stream(vec![GuildId::new(1), GuildId::new(1)], |event: &Event| {
+                event.kind() == EventType::RoleDelete
+            });
+        assert_eq!(1, standby.stats().guilds());
+
+        let results = standby.process(&Event::RoleDelete(RoleDelete {
+            guild_id: GuildId::new(1),
+            role_id: RoleId::new(4),
+        }));
+        assert_eq!(1, results.sent());
+        assert!(matches!(stream.next().await, Some(Event::RoleDelete(_))));
+    }
+
+    #[tokio::test]
+    async fn test_process_with_shard() {
+        let standby = Standby::new();
+        let wait =
+            standby.wait_for_shard_event(1, |event: &Event| event.kind() == EventType::Resumed);
+        let mut stream = standby
+            .wait_for_shard_event_stream(2, |event: &Event| event.kind() == EventType::Resumed);
+        assert_eq!(2, standby.stats().shards());
+
+        // Shard waiters aren't checked when the shard isn't known.
+        assert_eq!(0, standby.process(&Event::Resumed).matched());
+        assert_eq!(1, standby.process_with_shard(&Event::Resumed, 2).sent());
+        assert_eq!(Some(Event::Resumed), stream.next().await);
+
+        assert_eq!(
+            1,
+            standby.process_with_shard(&Event::Resumed, 1).fulfilled()
+        );
+        assert_eq!(Ok(Event::Resumed), wait.await);
+        assert!(!standby.0.shards.contains_key(&1));
+
+        drop(stream);
+        assert!(standby.0.shards.is_empty());
+    }
+
     #[tokio::test]
     async fn test_handles_wrong_events() {
         let standby = Standby::new();
@@ -1134,13 +2099,13 @@ mod tests {
         // generic event handler gets other guild events
         let wait = standby.wait_for_event(|event: &Event| event.kind() == EventType::RoleDelete);
         standby.process(&Event::RoleDelete(RoleDelete {
-            guild_id: GuildId(1),
-            role_id: RoleId(2),
+            guild_id: GuildId::new(1),
+            role_id: RoleId::new(2),
         }));
         assert!(matches!(wait.await, Ok(Event::RoleDelete(_))));
 
         // guild event handler gets message creates or reaction events
-        let wait = standby.wait_for(GuildId(1), |event: &Event| {
+        let wait = standby.wait_for(GuildId::new(1), |event: &Event| {
             event.kind() == EventType::ReactionAdd
         });
         standby.process(&Event::ReactionAdd(Box::new(ReactionAdd(reaction()))));
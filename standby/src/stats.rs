@@ -0,0 +1,150 @@
+/// Status of a bystander after processing an event.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ProcessStatus {
+    /// Bystander's receiver was dropped, so it should be removed.
+    Dropped,
+    /// Bystander was a future and matched the event, so it should be removed.
+    Fulfilled,
+    /// Bystander was a stream and was sent the event.
+    Sent,
+    /// Bystander's check didn't match the event.
+    Skipped,
+}
+
+impl ProcessStatus {
+    /// Whether the bystander should be removed.
+    pub(crate) const fn remove(self) -> bool {
+        matches!(self, Self::Dropped | Self::Fulfilled)
+    }
+}
+
+/// Results of processing an event with [`Standby::process`].
+///
+/// This can be used to measure how much work processing an event took and to
+/// detect bystanders that are never removed.
+///
+/// [`Standby::process`]: crate::Standby::process
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ProcessResults {
+    dropped: usize,
+    fulfilled: usize,
+    sent: usize,
+    skipped: usize,
+}
+
+impl ProcessResults {
+    /// Number of bystanders that were removed because their futures or
+    /// streams were dropped.
+    pub const fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    /// Number of futures that matched the event and were fulfilled.
+    pub const fn fulfilled(&self) -> usize {
+        self.fulfilled
+    }
+
+    /// Number of bystanders that matched the event.
+    ///
+    /// This is the sum of [`fulfilled`] and [`sent`].
+    ///
+    /// [`fulfilled`]: Self::fulfilled
+    /// [`sent`]: Self::sent
+    pub const fn matched(&self) -> usize {
+        self.fulfilled + self.sent
+    }
+
+    /// Number of streams that matched the event and were sent it.
+    pub const fn sent(&self) -> usize {
+        self.sent
+    }
+
+    /// Number of bystanders whose checks were called but didn't match the
+    /// event.
+    pub const fn skipped(&self) -> usize {
+        self.skipped
+    }
+
+    pub(crate) fn add(&mut self, status: ProcessStatus) {
+        match status {
+            ProcessStatus::Dropped => self.dropped += 1,
+            ProcessStatus::Fulfilled => self.fulfilled += 1,
+            ProcessStatus::Sent => self.sent += 1,
+            ProcessStatus::Skipped => self.skipped += 1,
+        }
+    }
+}
+
+/// Number of bystanders currently registered with a [`Standby`], by the kind
+/// of event they're waiting for.
+///
+/// Refer to [`Standby::stats`].
+///
+/// [`Standby`]: crate::Standby
+/// [`Standby::stats`]: crate::Standby::stats
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct StandbyStats {
+    pub(crate) events: usize,
+    pub(crate) guilds: usize,
+    pub(crate) messages: usize,
+    pub(crate) reactions: usize,
+    pub(crate) shards: usize,
+}
+
+impl StandbyStats {
+    /// Number of bystanders waiting for any event.
+    pub const fn events(&self) -> usize {
+        self.events
+    }
+
+    /// Number of bystanders waiting for an event in a guild.
+    pub const fn guilds(&self) -> usize {
+        self.guilds
+    }
+
+    /// Number of bystanders waiting for a message in a channel.
+    pub const fn messages(&self) -> usize {
+        self.messages
+    }
+
+    /// Number of bystanders waiting for a reaction on a message.
+    pub const fn reactions(&self) -> usize {
+        self.reactions
+    }
+
+    /// Number of bystanders waiting for an event received by a shard.
+    pub const fn shards(&self) -> usize {
+        self.shards
+    }
+
+    /// Total number of registered bystanders.
+    pub const fn total(&self) -> usize {
+        self.events + self.guilds + self.messages + self.reactions + self.shards
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ProcessResults, ProcessStatus, StandbyStats};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(ProcessResults: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(StandbyStats: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_process_results() {
+        let mut results = ProcessResults::default();
+        results.add(ProcessStatus::Dropped);
+        results.add(ProcessStatus::Fulfilled);
+        results.add(ProcessStatus::Sent);
+        results.add(ProcessStatus::Sent);
+        results.add(ProcessStatus::Skipped);
+
+        assert_eq!(1, results.dropped());
+        assert_eq!(1, results.fulfilled());
+        assert_eq!(3, results.matched());
+        assert_eq!(2, results.sent());
+        assert_eq!(1, results.skipped());
+    }
+}
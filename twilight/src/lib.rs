@@ -111,12 +111,25 @@
 //! in any other crate. Currently, it contains a trait to make extracting data
 //! from Discord identifiers (Snowflakes) easier.
 //!
+//! ### [`twilight-validate`]
+//!
+//! Validation of user-provided content, such as message content, embeds,
+//! usernames, and channel names, against Discord's limits. It is used by the
+//! HTTP crate to reject invalid requests before they are sent.
+//!
 //! ### [`twilight-gateway-queue`]
 //!
 //! A trait and some implementations that are used by the gateway to ratelimit
 //! identify calls. Developers should prefer to use the re-exports of these
 //! crates through the gateway.
 //!
+//! ### [`twilight-http-ratelimiting`]
+//!
+//! A trait and an in-memory implementation of the ratelimiter used by
+//! [`twilight-http`], for HTTP proxies and custom clients that need the same
+//! bucket semantics. Developers should prefer to use the re-exports of these
+//! crates through the HTTP client.
+//!
 //! ## Examples
 //!
 //! ```rust,no_run
@@ -226,8 +239,10 @@
 //! [`twilight-gateway-queue`]: https://twilight.rs/chapter_1_crates/section_7_first_party/section_5_gateway_queue.html
 //! [`twilight-gateway`]: https://twilight.rs/chapter_1_crates/section_3_gateway.html
 //! [`twilight-http`]: https://twilight.rs/chapter_1_crates/section_2_http.html
+//! [`twilight-http-ratelimiting`]: https://docs.rs/twilight-http-ratelimiting
 //! [`twilight-lavalink`]: https://twilight.rs/chapter_1_crates/section_7_first_party/section_3_lavalink.html
 //! [`twilight-mention`]: https://twilight.rs/chapter_1_crates/section_7_first_party/section_2_mention.html
 //! [`twilight-model`]: https://twilight.rs/chapter_1_crates/section_1_model.html
 //! [`twilight-standby`]: https://twilight.rs/chapter_1_crates/section_6_standby.html
 //! [`twilight-util`]: https://twilight.rs/chapter_1_crates/section_7_first_party/section_4_util.html
+//! [`twilight-validate`]: https://docs.rs/twilight-validate
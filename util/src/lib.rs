@@ -7,10 +7,16 @@
 //!
 //! ## Features
 //!
+//! ### `link`
+//!
+//! Allows parsing IDs, tokens, and codes out of links to webhooks, messages, invites, and
+//! templates, such as links provided by users.
+//!
 //! ### `snowflake`
 //!
 //! Allows the use of the `Snowflake` trait, which provides methods for the extraction of
-//! structured information from [Discord snowflakes].
+//! structured information from [Discord snowflakes], and the generation of new snowflakes
+//! from their parts.
 //!
 //! [`twilight-rs`]: https://github.com/twilight-rs/twilight
 //! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
@@ -0,0 +1,128 @@
+//! Utilities for parsing invite links.
+//!
+//! Invite links are created by the clients when inviting people to a guild.
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Hosts and paths that invite links begin with.
+const PREFIXES: &[&str] = &[
+    "discord.gg/",
+    "discord.com/invite/",
+    "discordapp.com/invite/",
+];
+
+/// Error when [parsing] an invite link.
+///
+/// [parsing]: parse
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum InviteParseError {
+    /// Segment of the URL path containing the code is missing.
+    SegmentMissing,
+}
+
+impl Display for InviteParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::SegmentMissing => f.write_str("url is missing the invite code segment"),
+        }
+    }
+}
+
+impl Error for InviteParseError {}
+
+/// Parse the code from an invite link, if it exists in the string.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_util::link::invite;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// assert_eq!("twilight-rs", invite::parse("https://discord.gg/twilight-rs")?);
+/// assert_eq!("twilight-rs", invite::parse("https://discord.com/invite/twilight-rs")?);
+/// # Ok(()) }
+/// ```
+///
+/// # Errors
+///
+/// Returns [`InviteParseError::SegmentMissing`] if the string isn't an invite link or
+/// the link doesn't contain a code.
+pub fn parse(url: &str) -> Result<&str, InviteParseError> {
+    let path = PREFIXES
+        .iter()
+        .find_map(|prefix| url.split(prefix).nth(1))
+        .ok_or(InviteParseError::SegmentMissing)?;
+
+    let code = path
+        .split(|c| c == '/' || c == '?' || c == '#')
+        .next()
+        .ok_or(InviteParseError::SegmentMissing)?;
+
+    if code.is_empty() {
+        return Err(InviteParseError::SegmentMissing);
+    }
+
+    Ok(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InviteParseError;
+    use static_assertions::assert_impl_all;
+    use std::{
+        error::Error,
+        fmt::{Debug, Display},
+    };
+
+    assert_impl_all!(
+        InviteParseError: Clone,
+        Debug,
+        Display,
+        Eq,
+        Error,
+        PartialEq
+    );
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            "twilight-rs",
+            super::parse("https://discord.gg/twilight-rs").unwrap()
+        );
+        assert_eq!(
+            "twilight-rs",
+            super::parse("discord.gg/twilight-rs").unwrap()
+        );
+        assert_eq!(
+            "twilight-rs",
+            super::parse("https://discord.com/invite/twilight-rs").unwrap()
+        );
+        assert_eq!(
+            "twilight-rs",
+            super::parse("https://discordapp.com/invite/twilight-rs/").unwrap()
+        );
+        assert_eq!(
+            "twilight-rs",
+            super::parse("https://discord.gg/twilight-rs?event=123").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        // Base URL is improper.
+        assert_eq!(
+            InviteParseError::SegmentMissing,
+            super::parse("https://discord.com/channels/123").unwrap_err(),
+        );
+        // No code is present.
+        assert_eq!(
+            InviteParseError::SegmentMissing,
+            super::parse("https://discord.gg/").unwrap_err(),
+        );
+    }
+}
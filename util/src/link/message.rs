@@ -0,0 +1,196 @@
+//! Utilities for parsing message links.
+//!
+//! Message links are created by the "Copy Message Link" option of the
+//! clients.
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    num::ParseIntError,
+};
+use twilight_model::id::{ChannelId, GuildId, MessageId};
+
+/// Hosts and paths that message links begin with.
+const PREFIXES: &[&str] = &["discord.com/channels/", "discordapp.com/channels/"];
+
+/// Error when [parsing] a message link.
+///
+/// [parsing]: parse
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum MessageLinkParseError {
+    /// ID segment in the URL path is not an integer.
+    IdInvalid {
+        /// Reason for the error.
+        source: ParseIntError,
+    },
+    /// Required segment of the URL path is missing.
+    SegmentMissing,
+}
+
+impl Display for MessageLinkParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::IdInvalid { .. } => f.write_str("url path segment isn't a valid ID"),
+            Self::SegmentMissing => f.write_str("url is missing a required path segment"),
+        }
+    }
+}
+
+impl Error for MessageLinkParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::IdInvalid { source } => Some(source),
+            Self::SegmentMissing => None,
+        }
+    }
+}
+
+/// Parse the guild, channel, and message IDs from a message link, if it
+/// exists in the string.
+///
+/// The guild ID is `None` if the message is in a private channel, in which
+/// case the link contains `@me` instead of a guild ID.
+///
+/// # Examples
+///
+/// Parse a link to a message in a guild:
+///
+/// ```
+/// use twilight_model::id::{ChannelId, GuildId, MessageId};
+/// use twilight_util::link::message;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let url = "https://discord.com/channels/745809834183753828/745811002771374151/800000000000000000";
+///
+/// let (guild_id, channel_id, message_id) = message::parse(url)?;
+/// assert_eq!(Some(GuildId::new(745809834183753828)), guild_id);
+/// assert_eq!(ChannelId::new(745811002771374151), channel_id);
+/// assert_eq!(MessageId::new(800000000000000000), message_id);
+/// # Ok(()) }
+/// ```
+///
+/// # Errors
+///
+/// Returns [`MessageLinkParseError::IdInvalid`] if one of the ID segments of
+/// the URL is not a valid integer.
+///
+/// Returns [`MessageLinkParseError::SegmentMissing`] if one of the required
+/// segments is missing. This can be the "channels" standard segment of the
+/// URL or one of the segments containing an ID.
+pub fn parse(url: &str) -> Result<(Option<GuildId>, ChannelId, MessageId), MessageLinkParseError> {
+    let path = PREFIXES
+        .iter()
+        .find_map(|prefix| url.split(prefix).nth(1))
+        .ok_or(MessageLinkParseError::SegmentMissing)?;
+
+    let mut segments = path.split(|c| c == '/' || c == '?' || c == '#');
+
+    let guild_segment = segments
+        .next()
+        .ok_or(MessageLinkParseError::SegmentMissing)?;
+    let channel_segment = segments
+        .next()
+        .ok_or(MessageLinkParseError::SegmentMissing)?;
+    let message_segment = segments
+        .next()
+        .ok_or(MessageLinkParseError::SegmentMissing)?;
+
+    let guild_id = if guild_segment == "@me" {
+        None
+    } else {
+        Some(GuildId::new(parse_id(guild_segment)?))
+    };
+
+    Ok((
+        guild_id,
+        ChannelId::new(parse_id(channel_segment)?),
+        MessageId::new(parse_id(message_segment)?),
+    ))
+}
+
+fn parse_id(segment: &str) -> Result<u64, MessageLinkParseError> {
+    // If we don't have this check it'll return `IdInvalid`, which isn't right.
+    if segment.is_empty() {
+        return Err(MessageLinkParseError::SegmentMissing);
+    }
+
+    segment
+        .parse()
+        .map_err(|source| MessageLinkParseError::IdInvalid { source })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MessageLinkParseError;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{
+        error::Error,
+        fmt::{Debug, Display},
+    };
+    use twilight_model::id::{ChannelId, GuildId, MessageId};
+
+    assert_fields!(MessageLinkParseError::IdInvalid: source);
+    assert_impl_all!(
+        MessageLinkParseError: Clone,
+        Debug,
+        Display,
+        Eq,
+        Error,
+        PartialEq
+    );
+
+    #[test]
+    fn test_parse() {
+        let expected = (Some(GuildId::new(1)), ChannelId::new(2), MessageId::new(3));
+
+        assert_eq!(
+            expected,
+            super::parse("https://discord.com/channels/1/2/3").unwrap(),
+        );
+        assert_eq!(
+            expected,
+            super::parse("https://canary.discord.com/channels/1/2/3").unwrap(),
+        );
+        assert_eq!(
+            expected,
+            super::parse("https://discordapp.com/channels/1/2/3/").unwrap(),
+        );
+        assert_eq!(
+            expected,
+            super::parse("https://ptb.discord.com/channels/1/2/3?foo=bar").unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_parse_private() {
+        assert_eq!(
+            (None, ChannelId::new(2), MessageId::new(3)),
+            super::parse("https://discord.com/channels/@me/2/3").unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        // Base URL is improper.
+        assert_eq!(
+            MessageLinkParseError::SegmentMissing,
+            super::parse("https://discord.com/foo/1/2/3").unwrap_err(),
+        );
+        // Message ID is missing.
+        assert_eq!(
+            MessageLinkParseError::SegmentMissing,
+            super::parse("https://discord.com/channels/1/2").unwrap_err(),
+        );
+        assert_eq!(
+            MessageLinkParseError::SegmentMissing,
+            super::parse("https://discord.com/channels/1/2/").unwrap_err(),
+        );
+        // ID segment isn't an integer.
+        assert!(matches!(
+            super::parse("https://discord.com/channels/1/notaninteger/3").unwrap_err(),
+            MessageLinkParseError::IdInvalid { .. },
+        ));
+    }
+}
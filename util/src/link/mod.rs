@@ -1,3 +1,6 @@
 //! Utilities for parsing and formatting links to various resources.
 
+pub mod invite;
+pub mod message;
+pub mod template;
 pub mod webhook;
@@ -0,0 +1,124 @@
+//! Utilities for parsing template links.
+//!
+//! Template links are created by the clients when creating a template of a guild.
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Hosts and paths that template links begin with.
+const PREFIXES: &[&str] = &[
+    "discord.new/",
+    "discord.com/template/",
+    "discordapp.com/template/",
+];
+
+/// Error when [parsing] a template link.
+///
+/// [parsing]: parse
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum TemplateParseError {
+    /// Segment of the URL path containing the code is missing.
+    SegmentMissing,
+}
+
+impl Display for TemplateParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::SegmentMissing => f.write_str("url is missing the template code segment"),
+        }
+    }
+}
+
+impl Error for TemplateParseError {}
+
+/// Parse the code from a template link, if it exists in the string.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_util::link::template;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// assert_eq!("NJSmsp8ZVEeF", template::parse("https://discord.new/NJSmsp8ZVEeF")?);
+/// assert_eq!("NJSmsp8ZVEeF", template::parse("https://discord.com/template/NJSmsp8ZVEeF")?);
+/// # Ok(()) }
+/// ```
+///
+/// # Errors
+///
+/// Returns [`TemplateParseError::SegmentMissing`] if the string isn't a template link or
+/// the link doesn't contain a code.
+pub fn parse(url: &str) -> Result<&str, TemplateParseError> {
+    let path = PREFIXES
+        .iter()
+        .find_map(|prefix| url.split(prefix).nth(1))
+        .ok_or(TemplateParseError::SegmentMissing)?;
+
+    let code = path
+        .split(|c| c == '/' || c == '?' || c == '#')
+        .next()
+        .ok_or(TemplateParseError::SegmentMissing)?;
+
+    if code.is_empty() {
+        return Err(TemplateParseError::SegmentMissing);
+    }
+
+    Ok(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TemplateParseError;
+    use static_assertions::assert_impl_all;
+    use std::{
+        error::Error,
+        fmt::{Debug, Display},
+    };
+
+    assert_impl_all!(
+        TemplateParseError: Clone,
+        Debug,
+        Display,
+        Eq,
+        Error,
+        PartialEq
+    );
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            "NJSmsp8ZVEeF",
+            super::parse("https://discord.new/NJSmsp8ZVEeF").unwrap()
+        );
+        assert_eq!(
+            "NJSmsp8ZVEeF",
+            super::parse("discord.new/NJSmsp8ZVEeF").unwrap()
+        );
+        assert_eq!(
+            "NJSmsp8ZVEeF",
+            super::parse("https://discord.com/template/NJSmsp8ZVEeF").unwrap()
+        );
+        assert_eq!(
+            "NJSmsp8ZVEeF",
+            super::parse("https://discordapp.com/template/NJSmsp8ZVEeF/").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        // Base URL is improper.
+        assert_eq!(
+            TemplateParseError::SegmentMissing,
+            super::parse("https://discord.com/channels/123").unwrap_err(),
+        );
+        // No code is present.
+        assert_eq!(
+            TemplateParseError::SegmentMissing,
+            super::parse("https://discord.new/").unwrap_err(),
+        );
+    }
+}
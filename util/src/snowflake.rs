@@ -1,9 +1,82 @@
 //! Provides the Snowflake trait for defining extractable information from a Discord Snowflake.
+//!
+//! New Snowflakes can be generated from their parts with [`SnowflakeParts`],
+//! which is useful for creating boundaries for `before` and `after`
+//! pagination.
 
-use twilight_model::id::{
-    AttachmentId, AuditLogEntryId, ChannelId, EmojiId, GenericId, GuildId, IntegrationId,
-    MessageId, RoleId, UserId, WebhookId,
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    time::{SystemTime, SystemTimeError, UNIX_EPOCH},
 };
+use twilight_model::id::Id;
+
+/// Discord's custom epoch, the unix time in milliseconds for the first second of 2015.
+const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
+/// Maximum value of the increment of a Snowflake.
+const INCREMENT_MAXIMUM: u16 = 0xFFF;
+
+/// Maximum value of the process and worker ids of a Snowflake.
+const PROCESS_ID_MAXIMUM: u8 = 0x1F;
+
+/// Maximum number of milliseconds since the Discord epoch of a Snowflake.
+const TIMESTAMP_MAXIMUM: u64 = (1 << 42) - 1;
+
+/// Error when [generating] a Snowflake from its parts.
+///
+/// [generating]: SnowflakeParts::into_id
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum SnowflakePartsError {
+    /// Increment is larger than 4095.
+    IncrementInvalid {
+        /// Provided increment.
+        increment: u16,
+    },
+    /// Process id is larger than 31.
+    ProcessIdInvalid {
+        /// Provided process id.
+        process_id: u8,
+    },
+    /// Timestamp is before the Discord epoch, the first second of 2015, or
+    /// too far in the future.
+    TimestampInvalid {
+        /// Provided timestamp.
+        timestamp: i64,
+    },
+    /// Worker id is larger than 31.
+    WorkerIdInvalid {
+        /// Provided worker id.
+        worker_id: u8,
+    },
+}
+
+impl Display for SnowflakePartsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::IncrementInvalid { increment } => f.write_fmt(format_args!(
+                "increment is {} but must be at most {}",
+                increment, INCREMENT_MAXIMUM,
+            )),
+            Self::ProcessIdInvalid { process_id } => f.write_fmt(format_args!(
+                "process id is {} but must be at most {}",
+                process_id, PROCESS_ID_MAXIMUM,
+            )),
+            Self::TimestampInvalid { timestamp } => f.write_fmt(format_args!(
+                "timestamp {} is outside of the range of snowflakes",
+                timestamp,
+            )),
+            Self::WorkerIdInvalid { worker_id } => f.write_fmt(format_args!(
+                "worker id is {} but must be at most {}",
+                worker_id, PROCESS_ID_MAXIMUM,
+            )),
+        }
+    }
+}
+
+impl Error for SnowflakePartsError {}
 
 /// Snowflake is a trait for defining extractable information from a Snowflake. A Snowflake is a
 /// u64 generated by Discord to uniquely identify a resource.
@@ -24,7 +97,7 @@ pub trait Snowflake {
     /// use twilight_util::snowflake::Snowflake;
     /// use twilight_model::id::UserId;
     ///
-    /// let id = UserId(105484726235607040);
+    /// let id = UserId::new(105484726235607040);
     ///
     /// assert_eq!(
     ///     "2015-10-19T01:58:38.546+00:00",
@@ -39,7 +112,7 @@ pub trait Snowflake {
     /// use twilight_util::snowflake::Snowflake;
     /// use twilight_model::id::UserId;
     ///
-    /// let id = UserId(105484726235607040);
+    /// let id = UserId::new(105484726235607040);
     /// let dur = Duration::milliseconds(id.timestamp());
     /// // Or use seconds, at the cost of lost precision.
     /// let ts = OffsetDateTime::from_unix_timestamp_nanos(dur.whole_nanoseconds());
@@ -48,9 +121,6 @@ pub trait Snowflake {
     /// ```
     #[allow(clippy::cast_possible_wrap)]
     fn timestamp(&self) -> i64 {
-        // Discord's custom epoch, the unix time in milliseconds for the first second of 2015.
-        const DISCORD_EPOCH: u64 = 1_420_070_400_000;
-
         ((self.id() >> 22) + DISCORD_EPOCH) as i64
     }
 
@@ -78,78 +148,172 @@ pub trait Snowflake {
     fn increment(&self) -> u16 {
         (self.id() & 0xFFF) as u16
     }
-}
 
-impl Snowflake for AttachmentId {
-    fn id(&self) -> u64 {
-        self.0
+    /// Decompose the Snowflake into all of its parts.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twilight_model::id::UserId;
+    /// use twilight_util::snowflake::Snowflake;
+    ///
+    /// let parts = UserId::new(105_484_726_235_607_040).parts();
+    ///
+    /// assert_eq!(1_445_219_918_546, parts.timestamp);
+    /// assert_eq!(11, parts.process_id);
+    /// ```
+    fn parts(&self) -> SnowflakeParts {
+        SnowflakeParts {
+            increment: self.increment(),
+            process_id: self.process_id(),
+            timestamp: self.timestamp(),
+            worker_id: self.worker_id(),
+        }
     }
 }
 
-impl Snowflake for AuditLogEntryId {
-    fn id(&self) -> u64 {
-        self.0
-    }
+/// Parts of a Snowflake, used to generate new Snowflakes.
+///
+/// # Examples
+///
+/// Create a message ID to only retrieve messages sent in the last two weeks
+/// with the `after` parameter of a request:
+///
+/// ```rust
+/// use std::time::{Duration, SystemTime};
+/// use twilight_model::id::MessageId;
+/// use twilight_util::snowflake::SnowflakeParts;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let two_weeks_ago = SystemTime::now() - Duration::from_secs(60 * 60 * 24 * 14);
+/// let after: MessageId = SnowflakeParts::from_system_time(two_weeks_ago)?.into_id()?;
+/// # Ok(()) }
+/// ```
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SnowflakeParts {
+    /// Increment of the Snowflake, at most 4095.
+    pub increment: u16,
+    /// Id of the internal process that generated the Snowflake, at most 31.
+    pub process_id: u8,
+    /// Unix timestamp of the Snowflake in milliseconds.
+    pub timestamp: i64,
+    /// Id of the internal worker that generated the Snowflake, at most 31.
+    pub worker_id: u8,
 }
 
-impl Snowflake for ChannelId {
-    fn id(&self) -> u64 {
-        self.0
+impl SnowflakeParts {
+    /// Create the parts of the lowest Snowflake generated at a Unix timestamp
+    /// in milliseconds.
+    pub const fn from_timestamp(timestamp: i64) -> Self {
+        Self {
+            increment: 0,
+            process_id: 0,
+            timestamp,
+            worker_id: 0,
+        }
     }
-}
 
-impl Snowflake for EmojiId {
-    fn id(&self) -> u64 {
-        self.0
-    }
-}
+    /// Create the parts of the lowest Snowflake generated at a system time.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SystemTimeError`] if the time is before the Unix epoch.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn from_system_time(time: SystemTime) -> Result<Self, SystemTimeError> {
+        let duration = time.duration_since(UNIX_EPOCH)?;
 
-impl Snowflake for GenericId {
-    fn id(&self) -> u64 {
-        self.0
+        Ok(Self::from_timestamp(duration.as_millis() as i64))
     }
-}
 
-impl Snowflake for GuildId {
-    fn id(&self) -> u64 {
-        self.0
-    }
-}
+    /// Generate a Snowflake from the parts.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twilight_model::id::UserId;
+    /// use twilight_util::snowflake::{Snowflake, SnowflakeParts};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let id = UserId::new(105_484_726_235_607_040);
+    /// assert_eq!(id, id.parts().into_id()?);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnowflakePartsError::IncrementInvalid`] if the increment is
+    /// larger than 4095.
+    ///
+    /// Returns [`SnowflakePartsError::ProcessIdInvalid`] if the process id is
+    /// larger than 31.
+    ///
+    /// Returns [`SnowflakePartsError::TimestampInvalid`] if the timestamp is
+    /// before the Discord epoch or too far in the future to be represented.
+    ///
+    /// Returns [`SnowflakePartsError::WorkerIdInvalid`] if the worker id is
+    /// larger than 31.
+    #[allow(clippy::cast_sign_loss)]
+    pub fn into_id<T>(self) -> Result<Id<T>, SnowflakePartsError> {
+        if self.increment > INCREMENT_MAXIMUM {
+            return Err(SnowflakePartsError::IncrementInvalid {
+                increment: self.increment,
+            });
+        }
 
-impl Snowflake for IntegrationId {
-    fn id(&self) -> u64 {
-        self.0
-    }
-}
+        if self.process_id > PROCESS_ID_MAXIMUM {
+            return Err(SnowflakePartsError::ProcessIdInvalid {
+                process_id: self.process_id,
+            });
+        }
 
-impl Snowflake for MessageId {
-    fn id(&self) -> u64 {
-        self.0
-    }
-}
+        if self.worker_id > PROCESS_ID_MAXIMUM {
+            return Err(SnowflakePartsError::WorkerIdInvalid {
+                worker_id: self.worker_id,
+            });
+        }
 
-impl Snowflake for RoleId {
-    fn id(&self) -> u64 {
-        self.0
-    }
-}
+        // Negative timestamps are before the Discord epoch.
+        let since_epoch = if self.timestamp < 0 {
+            None
+        } else {
+            (self.timestamp as u64).checked_sub(DISCORD_EPOCH)
+        };
 
-impl Snowflake for UserId {
-    fn id(&self) -> u64 {
-        self.0
+        let since_epoch = match since_epoch {
+            Some(since_epoch) if since_epoch <= TIMESTAMP_MAXIMUM => since_epoch,
+            _ => {
+                return Err(SnowflakePartsError::TimestampInvalid {
+                    timestamp: self.timestamp,
+                })
+            }
+        };
+
+        Ok(Id::new(
+            since_epoch << 22
+                | u64::from(self.worker_id) << 17
+                | u64::from(self.process_id) << 12
+                | u64::from(self.increment),
+        ))
     }
 }
 
-impl Snowflake for WebhookId {
+impl<T> Snowflake for Id<T> {
     fn id(&self) -> u64 {
-        self.0
+        self.get()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Snowflake;
-    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use super::{Snowflake, SnowflakeParts, SnowflakePartsError};
+    use static_assertions::{assert_fields, assert_impl_all, assert_obj_safe};
+    use std::{
+        error::Error,
+        fmt::{Debug, Display},
+        hash::Hash,
+        time::{Duration, UNIX_EPOCH},
+    };
     use twilight_model::id::{
         AttachmentId, AuditLogEntryId, ChannelId, EmojiId, GenericId, GuildId, IntegrationId,
         MessageId, RoleId, UserId, WebhookId,
@@ -167,36 +331,108 @@ mod tests {
     assert_impl_all!(UserId: Snowflake);
     assert_impl_all!(WebhookId: Snowflake);
     assert_obj_safe!(Snowflake);
+    assert_fields!(SnowflakeParts: increment, process_id, timestamp, worker_id);
+    assert_impl_all!(SnowflakeParts: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+    assert_fields!(SnowflakePartsError::IncrementInvalid: increment);
+    assert_fields!(SnowflakePartsError::ProcessIdInvalid: process_id);
+    assert_fields!(SnowflakePartsError::TimestampInvalid: timestamp);
+    assert_fields!(SnowflakePartsError::WorkerIdInvalid: worker_id);
+    assert_impl_all!(
+        SnowflakePartsError: Clone,
+        Debug,
+        Display,
+        Eq,
+        Error,
+        PartialEq,
+        Send,
+        Sync
+    );
 
     #[test]
     fn test_timestamp() {
         let expected: i64 = 1_445_219_918_546;
-        let id = GenericId(105_484_726_235_607_040);
+        let id = GenericId::new(105_484_726_235_607_040);
 
-        assert_eq!(expected, id.timestamp())
+        assert_eq!(expected, Snowflake::timestamp(&id))
     }
 
     #[test]
     fn test_worker_id() {
         let expected: u8 = 8;
-        let id = GenericId(762_022_344_856_174_632);
+        let id = GenericId::new(762_022_344_856_174_632);
 
-        assert_eq!(expected, id.worker_id())
+        assert_eq!(expected, Snowflake::worker_id(&id))
     }
 
     #[test]
     fn test_process_id() {
         let expected: u8 = 1;
-        let id = GenericId(61_189_081_970_774_016);
+        let id = GenericId::new(61_189_081_970_774_016);
 
-        assert_eq!(expected, id.process_id())
+        assert_eq!(expected, Snowflake::process_id(&id))
     }
 
     #[test]
     fn test_increment() {
         let expected: u16 = 40;
-        let id = GenericId(762_022_344_856_174_632);
+        let id = GenericId::new(762_022_344_856_174_632);
+
+        assert_eq!(expected, Snowflake::increment(&id))
+    }
 
-        assert_eq!(expected, id.increment())
+    #[test]
+    fn test_parts_round_trip() {
+        let id = GenericId::new(762_022_344_856_174_632);
+        let parts = id.parts();
+
+        assert_eq!(
+            SnowflakeParts {
+                increment: 40,
+                process_id: 0,
+                timestamp: 1_601_750_684_704,
+                worker_id: 8,
+            },
+            parts
+        );
+        assert_eq!(Ok(id), parts.into_id());
+    }
+
+    #[test]
+    fn test_from_timestamp() {
+        let id: GenericId = SnowflakeParts::from_timestamp(1_445_219_918_546)
+            .into_id()
+            .unwrap();
+        assert_eq!(105_484_726_235_561_984, id.get());
+        assert_eq!(1_445_219_918_546, id.timestamp());
+
+        let time = UNIX_EPOCH + Duration::from_millis(1_445_219_918_546);
+        assert_eq!(
+            SnowflakeParts::from_timestamp(1_445_219_918_546),
+            SnowflakeParts::from_system_time(time).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_into_id_invalid() {
+        assert_eq!(
+            Err(SnowflakePartsError::TimestampInvalid { timestamp: 0 }),
+            SnowflakeParts::from_timestamp(0).into_id::<()>()
+        );
+        assert_eq!(
+            Err(SnowflakePartsError::WorkerIdInvalid { worker_id: 32 }),
+            SnowflakeParts {
+                worker_id: 32,
+                ..SnowflakeParts::from_timestamp(1_445_219_918_546)
+            }
+            .into_id::<()>()
+        );
+        assert_eq!(
+            Err(SnowflakePartsError::IncrementInvalid { increment: 4096 }),
+            SnowflakeParts {
+                increment: 4096,
+                ..SnowflakeParts::from_timestamp(1_445_219_918_546)
+            }
+            .into_id::<()>()
+        );
     }
 }
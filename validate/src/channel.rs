@@ -0,0 +1,96 @@
+//! Validation of channel fields.
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// A channel field is not valid.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum ChannelValidationError {
+    /// The name's length is not between [the minimum][`NAME_LENGTH_MIN`] and
+    /// [the maximum][`NAME_LENGTH_MAX`].
+    ///
+    /// [`NAME_LENGTH_MAX`]: Self::NAME_LENGTH_MAX
+    /// [`NAME_LENGTH_MIN`]: Self::NAME_LENGTH_MIN
+    NameLengthInvalid {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+}
+
+impl ChannelValidationError {
+    /// The maximum channel name length in codepoints.
+    pub const NAME_LENGTH_MAX: usize = 100;
+
+    /// The minimum channel name length in codepoints.
+    pub const NAME_LENGTH_MIN: usize = 2;
+}
+
+impl Display for ChannelValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::NameLengthInvalid { chars } => write!(
+                f,
+                "the name is {} characters long, but must be between {} and {}",
+                chars,
+                Self::NAME_LENGTH_MIN,
+                Self::NAME_LENGTH_MAX
+            ),
+        }
+    }
+}
+
+impl Error for ChannelValidationError {}
+
+/// Validate that a channel's name is of a valid length.
+///
+/// Refer to [the Discord docs][docs] for the limits.
+///
+/// # Errors
+///
+/// Returns [`ChannelValidationError::NameLengthInvalid`] if the name is too
+/// short or too long.
+///
+/// [docs]: https://discord.com/developers/docs/resources/channel#channel-object-channel-structure
+pub fn name(value: &str) -> Result<(), ChannelValidationError> {
+    let chars = value.chars().count();
+
+    if (ChannelValidationError::NAME_LENGTH_MIN..=ChannelValidationError::NAME_LENGTH_MAX)
+        .contains(&chars)
+    {
+        Ok(())
+    } else {
+        Err(ChannelValidationError::NameLengthInvalid { chars })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ChannelValidationError;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug};
+
+    assert_fields!(ChannelValidationError::NameLengthInvalid: chars);
+    assert_impl_all!(ChannelValidationError: Clone, Debug, Error, Send, Sync);
+
+    #[test]
+    fn test_name() {
+        assert!(super::name("aa").is_ok());
+        assert!(super::name(&"a".repeat(100)).is_ok());
+
+        assert!(matches!(
+            super::name(""),
+            Err(ChannelValidationError::NameLengthInvalid { chars: 0 })
+        ));
+        assert!(matches!(
+            super::name("a"),
+            Err(ChannelValidationError::NameLengthInvalid { chars: 1 })
+        ));
+        assert!(matches!(
+            super::name(&"a".repeat(101)),
+            Err(ChannelValidationError::NameLengthInvalid { chars: 101 })
+        ));
+    }
+}
@@ -0,0 +1,51 @@
+//! # twilight-validate
+//!
+//! [![discord badge][]][discord link] [![github badge][]][github link] [![license badge][]][license link] ![rust badge]
+//!
+//! `twilight-validate` validates user-provided content against Discord's limits, such as the
+//! length of message content, embeds, usernames, nicknames, channel names, and stickers.
+//!
+//! It is used by [`twilight-http`] so that invalid requests fail before they are sent, but can
+//! also be used on its own to check input from users early.
+//!
+//! ## Examples
+//!
+//! Check whether a message's content is too long:
+//!
+//! ```
+//! use twilight_validate::message::{self, MessageValidationError};
+//!
+//! assert!(message::content("twilight").is_ok());
+//! assert!(matches!(
+//!     message::content(&"a".repeat(2001)),
+//!     Err(MessageValidationError::ContentTooLarge { chars: 2001 }),
+//! ));
+//! ```
+//!
+//! [`twilight-http`]: https://docs.rs/twilight-http
+//! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
+//! [discord link]: https://discord.gg/7jj8n7D
+//! [github badge]: https://img.shields.io/badge/github-twilight-6f42c1.svg?style=for-the-badge&logo=github
+//! [github link]: https://github.com/twilight-rs/twilight
+//! [license badge]: https://img.shields.io/badge/license-ISC-blue.svg?style=for-the-badge&logo=pastebin
+//! [license link]: https://github.com/twilight-rs/twilight/blob/trunk/LICENSE.md
+//! [rust badge]: https://img.shields.io/badge/rust-1.48+-93450a.svg?style=for-the-badge&logo=rust
+
+#![deny(
+    clippy::all,
+    clippy::pedantic,
+    future_incompatible,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    unsafe_code,
+    unused,
+    warnings
+)]
+#![allow(clippy::module_name_repetitions)]
+
+pub mod channel;
+pub mod embed;
+pub mod message;
+pub mod sticker;
+pub mod user;
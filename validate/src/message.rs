@@ -0,0 +1,80 @@
+//! Validation of message fields.
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// A message field is not valid.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum MessageValidationError {
+    /// The content is larger than [the maximum][`CONTENT_LENGTH`].
+    ///
+    /// [`CONTENT_LENGTH`]: Self::CONTENT_LENGTH
+    ContentTooLarge {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+}
+
+impl MessageValidationError {
+    /// The maximum message content length in codepoints.
+    pub const CONTENT_LENGTH: usize = 2000;
+}
+
+impl Display for MessageValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::ContentTooLarge { chars } => write!(
+                f,
+                "the content is {} characters long, but the max is {}",
+                chars,
+                Self::CONTENT_LENGTH
+            ),
+        }
+    }
+}
+
+impl Error for MessageValidationError {}
+
+/// Validate that a message's content is within Discord's limit.
+///
+/// Refer to [the Discord docs][docs] for the limit.
+///
+/// # Errors
+///
+/// Returns [`MessageValidationError::ContentTooLarge`] if the content is
+/// longer than [`MessageValidationError::CONTENT_LENGTH`].
+///
+/// [docs]: https://discord.com/developers/docs/resources/channel#create-message-jsonform-params
+pub fn content(value: &str) -> Result<(), MessageValidationError> {
+    let chars = value.chars().count();
+
+    if chars > MessageValidationError::CONTENT_LENGTH {
+        return Err(MessageValidationError::ContentTooLarge { chars });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MessageValidationError;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug};
+
+    assert_fields!(MessageValidationError::ContentTooLarge: chars);
+    assert_impl_all!(MessageValidationError: Clone, Debug, Error, Send, Sync);
+
+    #[test]
+    fn test_content() {
+        assert!(super::content("").is_ok());
+        assert!(super::content(&"a".repeat(2000)).is_ok());
+
+        assert!(matches!(
+            super::content(&"a".repeat(2001)),
+            Err(MessageValidationError::ContentTooLarge { chars: 2001 })
+        ));
+    }
+}
@@ -0,0 +1,218 @@
+//! Validation of sticker fields.
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// A sticker field is not valid.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum StickerValidationError {
+    /// The description's length is not between
+    /// [the minimum][`DESCRIPTION_LENGTH_MIN`] and
+    /// [the maximum][`DESCRIPTION_LENGTH_MAX`].
+    ///
+    /// [`DESCRIPTION_LENGTH_MAX`]: Self::DESCRIPTION_LENGTH_MAX
+    /// [`DESCRIPTION_LENGTH_MIN`]: Self::DESCRIPTION_LENGTH_MIN
+    DescriptionLengthInvalid {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+    /// The name's length is not between [the minimum][`NAME_LENGTH_MIN`] and
+    /// [the maximum][`NAME_LENGTH_MAX`].
+    ///
+    /// [`NAME_LENGTH_MAX`]: Self::NAME_LENGTH_MAX
+    /// [`NAME_LENGTH_MIN`]: Self::NAME_LENGTH_MIN
+    NameLengthInvalid {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+    /// The tags' length is not between [the minimum][`TAGS_LENGTH_MIN`] and
+    /// [the maximum][`TAGS_LENGTH_MAX`].
+    ///
+    /// [`TAGS_LENGTH_MAX`]: Self::TAGS_LENGTH_MAX
+    /// [`TAGS_LENGTH_MIN`]: Self::TAGS_LENGTH_MIN
+    TagsLengthInvalid {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+}
+
+impl StickerValidationError {
+    /// The maximum sticker description length in codepoints.
+    pub const DESCRIPTION_LENGTH_MAX: usize = 100;
+
+    /// The minimum sticker description length in codepoints, unless the
+    /// description is empty.
+    pub const DESCRIPTION_LENGTH_MIN: usize = 2;
+
+    /// The maximum sticker name length in codepoints.
+    pub const NAME_LENGTH_MAX: usize = 30;
+
+    /// The minimum sticker name length in codepoints.
+    pub const NAME_LENGTH_MIN: usize = 2;
+
+    /// The maximum sticker tags length in codepoints.
+    pub const TAGS_LENGTH_MAX: usize = 200;
+
+    /// The minimum sticker tags length in codepoints.
+    pub const TAGS_LENGTH_MIN: usize = 2;
+}
+
+impl Display for StickerValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::DescriptionLengthInvalid { chars } => write!(
+                f,
+                "the description is {} characters long, but must be empty or between {} and {}",
+                chars,
+                Self::DESCRIPTION_LENGTH_MIN,
+                Self::DESCRIPTION_LENGTH_MAX
+            ),
+            Self::NameLengthInvalid { chars } => write!(
+                f,
+                "the name is {} characters long, but must be between {} and {}",
+                chars,
+                Self::NAME_LENGTH_MIN,
+                Self::NAME_LENGTH_MAX
+            ),
+            Self::TagsLengthInvalid { chars } => write!(
+                f,
+                "the tags are {} characters long, but must be between {} and {}",
+                chars,
+                Self::TAGS_LENGTH_MIN,
+                Self::TAGS_LENGTH_MAX
+            ),
+        }
+    }
+}
+
+impl Error for StickerValidationError {}
+
+/// Validate that a sticker's description is either empty or of a valid
+/// length.
+///
+/// Refer to [the Discord docs][docs] for the limits.
+///
+/// # Errors
+///
+/// Returns [`StickerValidationError::DescriptionLengthInvalid`] if the
+/// description is too short or too long.
+///
+/// [docs]: https://discord.com/developers/docs/resources/sticker#create-guild-sticker
+pub fn description(value: &str) -> Result<(), StickerValidationError> {
+    let chars = value.chars().count();
+
+    if chars == 0
+        || (StickerValidationError::DESCRIPTION_LENGTH_MIN
+            ..=StickerValidationError::DESCRIPTION_LENGTH_MAX)
+            .contains(&chars)
+    {
+        Ok(())
+    } else {
+        Err(StickerValidationError::DescriptionLengthInvalid { chars })
+    }
+}
+
+/// Validate that a sticker's name is of a valid length.
+///
+/// Refer to [the Discord docs][docs] for the limits.
+///
+/// # Errors
+///
+/// Returns [`StickerValidationError::NameLengthInvalid`] if the name is too
+/// short or too long.
+///
+/// [docs]: https://discord.com/developers/docs/resources/sticker#create-guild-sticker
+pub fn name(value: &str) -> Result<(), StickerValidationError> {
+    let chars = value.chars().count();
+
+    if (StickerValidationError::NAME_LENGTH_MIN..=StickerValidationError::NAME_LENGTH_MAX)
+        .contains(&chars)
+    {
+        Ok(())
+    } else {
+        Err(StickerValidationError::NameLengthInvalid { chars })
+    }
+}
+
+/// Validate that a sticker's tags are of a valid length.
+///
+/// Refer to [the Discord docs][docs] for the limits.
+///
+/// # Errors
+///
+/// Returns [`StickerValidationError::TagsLengthInvalid`] if the tags are too
+/// short or too long.
+///
+/// [docs]: https://discord.com/developers/docs/resources/sticker#create-guild-sticker
+pub fn tags(value: &str) -> Result<(), StickerValidationError> {
+    let chars = value.chars().count();
+
+    if (StickerValidationError::TAGS_LENGTH_MIN..=StickerValidationError::TAGS_LENGTH_MAX)
+        .contains(&chars)
+    {
+        Ok(())
+    } else {
+        Err(StickerValidationError::TagsLengthInvalid { chars })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StickerValidationError;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug};
+
+    assert_fields!(StickerValidationError::DescriptionLengthInvalid: chars);
+    assert_fields!(StickerValidationError::NameLengthInvalid: chars);
+    assert_fields!(StickerValidationError::TagsLengthInvalid: chars);
+    assert_impl_all!(StickerValidationError: Clone, Debug, Error, Send, Sync);
+
+    #[test]
+    fn test_description() {
+        assert!(super::description("").is_ok());
+        assert!(super::description("aa").is_ok());
+        assert!(super::description(&"a".repeat(100)).is_ok());
+
+        assert!(matches!(
+            super::description("a"),
+            Err(StickerValidationError::DescriptionLengthInvalid { chars: 1 })
+        ));
+        assert!(matches!(
+            super::description(&"a".repeat(101)),
+            Err(StickerValidationError::DescriptionLengthInvalid { chars: 101 })
+        ));
+    }
+
+    #[test]
+    fn test_name() {
+        assert!(super::name("aa").is_ok());
+        assert!(super::name(&"a".repeat(30)).is_ok());
+
+        assert!(matches!(
+            super::name("a"),
+            Err(StickerValidationError::NameLengthInvalid { chars: 1 })
+        ));
+        assert!(matches!(
+            super::name(&"a".repeat(31)),
+            Err(StickerValidationError::NameLengthInvalid { chars: 31 })
+        ));
+    }
+
+    #[test]
+    fn test_tags() {
+        assert!(super::tags("aa").is_ok());
+        assert!(super::tags(&"a".repeat(200)).is_ok());
+
+        assert!(matches!(
+            super::tags(""),
+            Err(StickerValidationError::TagsLengthInvalid { chars: 0 })
+        ));
+        assert!(matches!(
+            super::tags(&"a".repeat(201)),
+            Err(StickerValidationError::TagsLengthInvalid { chars: 201 })
+        ));
+    }
+}
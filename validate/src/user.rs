@@ -0,0 +1,200 @@
+//! Validation of usernames and nicknames.
+//!
+//! Refer to [the Discord docs][docs] for the rules.
+//!
+//! [docs]: https://discord.com/developers/docs/resources/user#usernames-and-nicknames
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Substrings that usernames may not contain.
+const USERNAME_INVALID_SUBSTRINGS: &[&str] = &["@", "#", ":", "```", "discord"];
+
+/// Strings that usernames may not be.
+const USERNAME_INVALID_STRINGS: &[&str] = &["everyone", "here"];
+
+/// A username or nickname is not valid.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum UserValidationError {
+    /// The nickname's length is not between
+    /// [the minimum][`NICKNAME_LENGTH_MIN`] and
+    /// [the maximum][`NICKNAME_LENGTH_MAX`].
+    ///
+    /// [`NICKNAME_LENGTH_MAX`]: Self::NICKNAME_LENGTH_MAX
+    /// [`NICKNAME_LENGTH_MIN`]: Self::NICKNAME_LENGTH_MIN
+    NicknameLengthInvalid {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+    /// The username contains a disallowed substring, such as `@` or
+    /// `discord`, or is a disallowed name, such as `everyone`.
+    UsernameContentsInvalid {
+        /// The disallowed substring or name.
+        invalid: &'static str,
+    },
+    /// The username's length is not between
+    /// [the minimum][`USERNAME_LENGTH_MIN`] and
+    /// [the maximum][`USERNAME_LENGTH_MAX`].
+    ///
+    /// [`USERNAME_LENGTH_MAX`]: Self::USERNAME_LENGTH_MAX
+    /// [`USERNAME_LENGTH_MIN`]: Self::USERNAME_LENGTH_MIN
+    UsernameLengthInvalid {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+}
+
+impl UserValidationError {
+    /// The maximum nickname length in codepoints.
+    pub const NICKNAME_LENGTH_MAX: usize = 32;
+
+    /// The minimum nickname length in codepoints.
+    pub const NICKNAME_LENGTH_MIN: usize = 1;
+
+    /// The maximum username length in codepoints.
+    pub const USERNAME_LENGTH_MAX: usize = 32;
+
+    /// The minimum username length in codepoints.
+    pub const USERNAME_LENGTH_MIN: usize = 2;
+}
+
+impl Display for UserValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::NicknameLengthInvalid { chars } => write!(
+                f,
+                "the nickname is {} characters long, but must be between {} and {}",
+                chars,
+                Self::NICKNAME_LENGTH_MIN,
+                Self::NICKNAME_LENGTH_MAX
+            ),
+            Self::UsernameContentsInvalid { invalid } => {
+                write!(f, "the username may not contain or be \"{}\"", invalid)
+            }
+            Self::UsernameLengthInvalid { chars } => write!(
+                f,
+                "the username is {} characters long, but must be between {} and {}",
+                chars,
+                Self::USERNAME_LENGTH_MIN,
+                Self::USERNAME_LENGTH_MAX
+            ),
+        }
+    }
+}
+
+impl Error for UserValidationError {}
+
+/// Validate that a nickname is of a valid length.
+///
+/// # Errors
+///
+/// Returns [`UserValidationError::NicknameLengthInvalid`] if the nickname is
+/// too short or too long.
+pub fn nickname(value: &str) -> Result<(), UserValidationError> {
+    let chars = value.chars().count();
+
+    if (UserValidationError::NICKNAME_LENGTH_MIN..=UserValidationError::NICKNAME_LENGTH_MAX)
+        .contains(&chars)
+    {
+        Ok(())
+    } else {
+        Err(UserValidationError::NicknameLengthInvalid { chars })
+    }
+}
+
+/// Validate that a username is of a valid length and doesn't contain
+/// disallowed content.
+///
+/// # Errors
+///
+/// Returns [`UserValidationError::UsernameLengthInvalid`] if the username is
+/// too short or too long.
+///
+/// Returns [`UserValidationError::UsernameContentsInvalid`] if the username
+/// contains a disallowed substring or is a disallowed name.
+pub fn username(value: &str) -> Result<(), UserValidationError> {
+    let chars = value.chars().count();
+
+    if !(UserValidationError::USERNAME_LENGTH_MIN..=UserValidationError::USERNAME_LENGTH_MAX)
+        .contains(&chars)
+    {
+        return Err(UserValidationError::UsernameLengthInvalid { chars });
+    }
+
+    if let Some(invalid) = USERNAME_INVALID_SUBSTRINGS
+        .iter()
+        .copied()
+        .find(|invalid| value.contains(invalid))
+    {
+        return Err(UserValidationError::UsernameContentsInvalid { invalid });
+    }
+
+    if let Some(invalid) = USERNAME_INVALID_STRINGS
+        .iter()
+        .copied()
+        .find(|invalid| value == *invalid)
+    {
+        return Err(UserValidationError::UsernameContentsInvalid { invalid });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UserValidationError;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug};
+
+    assert_fields!(UserValidationError::NicknameLengthInvalid: chars);
+    assert_fields!(UserValidationError::UsernameContentsInvalid: invalid);
+    assert_fields!(UserValidationError::UsernameLengthInvalid: chars);
+    assert_impl_all!(UserValidationError: Clone, Debug, Error, Send, Sync);
+
+    #[test]
+    fn test_nickname() {
+        assert!(super::nickname("a").is_ok());
+        assert!(super::nickname(&"a".repeat(32)).is_ok());
+
+        assert!(matches!(
+            super::nickname(""),
+            Err(UserValidationError::NicknameLengthInvalid { chars: 0 })
+        ));
+        assert!(matches!(
+            super::nickname(&"a".repeat(33)),
+            Err(UserValidationError::NicknameLengthInvalid { chars: 33 })
+        ));
+    }
+
+    #[test]
+    fn test_username() {
+        assert!(super::username("aa").is_ok());
+        assert!(super::username(&"a".repeat(32)).is_ok());
+
+        assert!(matches!(
+            super::username("a"),
+            Err(UserValidationError::UsernameLengthInvalid { chars: 1 })
+        ));
+        assert!(matches!(
+            super::username(&"a".repeat(33)),
+            Err(UserValidationError::UsernameLengthInvalid { chars: 33 })
+        ));
+        assert!(matches!(
+            super::username("twilight#1234"),
+            Err(UserValidationError::UsernameContentsInvalid { invalid: "#" })
+        ));
+        assert!(matches!(
+            super::username("my discord bot"),
+            Err(UserValidationError::UsernameContentsInvalid { invalid: "discord" })
+        ));
+        assert!(matches!(
+            super::username("everyone"),
+            Err(UserValidationError::UsernameContentsInvalid {
+                invalid: "everyone"
+            })
+        ));
+    }
+}